@@ -4,22 +4,35 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use alloy_consensus::{BlockBody, BlockHeader, Transaction};
-use alloy_primitives::TxKind;
-use alloy_primitives::{Address, PrimitiveSignature, B256, U256};
+use alloy_primitives::{Address, B256, U256};
 use alloy_rpc_types::engine::{
     ExecutionPayloadEnvelopeV3, ForkchoiceState, PayloadAttributes, PayloadStatusEnum,
 };
 use jsonrpsee::http_client::{transport::HttpBackend, HttpClient};
-use reth::network::PeersHandleProvider;
+use reth::network::NetworkInfo;
+use reth::spot_meta::{erc20_contract_to_spot_token, system_tx_signature};
 use reth_chainspec::{EthChainSpec, EthereumHardforks};
-use reth_hyperliquid_types::PrecompilesCache;
+use reth_db_api::{models::HlSidecar, tables, transaction::DbTxMut};
+use reth_hyperliquid_types::{
+    archive::{read_archive_block, BlockAndReceipts, EvmBlock},
+    PrecompilesCache,
+};
+use reth_metrics::{
+    metrics::{Counter, Gauge},
+    Metrics,
+};
 use reth_node_api::{Block, FullNodeComponents, PayloadTypes};
+use reth_network_p2p::sync::{NetworkSyncUpdater, SyncState};
 use reth_node_builder::EngineTypes;
 use reth_node_builder::NodeTypesWithEngine;
 use reth_node_builder::{rpc::RethRpcAddOns, FullNode};
+use reth_node_ethereum::evm::RecentReadPrecompileCalls;
 use reth_payload_builder::{EthBuiltPayload, EthPayloadBuilderAttributes, PayloadId};
-use reth_primitives::{Transaction as TypedTransaction, TransactionSigned};
-use reth_provider::{BlockHashReader, BlockReader, StageCheckpointReader};
+use reth_primitives::TransactionSigned;
+use reth_provider::{
+    BlockHashReader, BlockReader, DBProvider, DatabaseProviderFactory, ProviderResult,
+    StageCheckpointReader,
+};
 use reth_rpc_api::EngineApiClient;
 use reth_rpc_layer::AuthClientService;
 use reth_stages::StageId;
@@ -28,19 +41,47 @@ use time::{format_description, Duration, OffsetDateTime};
 use tokio::sync::Mutex;
 use tracing::{debug, info};
 
-use crate::serialized::{BlockAndReceipts, EvmBlock};
-use crate::spot_meta::erc20_contract_to_spot_token;
-
 /// Poll interval when tailing an *open* hourly file.
 const TAIL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(25);
 /// Sub‑directory that contains day folders (inside `local_ingest_dir`).
 const HOURLY_SUBDIR: &str = "hourly";
+/// Poll interval when looking for the highest block available in the archive.
+const ARCHIVE_TIP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+/// Number of blocks behind the highest available block up to which the node is considered synced.
+const SYNC_DISTANCE: u64 = 64;
 
 pub(crate) struct BlockIngest {
     pub ingest_dir: PathBuf,
     pub local_ingest_dir: Option<PathBuf>,
     pub local_blocks_cache: Arc<Mutex<BTreeMap<u64, BlockAndReceipts>>>, // height → block
     pub precompiles_cache: PrecompilesCache,
+    /// Read precompile calls of the ingested blocks, shared with the streaming ExEx if enabled
+    pub stream_precompile_calls: Option<PrecompilesCache>,
+    /// Read precompile calls of the recently ingested blocks, resolving the calls of `eth_call`
+    pub recent_precompile_calls: Arc<RecentReadPrecompileCalls>,
+    pub metrics: IngestMetrics,
+}
+
+/// Metrics for the block ingest loop.
+#[derive(Clone, Metrics)]
+#[metrics(scope = "ingest")]
+pub(crate) struct IngestMetrics {
+    /// The highest block height found in the archive (`--ingest-dir`)
+    pub(crate) archive_height: Gauge,
+    /// The highest block height tailed from the local hl-node output (`--local-ingest-dir`)
+    pub(crate) local_tail_height: Gauge,
+    /// The height of the last block made canonical through a forkchoice update
+    pub(crate) canonical_head: Gauge,
+    /// Seconds between the canonical head block timestamp and the wall clock
+    pub(crate) head_timestamp_lag_seconds: Gauge,
+    /// Number of blocks collected from the archive
+    pub(crate) archive_blocks: Counter,
+    /// Number of blocks collected from the local hl-node output
+    pub(crate) local_blocks: Counter,
+    /// Number of blocks submitted to the engine
+    pub(crate) ingested_blocks: Counter,
+    /// Blocks submitted to the engine per second, measured between forkchoice updates
+    pub(crate) blocks_per_second: Gauge,
 }
 
 #[derive(Deserialize)]
@@ -118,11 +159,58 @@ async fn submit_payload<Engine: PayloadTypes + EngineTypes>(
         .await?
     };
 
-    assert_eq!(submission.status.as_str(), expected_status.as_str());
+    if submission.status.as_str() != expected_status.as_str() {
+        // the `bundle` invalid block hook records the block for `reth debug replay-bundle`
+        return Err(format!(
+            "unexpected payload status {:?}, expected {}",
+            submission.status,
+            expected_status.as_str()
+        )
+        .into());
+    }
 
     Ok(submission.latest_valid_hash.unwrap_or_default())
 }
 
+/// Returns the entry of `dir` with the highest numeric name, parsed with `parse`.
+fn max_numeric_entry(dir: &Path, parse: impl Fn(&str) -> Option<u64>) -> Option<(u64, PathBuf)> {
+    std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let n = parse(entry.file_name().to_str()?)?;
+            Some((n, entry.path()))
+        })
+        .max_by_key(|(n, _)| *n)
+}
+
+/// Returns the highest block height stored in the archive laid out as
+/// `<ingest_dir>/<millions>/<thousands>/<height>.rmp.lz4`.
+pub(crate) fn highest_archive_height(ingest_dir: &Path) -> Option<u64> {
+    let (_, millions) = max_numeric_entry(ingest_dir, |name| name.parse().ok())?;
+    let (_, thousands) = max_numeric_entry(&millions, |name| name.parse().ok())?;
+    let (height, _) = max_numeric_entry(&thousands, |name| {
+        name.strip_suffix(".rmp.lz4").and_then(|height| height.parse().ok())
+    })?;
+    Some(height)
+}
+
+/// Returns the blocks of the most recent hourly file of the local hl-node output, laid out as
+/// `<local_ingest_dir>/hourly/<day>/<hour>`.
+pub(crate) fn latest_local_blocks(local_ingest_dir: &Path) -> Option<Vec<BlockAndReceipts>> {
+    let (_, day) =
+        max_numeric_entry(&local_ingest_dir.join(HOURLY_SUBDIR), |name| name.parse().ok())?;
+    let (_, hour_file) = max_numeric_entry(&day, |name| name.parse().ok())?;
+    let file = std::fs::File::open(hour_file).ok()?;
+    let blocks = BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str::<LocalBlockAndReceipts>(&line).ok())
+        .map(|LocalBlockAndReceipts(_, block)| block)
+        .collect();
+    Some(blocks)
+}
+
 fn datetime_from_timestamp(ts_sec: u64) -> OffsetDateTime {
     OffsetDateTime::from_unix_timestamp_nanos((ts_sec as i128) * 1_000 * 1_000_000)
         .expect("timestamp out of range")
@@ -139,21 +227,19 @@ impl BlockIngest {
         // Not a one liner (using .or) to include logs
         if let Some(block) = self.try_collect_local_block(height).await {
             info!("Returning locally synced block for @ Height [{height}]");
+            self.metrics.local_blocks.increment(1);
             return Some(block);
         } else {
-            self.try_collect_s3_block(height)
+            let block = self.try_collect_s3_block(height)?;
+            self.metrics.archive_blocks.increment(1);
+            Some(block)
         }
     }
 
     pub(crate) fn try_collect_s3_block(&self, height: u64) -> Option<BlockAndReceipts> {
-        let f = ((height - 1) / 1_000_000) * 1_000_000;
-        let s = ((height - 1) / 1_000) * 1_000;
-        let path = format!("{}/{f}/{s}/{height}.rmp.lz4", self.ingest_dir.to_string_lossy());
-        let file = std::fs::read(path).ok()?;
-        let mut decoder = lz4_flex::frame::FrameDecoder::new(&file[..]);
-        let blocks: Vec<BlockAndReceipts> = rmp_serde::from_read(&mut decoder).unwrap();
+        let block = read_archive_block(&self.ingest_dir, height)?;
         info!("Returning s3 synced block for @ Height [{height}]");
-        Some(blocks[0].clone())
+        Some(block)
     }
 
     async fn try_collect_local_block(&self, height: u64) -> Option<BlockAndReceipts> {
@@ -161,11 +247,35 @@ impl BlockIngest {
         u_cache.remove(&height)
     }
 
-    async fn start_local_ingest_loop(&self, current_head: u64, current_ts: u64) {
+    fn start_archive_tip_loop(&self, network: impl NetworkSyncUpdater + Clone) {
+        let ingest_dir = self.ingest_dir.clone();
+        let metrics = self.metrics.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let dir = ingest_dir.clone();
+                if let Ok(Some(height)) =
+                    tokio::task::spawn_blocking(move || highest_archive_height(&dir)).await
+                {
+                    metrics.archive_height.set(height as f64);
+                    network.update_source_height("archive", height);
+                }
+                tokio::time::sleep(ARCHIVE_TIP_INTERVAL).await;
+            }
+        });
+    }
+
+    async fn start_local_ingest_loop(
+        &self,
+        current_head: u64,
+        current_ts: u64,
+        network: impl NetworkSyncUpdater + Clone,
+    ) {
         let Some(root) = &self.local_ingest_dir else { return }; // nothing to do
         let root = root.to_owned();
         let cache = self.local_blocks_cache.clone();
         let precompiles_cache = self.precompiles_cache.clone();
+        let metrics = self.metrics.clone();
 
         tokio::spawn(async move {
             let mut next_height = current_head;
@@ -202,6 +312,8 @@ impl BlockIngest {
                             u_pre_cache.insert(h, precompiles);
                         }
                         next_height = next_expected_height;
+                        metrics.local_tail_height.set((next_height - 1) as f64);
+                        network.update_source_height("hl-node", next_height - 1);
                     }
                 }
 
@@ -239,7 +351,6 @@ impl BlockIngest {
         AddOns: RethRpcAddOns<Node>,
         Engine: EngineTypes,
         Node::Types: NodeTypesWithEngine<ChainSpec: EthereumHardforks, Engine = Engine>,
-        Node::Network: PeersHandleProvider,
         AddOns: RethRpcAddOns<Node>,
         Engine::ExecutionPayloadEnvelopeV3: From<Engine::BuiltPayload>,
         Engine::ExecutionPayloadEnvelopeV4: From<Engine::BuiltPayload>,
@@ -265,14 +376,24 @@ impl BlockIngest {
             .timestamp();
 
         info!("Current height {height}, timestamp {current_block_timestamp}");
-        self.start_local_ingest_loop(height, current_block_timestamp).await;
+        self.start_archive_tip_loop(node.network.clone());
+        self.start_local_ingest_loop(height, current_block_timestamp, node.network.clone()).await;
+        let mut blocks_since_fcu = 0u64;
+        // Sidecars of the blocks submitted since the last forkchoice update
+        let mut sidecars = Vec::new();
 
         loop {
             let Some(original_block) = self.collect_block(height).await else {
                 tokio::time::sleep(std::time::Duration::from_millis(25)).await;
                 continue;
             };
+            let (system_txs, read_precompile_calls) = original_block.encode_sidecar()?;
+            sidecars.push((height, HlSidecar { system_txs, read_precompile_calls }));
+            self.recent_precompile_calls.record(height, &original_block.read_precompile_calls);
             let EvmBlock::Reth115(mut block) = original_block.block;
+            if let Some(calls) = &self.stream_precompile_calls {
+                calls.lock().insert(height, original_block.read_precompile_calls);
+            }
             {
                 debug!(target: "reth::cli", ?block, "Built new payload");
                 let timestamp = block.header().timestamp();
@@ -284,35 +405,20 @@ impl BlockIngest {
                     let mut system_txs = vec![];
 
                     for transaction in original_block.system_txs {
-                        let TypedTransaction::Legacy(tx) = &transaction.tx else {
-                            panic!("Unexpected transaction type");
-                        };
-                        let TxKind::Call(to) = tx.to else {
-                            panic!("Unexpected contract creation");
-                        };
-                        let s = if tx.input().is_empty() {
-                            U256::from(0x1)
-                        } else {
-                            loop {
-                                if let Some(spot) = evm_map.get(&to) {
-                                    break spot.to_s();
-                                }
-
-                                info!(
-                                    "Contract not found: {:?} from spot mapping, fetching again...",
-                                    to
-                                );
-                                evm_map =
-                                    erc20_contract_to_spot_token(node.chain_spec().chain_id())
-                                        .await?;
+                        let signature = loop {
+                            if let Some(signature) =
+                                system_tx_signature(&transaction.tx, &evm_map)?
+                            {
+                                break signature;
                             }
+
+                            info!(
+                                "Contract not found: {:?} from spot mapping, fetching again...",
+                                transaction.tx.to()
+                            );
+                            evm_map =
+                                erc20_contract_to_spot_token(node.chain_spec().chain_id()).await?;
                         };
-                        let signature = PrimitiveSignature::new(
-                            // from anvil
-                            U256::from(0x1),
-                            s,
-                            true,
-                        );
                         let typed_transaction = transaction.tx;
                         let tx = TransactionSigned::new(
                             typed_transaction,
@@ -352,6 +458,8 @@ impl BlockIngest {
                     PayloadStatusEnum::Valid,
                 )
                 .await?;
+                self.metrics.ingested_blocks.increment(1);
+                blocks_since_fcu += 1;
 
                 let current_timestamp = std::time::SystemTime::now()
                     .duration_since(std::time::UNIX_EPOCH)
@@ -359,6 +467,21 @@ impl BlockIngest {
                     .as_millis();
 
                 if height % 100 == 0 || current_timestamp - previous_timestamp > 100 {
+                    // Store the sidecars before the blocks become canonical, so they are always
+                    // available for the persisted blocks. The write transaction blocks until the
+                    // engine's persistence releases the database, so it runs off the runtime.
+                    let provider = node.provider.clone();
+                    let batch = std::mem::take(&mut sidecars);
+                    tokio::task::spawn_blocking(move || -> ProviderResult<()> {
+                        let provider_rw = provider.database_provider_rw()?;
+                        for (number, sidecar) in batch {
+                            provider_rw.tx_ref().put::<tables::HlSidecars>(number, sidecar)?;
+                        }
+                        provider_rw.commit()?;
+                        Ok(())
+                    })
+                    .await??;
+
                     EngineApiClient::<Engine>::fork_choice_updated_v2(
                         &engine_api,
                         ForkchoiceState {
@@ -368,8 +491,25 @@ impl BlockIngest {
                         },
                         None,
                     )
-                    .await
-                    .unwrap();
+                    .await?;
+
+                    let elapsed_ms = current_timestamp.saturating_sub(previous_timestamp).max(1);
+                    self.metrics
+                        .blocks_per_second
+                        .set(blocks_since_fcu as f64 * 1000.0 / elapsed_ms as f64);
+                    self.metrics.canonical_head.set(height as f64);
+
+                    // report archive catch-up through `eth_syncing`
+                    let highest = node.network.highest_block().unwrap_or(height);
+                    node.network.update_sync_state(if height + SYNC_DISTANCE < highest {
+                        SyncState::Syncing
+                    } else {
+                        SyncState::Idle
+                    });
+                    self.metrics.head_timestamp_lag_seconds.set(
+                        (current_timestamp / 1000).saturating_sub(timestamp as u128) as f64,
+                    );
+                    blocks_since_fcu = 0;
                     previous_timestamp = current_timestamp;
                 }
                 previous_hash = block_hash;
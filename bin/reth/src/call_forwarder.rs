@@ -8,10 +8,13 @@ use jsonrpsee::{
     types::{error::INTERNAL_ERROR_CODE, ErrorObject},
 };
 use jsonrpsee_core::{async_trait, client::ClientT, ClientError, RpcResult};
+use reth_hyperliquid_types::PrecompileOverrides;
 
 #[rpc(server, namespace = "eth")]
 pub(crate) trait CallForwarderApi {
     /// Executes a new message call immediately without creating a transaction on the block chain.
+    ///
+    /// Read precompile overrides are only passed on to the upstream if set.
     #[method(name = "call")]
     async fn call(
         &self,
@@ -19,6 +22,7 @@ pub(crate) trait CallForwarderApi {
         block_number: Option<BlockId>,
         state_overrides: Option<StateOverride>,
         block_overrides: Option<Box<BlockOverrides>>,
+        precompile_overrides: Option<PrecompileOverrides>,
     ) -> RpcResult<Bytes>;
 
     /// Generates and returns an estimate of how much gas is necessary to allow the transaction to
@@ -53,16 +57,20 @@ impl CallForwarderApiServer for CallForwarderExt {
         block_number: Option<BlockId>,
         state_overrides: Option<StateOverride>,
         block_overrides: Option<Box<BlockOverrides>>,
+        precompile_overrides: Option<PrecompileOverrides>,
     ) -> RpcResult<Bytes> {
-        let result = self
-            .client
-            .clone()
-            .request(
-                "eth_call",
-                rpc_params![request, block_number, state_overrides, block_overrides],
-            )
-            .await
-            .map_err(|e| match e {
+        let mut params = rpc_params![request, block_number, state_overrides, block_overrides];
+        if let Some(precompile_overrides) = precompile_overrides {
+            params.insert(precompile_overrides).map_err(|e| {
+                ErrorObject::owned(
+                    INTERNAL_ERROR_CODE,
+                    format!("Failed to serialize precompile overrides: {:?}", e),
+                    Some(()),
+                )
+            })?;
+        }
+        let result =
+            self.client.clone().request("eth_call", params).await.map_err(|e| match e {
                 ClientError::Call(e) => e,
                 _ => ErrorObject::owned(
                     INTERNAL_ERROR_CODE,
@@ -2,14 +2,14 @@
 
 use crate::{
     args::LogArgs,
-    commands::debug_cmd,
+    commands::{debug_cmd, export_parquet, rpc_replica},
     version::{LONG_VERSION, SHORT_VERSION},
 };
 use clap::{value_parser, Parser, Subcommand};
 use reth_chainspec::ChainSpec;
 use reth_cli::chainspec::ChainSpecParser;
 use reth_cli_commands::{
-    config_cmd, db, dump_genesis, import, init_cmd, init_state,
+    config_cmd, db, dump_genesis, dump_state, import, init_cmd, init_state,
     node::{self, NoArgs},
     p2p, prune, recover, stage,
 };
@@ -169,6 +169,9 @@ impl<C: ChainSpecParser<ChainSpec = ChainSpec>, Ext: clap::Args + fmt::Debug> Cl
                 runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode, _, _>(components))
             }
             Commands::DumpGenesis(command) => runner.run_blocking_until_ctrl_c(command.execute()),
+            Commands::DumpState(command) => {
+                runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode>())
+            }
             Commands::Db(command) => {
                 runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode>())
             }
@@ -188,6 +191,12 @@ impl<C: ChainSpecParser<ChainSpec = ChainSpec>, Ext: clap::Args + fmt::Debug> Cl
                 runner.run_command_until_exit(|ctx| command.execute::<EthereumNode>(ctx))
             }
             Commands::Prune(command) => runner.run_until_ctrl_c(command.execute::<EthereumNode>()),
+            Commands::RpcReplica(command) => {
+                runner.run_command_until_exit(|ctx| command.execute::<EthereumNode>(ctx))
+            }
+            Commands::ExportParquet(command) => {
+                runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode>())
+            }
         }
     }
 
@@ -218,6 +227,9 @@ pub enum Commands<C: ChainSpecParser, Ext: clap::Args + fmt::Debug> {
     Import(import::ImportCommand<C>),
     /// Dumps genesis block JSON configuration to stdout.
     DumpGenesis(dump_genesis::DumpGenesisCommand<C>),
+    /// Dumps the state at a block in the format read by `init-state`.
+    #[command(name = "dump-state")]
+    DumpState(dump_state::DumpStateCommand<C>),
     /// Database debugging utilities
     #[command(name = "db")]
     Db(db::Command<C>),
@@ -243,6 +255,12 @@ pub enum Commands<C: ChainSpecParser, Ext: clap::Args + fmt::Debug> {
     /// Prune according to the configuration without any limits
     #[command(name = "prune")]
     Prune(prune::PruneCommand<C>),
+    /// Serve the RPC of a running node from its datadir, without ingesting blocks
+    #[command(name = "rpc-replica")]
+    RpcReplica(Box<rpc_replica::Command<C>>),
+    /// Export the blocks, transactions, logs and ERC20 transfers to Parquet files
+    #[command(name = "export-parquet")]
+    ExportParquet(Box<export_parquet::Command<C>>),
 }
 
 #[cfg(test)]
@@ -12,6 +12,8 @@ mod build_block;
 mod execution;
 mod in_memory_merkle;
 mod merkle;
+mod replay_archive;
+mod replay_bundle;
 
 /// `reth debug` command
 #[derive(Debug, Parser)]
@@ -31,6 +33,10 @@ pub enum Subcommands<C: ChainSpecParser> {
     InMemoryMerkle(in_memory_merkle::Command<C>),
     /// Debug block building.
     BuildBlock(build_block::Command<C>),
+    /// Re-execute a replay bundle written by the `bundle` invalid block hook.
+    ReplayBundle(replay_bundle::Command<C>),
+    /// Re-execute archived blocks on top of the database state and report the mismatches.
+    ReplayArchive(replay_archive::Command<C>),
 }
 
 impl<C: ChainSpecParser<ChainSpec = ChainSpec>> Command<C> {
@@ -46,6 +52,8 @@ impl<C: ChainSpecParser<ChainSpec = ChainSpec>> Command<C> {
             Subcommands::Merkle(command) => command.execute::<N>(ctx).await,
             Subcommands::InMemoryMerkle(command) => command.execute::<N>(ctx).await,
             Subcommands::BuildBlock(command) => command.execute::<N>(ctx).await,
+            Subcommands::ReplayBundle(command) => command.execute().await,
+            Subcommands::ReplayArchive(command) => command.execute::<N>(ctx).await,
         }
     }
 }
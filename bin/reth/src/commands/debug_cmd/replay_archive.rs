@@ -0,0 +1,221 @@
+//! Command for re-executing archived blocks and comparing the results against hl-node.
+
+use crate::spot_meta::{erc20_contract_to_spot_token, system_tx_signature};
+use alloy_consensus::{BlockHeader, Transaction as _};
+use alloy_primitives::{BlockNumber, TxHash, B256};
+use clap::Parser;
+use eyre::{eyre, WrapErr};
+use reth_chainspec::{ChainSpec, EthChainSpec};
+use reth_cli::chainspec::ChainSpecParser;
+use reth_cli_commands::common::{AccessRights, CliNodeTypes, Environment, EnvironmentArgs};
+use reth_cli_runner::CliContext;
+use reth_ethereum_primitives::EthPrimitives;
+use reth_evm::execute::{BasicBlockExecutor, Executor};
+use reth_hyperliquid_types::archive::{read_archive_block, EvmBlock, LegacyReceipt};
+use reth_node_ethereum::{EthEvmConfig, EthExecutionStrategyFactory};
+use reth_primitives::{Receipt, RecoveredBlock, TransactionSigned};
+use reth_primitives_traits::Block as _;
+use reth_provider::{
+    ChainSpecProvider, HashedPostStateProvider, StateProviderFactory, StateRootProvider,
+};
+use reth_revm::database::StateProviderDatabase;
+use serde::Serialize;
+use std::{
+    io::{BufWriter, Write},
+    path::PathBuf,
+};
+use tracing::*;
+
+/// `reth debug replay-archive` command
+/// Executes the blocks of the hl-node archive on top of the state of the database before the
+/// first block, keeping the changes in memory, and compares the state roots with the headers and
+/// the receipts with the archived ones. The database is opened read-only.
+#[derive(Debug, Parser)]
+pub struct Command<C: ChainSpecParser> {
+    #[command(flatten)]
+    env: EnvironmentArgs<C>,
+
+    /// The directory of the hl-node archive to read the blocks from.
+    #[arg(long, value_name = "PATH")]
+    ingest_dir: PathBuf,
+
+    /// The first block to execute. The state at the block before it must be available in the
+    /// database.
+    #[arg(long)]
+    from: BlockNumber,
+
+    /// The last block to execute.
+    #[arg(long)]
+    to: BlockNumber,
+
+    /// The file to write the mismatches to, one JSON object per line.
+    #[arg(long, value_name = "FILE")]
+    report: PathBuf,
+}
+
+/// A difference between the replayed block and the archived one, written to the report.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Mismatch {
+    StateRoot {
+        block: BlockNumber,
+        expected: B256,
+        got: B256,
+    },
+    GasUsed {
+        block: BlockNumber,
+        expected: u64,
+        got: u64,
+    },
+    ReceiptCount {
+        block: BlockNumber,
+        expected: usize,
+        got: usize,
+    },
+    Receipt {
+        block: BlockNumber,
+        index: usize,
+        hash: TxHash,
+        fields: Vec<&'static str>,
+        expected: LegacyReceipt,
+        got: Receipt,
+    },
+}
+
+/// Returns the fields of a replayed receipt that differ from the one recorded by hl-node.
+pub(crate) fn receipt_mismatches(
+    replayed: &Receipt,
+    expected: &LegacyReceipt,
+) -> Vec<&'static str> {
+    let mut fields = Vec::new();
+    if replayed.success != expected.success {
+        fields.push("success");
+    }
+    if replayed.cumulative_gas_used != expected.cumulative_gas_used {
+        fields.push("cumulative_gas_used");
+    }
+    if replayed.logs != expected.logs {
+        fields.push("logs");
+    }
+    fields
+}
+
+impl<C: ChainSpecParser<ChainSpec = ChainSpec>> Command<C> {
+    /// Execute `debug replay-archive` command
+    pub async fn execute<N: CliNodeTypes<ChainSpec = C::ChainSpec, Primitives = EthPrimitives>>(
+        self,
+        _ctx: CliContext,
+    ) -> eyre::Result<()> {
+        if self.from == 0 || self.from > self.to {
+            eyre::bail!("invalid block range {}..={}", self.from, self.to)
+        }
+
+        let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RO)?;
+        let chain_spec = provider_factory.chain_spec();
+        let mut spot_map = erc20_contract_to_spot_token(chain_spec.chain_id()).await?;
+
+        let state_provider = provider_factory.history_by_block_number(self.from - 1)?;
+        let evm_config =
+            EthEvmConfig::new(chain_spec.clone()).with_ingest_dir(self.ingest_dir.clone());
+        let mut executor = BasicBlockExecutor::new(
+            EthExecutionStrategyFactory::new(chain_spec.clone(), evm_config),
+            StateProviderDatabase::new(&state_provider),
+        );
+
+        let mut report = BufWriter::new(reth_fs_util::create_file(&self.report)?);
+        let mut total_mismatches = 0;
+        for number in self.from..=self.to {
+            let ingested = read_archive_block(&self.ingest_dir, number)
+                .ok_or_else(|| eyre!("block {number} is missing from the archive"))?;
+
+            // The node executes the system transactions before the user ones
+            let EvmBlock::Reth115(mut block) = ingested.block;
+            let mut transactions =
+                Vec::with_capacity(ingested.system_txs.len() + block.body().transactions.len());
+            for system_tx in &ingested.system_txs {
+                let mut signature = system_tx_signature(&system_tx.tx, &spot_map)?;
+                if signature.is_none() {
+                    // the token may have been deployed since the spot tokens were fetched
+                    spot_map = erc20_contract_to_spot_token(chain_spec.chain_id()).await?;
+                    signature = system_tx_signature(&system_tx.tx, &spot_map)?;
+                }
+                let signature = signature.ok_or_else(|| {
+                    eyre!("unknown spot token {:?} in block {number}", system_tx.tx.to())
+                })?;
+                transactions.push(TransactionSigned::new_unhashed(system_tx.tx.clone(), signature));
+            }
+            transactions.append(&mut block.body_mut().transactions);
+            block.body_mut().transactions = transactions;
+            let block: RecoveredBlock<_> = block.try_recover()?;
+
+            let result =
+                executor.execute_one(&block).wrap_err_with(|| format!("block {number}"))?;
+            let state_root = state_provider
+                .state_root(state_provider.hashed_post_state(&executor.state().bundle_state))?;
+
+            let mut mismatches = Vec::new();
+            if state_root != block.state_root() {
+                mismatches.push(Mismatch::StateRoot {
+                    block: number,
+                    expected: block.state_root(),
+                    got: state_root,
+                });
+            }
+            if result.gas_used != block.gas_used() {
+                mismatches.push(Mismatch::GasUsed {
+                    block: number,
+                    expected: block.gas_used(),
+                    got: result.gas_used,
+                });
+            }
+
+            // hl-node records the receipts of the system transactions separately
+            let user_receipts = &result.receipts[ingested.system_txs.len()..];
+            if user_receipts.len() != ingested.receipts.len() {
+                mismatches.push(Mismatch::ReceiptCount {
+                    block: number,
+                    expected: ingested.receipts.len(),
+                    got: user_receipts.len(),
+                });
+            }
+            for (offset, (got, expected)) in user_receipts.iter().zip(ingested.receipts).enumerate()
+            {
+                let fields = receipt_mismatches(got, &expected);
+                if !fields.is_empty() {
+                    let index = ingested.system_txs.len() + offset;
+                    mismatches.push(Mismatch::Receipt {
+                        block: number,
+                        index,
+                        hash: *block.body().transactions[index].hash(),
+                        fields,
+                        expected,
+                        got: got.clone(),
+                    });
+                }
+            }
+
+            if !mismatches.is_empty() {
+                warn!(target: "reth::cli", block = number, mismatches = mismatches.len(), "Replayed block diverges");
+            }
+            for mismatch in &mismatches {
+                serde_json::to_writer(&mut report, mismatch)?;
+                writeln!(report)?;
+            }
+            total_mismatches += mismatches.len();
+
+            if number % 1000 == 0 {
+                info!(target: "reth::cli", block = number, total_mismatches, "Replayed blocks");
+            }
+        }
+        report.flush()?;
+
+        info!(target: "reth::cli",
+            from = self.from,
+            to = self.to,
+            total_mismatches,
+            report = %self.report.display(),
+            "Replayed archive"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+//! Command for re-executing a replay bundle in isolation.
+
+use super::replay_archive::receipt_mismatches;
+use alloy_consensus::{BlockHeader, Transaction};
+use alloy_rpc_types_trace::geth::CallConfig;
+use clap::Parser;
+use reth_chainspec::ChainSpec;
+use reth_cli::chainspec::ChainSpecParser;
+use reth_evm::{
+    execute::{BlockExecutorProvider, Executor},
+    system_calls::SystemCaller,
+    ConfigureEvm, ConfigureEvmEnv, Evm,
+};
+use reth_invalid_block_hooks::ReplayBundle;
+use reth_node_ethereum::{BasicBlockExecutorProvider, EthEvmConfig, EthExecutionStrategyFactory};
+use reth_primitives::{Block, Receipt, RecoveredBlock};
+use reth_primitives_traits::Block as _;
+use reth_revm::DatabaseCommit;
+use revm_inspectors::tracing::{TracingInspector, TracingInspectorConfig};
+use std::{path::PathBuf, sync::Arc};
+use tracing::*;
+
+/// `reth debug replay-bundle` command
+/// Re-executes a block from a replay bundle written by the `bundle` invalid block hook
+/// (`--debug.invalid-block-hook bundle`), without a database. The receipts are compared against
+/// the ones produced by the node and, if the bundle holds the ingested block, by hl-node.
+#[derive(Debug, Parser)]
+pub struct Command<C: ChainSpecParser> {
+    /// The chain this node is running.
+    ///
+    /// Possible values are either a built-in chain or the path to a chain specification file.
+    #[arg(
+        long,
+        value_name = "CHAIN_OR_PATH",
+        long_help = C::help_message(),
+        default_value = C::SUPPORTED_CHAINS[0],
+        value_parser = C::parser()
+    )]
+    chain: Arc<C::ChainSpec>,
+
+    /// The replay bundle to re-execute.
+    #[arg(value_name = "BUNDLE")]
+    bundle: PathBuf,
+
+    /// Writes the call traces of the block transactions to the given file.
+    ///
+    /// The transactions are traced one by one on top of the pre-block system calls.
+    #[arg(long, value_name = "PATH")]
+    trace: Option<PathBuf>,
+}
+
+impl<C: ChainSpecParser<ChainSpec = ChainSpec>> Command<C> {
+    /// Execute `debug replay-bundle` command
+    pub async fn execute(self) -> eyre::Result<()> {
+        let bundle: ReplayBundle<Block, Receipt> =
+            serde_json::from_str(&reth_fs_util::read_to_string(&self.bundle)?)?;
+        let block = RecoveredBlock::new_unhashed(bundle.block.clone(), bundle.senders.clone());
+        info!(target: "reth::cli", number = block.number(), hash = %block.hash(), "Replaying block");
+
+        // Read precompile results are served from the bundle only
+        let evm_config = EthEvmConfig::new(self.chain.clone())
+            .with_read_precompile_calls_reader(Some(Arc::new(bundle.read_precompile_calls())));
+        let executor = BasicBlockExecutorProvider::new(EthExecutionStrategyFactory::new(
+            self.chain.clone(),
+            evm_config.clone(),
+        ));
+        let output = executor.executor(bundle.pre_state.clone().into_cache_db()).execute(&block)?;
+
+        let mut mismatches = 0;
+        if output.gas_used != block.gas_used() {
+            mismatches += 1;
+            warn!(target: "reth::cli", replayed = output.gas_used, header = block.gas_used(), "Gas used mismatch");
+        }
+        // The node produces no receipts if it failed to execute the block
+        if output.receipts.len() != bundle.receipts.len() {
+            mismatches += 1;
+            warn!(target: "reth::cli", replayed = output.receipts.len(), produced = bundle.receipts.len(), "Receipt count mismatch against the node");
+        }
+        for (index, (tx, (replayed, produced))) in block
+            .body()
+            .transactions
+            .iter()
+            .zip(output.receipts.iter().zip(&bundle.receipts))
+            .enumerate()
+        {
+            if replayed != produced {
+                mismatches += 1;
+                warn!(target: "reth::cli", index, hash = %tx.hash(), ?replayed, ?produced, "Receipt mismatch against the node");
+            }
+        }
+
+        if let Some(ingested) = &bundle.ingested {
+            // hl-node records the receipts of the system transactions separately, and they
+            // precede the user transactions in the executed block
+            let system_txs = ingested.system_txs.len();
+            if output.receipts.len() != system_txs + ingested.receipts.len() {
+                mismatches += 1;
+                warn!(target: "reth::cli", replayed = output.receipts.len(), system_txs, hl_node = ingested.receipts.len(), "Receipt count mismatch against hl-node");
+            }
+            for (offset, expected) in ingested.receipts.iter().enumerate() {
+                let index = system_txs + offset;
+                let Some(replayed) = output.receipts.get(index) else { break };
+                let fields = receipt_mismatches(replayed, expected);
+                if !fields.is_empty() {
+                    mismatches += 1;
+                    warn!(target: "reth::cli", index, hash = %block.body().transactions[index].hash(), ?fields, ?replayed, ?expected, "Receipt mismatch against hl-node");
+                }
+            }
+        } else {
+            warn!(target: "reth::cli", "Bundle has no ingested block, skipping the comparison against hl-node");
+        }
+
+        if let Some(path) = &self.trace {
+            let mut db = bundle.pre_state.into_cache_db();
+            let evm_env = evm_config.evm_env(block.header());
+            SystemCaller::new(self.chain.clone()).apply_pre_execution_changes(
+                block.header(),
+                &mut evm_config.evm_with_env(&mut db, evm_env.clone()),
+            )?;
+
+            let call_config = CallConfig::default();
+            let mut traces = Vec::with_capacity(block.body().transactions.len());
+            for tx in block.transactions_recovered() {
+                let mut inspector = TracingInspector::new(
+                    TracingInspectorConfig::from_geth_call_config(&call_config),
+                );
+                let result_and_state = evm_config
+                    .evm_with_env_and_inspector(&mut db, evm_env.clone(), &mut inspector)
+                    .transact(evm_config.tx_env(tx))?;
+                traces.push(
+                    inspector
+                        .with_transaction_gas_limit(tx.gas_limit())
+                        .into_geth_builder()
+                        .geth_call_traces(call_config, result_and_state.result.gas_used()),
+                );
+                db.commit(result_and_state.state);
+            }
+            reth_fs_util::write(path, serde_json::to_vec_pretty(&traces)?)?;
+            info!(target: "reth::cli", path = %path.display(), "Wrote call traces");
+        }
+
+        if mismatches == 0 {
+            info!(target: "reth::cli", "Replayed block matches");
+        } else {
+            warn!(target: "reth::cli", mismatches, state_root = ?bundle.state_root, "Replayed block diverges");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+//! `reth export-parquet` command. Exports the history to Parquet files for analytics.
+
+use alloy_primitives::BlockNumber;
+use clap::Parser;
+use reth_chainspec::ChainSpec;
+use reth_cli::chainspec::ChainSpecParser;
+use reth_cli_commands::common::{AccessRights, CliNodeTypes, Environment, EnvironmentArgs};
+use reth_ethereum_primitives::EthPrimitives;
+use reth_exex::BackfillJobFactory;
+use reth_exex_parquet::PartitionWriter;
+use reth_node_ethereum::{BasicBlockExecutorProvider, EthEvmConfig, EthExecutionStrategyFactory};
+use reth_provider::{providers::BlockchainProvider, ChainSpecProvider};
+use std::{path::PathBuf, time::Instant};
+use tracing::*;
+
+/// `reth export-parquet` command
+///
+/// Re-executes the blocks of the database to write the blocks, transactions, logs and decoded
+/// ERC20 transfers as Parquet files, partitioned by block ranges aligned to `--partition-size`.
+/// Partitions already written are skipped, so an interrupted export resumes by running the same
+/// command again. The node keeps the export up to date with `--exex-parquet.dir` pointing at the
+/// same directory.
+#[derive(Debug, Parser)]
+pub struct Command<C: ChainSpecParser> {
+    #[command(flatten)]
+    env: EnvironmentArgs<C>,
+
+    /// The directory of the hl-node archive, serving the read precompile calls to the
+    /// re-execution of the blocks.
+    #[arg(long, value_name = "PATH")]
+    ingest_dir: PathBuf,
+
+    /// The first block to export.
+    #[arg(long)]
+    from: BlockNumber,
+
+    /// The last block to export.
+    #[arg(long)]
+    to: BlockNumber,
+
+    /// The directory to write the Parquet files to.
+    #[arg(long, value_name = "DIR")]
+    output: PathBuf,
+
+    /// The number of blocks of a partition.
+    #[arg(long, default_value_t = 10_000)]
+    partition_size: u64,
+}
+
+impl<C: ChainSpecParser<ChainSpec = ChainSpec>> Command<C> {
+    /// Execute `export-parquet` command
+    pub async fn execute<N: CliNodeTypes<ChainSpec = C::ChainSpec, Primitives = EthPrimitives>>(
+        self,
+    ) -> eyre::Result<()> {
+        if self.from > self.to {
+            eyre::bail!("invalid block range {}..={}", self.from, self.to)
+        }
+
+        let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RO)?;
+        let chain_spec = provider_factory.chain_spec();
+        let provider = BlockchainProvider::new(provider_factory)?;
+        let executor = BasicBlockExecutorProvider::new(EthExecutionStrategyFactory::new(
+            chain_spec.clone(),
+            EthEvmConfig::new(chain_spec).with_ingest_dir(self.ingest_dir),
+        ));
+        let backfill = BackfillJobFactory::new(executor, provider);
+        let writer = PartitionWriter::new(self.output, self.partition_size);
+
+        let mut first = self.from;
+        while first <= self.to {
+            let range = first..=(*writer.partition_of(first).end()).min(self.to);
+            first = range.end() + 1;
+            if writer.is_written(&range)? {
+                debug!(target: "reth::cli", ?range, "Partition already written, skipping");
+                continue
+            }
+
+            let start = Instant::now();
+            let chains = backfill.backfill(range.clone()).collect::<Result<Vec<_>, _>>()?;
+            writer.write(
+                &range,
+                chains.iter().flat_map(|chain| {
+                    chain
+                        .blocks_and_receipts()
+                        .map(|(block, receipts)| (block, receipts.as_slice()))
+                }),
+            )?;
+            info!(target: "reth::cli", ?range, elapsed = ?start.elapsed(), "Wrote partition");
+        }
+
+        info!(target: "reth::cli", from = self.from, to = self.to, dir = %writer.dir().display(), "Exported blocks");
+        Ok(())
+    }
+}
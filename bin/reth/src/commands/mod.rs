@@ -1,3 +1,5 @@
 //! This contains all of the `reth` commands
 
 pub mod debug_cmd;
+pub mod export_parquet;
+pub mod rpc_replica;
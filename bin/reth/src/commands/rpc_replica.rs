@@ -0,0 +1,342 @@
+//! `reth rpc-replica` command. Serves the RPC of a running node from its datadir.
+
+use alloy_consensus::BlockHeader;
+use clap::Parser;
+use jsonrpsee::server::RpcServiceBuilder;
+use reth_chainspec::ChainSpec;
+use reth_cli::chainspec::ChainSpecParser;
+use reth_cli_commands::common::{AccessRights, CliNodeTypes, Environment, EnvironmentArgs};
+use reth_cli_runner::CliContext;
+use reth_cli_util::parse_duration_from_secs_or_ms;
+use reth_ethereum_primitives::EthPrimitives;
+use reth_execution_types::{Chain, ExecutionOutcome};
+use reth_hyperliquid_types::{
+    archive::read_archive_block, ReadPrecompileCallsReader, ReadPrecompileProvider,
+};
+use reth_node_api::HeaderTy;
+use reth_node_core::args::RpcServerArgs;
+use reth_node_ethereum::{
+    consensus::EthBeaconConsensus,
+    evm::{RecentReadPrecompileCalls, SidecarReadPrecompileCalls},
+    BasicBlockExecutorProvider, EthEvmConfig, EthExecutionStrategyFactory,
+};
+use reth_primitives::SealedHeader;
+use reth_provider::{
+    providers::{BlockchainProvider, ProviderNodeTypes},
+    BlockHashReader, BlockNumReader, BlockReader, CanonChainTracker, CanonStateNotification,
+    ChainSpecProvider, ChainStateBlockReader, DatabaseProviderFactory, HeaderProvider,
+    ProviderError, ProviderResult, ReceiptProvider, StaticFileProviderFactory,
+};
+use reth_rpc::{EthApi, SlowQueryApi};
+use reth_rpc_api::SlowQueryApiServer;
+use reth_rpc_builder::{
+    config::RethRpcServerConfig, slow_query::SlowQueryLayer, RethRpcModule, RpcModuleBuilder,
+};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+use tracing::*;
+
+/// `reth rpc-replica` command
+///
+/// Opens the database and static files of a running node read-only and serves the RPC on top of
+/// them, without ingesting blocks. New canonical blocks are picked up by polling the progress
+/// persisted by the node, so the replica trails the node by the blocks it still holds in memory
+/// (see `--engine.persistence-threshold`).
+///
+/// The ports and the IPC path must differ from the ones of the node, e.g. with `--http.port` and
+/// `--ipcpath` or `--ipcdisable`.
+#[derive(Debug, Parser)]
+pub struct Command<C: ChainSpecParser> {
+    #[command(flatten)]
+    env: EnvironmentArgs<C>,
+
+    /// The directory of the hl-node archive, serving the read precompile calls of the
+    /// historical blocks to `eth_call` and the tracing APIs.
+    #[arg(long, value_name = "PATH")]
+    ingest_dir: PathBuf,
+
+    /// Enable hl-node compliant mode, see the option of the same name of the node.
+    #[arg(long)]
+    hl_node_compliant: bool,
+
+    /// Interval at which the database is polled for new canonical blocks.
+    #[arg(long = "replica.poll-interval", value_parser = parse_duration_from_secs_or_ms, default_value = "250ms")]
+    poll_interval: Duration,
+
+    #[command(flatten)]
+    rpc: RpcServerArgs,
+}
+
+impl<C: ChainSpecParser<ChainSpec = ChainSpec>> Command<C> {
+    /// Execute `rpc-replica` command
+    pub async fn execute<N: CliNodeTypes<ChainSpec = C::ChainSpec, Primitives = EthPrimitives>>(
+        self,
+        ctx: CliContext,
+    ) -> eyre::Result<()> {
+        if self.hl_node_compliant {
+            info!(target: "reth::cli", "hl-node compliant mode enabled");
+            std::env::set_var("HL_NODE_COMPLIANT", "true");
+        }
+
+        let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RO)?;
+        // Pick up the static files written by the node
+        provider_factory.static_file_provider().watch_directory();
+        let chain_spec = provider_factory.chain_spec();
+        let provider = BlockchainProvider::new(provider_factory)?;
+
+        let evm_config =
+            EthEvmConfig::new(chain_spec.clone()).with_ingest_dir(self.ingest_dir.clone());
+        let block_executor = BasicBlockExecutorProvider::new(EthExecutionStrategyFactory::new(
+            chain_spec.clone(),
+            evm_config.clone(),
+        ));
+        // Serves the read precompile calls of `eth_call` that are not recorded by their block,
+        // from the calls of the blocks followed since the start
+        let recent_precompile_calls = Arc::new(RecentReadPrecompileCalls::default());
+        let evm_config = evm_config.with_read_precompile_provider(Some(
+            recent_precompile_calls.clone() as Arc<dyn ReadPrecompileProvider>,
+        ));
+
+        let mut modules = RpcModuleBuilder::default()
+            .with_provider(provider.clone())
+            .with_noop_pool()
+            .with_noop_network()
+            .with_executor(ctx.task_executor.clone())
+            .with_evm_config(evm_config)
+            .with_block_executor(block_executor)
+            .with_consensus(EthBeaconConsensus::new(chain_spec))
+            .build(self.rpc.transport_rpc_module_config(), Box::new(EthApi::with_spawner));
+        let slow_queries = SlowQueryLayer::new(self.rpc.slow_query_config());
+        modules.merge_if_module_configured(
+            RethRpcModule::Admin,
+            SlowQueryApi::new(slow_queries.log().clone()).into_rpc(),
+        )?;
+
+        // the servers stop when the handle is dropped
+        let handle = self
+            .rpc
+            .rpc_server_config()
+            .set_rpc_middleware(RpcServiceBuilder::new().layer(slow_queries))
+            .start(&modules)
+            .await?;
+        if let Some(path) = handle.ipc_endpoint() {
+            info!(target: "reth::cli", %path, "RPC IPC server started");
+        }
+        if let Some(addr) = handle.http_local_addr() {
+            info!(target: "reth::cli", url=%addr, "RPC HTTP server started");
+        }
+        if let Some(addr) = handle.ws_local_addr() {
+            info!(target: "reth::cli", url=%addr, "RPC WS server started");
+        }
+
+        let calls_reader = SidecarReadPrecompileCalls::new(provider.clone());
+        let mut follower = CanonicalHeadFollower::new(provider)?;
+        info!(target: "reth::cli", head = follower.head().number(), "Following the canonical head");
+        let mut interval = tokio::time::interval(self.poll_interval);
+        loop {
+            interval.tick().await;
+            match follower.poll() {
+                Ok(count) => {
+                    let head = follower.head().number();
+                    for number in (head + 1).saturating_sub(count as u64)..=head {
+                        record_precompile_calls(
+                            &recent_precompile_calls,
+                            &calls_reader,
+                            &self.ingest_dir,
+                            number,
+                        );
+                    }
+                }
+                Err(err) => warn!(target: "reth::cli", %err, "Failed to follow the canonical head"),
+            }
+        }
+    }
+}
+
+/// Records the read precompile calls of the block, read from the sidecars stored by the node or
+/// from the archive.
+fn record_precompile_calls(
+    recent: &RecentReadPrecompileCalls,
+    reader: &dyn ReadPrecompileCallsReader,
+    ingest_dir: &Path,
+    number: u64,
+) {
+    let calls = match reader.read_precompile_calls(number) {
+        Ok(calls) => calls,
+        Err(err) => {
+            warn!(target: "reth::cli", %err, number, "Failed to read recorded precompile calls");
+            None
+        }
+    };
+    match calls.or_else(|| read_archive_block(ingest_dir, number).map(|b| b.read_precompile_calls))
+    {
+        Some(calls) => recent.record(number, &calls),
+        None => debug!(target: "reth::cli", number, "Read precompile calls of the block not found"),
+    }
+}
+
+/// Follows the canonical head persisted by another process writing to the database.
+///
+/// The head is published to the in-memory state of the [`BlockchainProvider`], which the RPC
+/// reads the latest block from, and the new blocks are sent to its canonical state subscribers,
+/// e.g. `eth_subscribe` and the RPC caches.
+#[derive(Debug)]
+pub struct CanonicalHeadFollower<N: ProviderNodeTypes> {
+    provider: BlockchainProvider<N>,
+    head: SealedHeader<HeaderTy<N>>,
+}
+
+impl<N: ProviderNodeTypes> CanonicalHeadFollower<N> {
+    /// Creates a new follower starting at the current head of the provider.
+    pub fn new(provider: BlockchainProvider<N>) -> ProviderResult<Self> {
+        let best = provider.best_block_number()?;
+        let head = provider
+            .sealed_header(best)?
+            .ok_or_else(|| ProviderError::HeaderNotFound(best.into()))?;
+        Ok(Self { provider, head })
+    }
+
+    /// Returns the last canonical head published.
+    pub const fn head(&self) -> &SealedHeader<HeaderTy<N>> {
+        &self.head
+    }
+
+    /// Publishes the blocks persisted since the last poll, returning their number.
+    pub fn poll(&mut self) -> ProviderResult<usize> {
+        let database = self.provider.database_provider_ro()?;
+        let best = database.best_block_number()?;
+        if best == self.head.number() && database.block_hash(best)? == Some(self.head.hash()) {
+            return Ok(0)
+        }
+
+        if best < self.head.number() ||
+            database.block_hash(self.head.number())? != Some(self.head.hash())
+        {
+            // The database was unwound, e.g. with `reth stage unwind`. The blocks removed from it
+            // can't be read anymore, so the subscribers are not notified of the revert.
+            let head = database
+                .sealed_header(best)?
+                .ok_or_else(|| ProviderError::HeaderNotFound(best.into()))?;
+            warn!(target: "reth::cli", from = self.head.number(), to = best, "Database was unwound, resetting the canonical head");
+            self.set_head(&database, head)?;
+            return Ok(0)
+        }
+
+        let first = self.head.number() + 1;
+        let blocks = database.sealed_block_with_senders_range(first..=best)?;
+        let mut receipts = Vec::with_capacity(blocks.len());
+        for block in &blocks {
+            receipts.push(database.receipts_by_block(block.number().into())?.unwrap_or_default());
+        }
+        let Some(tip) = blocks.last() else { return Ok(0) };
+        let head = tip.clone_sealed_header();
+        let count = blocks.len();
+
+        // The state changes are not needed by the RPC subscribers, only the receipts
+        let chain = Chain::new(
+            blocks,
+            ExecutionOutcome::new(Default::default(), receipts, first, Vec::new()),
+            None,
+        );
+        self.set_head(&database, head)?;
+        self.provider
+            .canonical_in_memory_state()
+            .notify_canon_state(CanonStateNotification::Commit { new: Arc::new(chain) });
+
+        debug!(target: "reth::cli", head = best, count, "Published new canonical blocks");
+        Ok(count)
+    }
+
+    fn set_head<P>(&mut self, database: &P, head: SealedHeader<HeaderTy<N>>) -> ProviderResult<()>
+    where
+        P: ChainStateBlockReader + HeaderProvider<Header = HeaderTy<N>>,
+    {
+        if let Some(number) = database.last_finalized_block_number()? {
+            if let Some(header) = database.sealed_header(number)? {
+                self.provider.set_finalized(header);
+            }
+        }
+        if let Some(number) = database.last_safe_block_number()? {
+            if let Some(header) = database.sealed_header(number)? {
+                self.provider.set_safe(header);
+            }
+        }
+        self.provider.set_canonical_head(head.clone());
+        self.head = head;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use reth_primitives::SealedBlock;
+    use reth_provider::{
+        test_utils::{create_test_provider_factory, MockNodeTypesWithDB},
+        CanonStateSubscriptions, ProviderFactory, StageCheckpointWriter,
+    };
+    use reth_stages::{StageCheckpoint, StageId};
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+    use std::sync::mpsc;
+
+    fn insert_blocks(factory: &ProviderFactory<MockNodeTypesWithDB>, blocks: &[SealedBlock]) {
+        let provider_rw = factory.provider_rw().unwrap();
+        for block in blocks {
+            provider_rw.insert_historical_block(block.clone().try_recover().unwrap()).unwrap();
+        }
+        let tip = blocks.last().unwrap().number;
+        provider_rw.save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(tip)).unwrap();
+        provider_rw.commit().unwrap();
+    }
+
+    #[test]
+    fn follows_blocks_persisted_by_concurrent_writer() {
+        let mut rng = generators::rng();
+        let blocks = random_block_range(
+            &mut rng,
+            0..=30,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 0..1, ..Default::default() },
+        );
+
+        let factory = create_test_provider_factory();
+        insert_blocks(&factory, &blocks[..=10]);
+        let provider = BlockchainProvider::new(factory.clone()).unwrap();
+        let mut notifications = provider.subscribe_to_canonical_state();
+        let mut follower = CanonicalHeadFollower::new(provider.clone()).unwrap();
+        assert_eq!(follower.head().number, 10);
+        assert_eq!(follower.poll().unwrap(), 0);
+
+        // The writer persists the blocks one by one while the follower polls
+        let (done_tx, done_rx) = mpsc::channel();
+        let writer_blocks = blocks.clone();
+        let writer = std::thread::spawn(move || {
+            for block in &writer_blocks[11..] {
+                insert_blocks(&factory, std::slice::from_ref(block));
+            }
+            done_tx.send(()).unwrap();
+        });
+        loop {
+            let done = done_rx.try_recv().is_ok();
+            follower.poll().unwrap();
+            if done {
+                break
+            }
+        }
+        writer.join().unwrap();
+
+        assert_eq!(follower.head().hash(), blocks[30].hash());
+        assert_eq!(provider.best_block_number().unwrap(), 30);
+        assert_eq!(provider.block_hash(30).unwrap(), Some(blocks[30].hash()));
+
+        // Every block is published exactly once, in order
+        let mut published = Vec::new();
+        while let Ok(notification) = notifications.try_recv() {
+            published.extend(notification.committed().blocks().keys().copied());
+        }
+        assert_eq!(published, (11..=30).collect::<Vec<_>>());
+    }
+}
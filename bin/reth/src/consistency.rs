@@ -0,0 +1,367 @@
+//! Startup check that the database follows the same chain as the ingest sources.
+
+use std::path::Path;
+
+use alloy_consensus::{BlockHeader, Transaction};
+use reth_chainspec::EthChainSpec;
+use reth_hyperliquid_types::archive::{read_archive_block, EvmBlock};
+use reth_primitives::SealedBlock;
+use reth_provider::{BlockHashReader, StageCheckpointReader};
+use reth_stages::StageId;
+use tracing::{info, warn};
+
+use crate::block_ingest::{highest_archive_height, latest_local_blocks};
+
+/// Returns the archived block at `height`, if present.
+fn archive_block(ingest_dir: &Path, height: u64) -> Option<SealedBlock> {
+    let EvmBlock::Reth115(block) = read_archive_block(ingest_dir, height)?.block;
+    Some(block)
+}
+
+/// Checks that the blocks of the database match the blocks of the ingest sources, the archive at
+/// `ingest_dir` and the local hl-node output at `local_ingest_dir`.
+///
+/// This runs before the node is launched, so that it refuses to ingest blocks on top of a
+/// foreign chain.
+pub(crate) fn check_ingest_consistency<P>(
+    provider: &P,
+    chain_spec: &impl EthChainSpec,
+    ingest_dir: &Path,
+    local_ingest_dir: Option<&Path>,
+) -> eyre::Result<()>
+where
+    P: BlockHashReader + StageCheckpointReader,
+{
+    check_archive_consistency(provider, chain_spec, ingest_dir, |height| {
+        archive_block(ingest_dir, height)
+    })?;
+    if let Some(local_ingest_dir) = local_ingest_dir {
+        check_local_consistency(provider, chain_spec, local_ingest_dir)?;
+    }
+    Ok(())
+}
+
+/// Checks that the blocks of the database match the blocks of the archive at `ingest_dir`, read
+/// with `archive_block`.
+///
+/// The genesis and chain id of the archive are checked against the chain spec, then the hash
+/// of the database head and of heights sampled at exponentially growing distances below it are
+/// compared with the archived blocks. On a mismatch, the first divergent height is found by
+/// bisecting between the highest matching and the lowest mismatching sample, and an error
+/// describing it is returned.
+fn check_archive_consistency<P>(
+    provider: &P,
+    chain_spec: &impl EthChainSpec,
+    ingest_dir: &Path,
+    archive_block: impl Fn(u64) -> Option<SealedBlock>,
+) -> eyre::Result<()>
+where
+    P: BlockHashReader + StageCheckpointReader,
+{
+    let genesis_hash = chain_spec.genesis_hash();
+    if let Some(block) = archive_block(1) {
+        if block.parent_hash() != genesis_hash {
+            eyre::bail!(
+                "the archive at {} does not follow the genesis of chain {}: block 1 has parent \
+                 {}, expected {genesis_hash}. Is `--ingest-dir` pointing at the archive of \
+                 another network?",
+                ingest_dir.display(),
+                chain_spec.chain_id(),
+                block.parent_hash()
+            )
+        }
+    }
+
+    let head = provider.get_stage_checkpoint(StageId::Finish)?.unwrap_or_default().block_number;
+    if head == 0 {
+        return Ok(())
+    }
+
+    // The head, then heights at exponentially growing distances below it
+    let mut samples = vec![head];
+    let mut distance = 1;
+    while distance < head {
+        samples.push(head - distance);
+        distance *= 2;
+    }
+
+    let mut matching = None;
+    let mut diverging = None;
+    let mut checked_chain_id = false;
+    let mut head_archived = false;
+    for &height in &samples {
+        let Some(block) = archive_block(height) else { continue };
+        head_archived |= height == head;
+
+        if !checked_chain_id {
+            if let Some(chain_id) = block.body().transactions.iter().find_map(|tx| tx.chain_id()) {
+                if chain_id != chain_spec.chain_id() {
+                    eyre::bail!(
+                        "the archive at {} holds transactions of chain {chain_id}, but the node \
+                         runs chain {}. Is `--ingest-dir` pointing at the archive of another \
+                         network?",
+                        ingest_dir.display(),
+                        chain_spec.chain_id()
+                    )
+                }
+                checked_chain_id = true;
+            }
+        }
+
+        if provider.block_hash(height)? == Some(block.hash()) {
+            matching = Some(height);
+            break
+        }
+        diverging = Some(height);
+    }
+
+    if !head_archived {
+        warn!(
+            target: "reth::cli",
+            head,
+            archive_height = ?highest_archive_height(ingest_dir),
+            "Database head is ahead of the archive, only the archived samples are checked"
+        );
+    }
+
+    let Some(mut high) = diverging else {
+        match matching {
+            Some(height) => {
+                info!(target: "reth::cli", head, height, "Database matches the archive")
+            }
+            None => {
+                warn!(target: "reth::cli", head, "No sampled block found in the archive, skipping the consistency check")
+            }
+        }
+        return Ok(())
+    };
+
+    // Block hashes commit to their parents, so every block below a matching one matches too
+    let mut low = matching.unwrap_or(0);
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        let Some(block) = archive_block(mid) else { break };
+        if provider.block_hash(mid)? == Some(block.hash()) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    let divergence = if high - low > 1 {
+        format!("between blocks {} and {high}", low + 1)
+    } else {
+        format!("at block {high}")
+    };
+    eyre::bail!(
+        "the database diverges from the archive {} {divergence} (database head {head}). \
+         Point `--ingest-dir` at the archive of this chain, or unwind the database below the \
+         divergence with `reth stage unwind to-block {low}` and restart. Pass \
+         `--skip-consistency-check` to start anyway",
+        ingest_dir.display()
+    )
+}
+
+/// Checks that the blocks of the most recent hourly file of the local hl-node output at
+/// `local_ingest_dir` match the database.
+///
+/// Block hashes commit to their parents, so it is enough to compare the highest local block at or
+/// below the database head, and to check that the local block above the head extends it.
+fn check_local_consistency<P>(
+    provider: &P,
+    chain_spec: &impl EthChainSpec,
+    local_ingest_dir: &Path,
+) -> eyre::Result<()>
+where
+    P: BlockHashReader + StageCheckpointReader,
+{
+    let Some(blocks) = latest_local_blocks(local_ingest_dir) else {
+        warn!(
+            target: "reth::cli",
+            dir = %local_ingest_dir.display(),
+            "No local hl-node output found, skipping its consistency check"
+        );
+        return Ok(())
+    };
+
+    let head = provider.get_stage_checkpoint(StageId::Finish)?.unwrap_or_default().block_number;
+    let mut below_head = None;
+    let mut above_head = None;
+    for block in blocks {
+        let EvmBlock::Reth115(block) = block.block;
+        if let Some(chain_id) = block.body().transactions.iter().find_map(|tx| tx.chain_id()) {
+            if chain_id != chain_spec.chain_id() {
+                eyre::bail!(
+                    "the local hl-node output at {} holds transactions of chain {chain_id}, but \
+                     the node runs chain {}. Is `--local-ingest-dir` pointing at the output of a \
+                     node of another network?",
+                    local_ingest_dir.display(),
+                    chain_spec.chain_id()
+                )
+            }
+        }
+
+        let number = block.header().number();
+        if number <= head {
+            if below_head.as_ref().is_none_or(|(height, _)| *height < number) {
+                below_head = Some((number, block.hash()));
+            }
+        } else if number == head + 1 {
+            above_head = Some(block.parent_hash());
+        }
+    }
+
+    if let Some((height, hash)) = below_head {
+        if provider.block_hash(height)? != Some(hash) {
+            eyre::bail!(
+                "the database diverges from the local hl-node output at {} at or below block \
+                 {height} (database head {head}). Point `--local-ingest-dir` at the output of a \
+                 node of this chain, or unwind the database below the divergence and restart. \
+                 Pass `--skip-consistency-check` to start anyway",
+                local_ingest_dir.display()
+            )
+        }
+    }
+    if let Some(parent_hash) = above_head {
+        if head > 0 && provider.block_hash(head)? != Some(parent_hash) {
+            eyre::bail!(
+                "block {} of the local hl-node output at {} does not extend the database head \
+                 {head}. Point `--local-ingest-dir` at the output of a node of this chain, or \
+                 pass `--skip-consistency-check` to start anyway",
+                head + 1,
+                local_ingest_dir.display()
+            )
+        }
+    }
+
+    info!(target: "reth::cli", head, "Database matches the local hl-node output");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use reth_chainspec::{ChainSpec, MAINNET, SEPOLIA};
+    use reth_provider::{
+        test_utils::{create_test_provider_factory, MockNodeTypesWithDB},
+        ProviderFactory, StageCheckpointWriter,
+    };
+    use reth_stages::StageCheckpoint;
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams, Rng};
+    use std::{
+        collections::BTreeMap,
+        ops::{Range, RangeInclusive},
+    };
+
+    const HEAD: u64 = 100;
+
+    /// Random blocks in `range` on top of `parent`.
+    fn blocks(
+        rng: &mut impl Rng,
+        range: RangeInclusive<u64>,
+        parent: B256,
+        tx_count: Range<u8>,
+    ) -> Vec<SealedBlock> {
+        random_block_range(
+            rng,
+            range,
+            BlockRangeParams { parent: Some(parent), tx_count, ..Default::default() },
+        )
+    }
+
+    /// Creates a database holding `blocks`, with the last one as the head.
+    fn database(blocks: &[SealedBlock]) -> ProviderFactory<MockNodeTypesWithDB> {
+        let factory = create_test_provider_factory();
+        let provider_rw = factory.provider_rw().unwrap();
+        for block in blocks {
+            provider_rw.insert_historical_block(block.clone().try_recover().unwrap()).unwrap();
+        }
+        let head = blocks.last().unwrap().number;
+        provider_rw.save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(head)).unwrap();
+        provider_rw.commit().unwrap();
+        factory
+    }
+
+    /// Checks the database against an archive holding `archived`.
+    fn check(
+        factory: &ProviderFactory<MockNodeTypesWithDB>,
+        chain_spec: &ChainSpec,
+        archived: &[SealedBlock],
+    ) -> eyre::Result<()> {
+        let archive: BTreeMap<_, _> =
+            archived.iter().map(|block| (block.number, block.clone())).collect();
+        let provider = factory.provider().unwrap();
+        check_archive_consistency(&provider, chain_spec, Path::new("archive"), |height| {
+            archive.get(&height).cloned()
+        })
+    }
+
+    /// Blocks `1..=HEAD` of the database, and of an archive that diverges from it at `divergence`.
+    fn diverging_chains(divergence: u64) -> (Vec<SealedBlock>, Vec<SealedBlock>) {
+        let mut rng = generators::rng();
+        let local = blocks(&mut rng, 1..=HEAD, MAINNET.genesis_hash(), 0..1);
+        let common = divergence as usize - 1;
+        let parent = common.checked_sub(1).map_or(MAINNET.genesis_hash(), |i| local[i].hash());
+        let mut archived = local[..common].to_vec();
+        archived.extend(blocks(&mut rng, divergence..=HEAD, parent, 0..1));
+        (local, archived)
+    }
+
+    #[test]
+    fn matching_archive() {
+        let (local, _) = diverging_chains(HEAD);
+        let factory = database(&local);
+        check(&factory, &MAINNET, &local).unwrap();
+        // The archive may lag behind the database
+        check(&factory, &MAINNET, &local[..50]).unwrap();
+    }
+
+    #[test]
+    fn finds_first_divergent_block() {
+        for divergence in [1, 2, 37, 61, 99, HEAD] {
+            let (local, archived) = diverging_chains(divergence);
+            let err = check(&database(&local), &MAINNET, &archived).unwrap_err().to_string();
+            assert!(
+                err.contains(&format!("at block {divergence} (database head {HEAD})")),
+                "divergence at {divergence}: {err}"
+            );
+            assert!(err.contains(&format!("to-block {}", divergence - 1)), "{err}");
+        }
+    }
+
+    #[test]
+    fn bisection_stops_at_archive_gap() {
+        let (local, mut archived) = diverging_chains(61);
+        archived.retain(|block| !(50..=65).contains(&block.number));
+        let err = check(&database(&local), &MAINNET, &archived).unwrap_err().to_string();
+        // Samples 36 and 68 bracket the divergence, the bisection can't read block 52
+        assert!(err.contains("between blocks 37 and 68"), "{err}");
+    }
+
+    #[test]
+    fn detects_foreign_genesis() {
+        let mut rng = generators::rng();
+        let local = blocks(&mut rng, 1..=HEAD, MAINNET.genesis_hash(), 0..1);
+        let archived = blocks(&mut rng, 1..=HEAD, B256::repeat_byte(0x11), 0..1);
+        let err = check(&database(&local), &MAINNET, &archived).unwrap_err().to_string();
+        assert!(err.contains("does not follow the genesis of chain 1"), "{err}");
+    }
+
+    #[test]
+    fn detects_foreign_chain_id() {
+        let mut rng = generators::rng();
+        // Random transactions are signed for chain 1
+        let local = blocks(&mut rng, 1..=HEAD, SEPOLIA.genesis_hash(), 1..3);
+        let factory = database(&local);
+        let err = check(&factory, &SEPOLIA, &local).unwrap_err().to_string();
+        assert!(
+            err.contains(&format!(
+                "holds transactions of chain 1, but the node runs chain {}",
+                SEPOLIA.chain_id()
+            )),
+            "{err}"
+        );
+        check(&factory, &MAINNET, &local[1..]).unwrap();
+    }
+}
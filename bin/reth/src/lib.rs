@@ -29,6 +29,7 @@
 
 pub mod cli;
 pub mod commands;
+pub mod spot_meta;
 
 /// Re-exported utils.
 pub mod utils {
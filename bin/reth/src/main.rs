@@ -5,21 +5,28 @@ static ALLOC: reth_cli_util::allocator::Allocator = reth_cli_util::allocator::ne
 
 mod block_ingest;
 mod call_forwarder;
-mod serialized;
-mod spot_meta;
+mod consistency;
 mod tx_forwarder;
 
-use std::{collections::BTreeMap, path::PathBuf, sync::Arc};
+use std::{collections::BTreeMap, net::SocketAddr, path::PathBuf, sync::Arc};
 
-use block_ingest::BlockIngest;
+use block_ingest::{BlockIngest, IngestMetrics};
 use call_forwarder::CallForwarderApiServer;
 use clap::{Args, Parser};
+use consistency::check_ingest_consistency;
 use reth::cli::Cli;
 use reth_ethereum_cli::chainspec::EthereumChainSpecParser;
+use reth_exex_parquet::{ParquetExEx, PartitionWriter};
+use reth_exex_stream::{ExExStream, ReadPrecompileCallsSource, StreamConfig};
 use reth_hyperliquid_types::PrecompilesCache;
-use reth_node_ethereum::EthereumNode;
+use reth_node_builder::components::NoopNetworkBuilder;
+use reth_node_ethereum::{evm::RecentReadPrecompileCalls, node::EthereumAddOns, EthereumNode};
+use reth_provider::{
+    providers::{NodeTypesWithDBAdapter, StaticFileProvider},
+    ProviderFactory,
+};
 use tokio::sync::Mutex;
-use tracing::info;
+use tracing::{error, info, warn};
 use tx_forwarder::EthForwarderApiServer;
 
 #[derive(Args, Debug, Clone)]
@@ -40,6 +47,34 @@ struct HyperliquidExtArgs {
     /// 3. filters out logs and transactions from subscription.
     #[arg(long, default_value = "false")]
     pub hl_node_compliant: bool,
+
+    /// Skip the startup check that the database head and sampled earlier blocks match the
+    /// blocks of the archive at the same heights.
+    #[arg(long)]
+    pub skip_consistency_check: bool,
+
+    /// Serve the canonical chain notifications to external indexers over WebSocket on the given
+    /// address, with the `exex_subscribe` subscription.
+    #[arg(long = "exex-stream.ws-addr", value_name = "ADDR")]
+    pub exex_stream_ws_addr: Option<SocketAddr>,
+
+    /// Serve the canonical chain notifications to external indexers over IPC at the given path,
+    /// with the `exex_subscribe` subscription.
+    #[arg(long = "exex-stream.ipc-path", value_name = "PATH")]
+    pub exex_stream_ipc_path: Option<String>,
+
+    /// Number of blocks below the tip kept in the stream WAL, that subscribers can resume from.
+    #[arg(long = "exex-stream.retention-blocks", default_value_t = 100_000)]
+    pub exex_stream_retention_blocks: u64,
+
+    /// Export the committed blocks, transactions, logs and ERC20 transfers as Parquet files to
+    /// the given directory. The history is exported by `reth export-parquet`.
+    #[arg(long = "exex-parquet.dir", value_name = "DIR")]
+    pub exex_parquet_dir: Option<PathBuf>,
+
+    /// Number of blocks of a Parquet partition.
+    #[arg(long = "exex-parquet.partition-size", default_value_t = 10_000)]
+    pub exex_parquet_partition_size: u64,
 }
 
 fn main() {
@@ -52,6 +87,8 @@ fn main() {
 
     let precompiles_cache = PrecompilesCache::new(parking_lot::Mutex::new(BTreeMap::new()));
     let local_blocks_cache = Arc::new(Mutex::new(BTreeMap::new()));
+    // Serves the read precompile calls of `eth_call` that are not recorded by their block
+    let recent_precompile_calls = Arc::new(RecentReadPrecompileCalls::default());
 
     if let Err(err) = Cli::<EthereumChainSpecParser, HyperliquidExtArgs>::parse().run(
         |builder, ext_args| async move {
@@ -62,15 +99,70 @@ fn main() {
 
             let ingest_dir = builder.config().ingest_dir.clone().expect("ingest dir not set");
             let local_ingest_dir = builder.config().local_ingest_dir.clone();
+            let stream_precompile_calls = (ext_args.exex_stream_ws_addr.is_some() ||
+                ext_args.exex_stream_ipc_path.is_some())
+            .then(PrecompilesCache::default);
+            let stream_config = stream_precompile_calls.clone().map(|calls| StreamConfig {
+                ws_addr: ext_args.exex_stream_ws_addr,
+                ipc_path: ext_args.exex_stream_ipc_path,
+                wal_dir: builder.config().datadir().exex_stream(),
+                retention_blocks: ext_args.exex_stream_retention_blocks,
+                calls: ReadPrecompileCallsSource::new(calls, Some(ingest_dir.clone())),
+            });
+
+            // Checked before launching, so that the engine doesn't start persisting blocks on
+            // top of a chain that differs from the ingest sources
+            if ext_args.skip_consistency_check {
+                warn!(target: "reth::cli", "Skipping the ingest consistency check");
+            } else {
+                let static_files = builder.config().datadir().static_files();
+                reth_fs_util::create_dir_all(&static_files)?;
+                let provider_factory =
+                    ProviderFactory::<NodeTypesWithDBAdapter<EthereumNode, _>>::new(
+                        builder.db().clone(),
+                        builder.config().chain.clone(),
+                        StaticFileProvider::read_only(static_files, false)?,
+                    );
+                check_ingest_consistency(
+                    &provider_factory.provider()?,
+                    builder.config().chain.as_ref(),
+                    &ingest_dir,
+                    local_ingest_dir.as_deref(),
+                )?;
+            }
+
             info!(target: "reth::cli", "Launching node");
-            let handle = builder
-                .node(EthereumNode::default())
+            // blocks are only ever ingested through the engine API, so the devp2p stack is
+            // replaced by a no-op network
+            let mut builder = builder
+                .with_types::<EthereumNode>()
+                .with_components(EthereumNode::components().network(NoopNetworkBuilder::default()))
+                .with_add_ons(EthereumAddOns::default())
                 .add_precompiles_cache(precompiles_cache.clone())
+                .add_read_precompile_provider(recent_precompile_calls.clone());
+            if let Some(config) = stream_config {
+                info!(target: "reth::cli", "Streaming ExEx enabled");
+                builder = builder.install_exex("stream", move |ctx| async move {
+                    Ok(ExExStream::new(ctx, config).await?.run())
+                });
+            }
+            if let Some(dir) = ext_args.exex_parquet_dir.clone() {
+                info!(target: "reth::cli", dir = %dir.display(), "Parquet export ExEx enabled");
+                let writer = PartitionWriter::new(dir, ext_args.exex_parquet_partition_size);
+                builder = builder.install_exex("parquet", move |ctx| async move {
+                    Ok(ParquetExEx::new(ctx, writer)?.run())
+                });
+            }
+            let handle = builder
                 .extend_rpc_modules(move |ctx| {
                     let upstream_rpc_url = ext_args.upstream_rpc_url;
-                    ctx.modules.replace_configured(
-                        tx_forwarder::EthForwarderExt::new(upstream_rpc_url.clone()).into_rpc(),
-                    )?;
+                    let tx_forwarder = tx_forwarder::EthForwarderExt::new(upstream_rpc_url.clone());
+                    ctx.modules.replace_configured(tx_forwarder.clone().into_rpc())?;
+                    if let Some(graphql) =
+                        ctx.registry.graphql_module_with_tx_forwarder(Arc::new(tx_forwarder))
+                    {
+                        ctx.modules.replace_http(graphql)?;
+                    }
 
                     if ext_args.forward_call {
                         ctx.modules.replace_configured(
@@ -85,9 +177,20 @@ fn main() {
                 .launch()
                 .await?;
 
-            let ingest =
-                BlockIngest { ingest_dir, local_ingest_dir, local_blocks_cache, precompiles_cache };
-            ingest.run(handle.node).await.unwrap();
+            let ingest = BlockIngest {
+                ingest_dir,
+                local_ingest_dir,
+                local_blocks_cache,
+                precompiles_cache,
+                stream_precompile_calls,
+                recent_precompile_calls,
+                metrics: IngestMetrics::default(),
+            };
+            if let Err(err) = ingest.run(handle.node).await {
+                // e.g. a block rejected by the engine, recorded by the `bundle` invalid block hook
+                error!(target: "reth::cli", %err, "Block ingest failed, shutting down");
+                return Err(eyre::eyre!("block ingest failed: {err}"))
+            }
             handle.node_exit_future.await
         },
     ) {
@@ -1,5 +1,8 @@
-use alloy_primitives::{Address, U256};
+//! Spot token metadata of HyperCore, used to sign the system transactions of ingested blocks.
+
+use alloy_primitives::{Address, PrimitiveSignature, TxKind, U256};
 use eyre::{Error, Result};
+use reth_primitives::Transaction;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
@@ -23,12 +26,17 @@ struct SpotMeta {
     tokens: Vec<SpotToken>,
 }
 
-pub(crate) struct SpotId {
+/// Index of a HyperCore spot token.
+#[derive(Debug, Clone, Copy)]
+pub struct SpotId {
+    /// The token index.
     pub index: u64,
 }
 
 impl SpotId {
-    pub(crate) fn to_s(&self) -> U256 {
+    /// Returns the system address of the token, which sends its system transactions, as the `s`
+    /// value of their signature.
+    pub fn to_s(&self) -> U256 {
         let mut addr = [0u8; 32];
         addr[12] = 0x20;
         addr[24..32].copy_from_slice(self.index.to_be_bytes().as_ref());
@@ -47,7 +55,8 @@ async fn fetch_spot_meta(chain_id: u64) -> Result<SpotMeta> {
     Ok(response.json().await?)
 }
 
-pub(crate) async fn erc20_contract_to_spot_token(
+/// Fetches the spot tokens of the chain, keyed by their ERC20 contract.
+pub async fn erc20_contract_to_spot_token(
     chain_id: u64,
 ) -> Result<BTreeMap<Address, SpotId>> {
     let meta = fetch_spot_meta(chain_id).await?;
@@ -59,3 +68,28 @@ pub(crate) async fn erc20_contract_to_spot_token(
     }
     Ok(map)
 }
+
+/// Returns the signature of a system transaction, encoding its sender in `s`.
+///
+/// Native token transfers, with an empty input, are sent by
+/// `0x2222222222222222222222222222222222222222`, spot token transfers by the system address of
+/// the token. Returns `None` if the token contract is missing from `spot_map`.
+pub fn system_tx_signature(
+    tx: &Transaction,
+    spot_map: &BTreeMap<Address, SpotId>,
+) -> Result<Option<PrimitiveSignature>> {
+    let Transaction::Legacy(tx) = tx else {
+        return Err(Error::msg("unexpected system transaction type"));
+    };
+    let TxKind::Call(to) = tx.to else {
+        return Err(Error::msg("unexpected system contract creation"));
+    };
+    let s = if tx.input.is_empty() {
+        U256::from(0x1)
+    } else {
+        let Some(spot) = spot_map.get(&to) else { return Ok(None) };
+        spot.to_s()
+    };
+    // from anvil
+    Ok(Some(PrimitiveSignature::new(U256::from(0x1), s, true)))
+}
@@ -5,6 +5,7 @@ use jsonrpsee::{
     types::{error::INTERNAL_ERROR_CODE, ErrorObject},
 };
 use jsonrpsee_core::{async_trait, client::ClientT, ClientError, RpcResult};
+use reth_rpc_graphql::RawTransactionForwarder;
 
 #[rpc(server, namespace = "eth")]
 pub(crate) trait EthForwarderApi {
@@ -12,6 +13,7 @@ pub(crate) trait EthForwarderApi {
     async fn send_raw_transaction(&self, tx: Bytes) -> RpcResult<B256>;
 }
 
+#[derive(Clone)]
 pub(crate) struct EthForwarderExt {
     client: HttpClient,
 }
@@ -28,6 +30,13 @@ impl EthForwarderExt {
 #[async_trait]
 impl EthForwarderApiServer for EthForwarderExt {
     async fn send_raw_transaction(&self, tx: Bytes) -> RpcResult<B256> {
+        self.forward_raw_transaction(tx).await
+    }
+}
+
+#[async_trait]
+impl RawTransactionForwarder for EthForwarderExt {
+    async fn forward_raw_transaction(&self, tx: Bytes) -> RpcResult<B256> {
         let txhash =
             self.client.clone().request("eth_sendRawTransaction", vec![tx]).await.map_err(|e| {
                 match e {
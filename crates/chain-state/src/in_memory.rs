@@ -6,10 +6,10 @@ use crate::{
 };
 use alloy_consensus::{transaction::TransactionMeta, BlockHeader};
 use alloy_eips::{eip2718::Encodable2718, BlockHashOrNumber, BlockNumHash};
-use alloy_primitives::{map::HashMap, TxHash, B256};
+use alloy_primitives::{map::HashMap, Address, TxHash, B256};
 use parking_lot::RwLock;
 use reth_chainspec::ChainInfo;
-use reth_execution_types::{Chain, ExecutionOutcome};
+use reth_execution_types::{Chain, DeployedContract, ExecutionOutcome};
 use reth_metrics::{metrics::Gauge, Metrics};
 use reth_primitives::{EthPrimitives, NodePrimitives, RecoveredBlock, SealedBlock, SealedHeader};
 use reth_primitives_traits::{BlockBody as _, SignedTransaction};
@@ -769,6 +769,11 @@ pub struct ExecutedBlock<N: NodePrimitives = EthPrimitives> {
     pub execution_output: Arc<ExecutionOutcome<N::Receipt>>,
     /// Block's hashed state.
     pub hashed_state: Arc<HashedPostState>,
+    /// Accounts changed by the internal calls of each transaction of the block, found while it
+    /// was executed.
+    pub internal_accounts: Arc<Vec<Vec<Address>>>,
+    /// Contracts deployed by the block, found while it was executed.
+    pub contract_creations: Arc<Vec<DeployedContract>>,
 }
 
 impl<N: NodePrimitives> Default for ExecutedBlock<N> {
@@ -777,6 +782,8 @@ impl<N: NodePrimitives> Default for ExecutedBlock<N> {
             recovered_block: Default::default(),
             execution_output: Default::default(),
             hashed_state: Default::default(),
+            internal_accounts: Default::default(),
+            contract_creations: Default::default(),
         }
     }
 }
@@ -839,7 +846,16 @@ impl<N: NodePrimitives> ExecutedBlockWithTrieUpdates<N> {
         hashed_state: Arc<HashedPostState>,
         trie: Arc<TrieUpdates>,
     ) -> Self {
-        Self { block: ExecutedBlock { recovered_block, execution_output, hashed_state }, trie }
+        Self {
+            block: ExecutedBlock {
+                recovered_block,
+                execution_output,
+                hashed_state,
+                internal_accounts: Default::default(),
+                contract_creations: Default::default(),
+            },
+            trie,
+        }
     }
 
     /// Returns a reference to the trie updates for the block
@@ -2,9 +2,11 @@ use alloy_consensus::Header;
 use alloy_primitives::{hex, BlockHash};
 use clap::Parser;
 use reth_db::static_file::{
-    ColumnSelectorOne, ColumnSelectorTwo, HeaderWithHashMask, ReceiptMask, TransactionMask,
+    AccountChangeSetMask, ColumnSelectorOne, ColumnSelectorTwo, HeaderWithHashMask, HlSidecarMask,
+    ReceiptMask, StorageChangeSetMask, TransactionMask,
 };
 use reth_db_api::{
+    models::{HlSidecar, StaticFileAccountChangeSet, StaticFileStorageChangeSet},
     table::{Decompress, DupSort, Table},
     tables, RawKey, RawTable, Receipts, TableViewer, Transactions,
 };
@@ -73,6 +75,15 @@ impl Command {
                         (table_key::<tables::Receipts>(&key)?, <ReceiptMask<ReceiptTy<N>>>::MASK)
                     }
                     StaticFileSegment::BlockMeta => todo!(),
+                    StaticFileSegment::AccountChangeSets => {
+                        (table_key::<tables::AccountChangeSets>(&key)?, AccountChangeSetMask::MASK)
+                    }
+                    StaticFileSegment::StorageChangeSets => {
+                        (table_key::<tables::AccountChangeSets>(&key)?, StorageChangeSetMask::MASK)
+                    }
+                    StaticFileSegment::HlSidecars => {
+                        (table_key::<tables::HlSidecars>(&key)?, HlSidecarMask::MASK)
+                    }
                 };
 
                 let content = tool.provider_factory.static_file_provider().find_static_file(
@@ -117,6 +128,22 @@ impl Command {
                                 StaticFileSegment::BlockMeta => {
                                     todo!()
                                 }
+                                StaticFileSegment::AccountChangeSets => {
+                                    let changeset = StaticFileAccountChangeSet::decompress(
+                                        content[0].as_slice(),
+                                    )?;
+                                    println!("{}", serde_json::to_string_pretty(&changeset)?);
+                                }
+                                StaticFileSegment::StorageChangeSets => {
+                                    let changeset = StaticFileStorageChangeSet::decompress(
+                                        content[0].as_slice(),
+                                    )?;
+                                    println!("{}", serde_json::to_string_pretty(&changeset)?);
+                                }
+                                StaticFileSegment::HlSidecars => {
+                                    let sidecar = HlSidecar::decompress(content[0].as_slice())?;
+                                    println!("{}", serde_json::to_string_pretty(&sidecar)?);
+                                }
                             }
                         }
                     }
@@ -0,0 +1,57 @@
+use clap::Parser;
+use reth_provider::{providers::ProviderNodeTypes, ProviderFactory};
+use reth_prune::{PruneModes, PrunerBuilder};
+use reth_static_file::StaticFileProducer;
+use tracing::info;
+
+/// Number of database entries deleted per transaction.
+const DELETE_LIMIT: usize = 1_000_000;
+
+/// The arguments for the `reth db migrate-changesets` command
+#[derive(Parser, Debug)]
+pub struct Command {}
+
+impl Command {
+    /// Execute `db migrate-changesets` command
+    ///
+    /// Copies the account and storage changesets to static files, and then deletes them from the
+    /// database in batches of [`DELETE_LIMIT`] entries. An interrupted migration is resumed by
+    /// running the command again.
+    ///
+    /// Like the node, it only moves the changesets of blocks processed by all the
+    /// [`CHANGESET_STAGES`](reth_static_file::CHANGESET_STAGES), except the latest
+    /// [`CHANGESETS_DISTANCE`](reth_static_file::CHANGESETS_DISTANCE) blocks that may still be
+    /// reorged. The node moves the changesets on its own as it follows the chain, the command
+    /// moves the backlog of an existing datadir at once.
+    pub fn execute<N: ProviderNodeTypes>(
+        self,
+        provider_factory: ProviderFactory<N>,
+        prune_modes: PruneModes,
+    ) -> eyre::Result<()> {
+        eyre::ensure!(
+            prune_modes.account_history.is_none() && prune_modes.storage_history.is_none(),
+            "account or storage history pruning is configured, changesets are not moved to static files"
+        );
+
+        info!(target: "reth::cli", "Copying changesets from database to static files...");
+        let static_file_producer = StaticFileProducer::new(provider_factory.clone(), prune_modes);
+        let Some(migrated) = static_file_producer.lock().copy_changesets_to_static_files()? else {
+            info!(target: "reth::cli", "No changesets old enough to migrate");
+            return Ok(())
+        };
+        info!(target: "reth::cli", migrated, "Copied changesets from database to static files");
+
+        // Only the static file segments are pruned, deleting nothing but data that is already in
+        // static files.
+        info!(target: "reth::cli", "Deleting changesets from database...");
+        let mut pruner = PrunerBuilder::default()
+            .delete_limit(DELETE_LIMIT)
+            .build_with_provider_factory(provider_factory);
+        while !pruner.run(migrated)?.progress.is_finished() {
+            info!(target: "reth::cli", "Deleted a batch of entries from database");
+        }
+        info!(target: "reth::cli", "Deleted changesets from database");
+
+        Ok(())
+    }
+}
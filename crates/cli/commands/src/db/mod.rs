@@ -11,6 +11,7 @@ mod clear;
 mod diff;
 mod get;
 mod list;
+mod migrate_changesets;
 mod stats;
 /// DB List TUI
 mod tui;
@@ -46,6 +47,8 @@ pub enum Subcommands {
     },
     /// Deletes all table entries
     Clear(clear::Command),
+    /// Moves the account and storage changesets from the database to static files
+    MigrateChangesets(migrate_changesets::Command),
     /// Lists current and local database versions
     Version,
     /// Returns the full database path
@@ -131,6 +134,14 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
                 let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RW)?;
                 command.execute(provider_factory)?;
             }
+            Subcommands::MigrateChangesets(command) => {
+                let Environment { config, provider_factory, .. } =
+                    self.env.init::<N>(AccessRights::RW)?;
+                command.execute(
+                    provider_factory,
+                    config.prune.map(|prune| prune.segments).unwrap_or_default(),
+                )?;
+            }
             Subcommands::Version => {
                 let local_db_version = match get_db_version(&db_path) {
                     Ok(version) => Some(version),
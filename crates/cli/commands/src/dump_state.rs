@@ -0,0 +1,123 @@
+//! Command that dumps the state at a block in the format read by `init-state`.
+
+use crate::common::{AccessRights, CliNodeTypes, Environment, EnvironmentArgs};
+use alloy_primitives::hex;
+use alloy_rlp::Encodable;
+use clap::Parser;
+use reth_chainspec::{EthChainSpec, EthereumHardforks};
+use reth_cli::chainspec::ChainSpecParser;
+use reth_db_common::dump::write_state_dump;
+use reth_node_api::NodePrimitives;
+use reth_provider::{BlockNumReader, HeaderProvider, ProviderError};
+use sha2::{Digest, Sha256};
+use std::{
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
+use tracing::info;
+
+/// Dumps the state at a block as a JSONL file that can be read by `init-state`.
+///
+/// Writes `<BLOCK>.jsonl` with the state, `<BLOCK>.rlp` with the RLP encoded header of the block
+/// and `<BLOCK>.jsonl.sha256` with the checksum of the state file to the output directory.
+#[derive(Debug, Parser)]
+pub struct DumpStateCommand<C: ChainSpecParser> {
+    #[command(flatten)]
+    env: EnvironmentArgs<C>,
+
+    /// The block to dump the state at. Defaults to the latest block.
+    ///
+    /// The account and storage history of all blocks after it must not be pruned.
+    #[arg(long, value_name = "BLOCK")]
+    block: Option<u64>,
+
+    /// The directory to write the state, header and checksum files to.
+    #[arg(long, value_name = "OUTPUT_DIR")]
+    output: PathBuf,
+}
+
+impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> DumpStateCommand<C> {
+    /// Execute the `dump-state` command
+    pub async fn execute<N>(self) -> eyre::Result<()>
+    where
+        N: CliNodeTypes<
+            ChainSpec = C::ChainSpec,
+            Primitives: NodePrimitives<BlockHeader = alloy_consensus::Header>,
+        >,
+    {
+        info!(target: "reth::cli", "Reth dump-state starting");
+
+        let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RO)?;
+        let provider = provider_factory.provider()?;
+
+        let block = match self.block {
+            Some(block) => block,
+            None => provider.last_block_number()?,
+        };
+        let header = provider
+            .sealed_header(block)?
+            .ok_or_else(|| ProviderError::HeaderNotFound(block.into()))?;
+        let total_difficulty = provider
+            .header_td_by_number(block)?
+            .ok_or_else(|| ProviderError::TotalDifficultyNotFound(block))?;
+
+        reth_fs_util::create_dir_all(&self.output)?;
+        let state_path = self.output.join(format!("{block}.jsonl"));
+        let header_path = self.output.join(format!("{block}.rlp"));
+        let checksum_path = self.output.join(format!("{block}.jsonl.sha256"));
+
+        let mut header_rlp = Vec::new();
+        header.header().encode(&mut header_rlp);
+        reth_fs_util::write(&header_path, header_rlp)?;
+
+        info!(target: "reth::cli", block, path = ?state_path, "Dumping state");
+        let mut writer = HashingWriter {
+            inner: BufWriter::new(reth_fs_util::create_file(&state_path)?),
+            hasher: Sha256::new(),
+        };
+        let summary = write_state_dump(&provider, block, &mut writer)?;
+
+        // Same format as `sha256sum`, so the file can be checked with `sha256sum -c`
+        let checksum = hex::encode(writer.hasher.finalize());
+        reth_fs_util::write(&checksum_path, format!("{checksum}  {block}.jsonl\n"))?;
+
+        let init_command = format!(
+            "init-state --without-evm --header {} --header-hash {} --total-difficulty {} {}",
+            header_path.display(),
+            header.hash(),
+            total_difficulty,
+            state_path.display(),
+        );
+        info!(target: "reth::cli",
+            block,
+            hash = %header.hash(),
+            state_root = %summary.state_root,
+            accounts = summary.accounts,
+            storage_slots = summary.storage_slots,
+            %checksum,
+            %init_command,
+            "State dumped"
+        );
+
+        Ok(())
+    }
+}
+
+/// Writer computing the checksum of everything written through it.
+#[derive(Debug)]
+struct HashingWriter<W> {
+    inner: W,
+    hasher: Sha256,
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
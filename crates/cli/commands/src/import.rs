@@ -229,7 +229,8 @@ where
                 PruneModes::default(),
             )
             .builder()
-            .disable_all_if(&StageId::STATE_REQUIRED, || disable_exec),
+            .disable_all_if(&StageId::STATE_REQUIRED, || disable_exec)
+            .disable_all_if(&StageId::OPTIONAL, || disable_exec),
         )
         .build(provider_factory, static_file_producer);
 
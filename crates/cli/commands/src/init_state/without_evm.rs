@@ -46,7 +46,8 @@ where
 
     append_first_block(provider_rw, &header, total_difficulty)?;
 
-    for stage in StageId::ALL {
+    // The dummy blocks can't be indexed, so optional stages start after them once enabled
+    for stage in StageId::ALL.into_iter().chain(StageId::OPTIONAL) {
         provider_rw.save_stage_checkpoint(stage, StageCheckpoint::new(header.number()))?;
     }
 
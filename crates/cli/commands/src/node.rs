@@ -4,7 +4,7 @@ use clap::{value_parser, Args, Parser};
 use reth_chainspec::{EthChainSpec, EthereumHardforks};
 use reth_cli::chainspec::ChainSpecParser;
 use reth_cli_runner::CliContext;
-use reth_cli_util::parse_socket_address;
+use reth_cli_util::{parse_duration_from_secs, parse_socket_address};
 use reth_db::{init_db, DatabaseEnv};
 use reth_ethereum_cli::chainspec::EthereumChainSpecParser;
 use reth_node_builder::{NodeBuilder, WithLaunchContext};
@@ -16,7 +16,10 @@ use reth_node_core::{
     node_config::NodeConfig,
     version,
 };
-use std::{ffi::OsString, fmt, future::Future, net::SocketAddr, path::PathBuf, sync::Arc};
+use std::{
+    ffi::OsString, fmt, future::Future, net::SocketAddr, path::PathBuf, sync::Arc,
+    time::Duration,
+};
 
 /// Start the node
 #[derive(Debug, Parser)]
@@ -48,6 +51,11 @@ pub struct NodeCommand<
     #[arg(long, value_name = "SOCKET", value_parser = parse_socket_address, help_heading = "Metrics")]
     pub metrics: Option<SocketAddr>,
 
+    /// Maximum lag of the head block timestamp behind the wall clock, in seconds, before the
+    /// `/health` and `/ready` endpoints of the metrics server report unhealthy.
+    #[arg(long = "metrics.max-head-lag", value_name = "SECONDS", value_parser = parse_duration_from_secs, default_value = "60", help_heading = "Metrics")]
+    pub metrics_max_head_lag: Duration,
+
     /// Add a new instance of a node.
     ///
     /// Configures the ports of the node to avoid conflicts with the defaults.
@@ -161,6 +169,7 @@ impl<
             config,
             chain,
             metrics,
+            metrics_max_head_lag,
             instance,
             with_unused_ports,
             network,
@@ -183,6 +192,7 @@ impl<
             config,
             chain,
             metrics,
+            metrics_max_head_lag,
             instance,
             network,
             rpc,
@@ -25,7 +25,7 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> PruneComma
         let static_file_producer =
             StaticFileProducer::new(provider_factory.clone(), prune_config.segments.clone());
         let lowest_static_file_height =
-            static_file_producer.lock().copy_to_static_files()?.min_block_num();
+            static_file_producer.lock().copy_to_static_files()?.min_block_num_without_changesets();
         info!(target: "reth::cli", ?lowest_static_file_height, "Copied data from database to static files");
 
         // Delete data which has been copied to static files.
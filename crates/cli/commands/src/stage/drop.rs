@@ -102,6 +102,7 @@ impl<C: ChainSpecParser> Command<C> {
                 tx.clear::<tables::StorageChangeSets>()?;
                 tx.clear::<tables::Bytecodes>()?;
                 tx.clear::<tables::Receipts<ReceiptTy<N>>>()?;
+                tx.clear::<tables::TransactionInternalAccounts>()?;
 
                 reset_prune_checkpoint(tx, PruneSegment::Receipts)?;
                 reset_prune_checkpoint(tx, PruneSegment::ContractLogs)?;
@@ -148,6 +149,34 @@ impl<C: ChainSpecParser> Command<C> {
 
                 insert_genesis_history(&provider_rw, self.env.chain.genesis().alloc.iter())?;
             }
+            StageEnum::AddressTransactions => {
+                tx.clear::<tables::AddressTransactions>()?;
+                reset_prune_checkpoint(tx, PruneSegment::AddressTransactions)?;
+
+                reset_stage_checkpoint(tx, StageId::IndexAddressTransactions)?;
+            }
+            StageEnum::LogIndex => {
+                tx.clear::<tables::LogAddressIndex>()?;
+                tx.clear::<tables::LogTopicIndex>()?;
+                reset_prune_checkpoint(tx, PruneSegment::LogIndex)?;
+
+                reset_stage_checkpoint(tx, StageId::IndexLogs)?;
+            }
+            StageEnum::TokenTransfers => {
+                tx.clear::<tables::TokenTransfers>()?;
+                tx.clear::<tables::HolderTokenTransfers>()?;
+                tx.clear::<tables::TokenBalanceDeltas>()?;
+                tx.clear::<tables::NativeTransfers>()?;
+                reset_prune_checkpoint(tx, PruneSegment::TokenTransfers)?;
+
+                reset_stage_checkpoint(tx, StageId::IndexTokenTransfers)?;
+            }
+            StageEnum::ContractCreators => {
+                tx.clear::<tables::ContractCreations>()?;
+                tx.clear::<tables::ContractCreators>()?;
+
+                reset_stage_checkpoint(tx, StageId::IndexContractCreators)?;
+            }
             StageEnum::TxLookup => {
                 tx.clear::<tables::TransactionHashNumbers>()?;
                 reset_prune_checkpoint(tx, PruneSegment::TransactionLookup)?;
@@ -182,6 +211,12 @@ fn reset_stage_checkpoint(
     tx: &Tx<reth_db::mdbx::RW>,
     stage_id: StageId,
 ) -> Result<(), DatabaseError> {
+    // Optional stages are removed rather than reset, to keep the engine from advancing them while
+    // they're disabled.
+    if StageId::OPTIONAL.contains(&stage_id) {
+        tx.delete::<tables::StageCheckpoints>(stage_id.to_string(), None)?;
+        return Ok(())
+    }
     tx.put::<tables::StageCheckpoints>(stage_id.to_string(), Default::default())?;
 
     Ok(())
@@ -40,8 +40,9 @@ use reth_provider::{
 use reth_stages::{
     stages::{
         AccountHashingStage, BodyStage, ExecutionStage, HeaderStage, IndexAccountHistoryStage,
-        IndexStorageHistoryStage, MerkleStage, SenderRecoveryStage, StorageHashingStage,
-        TransactionLookupStage,
+        IndexAddressTransactionsStage, IndexContractCreatorsStage, IndexLogsStage,
+        IndexStorageHistoryStage, IndexTokenTransfersStage, MerkleStage, SenderRecoveryStage,
+        StorageHashingStage, TransactionLookupStage,
     },
     ExecInput, ExecOutput, ExecutionStageThresholds, Stage, StageExt, UnwindInput, UnwindOutput,
 };
@@ -319,6 +320,36 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + Hardforks + EthereumHardforks>
                     )),
                     None,
                 ),
+                StageEnum::AddressTransactions => (
+                    Box::new(IndexAddressTransactionsStage::new(
+                        config.stages.index_address_transactions,
+                        etl_config,
+                        prune_modes.address_transactions,
+                    )),
+                    None,
+                ),
+                StageEnum::LogIndex => (
+                    Box::new(IndexLogsStage::new(
+                        config.stages.index_logs,
+                        etl_config,
+                        prune_modes.log_index,
+                    )),
+                    None,
+                ),
+                StageEnum::TokenTransfers => (
+                    Box::new(IndexTokenTransfersStage::new(
+                        config.stages.index_token_transfers,
+                        prune_modes.token_transfers,
+                    )),
+                    None,
+                ),
+                StageEnum::ContractCreators => (
+                    Box::new(IndexContractCreatorsStage::new(
+                        components.executor().clone(),
+                        config.stages.index_contract_creators,
+                    )),
+                    None,
+                ),
                 _ => return Ok(()),
             };
         if let Some(unwind_stage) = &unwind_stage {
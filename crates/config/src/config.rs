@@ -3,7 +3,7 @@
 use eyre::eyre;
 use reth_network_types::{PeersConfig, SessionsConfig};
 use reth_prune_types::PruneModes;
-use reth_stages_types::ExecutionStageThresholds;
+use reth_stages_types::{ExecutionStageThresholds, StageId};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::{
     ffi::OsStr,
@@ -120,6 +120,14 @@ pub struct StageConfig {
     pub index_account_history: IndexHistoryConfig,
     /// Index Storage History stage configuration.
     pub index_storage_history: IndexHistoryConfig,
+    /// Index Address Transactions stage configuration.
+    pub index_address_transactions: OptionalIndexConfig,
+    /// Index Logs stage configuration.
+    pub index_logs: OptionalIndexConfig,
+    /// Index Token Transfers stage configuration.
+    pub index_token_transfers: OptionalIndexConfig,
+    /// Index Contract Creators stage configuration.
+    pub index_contract_creators: IndexContractCreatorsConfig,
     /// Common ETL related configuration.
     pub etl: EtlConfig,
 }
@@ -135,6 +143,20 @@ impl StageConfig {
             .max(self.account_hashing.clean_threshold)
             .max(self.storage_hashing.clean_threshold)
     }
+
+    /// Returns the [`StageId::OPTIONAL`] stages that are enabled.
+    pub fn enabled_optional_stages(&self) -> Vec<StageId> {
+        StageId::OPTIONAL
+            .into_iter()
+            .filter(|stage_id| match stage_id {
+                StageId::IndexAddressTransactions => self.index_address_transactions.enabled,
+                StageId::IndexLogs => self.index_logs.enabled,
+                StageId::IndexTokenTransfers => self.index_token_transfers.enabled,
+                StageId::IndexContractCreators => self.index_contract_creators.enabled,
+                _ => false,
+            })
+            .collect()
+    }
 }
 
 /// Header stage configuration.
@@ -373,6 +395,45 @@ impl Default for IndexHistoryConfig {
     }
 }
 
+/// Optional index stage configuration.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct OptionalIndexConfig {
+    /// Whether the stage runs.
+    ///
+    /// Disabled by default, as enabling it indexes all blocks from genesis.
+    pub enabled: bool,
+    /// The maximum number of blocks to process before committing progress to the database.
+    pub commit_threshold: u64,
+}
+
+impl Default for OptionalIndexConfig {
+    fn default() -> Self {
+        Self { enabled: false, commit_threshold: 100_000 }
+    }
+}
+
+/// Contract creators index stage configuration.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct IndexContractCreatorsConfig {
+    /// Whether the stage runs.
+    ///
+    /// Disabled by default, as enabling it indexes all blocks from genesis.
+    pub enabled: bool,
+    /// The maximum number of blocks to process before committing progress to the database.
+    ///
+    /// Lower than for the other index stages, as the blocks are re-executed and the state they
+    /// touch is kept in memory until the commit.
+    pub commit_threshold: u64,
+}
+
+impl Default for IndexContractCreatorsConfig {
+    fn default() -> Self {
+        Self { enabled: false, commit_threshold: 10_000 }
+    }
+}
+
 /// Pruning configuration.
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default)]
@@ -409,6 +470,9 @@ impl PruneConfig {
                     receipts,
                     account_history,
                     storage_history,
+                    address_transactions,
+                    log_index,
+                    token_transfers,
                     receipts_log_filter,
                 },
         } = other;
@@ -424,6 +488,10 @@ impl PruneConfig {
         self.segments.receipts = self.segments.receipts.or(receipts);
         self.segments.account_history = self.segments.account_history.or(account_history);
         self.segments.storage_history = self.segments.storage_history.or(storage_history);
+        self.segments.address_transactions =
+            self.segments.address_transactions.or(address_transactions);
+        self.segments.log_index = self.segments.log_index.or(log_index);
+        self.segments.token_transfers = self.segments.token_transfers.or(token_transfers);
 
         if self.segments.receipts_log_filter.0.is_empty() && !receipts_log_filter.0.is_empty() {
             self.segments.receipts_log_filter = receipts_log_filter;
@@ -943,6 +1011,9 @@ receipts = 'full'
                 receipts: Some(PruneMode::Distance(1000)),
                 account_history: None,
                 storage_history: Some(PruneMode::Before(5000)),
+                address_transactions: None,
+                log_index: Some(PruneMode::Distance(4000)),
+                token_transfers: None,
                 receipts_log_filter: ReceiptsLogPruneConfig(BTreeMap::from([(
                     Address::random(),
                     PruneMode::Full,
@@ -958,6 +1029,9 @@ receipts = 'full'
                 receipts: Some(PruneMode::Full),
                 account_history: Some(PruneMode::Distance(2000)),
                 storage_history: Some(PruneMode::Distance(3000)),
+                address_transactions: Some(PruneMode::Full),
+                log_index: Some(PruneMode::Full),
+                token_transfers: Some(PruneMode::Distance(6000)),
                 receipts_log_filter: ReceiptsLogPruneConfig(BTreeMap::from([
                     (Address::random(), PruneMode::Distance(1000)),
                     (Address::random(), PruneMode::Before(2000)),
@@ -976,6 +1050,9 @@ receipts = 'full'
         assert_eq!(config1.segments.receipts, Some(PruneMode::Distance(1000)));
         assert_eq!(config1.segments.account_history, Some(PruneMode::Distance(2000)));
         assert_eq!(config1.segments.storage_history, Some(PruneMode::Before(5000)));
+        assert_eq!(config1.segments.address_transactions, Some(PruneMode::Full));
+        assert_eq!(config1.segments.log_index, Some(PruneMode::Distance(4000)));
+        assert_eq!(config1.segments.token_transfers, Some(PruneMode::Distance(6000)));
         assert_eq!(config1.segments.receipts_log_filter, original_filter);
     }
 
@@ -0,0 +1,289 @@
+use alloy_consensus::BlockHeader;
+use alloy_primitives::{Address, B256, U256};
+use reth_engine_primitives::InvalidBlockHook;
+use reth_evm::execute::{BlockExecutorProvider, Executor};
+use reth_hyperliquid_types::{
+    archive::{read_archive_block, BlockAndReceipts},
+    ReadPrecompileCalls, ReadPrecompileCallsReader,
+};
+use reth_primitives::{NodePrimitives, RecoveredBlock, SealedHeader};
+use reth_provider::{BlockExecutionOutput, ProviderError, StateProviderFactory};
+use reth_revm::{
+    database::StateProviderDatabase,
+    db::{CacheDB, EmptyDBTyped},
+    state::{AccountInfo, Bytecode},
+    Database,
+};
+use reth_tracing::tracing::{info, warn};
+use reth_trie::updates::TrieUpdates;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, error::Error, fs::File, io::BufWriter, path::PathBuf};
+
+/// Everything needed to re-execute a block in isolation, written by [`InvalidBlockBundleHook`]
+/// and replayed by `reth debug replay-bundle`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayBundle<B, R> {
+    /// The block as executed by the node, including the system transactions.
+    pub block: B,
+    /// The recovered senders of the block transactions.
+    pub senders: Vec<Address>,
+    /// The block as ingested from hl-node, along with its receipts and read precompile calls.
+    /// `None` if it was not found in the ingest directory.
+    pub ingested: Option<BlockAndReceipts>,
+    /// The state read by the execution of the block, as of its parent block.
+    pub pre_state: PreState,
+    /// The receipts produced by the node. Empty if the node failed to execute the block.
+    pub receipts: Vec<R>,
+    /// The state root computed by the node, if the block failed the state root check.
+    pub state_root: Option<B256>,
+}
+
+impl<B, R> ReplayBundle<B, R> {
+    /// Returns the read precompile calls recorded by hl-node for the block.
+    pub fn read_precompile_calls(&self) -> BundleReadPrecompileCalls {
+        BundleReadPrecompileCalls(
+            self.ingested.as_ref().map(|b| b.read_precompile_calls.clone()).unwrap_or_default(),
+        )
+    }
+}
+
+/// The read precompile calls of a [`ReplayBundle`].
+///
+/// They are returned for any block, since a bundle holds a single block.
+#[derive(Debug, Clone)]
+pub struct BundleReadPrecompileCalls(ReadPrecompileCalls);
+
+impl ReadPrecompileCallsReader for BundleReadPrecompileCalls {
+    fn read_precompile_calls(
+        &self,
+        _block_number: u64,
+    ) -> Result<Option<ReadPrecompileCalls>, Box<dyn Error + Send + Sync>> {
+        Ok(Some(self.0.clone()))
+    }
+}
+
+/// The state read by the execution of a block, as of its parent block.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PreState {
+    /// Accounts read during execution. `None` if the account didn't exist.
+    pub accounts: BTreeMap<Address, Option<AccountInfo>>,
+    /// Storage slots read during execution.
+    pub storage: BTreeMap<Address, BTreeMap<U256, U256>>,
+    /// Bytecodes loaded during execution, by code hash.
+    pub contracts: BTreeMap<B256, Bytecode>,
+    /// Block hashes read during execution, by block number.
+    pub block_hashes: BTreeMap<u64, B256>,
+}
+
+impl PreState {
+    /// Returns an in-memory database holding the state. Accounts and storage slots missing from
+    /// it are read as empty.
+    pub fn into_cache_db(self) -> CacheDB<EmptyDBTyped<ProviderError>> {
+        let mut db = CacheDB::new(EmptyDBTyped::default());
+        for (address, info) in self.accounts {
+            let Some(mut info) = info else { continue };
+            info.code = self.contracts.get(&info.code_hash).cloned();
+            db.insert_account_info(address, info);
+        }
+        for (address, slots) in self.storage {
+            for (slot, value) in slots {
+                db.insert_account_storage(address, slot, value)
+                    .expect("empty database is infallible");
+            }
+        }
+        db.block_hashes
+            .extend(self.block_hashes.into_iter().map(|(number, hash)| (U256::from(number), hash)));
+        db
+    }
+}
+
+/// Database recording the state read from the inner database into a [`PreState`].
+///
+/// Execution reads every value from the database at most once, as [`State`] caches it, so the
+/// first read of a value is its value before the execution.
+///
+/// [`State`]: reth_revm::db::State
+#[derive(Debug)]
+struct PreStateRecorder<DB> {
+    inner: DB,
+    pre_state: PreState,
+}
+
+impl<DB: Database> Database for PreStateRecorder<DB> {
+    type Error = DB::Error;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        let info = self.inner.basic(address)?;
+        self.pre_state.accounts.entry(address).or_insert_with(|| info.clone());
+        Ok(info)
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        let code = self.inner.code_by_hash(code_hash)?;
+        self.pre_state.contracts.entry(code_hash).or_insert_with(|| code.clone());
+        Ok(code)
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        let value = self.inner.storage(address, index)?;
+        self.pre_state.storage.entry(address).or_default().entry(index).or_insert(value);
+        Ok(value)
+    }
+
+    fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
+        let hash = self.inner.block_hash(number)?;
+        self.pre_state.block_hashes.entry(number).or_insert(hash);
+        Ok(hash)
+    }
+}
+
+/// Writes a [`ReplayBundle`] of the given block to a file, so it can be re-executed in isolation.
+#[derive(Debug)]
+pub struct InvalidBlockBundleHook<P, E> {
+    /// The provider to read the historical state and do the EVM execution.
+    provider: P,
+    /// The block executor to record the pre-state with.
+    executor: E,
+    /// The directory to write the bundles to.
+    output_directory: PathBuf,
+    /// The directory of the hl-node archive to read the ingested blocks from.
+    ingest_dir: Option<PathBuf>,
+}
+
+impl<P, E> InvalidBlockBundleHook<P, E> {
+    /// Creates a new bundle hook.
+    pub const fn new(
+        provider: P,
+        executor: E,
+        output_directory: PathBuf,
+        ingest_dir: Option<PathBuf>,
+    ) -> Self {
+        Self { provider, executor, output_directory, ingest_dir }
+    }
+}
+
+impl<P, E, N> InvalidBlockBundleHook<P, E>
+where
+    P: StateProviderFactory + Send + Sync + 'static,
+    E: BlockExecutorProvider<Primitives = N>,
+    N: NodePrimitives,
+{
+    fn on_invalid_block(
+        &self,
+        parent_header: &SealedHeader<N::BlockHeader>,
+        block: &RecoveredBlock<N::Block>,
+        output: &BlockExecutionOutput<N::Receipt>,
+        trie_updates: Option<(&TrieUpdates, B256)>,
+    ) -> eyre::Result<()> {
+        // Re-execute the block to record the state it reads. If the execution fails, the state
+        // read until the failure is enough to reproduce it.
+        let db = PreStateRecorder {
+            inner: StateProviderDatabase::new(
+                self.provider.state_by_block_hash(parent_header.hash())?,
+            ),
+            pre_state: PreState::default(),
+        };
+        let mut executor = self.executor.executor(db);
+        if let Err(err) = executor.execute_one(block) {
+            warn!(
+                target: "engine::invalid_block_hooks::bundle",
+                number = block.number(),
+                %err,
+                "Block execution failed, the bundle has the state read until the failure"
+            );
+        }
+        let pre_state = executor.into_state().database.pre_state;
+
+        let ingested =
+            self.ingest_dir.as_deref().and_then(|dir| read_archive_block(dir, block.number()));
+        if ingested.is_none() {
+            warn!(
+                target: "engine::invalid_block_hooks::bundle",
+                number = block.number(),
+                "Ingested block not found, the bundle has no read precompile calls"
+            );
+        }
+
+        let (executed_block, senders) = block.clone().split();
+        let bundle = ReplayBundle {
+            block: executed_block,
+            senders,
+            ingested,
+            pre_state,
+            receipts: output.receipts.clone(),
+            state_root: trie_updates.map(|(_, state_root)| state_root),
+        };
+
+        let path =
+            self.output_directory.join(format!("{}_{}.bundle.json", block.number(), block.hash()));
+        serde_json::to_writer(BufWriter::new(File::create(&path)?), &bundle)?;
+        info!(
+            target: "engine::invalid_block_hooks::bundle",
+            number = block.number(),
+            hash = %block.hash(),
+            path = %path.display(),
+            "Wrote replay bundle"
+        );
+
+        Ok(())
+    }
+}
+
+impl<P, E, N: NodePrimitives> InvalidBlockHook<N> for InvalidBlockBundleHook<P, E>
+where
+    P: StateProviderFactory + Send + Sync + 'static,
+    E: BlockExecutorProvider<Primitives = N>,
+{
+    fn on_invalid_block(
+        &self,
+        parent_header: &SealedHeader<N::BlockHeader>,
+        block: &RecoveredBlock<N::Block>,
+        output: &BlockExecutionOutput<N::Receipt>,
+        trie_updates: Option<(&TrieUpdates, B256)>,
+    ) {
+        if let Err(err) = self.on_invalid_block(parent_header, block, output, trie_updates) {
+            warn!(target: "engine::invalid_block_hooks::bundle", %err, "Failed to invoke hook");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_revm::DatabaseRef;
+
+    #[test]
+    fn recorded_pre_state_replays_first_reads() {
+        let address = Address::with_last_byte(1);
+        let missing = Address::with_last_byte(2);
+        let code = Bytecode::new_raw(vec![0x60, 0x00].into());
+        let info = AccountInfo {
+            balance: U256::from(7),
+            nonce: 1,
+            code_hash: code.hash_slow(),
+            code: Some(code.clone()),
+        };
+
+        let mut source = CacheDB::new(EmptyDBTyped::<ProviderError>::default());
+        source.insert_account_info(address, info.clone());
+        source.insert_account_storage(address, U256::from(1), U256::from(42)).unwrap();
+        source.block_hashes.insert(U256::from(5), B256::with_last_byte(5));
+
+        let mut recorder = PreStateRecorder { inner: source, pre_state: PreState::default() };
+        assert_eq!(recorder.basic(address).unwrap(), Some(info.clone()));
+        assert_eq!(recorder.basic(missing).unwrap(), None);
+        assert_eq!(recorder.code_by_hash(info.code_hash).unwrap(), code);
+        assert_eq!(recorder.storage(address, U256::from(1)).unwrap(), U256::from(42));
+        assert_eq!(recorder.block_hash(5).unwrap(), B256::with_last_byte(5));
+        assert_eq!(recorder.pre_state.accounts.get(&missing), Some(&None));
+
+        // The recorded state must survive the bundle file roundtrip
+        let pre_state: PreState =
+            serde_json::from_str(&serde_json::to_string(&recorder.pre_state).unwrap()).unwrap();
+        let db = pre_state.into_cache_db();
+        assert_eq!(db.basic_ref(address).unwrap(), Some(info));
+        assert_eq!(db.basic_ref(missing).unwrap(), None);
+        assert_eq!(db.storage_ref(address, U256::from(1)).unwrap(), U256::from(42));
+        assert_eq!(db.block_hash_ref(5).unwrap(), B256::with_last_byte(5));
+    }
+}
@@ -1,5 +1,7 @@
 //! Invalid block hook implementations.
 
+mod bundle;
 mod witness;
 
+pub use bundle::{BundleReadPrecompileCalls, InvalidBlockBundleHook, PreState, ReplayBundle};
 pub use witness::InvalidBlockWitnessHook;
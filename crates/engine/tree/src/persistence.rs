@@ -11,6 +11,7 @@ use reth_provider::{
 };
 use reth_prune::{PrunerError, PrunerOutput, PrunerWithFactory};
 use reth_stages_api::{MetricEvent, MetricEventsSender};
+use reth_static_file::StaticFileProducer;
 use std::{
     sync::mpsc::{Receiver, SendError, Sender},
     time::Instant,
@@ -37,6 +38,8 @@ where
     incoming: Receiver<PersistenceAction<N::Primitives>>,
     /// The pruner
     pruner: PrunerWithFactory<ProviderFactory<N>>,
+    /// Moves the changesets of blocks that can't be reorged anymore to static files
+    static_file_producer: StaticFileProducer<ProviderFactory<N>>,
     /// metrics
     metrics: PersistenceMetrics,
     /// Sender for sync metrics - we only submit sync metrics for persisted blocks
@@ -54,7 +57,16 @@ where
         pruner: PrunerWithFactory<ProviderFactory<N>>,
         sync_metrics_tx: MetricEventsSender,
     ) -> Self {
-        Self { provider, incoming, pruner, metrics: PersistenceMetrics::default(), sync_metrics_tx }
+        let static_file_producer =
+            StaticFileProducer::new(provider.clone(), provider.prune_modes().clone());
+        Self {
+            provider,
+            incoming,
+            pruner,
+            static_file_producer,
+            metrics: PersistenceMetrics::default(),
+            sync_metrics_tx,
+        }
     }
 
     /// Prunes block data before the given block hash according to the configured prune
@@ -100,6 +112,9 @@ where
                             .sync_metrics_tx
                             .send(MetricEvent::SyncHeight { height: block_number });
 
+                        // The pruner deletes the moved changesets from the database
+                        self.static_file_producer.lock().copy_changesets_to_static_files()?;
+
                         if self.pruner.is_pruning_needed(block_number) {
                             // We log `PrunerOutput` inside the `Pruner`
                             let _ = self.prune_before(block_number)?;
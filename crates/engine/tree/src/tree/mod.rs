@@ -12,7 +12,7 @@ use alloy_consensus::{transaction::Recovered, BlockHeader};
 use alloy_eips::BlockNumHash;
 use alloy_primitives::{
     keccak256,
-    map::{B256Set, HashMap, HashSet},
+    map::{AddressMap, B256Set, HashMap, HashSet},
     BlockNumber, B256, U256,
 };
 use alloy_rpc_types_engine::{
@@ -37,20 +37,22 @@ use reth_ethereum_primitives::EthPrimitives;
 use reth_evm::{
     execute::BlockExecutorProvider,
     system_calls::{NoopHook, OnStateHook},
+    touched_accounts::TouchedAccountsCollector,
     ConfigureEvm, Evm,
 };
 use reth_payload_builder::PayloadBuilderHandle;
 use reth_payload_primitives::{EngineApiMessageVersion, PayloadBuilderAttributes};
 use reth_primitives_traits::{
-    Block, GotExpected, NodePrimitives, RecoveredBlock, SealedBlock, SealedHeader,
+    Block, BlockBody, GotExpected, NodePrimitives, RecoveredBlock, SealedBlock, SealedHeader,
     SignedTransaction,
 };
 use reth_provider::{
-    providers::ConsistentDbView, BlockReader, DBProvider, DatabaseProviderFactory,
-    ExecutionOutcome, HashedPostStateProvider, ProviderError, StateCommitmentProvider,
-    StateProviderBox, StateProviderFactory, StateReader, StateRootProvider, TransactionVariant,
+    providers::ConsistentDbView, AccountReader, BlockExecutionOutput, BlockExecutionResult,
+    BlockReader, DBProvider, DatabaseProviderFactory, ExecutionOutcome, HashedPostStateProvider,
+    ProviderError, StateCommitmentProvider, StateProviderBox, StateProviderFactory, StateReader,
+    StateRootProvider, TransactionVariant,
 };
-use reth_revm::{cancelled::ManualCancel, database::StateProviderDatabase};
+use reth_revm::{cancelled::ManualCancel, database::StateProviderDatabase, db::BundleState};
 use reth_stages_api::ControlFlow;
 use reth_trie::{
     trie_cursor::InMemoryTrieCursorFactory, updates::TrieUpdates, HashedPostState,
@@ -1742,6 +1744,8 @@ where
             recovered_block: Arc::new(RecoveredBlock::new_sealed(block, senders)),
             execution_output: Arc::new(execution_output),
             hashed_state: Arc::new(hashed_state),
+            internal_accounts: Default::default(),
+            contract_creations: Default::default(),
         }))
     }
 
@@ -2507,9 +2511,22 @@ where
         }
         trace!(target: "engine::tree", block=?block_num_hash, "Executing block");
 
+        let (touched_accounts, state_hook) = TouchedAccountsCollector::new(state_hook);
         let executor = self.executor_provider.executor(StateProviderDatabase::new(&state_provider));
         let execution_start = Instant::now();
-        let output = self.metrics.executor.execute_metered(executor, &block, state_hook)?;
+        let output =
+            match self.metrics.executor.execute_metered(executor, &block, Box::new(state_hook)) {
+                Ok(output) => output,
+                Err(err) => {
+                    // call post-block hook, the block produced no output
+                    let output = BlockExecutionOutput {
+                        result: BlockExecutionResult::default(),
+                        state: BundleState::default(),
+                    };
+                    self.invalid_block_hook.on_invalid_block(&parent_block, &block, &output, None);
+                    return Err(err.into())
+                }
+            };
         let execution_time = execution_start.elapsed();
         trace!(target: "engine::tree", elapsed = ?execution_time, number=?block_num_hash.number, "Executed block");
 
@@ -2523,6 +2540,19 @@ where
         }
 
         let hashed_state = self.provider.hashed_post_state(&output.state);
+        let touched_accounts = touched_accounts.finish();
+        let internal_accounts = touched_accounts.internal_accounts(
+            block.body().transactions(),
+            block.senders(),
+            block.header().beneficiary(),
+        );
+        // The state provider is at the parent block, so it holds the nonces before the block.
+        let contract_creations = touched_accounts.contract_creations(
+            block.body().transactions(),
+            block.senders(),
+            &mut AddressMap::default(),
+            |address| Ok(state_provider.basic_account(&address)?.map_or(0, |acc| acc.nonce)),
+        )?;
 
         trace!(target: "engine::tree", block=?block_num_hash, "Calculating block state root");
         let root_time = Instant::now();
@@ -2614,6 +2644,8 @@ where
                 recovered_block: Arc::new(block),
                 execution_output: Arc::new(ExecutionOutcome::from((output, block_num_hash.number))),
                 hashed_state: Arc::new(hashed_state),
+                internal_accounts: Arc::new(internal_accounts),
+                contract_creations: Arc::new(contract_creations),
             },
             trie: Arc::new(trie_output),
         };
@@ -4249,6 +4281,8 @@ mod tests {
                     recovered_block: Arc::new(block.clone()),
                     execution_output: Arc::new(ExecutionOutcome::default()),
                     hashed_state: Arc::new(HashedPostState::default()),
+                    internal_accounts: Default::default(),
+                    contract_creations: Default::default(),
                 },
                 trie: Arc::new(TrieUpdates::default()),
             });
@@ -4261,6 +4295,8 @@ mod tests {
                     recovered_block: Arc::new(block.clone()),
                     execution_output: Arc::new(ExecutionOutcome::default()),
                     hashed_state: Arc::new(HashedPostState::default()),
+                    internal_accounts: Default::default(),
+                    contract_creations: Default::default(),
                 },
                 trie: Arc::new(TrieUpdates::default()),
             });
@@ -21,31 +21,35 @@ use alloc::sync::Arc;
 use alloy_consensus::{BlockHeader, Header};
 use alloy_evm::eth::EthEvmContext;
 pub use alloy_evm::EthEvm;
-use alloy_primitives::Address;
 use alloy_primitives::U256;
 use core::{convert::Infallible, fmt::Debug};
 use parking_lot::RwLock;
 use reth_chainspec::{ChainSpec, EthChainSpec, MAINNET};
-use reth_evm::Database;
-use reth_evm::{ConfigureEvm, ConfigureEvmEnv, EvmEnv, EvmFactory, NextBlockEnvAttributes};
-use reth_hyperliquid_types::{PrecompilesCache, ReadPrecompileInput, ReadPrecompileResult};
+use reth_evm::{
+    ConfigureEvm, ConfigureEvmEnv, Database, EvmEnv, EvmFactory, NextBlockEnvAttributes,
+    PrecompileOverridesUnsupported,
+};
+use reth_hyperliquid_types::{
+    PrecompileOverrides, PrecompilesCache, ReadPrecompileCalls, ReadPrecompileCallsReader,
+    ReadPrecompileProvider,
+};
 use reth_node_builder::HyperliquidSharedState;
-use reth_primitives::SealedBlock;
-use reth_primitives::TransactionSigned;
-use reth_revm::context::result::{EVMError, HaltReason};
-use reth_revm::handler::EthPrecompiles;
-use reth_revm::inspector::NoOpInspector;
-use reth_revm::interpreter::interpreter::EthInterpreter;
-use reth_revm::MainBuilder;
+use reth_primitives::{SealedBlock, TransactionSigned};
 use reth_revm::{
-    context::{BlockEnv, CfgEnv, TxEnv},
+    context::{
+        result::{EVMError, HaltReason},
+        BlockEnv, CfgEnv, TxEnv,
+    },
     context_interface::block::BlobExcessGasAndPrice,
+    handler::EthPrecompiles,
+    inspector::NoOpInspector,
+    interpreter::interpreter::EthInterpreter,
     specification::hardfork::SpecId,
+    Context, Inspector, MainBuilder, MainContext,
 };
-use reth_revm::{Context, Inspector, MainContext};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf};
+use tracing::warn;
 
 mod config;
 mod fix;
@@ -89,6 +93,29 @@ impl EthEvmConfig {
         self
     }
 
+    /// Resolves read precompile calls that are missing from the ingested blocks through the given
+    /// provider, e.g. for `eth_call`.
+    ///
+    /// Must not be set on the configuration executing ingested blocks, which have to replay the
+    /// recorded results only.
+    pub fn with_read_precompile_provider(
+        mut self,
+        provider: Option<Arc<dyn ReadPrecompileProvider>>,
+    ) -> Self {
+        self.evm_factory.read_precompile_provider = provider;
+        self
+    }
+
+    /// Reads the recorded read precompile calls of the blocks from the given source before falling
+    /// back to the ingest directory, e.g. from the sidecars stored by the node when tracing.
+    pub fn with_read_precompile_calls_reader(
+        mut self,
+        reader: Option<Arc<dyn ReadPrecompileCallsReader>>,
+    ) -> Self {
+        self.evm_factory.read_precompile_calls_reader = reader;
+        self
+    }
+
     /// Creates a new Ethereum EVM configuration for the ethereum mainnet.
     pub fn mainnet() -> Self {
         Self::new(MAINNET.clone())
@@ -198,7 +225,7 @@ impl ConfigureEvmEnv for EthEvmConfig {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct BlockAndReceipts {
     #[serde(default)]
-    pub read_precompile_calls: Vec<(Address, Vec<(ReadPrecompileInput, ReadPrecompileResult)>)>,
+    pub read_precompile_calls: ReadPrecompileCalls,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -212,6 +239,9 @@ pub(crate) enum EvmBlock {
 pub struct HyperliquidEvmFactory {
     ingest_dir: Option<PathBuf>,
     shared_state: Option<HyperliquidSharedState>,
+    read_precompile_provider: Option<Arc<dyn ReadPrecompileProvider>>,
+    read_precompile_calls_reader: Option<Arc<dyn ReadPrecompileCallsReader>>,
+    precompile_overrides: Option<Arc<PrecompileOverrides>>,
 }
 
 pub(crate) fn collect_s3_block(ingest_path: PathBuf, height: u64) -> Option<BlockAndReceipts> {
@@ -227,14 +257,15 @@ pub(crate) fn collect_s3_block(ingest_path: PathBuf, height: u64) -> Option<Bloc
 pub(crate) fn get_locally_sourced_precompiles_for_height(
     precompiles_cache: PrecompilesCache,
     height: u64,
-) -> Option<Vec<(Address, Vec<(ReadPrecompileInput, ReadPrecompileResult)>)>> {
+) -> Option<ReadPrecompileCalls> {
     let mut u_cache = precompiles_cache.lock();
     u_cache.remove(&height)
 }
 
 pub(crate) fn collect_block(
-    ingest_path: PathBuf,
+    ingest_path: Option<PathBuf>,
     shared_state: Option<HyperliquidSharedState>,
+    read_precompile_calls_reader: Option<&dyn ReadPrecompileCallsReader>,
     height: u64,
 ) -> Option<BlockAndReceipts> {
     // Attempt to source precompile from the cache that is shared the binary level with the block
@@ -246,8 +277,18 @@ pub(crate) fn collect_block(
             return Some(BlockAndReceipts { read_precompile_calls: calls });
         }
     }
+    // Then from the calls recorded by the node, if they are available
+    if let Some(reader) = read_precompile_calls_reader {
+        match reader.read_precompile_calls(height) {
+            Ok(Some(calls)) => return Some(BlockAndReceipts { read_precompile_calls: calls }),
+            Ok(None) => {}
+            Err(err) => {
+                warn!(target: "evm", %err, height, "Failed to read recorded precompile calls")
+            }
+        }
+    }
     // Fallback to s3 always
-    collect_s3_block(ingest_path, height)
+    collect_s3_block(ingest_path?, height)
 }
 
 impl EvmFactory<EvmEnv> for HyperliquidEvmFactory {
@@ -259,28 +300,41 @@ impl EvmFactory<EvmEnv> for HyperliquidEvmFactory {
     type Context<DB: Database> = EthEvmContext<DB>;
 
     fn create_evm<DB: Database>(&self, db: DB, input: EvmEnv) -> Self::Evm<DB, NoOpInspector> {
+        let block_number = input.block_env.number;
         let block = collect_block(
-            self.ingest_dir.clone().unwrap(),
+            self.ingest_dir.clone(),
             self.shared_state.clone(),
-            input.block_env.number,
-        )
-        .expect("Failed to collect a submitted block. If sourcing locally, make sure your local hl-node is producing blocks.");
-        let cache = block.read_precompile_calls;
+            self.read_precompile_calls_reader.as_deref(),
+            block_number,
+        );
+        let missing = block.is_none();
+        let cache = block.map(|block| block.read_precompile_calls).unwrap_or_default();
+
+        let cache: HashMap<_, HashMap<_, _>> = cache
+            .into_iter()
+            .map(|(address, calls)| (address, HashMap::from_iter(calls.into_iter())))
+            .collect();
+
+        let mut precompiles =
+            ReplayPrecompile::new(EthPrecompiles::default(), Arc::new(RwLock::new(cache)));
+        // Overridden results take precedence over the recorded ones
+        if let Some(overrides) = &self.precompile_overrides {
+            precompiles = precompiles.with_overrides(overrides.clone());
+        }
+        if let Some(provider) = &self.read_precompile_provider {
+            precompiles = precompiles.with_provider(block_number, provider.clone());
+        } else if missing {
+            // Without a provider, calls can only be replayed from the collected block, so read
+            // precompile calls fail the execution instead of returning made up results
+            precompiles = precompiles.with_missing_calls(block_number);
+        }
 
         let evm = Context::mainnet()
             .with_db(db)
             .with_cfg(input.cfg_env)
             .with_block(input.block_env)
             .build_mainnet_with_inspector(NoOpInspector {})
-            .with_precompiles(ReplayPrecompile::new(
-                EthPrecompiles::default(),
-                Arc::new(RwLock::new(
-                    cache
-                        .into_iter()
-                        .map(|(address, calls)| (address, HashMap::from_iter(calls.into_iter())))
-                        .collect(),
-                )),
-            ));
+            .with_precompiles(precompiles);
 
         EthEvm::new(evm, false)
     }
@@ -301,6 +355,15 @@ impl ConfigureEvm for EthEvmConfig {
     fn evm_factory(&self) -> &Self::EvmFactory {
         &self.evm_factory
     }
+
+    fn with_precompile_overrides(
+        &self,
+        overrides: PrecompileOverrides,
+    ) -> Result<Self, PrecompileOverridesUnsupported> {
+        let mut config = self.clone();
+        config.evm_factory.precompile_overrides = Some(Arc::new(overrides));
+        Ok(config)
+    }
 }
 
 #[cfg(test)]
@@ -479,5 +542,9 @@ mod tests {
 }
 
 mod precompile_replay;
+mod read_precompile;
+mod sidecar;
 
 pub use precompile_replay::ReplayPrecompile;
+pub use read_precompile::{RecentReadPrecompileCalls, RECENT_BLOCKS};
+pub use sidecar::SidecarReadPrecompileCalls;
@@ -1,6 +1,9 @@
 use alloy_primitives::{Address, Bytes};
 use parking_lot::RwLock;
-use reth_hyperliquid_types::{ReadPrecompileInput, ReadPrecompileResult};
+use reth_hyperliquid_types::{
+    is_read_precompile, PrecompileOverrides, ReadPrecompileInput, ReadPrecompileProvider,
+    ReadPrecompileResult,
+};
 use reth_revm::{
     context::{Cfg, ContextTr},
     handler::{EthPrecompiles, PrecompileProvider},
@@ -10,10 +13,16 @@ use reth_revm::{
 use std::{collections::HashMap, sync::Arc};
 
 /// Precompile that replays cached results.
+///
+/// Overridden results take precedence over the cached ones. Calls missing from both are resolved
+/// through the [`ReadPrecompileProvider`], if any, and fail otherwise.
 #[derive(Clone)]
 pub struct ReplayPrecompile<CTX: ContextTr> {
     precompiles: EthPrecompiles<CTX>,
     cache: Arc<RwLock<HashMap<Address, HashMap<ReadPrecompileInput, ReadPrecompileResult>>>>,
+    overrides: Option<Arc<PrecompileOverrides>>,
+    provider: Option<(u64, Arc<dyn ReadPrecompileProvider>)>,
+    missing_block: Option<u64>,
 }
 
 impl<CTX: ContextTr> std::fmt::Debug for ReplayPrecompile<CTX> {
@@ -28,7 +37,76 @@ impl<CTX: ContextTr> ReplayPrecompile<CTX> {
         precompiles: EthPrecompiles<CTX>,
         cache: Arc<RwLock<HashMap<Address, HashMap<ReadPrecompileInput, ReadPrecompileResult>>>>,
     ) -> Self {
-        Self { precompiles, cache }
+        Self { precompiles, cache, overrides: None, provider: None, missing_block: None }
+    }
+
+    /// Serves the overridden results instead of the cached ones, matching calls by their input
+    /// only.
+    pub fn with_overrides(mut self, overrides: Arc<PrecompileOverrides>) -> Self {
+        self.overrides = Some(overrides);
+        self
+    }
+
+    /// Resolves calls missing from the cache through the given provider, as of the given block.
+    pub fn with_provider(
+        mut self,
+        block_number: u64,
+        provider: Arc<dyn ReadPrecompileProvider>,
+    ) -> Self {
+        self.provider = Some((block_number, provider));
+        self
+    }
+
+    /// Marks the recorded calls of the given block as missing, so that read precompile calls
+    /// absent from the overrides fail instead of being treated as unknown precompiles.
+    pub fn with_missing_calls(mut self, block_number: u64) -> Self {
+        self.missing_block = Some(block_number);
+        self
+    }
+
+    /// Returns the result of the call, resolving it through the provider if it's neither
+    /// overridden nor cached.
+    fn resolve(
+        &self,
+        address: &Address,
+        input: &ReadPrecompileInput,
+    ) -> Result<Option<ReadPrecompileResult>, PrecompileErrors> {
+        if let Some(result) = self
+            .overrides
+            .as_ref()
+            .and_then(|overrides| overrides.get(address))
+            .and_then(|calls| calls.get(&input.input))
+        {
+            return Ok(Some(result.clone()))
+        }
+        if let Some(result) = self.cache.read().get(address).and_then(|calls| calls.get(input)) {
+            return Ok(Some(result.clone()))
+        }
+
+        let Some((block_number, provider)) = &self.provider else {
+            return match self.missing_block {
+                Some(block_number) => Err(PrecompileErrors::Fatal {
+                    msg: format!("read precompile calls of block {block_number} are missing"),
+                }),
+                None => Ok(None),
+            }
+        };
+        let result = provider
+            .read(*block_number, *address, input)
+            .map_err(|err| PrecompileErrors::Fatal { msg: err.to_string() })?;
+        self.cache.write().entry(*address).or_default().insert(input.clone(), result.clone());
+        Ok(Some(result))
+    }
+
+    /// Returns `true` if calls to the address are served as read precompile calls.
+    ///
+    /// Without a provider or missing calls, only the addresses with cached or overridden calls are
+    /// known.
+    fn contains_read_precompile(&self, address: &Address) -> bool {
+        let resolves_unknown = self.provider.is_some() || self.missing_block.is_some();
+        (resolves_unknown && is_read_precompile(address)) ||
+            self.cache.read().get(address).is_some() ||
+            self.overrides.as_ref().is_some_and(|overrides| overrides.contains_key(address))
     }
 }
 
@@ -47,8 +125,7 @@ impl<CTX: ContextTr> PrecompileProvider for ReplayPrecompile<CTX> {
         bytes: &Bytes,
         gas_limit: u64,
     ) -> Result<Option<Self::Output>, PrecompileErrors> {
-        let cache = self.cache.read();
-        if let Some(precompile_calls) = cache.get(address) {
+        if self.contains_read_precompile(address) {
             let input = ReadPrecompileInput { input: bytes.clone(), gas_limit };
             let mut result = InterpreterResult {
                 result: InstructionResult::Return,
@@ -56,17 +133,21 @@ impl<CTX: ContextTr> PrecompileProvider for ReplayPrecompile<CTX> {
                 output: Bytes::new(),
             };
 
-            let Some(get) = precompile_calls.get(&input) else {
+            let Some(get) = self.resolve(address, &input)? else {
                 result.gas.spend_all();
                 result.result = InstructionResult::PrecompileError;
                 return Ok(Some(result))
             };
 
-            return match *get {
-                ReadPrecompileResult::Ok { gas_used, ref bytes } => {
-                    let underflow = result.gas.record_cost(gas_used);
-                    assert!(underflow, "Gas underflow is not possible");
-                    result.output = bytes.clone();
+            return match get {
+                ReadPrecompileResult::Ok { gas_used, bytes } => {
+                    // Overridden results apply to any gas limit, so they may not fit
+                    if !result.gas.record_cost(gas_used) {
+                        result.gas.spend_all();
+                        result.result = InstructionResult::OutOfGas;
+                        return Ok(Some(result))
+                    }
+                    result.output = bytes;
                     Ok(Some(result))
                 }
                 ReadPrecompileResult::OutOfGas => {
@@ -80,6 +161,9 @@ impl<CTX: ContextTr> PrecompileProvider for ReplayPrecompile<CTX> {
                     result.result = InstructionResult::PrecompileError;
                     Ok(Some(result))
                 }
+                ReadPrecompileResult::UnexpectedError if self.provider.is_some() => {
+                    Err(PrecompileErrors::Fatal { msg: "unexpected precompile error".to_string() })
+                }
                 ReadPrecompileResult::UnexpectedError => panic!("unexpected precompile error"),
             };
         }
@@ -89,12 +173,166 @@ impl<CTX: ContextTr> PrecompileProvider for ReplayPrecompile<CTX> {
     }
 
     fn contains(&self, address: &Address) -> bool {
-        self.precompiles.contains(address) || self.cache.read().get(address).is_some()
+        self.precompiles.contains(address) || self.contains_read_precompile(address)
     }
 
     fn warm_addresses(&self) -> Box<impl Iterator<Item = Address> + '_> {
-        let addresses: Vec<Address> =
-            self.precompiles.warm_addresses().chain(self.cache.read().keys().cloned()).collect();
+        let addresses: Vec<Address> = self
+            .precompiles
+            .warm_addresses()
+            .chain(self.cache.read().keys().cloned())
+            .chain(self.overrides.iter().flat_map(|overrides| overrides.keys().cloned()))
+            .collect();
         Box::new(addresses.into_iter())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_evm::eth::EthEvmContext;
+    use alloy_primitives::address;
+    use reth_revm::{database_interface::EmptyDB, Context, MainContext};
+    use std::{
+        error::Error,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    const SPOT_BALANCE: Address = address!("0x0000000000000000000000000000000000000801");
+
+    #[derive(Debug, Default)]
+    struct MockProvider {
+        calls: AtomicUsize,
+    }
+
+    impl ReadPrecompileProvider for MockProvider {
+        fn read(
+            &self,
+            block_number: u64,
+            address: Address,
+            input: &ReadPrecompileInput,
+        ) -> Result<ReadPrecompileResult, Box<dyn Error + Send + Sync>> {
+            assert_eq!((block_number, address), (10, SPOT_BALANCE));
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            Ok(ReadPrecompileResult::Ok { gas_used: 100, bytes: input.input.clone() })
+        }
+    }
+
+    #[test]
+    fn resolves_missing_calls_through_provider() {
+        let mut context: EthEvmContext<EmptyDB> = Context::mainnet();
+        let input = Bytes::from_static(&[1, 2, 3]);
+
+        let mut replay =
+            ReplayPrecompile::new(EthPrecompiles::default(), Arc::new(RwLock::new(HashMap::new())));
+        assert!(!replay.contains(&SPOT_BALANCE));
+        let result = replay.run(&mut context, &SPOT_BALANCE, &input, 1_000).unwrap();
+        assert!(result.is_none());
+
+        let provider = Arc::new(MockProvider::default());
+        let mut replay = replay.with_provider(10, provider.clone());
+        assert!(replay.contains(&SPOT_BALANCE));
+        for _ in 0..2 {
+            let result = replay.run(&mut context, &SPOT_BALANCE, &input, 1_000).unwrap().unwrap();
+            assert_eq!(result.result, InstructionResult::Return);
+            assert_eq!(result.output, input);
+            assert_eq!(result.gas.spent(), 100);
+        }
+
+        // The second call is served from the cache
+        assert_eq!(provider.calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn fails_calls_of_blocks_with_missing_calls() {
+        let mut context: EthEvmContext<EmptyDB> = Context::mainnet();
+        let input = Bytes::from_static(&[1, 2, 3]);
+
+        let mut replay =
+            ReplayPrecompile::new(EthPrecompiles::default(), Arc::new(RwLock::new(HashMap::new())))
+                .with_missing_calls(10);
+        assert!(replay.contains(&SPOT_BALANCE));
+        assert!(matches!(
+            replay.run(&mut context, &SPOT_BALANCE, &input, 1_000),
+            Err(PrecompileErrors::Fatal { .. })
+        ));
+    }
+
+    #[test]
+    fn overrides_match_input_for_any_gas_limit() {
+        let mut context: EthEvmContext<EmptyDB> = Context::mainnet();
+        let input = Bytes::from_static(&[1, 2, 3]);
+        let output = Bytes::from_static(&[4, 5, 6]);
+
+        let recorded = ReadPrecompileInput { input: input.clone(), gas_limit: 1_000 };
+        let cache = HashMap::from([(
+            SPOT_BALANCE,
+            HashMap::from([(recorded, ReadPrecompileResult::Error)]),
+        )]);
+        let overrides = PrecompileOverrides::from([(
+            SPOT_BALANCE,
+            HashMap::from([(
+                input.clone(),
+                ReadPrecompileResult::Ok { gas_used: 100, bytes: output.clone() },
+            )]),
+        )]);
+        let provider = Arc::new(MockProvider::default());
+        let mut replay =
+            ReplayPrecompile::new(EthPrecompiles::default(), Arc::new(RwLock::new(cache)))
+                .with_overrides(Arc::new(overrides))
+                .with_provider(10, provider.clone());
+
+        // The override takes precedence over the recorded call and applies to any gas limit
+        for gas_limit in [1_000, 5_000] {
+            let result =
+                replay.run(&mut context, &SPOT_BALANCE, &input, gas_limit).unwrap().unwrap();
+            assert_eq!(result.result, InstructionResult::Return);
+            assert_eq!(result.output, output);
+            assert_eq!(result.gas.spent(), 100);
+        }
+
+        // Results that don't fit the gas limit run out of gas
+        let result = replay.run(&mut context, &SPOT_BALANCE, &input, 50).unwrap().unwrap();
+        assert_eq!(result.result, InstructionResult::OutOfGas);
+        assert_eq!(result.gas.spent(), 50);
+
+        // Other inputs are resolved as usual
+        let other = Bytes::from_static(&[7]);
+        let result = replay.run(&mut context, &SPOT_BALANCE, &other, 1_000).unwrap().unwrap();
+        assert_eq!(result.output, other);
+        assert_eq!(provider.calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn overrides_serve_unknown_precompiles() {
+        let mut context: EthEvmContext<EmptyDB> = Context::mainnet();
+        let input = Bytes::from_static(&[1]);
+        let overrides = PrecompileOverrides::from([(
+            SPOT_BALANCE,
+            HashMap::from([(input.clone(), ReadPrecompileResult::Error)]),
+        )]);
+
+        let mut replay =
+            ReplayPrecompile::new(EthPrecompiles::default(), Arc::new(RwLock::new(HashMap::new())))
+                .with_overrides(Arc::new(overrides));
+        assert!(replay.contains(&SPOT_BALANCE));
+        let result = replay.run(&mut context, &SPOT_BALANCE, &input, 1_000).unwrap().unwrap();
+        assert_eq!(result.result, InstructionResult::PrecompileError);
+    }
+
+    #[test]
+    fn deserialize_overrides() {
+        let overrides: PrecompileOverrides = serde_json::from_str(
+            r#"{
+                "0x0000000000000000000000000000000000000801": {
+                    "0x010203": { "Ok": { "gas_used": 100, "bytes": "0x04" } }
+                }
+            }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            overrides[&SPOT_BALANCE].get(&Bytes::from_static(&[1, 2, 3])),
+            Some(ReadPrecompileResult::Ok { gas_used: 100, .. })
+        ));
+    }
+}
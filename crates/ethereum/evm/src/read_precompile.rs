@@ -0,0 +1,168 @@
+use alloy_primitives::{Address, Bytes};
+use parking_lot::RwLock;
+use reth_hyperliquid_types::{
+    ReadPrecompileCalls, ReadPrecompileInput, ReadPrecompileProvider, ReadPrecompileResult,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error,
+};
+
+/// Number of blocks whose recorded calls are kept by [`RecentReadPrecompileCalls`].
+pub const RECENT_BLOCKS: u64 = 1024;
+
+/// Recorded results of a call input, by block number, along with the gas limit they were recorded
+/// with.
+type RecordedResults = BTreeMap<u64, (u64, ReadPrecompileResult)>;
+
+#[derive(Debug, Default)]
+struct RecentCalls {
+    /// Results by precompile address and call input.
+    results: HashMap<(Address, Bytes), RecordedResults>,
+    /// Recorded call inputs by block number, to evict the calls of old blocks.
+    blocks: BTreeMap<u64, Vec<(Address, Bytes)>>,
+}
+
+/// [`ReadPrecompileProvider`] serving the read precompile calls recorded by the recently ingested
+/// blocks.
+///
+/// HyperCore state can't be queried as of a block, so a call that is missing from the recorded
+/// calls of its block is answered with the latest result recorded for the same input at or before
+/// the block, within the last [`RECENT_BLOCKS`] blocks. Calls whose input was not recorded in that
+/// window fail. The calls are recorded ahead of time, e.g. by the block ingest, so resolving them
+/// never blocks the execution.
+#[derive(Debug, Default)]
+pub struct RecentReadPrecompileCalls {
+    calls: RwLock<RecentCalls>,
+}
+
+impl RecentReadPrecompileCalls {
+    /// Records the read precompile calls of the block, evicting the calls of the blocks that are
+    /// more than [`RECENT_BLOCKS`] blocks older.
+    pub fn record(&self, block_number: u64, calls: &ReadPrecompileCalls) {
+        let mut recent = self.calls.write();
+        let mut inputs = Vec::new();
+        for (address, calls) in calls {
+            for (input, result) in calls {
+                let key = (*address, input.input.clone());
+                recent
+                    .results
+                    .entry(key.clone())
+                    .or_default()
+                    .insert(block_number, (input.gas_limit, result.clone()));
+                inputs.push(key);
+            }
+        }
+        recent.blocks.entry(block_number).or_default().extend(inputs);
+
+        let Some(&highest) = recent.blocks.keys().next_back() else { return };
+        while let Some(entry) = recent.blocks.first_entry() {
+            if *entry.key() + RECENT_BLOCKS > highest {
+                break
+            }
+            let (evicted, keys) = entry.remove_entry();
+            for key in keys {
+                if let Some(results) = recent.results.get_mut(&key) {
+                    results.remove(&evicted);
+                    if results.is_empty() {
+                        recent.results.remove(&key);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl ReadPrecompileProvider for RecentReadPrecompileCalls {
+    fn read(
+        &self,
+        block_number: u64,
+        address: Address,
+        input: &ReadPrecompileInput,
+    ) -> Result<ReadPrecompileResult, Box<dyn Error + Send + Sync>> {
+        let recent = self.calls.read();
+        recent
+            .results
+            .get(&(address, input.input.clone()))
+            .into_iter()
+            .flat_map(|results| results.range(..=block_number).rev())
+            // Failures may depend on the gas limit, successful results are checked against it by
+            // the precompile
+            .find(|(_, (gas_limit, result))| {
+                *gas_limit == input.gas_limit || matches!(result, ReadPrecompileResult::Ok { .. })
+            })
+            .map(|(_, (_, result))| result.clone())
+            .ok_or_else(|| {
+                format!(
+                    "read precompile call to {address} with input {} was not made by the last \
+                     {RECENT_BLOCKS} blocks",
+                    input.input
+                )
+                .into()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, bytes};
+
+    fn input(input: Bytes, gas_limit: u64) -> ReadPrecompileInput {
+        ReadPrecompileInput { input, gas_limit }
+    }
+
+    fn ok(gas_used: u64) -> ReadPrecompileResult {
+        ReadPrecompileResult::Ok { gas_used, bytes: bytes!("01") }
+    }
+
+    #[test]
+    fn serves_latest_recorded_result() {
+        let provider = RecentReadPrecompileCalls::default();
+        let address = address!("0000000000000000000000000000000000000800");
+        provider.record(10, &vec![(address, vec![(input(bytes!("abcd"), 30_000), ok(10))])]);
+        provider.record(12, &vec![(address, vec![(input(bytes!("abcd"), 50_000), ok(12))])]);
+
+        let call = input(bytes!("abcd"), 40_000);
+        assert!(provider.read(9, address, &call).is_err());
+        assert!(matches!(
+            provider.read(11, address, &call),
+            Ok(ReadPrecompileResult::Ok { gas_used: 10, .. })
+        ));
+        assert!(matches!(
+            provider.read(20, address, &call),
+            Ok(ReadPrecompileResult::Ok { gas_used: 12, .. })
+        ));
+        assert!(provider.read(20, address, &input(bytes!("ef"), 40_000)).is_err());
+    }
+
+    #[test]
+    fn serves_failures_of_the_same_gas_limit_only() {
+        let provider = RecentReadPrecompileCalls::default();
+        let address = address!("0000000000000000000000000000000000000801");
+        provider.record(
+            1,
+            &vec![(address, vec![(input(bytes!("ab"), 100), ReadPrecompileResult::OutOfGas)])],
+        );
+
+        assert!(matches!(
+            provider.read(1, address, &input(bytes!("ab"), 100)),
+            Ok(ReadPrecompileResult::OutOfGas)
+        ));
+        assert!(provider.read(1, address, &input(bytes!("ab"), 30_000)).is_err());
+    }
+
+    #[test]
+    fn evicts_old_blocks() {
+        let provider = RecentReadPrecompileCalls::default();
+        let address = address!("0000000000000000000000000000000000000800");
+        let call = input(bytes!("abcd"), 30_000);
+        provider.record(1, &vec![(address, vec![(call.clone(), ok(1))])]);
+        provider.record(RECENT_BLOCKS, &Vec::new());
+        assert!(provider.read(RECENT_BLOCKS, address, &call).is_ok());
+
+        provider.record(RECENT_BLOCKS + 1, &Vec::new());
+        assert!(provider.read(RECENT_BLOCKS + 1, address, &call).is_err());
+        assert!(provider.calls.read().results.is_empty());
+    }
+}
@@ -0,0 +1,36 @@
+use core::fmt;
+use reth_hyperliquid_types::{
+    archive::decode_read_precompile_calls, ReadPrecompileCalls, ReadPrecompileCallsReader,
+};
+use reth_storage_api::HlSidecarReader;
+use std::error::Error;
+
+/// [`ReadPrecompileCallsReader`] decoding the read precompile calls from the Hyperliquid sidecars
+/// stored by the node, either in the database or in static files.
+#[derive(Clone)]
+pub struct SidecarReadPrecompileCalls<P> {
+    provider: P,
+}
+
+impl<P> SidecarReadPrecompileCalls<P> {
+    /// Creates a new reader on top of the provider.
+    pub const fn new(provider: P) -> Self {
+        Self { provider }
+    }
+}
+
+impl<P> fmt::Debug for SidecarReadPrecompileCalls<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SidecarReadPrecompileCalls").finish_non_exhaustive()
+    }
+}
+
+impl<P: HlSidecarReader> ReadPrecompileCallsReader for SidecarReadPrecompileCalls<P> {
+    fn read_precompile_calls(
+        &self,
+        block_number: u64,
+    ) -> Result<Option<ReadPrecompileCalls>, Box<dyn Error + Send + Sync>> {
+        let Some(sidecar) = self.provider.hl_sidecar(block_number)? else { return Ok(None) };
+        Ok(Some(decode_read_precompile_calls(&sidecar.read_precompile_calls)?))
+    }
+}
@@ -5,4 +5,6 @@ pub use reth_evm::execute::BasicBlockExecutorProvider;
 #[doc(inline)]
 pub use reth_evm_ethereum::execute::{EthExecutionStrategyFactory, EthExecutorProvider};
 #[doc(inline)]
-pub use reth_evm_ethereum::{EthEvm, EthEvmConfig};
+pub use reth_evm_ethereum::{
+    EthEvm, EthEvmConfig, RecentReadPrecompileCalls, SidecarReadPrecompileCalls,
+};
@@ -1,7 +1,7 @@
 //! Ethereum Node types config.
 
 pub use crate::payload::EthereumPayloadBuilder;
-use crate::{EthEngineTypes, EthEvmConfig};
+use crate::{evm::SidecarReadPrecompileCalls, EthEngineTypes, EthEvmConfig};
 use reth_chainspec::ChainSpec;
 use reth_consensus::{ConsensusError, FullConsensus};
 use reth_ethereum_consensus::EthBeaconConsensus;
@@ -248,12 +248,24 @@ where
         ctx: &BuilderContext<Node>,
     ) -> eyre::Result<(Self::EVM, Self::Executor)> {
         let chain_spec = ctx.chain_spec();
+        let shared_state = ctx.shared_state();
         let evm_config = EthEvmConfig::new(ctx.chain_spec())
             .with_ingest_dir(ctx.ingest_dir())
-            .with_shared_state(ctx.shared_state());
+            .with_shared_state(shared_state.clone());
         let strategy_factory = EthExecutionStrategyFactory::new(chain_spec, evm_config.clone());
         let executor = BasicBlockExecutorProvider::new(strategy_factory);
 
+        // Ingested blocks are executed with the recorded read precompile results only, while the
+        // other EVMs (e.g. `eth_call`) resolve the missing ones through the provider. Tracing
+        // reads the recorded results from the sidecars stored by the node first.
+        let evm_config = evm_config
+            .with_read_precompile_provider(
+                shared_state.and_then(|state| state.read_precompile_provider),
+            )
+            .with_read_precompile_calls_reader(Some(Arc::new(SidecarReadPrecompileCalls::new(
+                ctx.provider().clone(),
+            ))));
+
         Ok((evm_config, executor))
     }
 }
@@ -343,6 +355,7 @@ where
         + 'static,
 {
     type Primitives = EthNetworkPrimitives;
+    type Network = NetworkHandle;
 
     async fn build_network(
         self,
@@ -0,0 +1,18 @@
+use alloy_primitives::Address;
+
+/// Contract deployed by a transaction of a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DeployedContract {
+    /// Index of the transaction in the block.
+    pub transaction_index: usize,
+    /// Address of the contract.
+    pub address: Address,
+    /// Sender of the transaction.
+    pub creator: Address,
+    /// Contract that deployed the contract with `CREATE` or `CREATE2` during the transaction.
+    ///
+    /// `None` if the contract was deployed by the transaction itself, or if the deploying
+    /// contract couldn't be told apart from other contracts deploying with `CREATE2` in the same
+    /// transaction.
+    pub factory: Option<Address>,
+}
@@ -20,6 +20,9 @@ pub use execute::*;
 mod execution_outcome;
 pub use execution_outcome::*;
 
+mod contract_creation;
+pub use contract_creation::*;
+
 /// Bincode-compatible serde implementations for commonly used types for (EVM) block execution.
 ///
 /// `bincode` crate doesn't work with optionally serializable serde fields, but some of the
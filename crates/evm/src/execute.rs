@@ -260,6 +260,11 @@ impl<F, DB: Database> BasicBlockExecutor<F, DB> {
             State::builder().with_database(db).with_bundle_update().without_state_clear().build();
         Self { strategy_factory, db }
     }
+
+    /// Returns the [`State`] containing the state changes of all blocks executed so far.
+    pub const fn state(&self) -> &State<DB> {
+        &self.db
+    }
 }
 
 impl<F, DB> Executor<DB> for BasicBlockExecutor<F, DB>
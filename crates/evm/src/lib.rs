@@ -22,6 +22,7 @@ pub use alloy_evm::evm::EvmFactory;
 use alloy_evm::{FromRecoveredTx, IntoTxEnv};
 use alloy_primitives::{Address, B256};
 use core::fmt::Debug;
+use reth_hyperliquid_types::PrecompileOverrides;
 use reth_primitives_traits::{BlockHeader, SignedTransaction};
 use revm::{context::TxEnv, inspector::Inspector};
 
@@ -40,6 +41,8 @@ pub mod system_calls;
 #[cfg(any(test, feature = "test-utils"))]
 /// test helpers for mocking executor
 pub mod test_utils;
+#[cfg(feature = "std")]
+pub mod touched_accounts;
 
 pub use alloy_evm::{Database, Evm, EvmEnv, EvmError, InvalidTxError};
 
@@ -105,8 +108,27 @@ pub trait ConfigureEvm: ConfigureEvmEnv {
     {
         self.evm_factory().create_evm_with_inspector(db, evm_env, inspector)
     }
+
+    /// Returns a copy of this configuration whose EVMs resolve the given read precompile calls
+    /// with the overridden results, e.g. to simulate an `eth_call` against hypothetical HyperCore
+    /// state.
+    ///
+    /// Configurations without read precompiles can't apply the overrides and return an error.
+    fn with_precompile_overrides(
+        &self,
+        overrides: PrecompileOverrides,
+    ) -> Result<Self, PrecompileOverridesUnsupported> {
+        let _ = overrides;
+        Err(PrecompileOverridesUnsupported)
+    }
 }
 
+/// Error returned by [`ConfigureEvm::with_precompile_overrides`] if the configuration has no read
+/// precompiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+#[display("read precompile overrides are not supported by the EVM configuration")]
+pub struct PrecompileOverridesUnsupported;
+
 impl<'b, T> ConfigureEvm for &'b T
 where
     T: ConfigureEvm,
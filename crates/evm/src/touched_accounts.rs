@@ -0,0 +1,312 @@
+//! Accounts touched by each transaction of a block, collected from the state changes while the
+//! block is executed.
+//!
+//! They tell which accounts the internal calls of a transaction changed, and which contracts it
+//! deployed. Contracts deployed by other contracts are told apart from the nonce changes of each
+//! transaction: a contract deployed with `CREATE` is attributed to the account whose nonce
+//! produces its address. A contract deployed with `CREATE2` is attributed to the only other
+//! account that deployed during the transaction, and is left without a factory if there is more
+//! than one.
+
+use crate::system_calls::{OnStateHook, StateChangeSource};
+use alloc::{boxed::Box, vec, vec::Vec};
+use alloy_consensus::Transaction;
+use alloy_primitives::{map::AddressMap, Address};
+use reth_execution_types::DeployedContract;
+use reth_storage_errors::provider::ProviderResult;
+use revm::state::EvmState;
+use std::sync::mpsc;
+
+/// Collects the accounts touched by each transaction of a block while it is executed.
+#[derive(Debug)]
+pub struct TouchedAccountsCollector {
+    touched: mpsc::Receiver<(usize, Vec<TouchedAccount>)>,
+}
+
+impl TouchedAccountsCollector {
+    /// Returns a new collector, and the state hook feeding it that forwards the state changes to
+    /// the given hook.
+    pub fn new(hook: Box<dyn OnStateHook>) -> (Self, TouchedAccountsHook) {
+        let (tx, rx) = mpsc::channel();
+        (Self { touched: rx }, TouchedAccountsHook { inner: hook, touched: tx })
+    }
+
+    /// Returns the accounts touched by the transactions of the executed block.
+    pub fn finish(self) -> BlockTouchedAccounts {
+        BlockTouchedAccounts { transactions: self.touched.try_iter().collect() }
+    }
+}
+
+/// Accounts touched by the transactions of an executed block.
+#[derive(Debug, Default)]
+pub struct BlockTouchedAccounts {
+    /// Accounts touched by each transaction, with the index of the transaction.
+    transactions: Vec<(usize, Vec<TouchedAccount>)>,
+}
+
+impl BlockTouchedAccounts {
+    /// Returns the accounts changed by the internal calls of each transaction of the block, i.e.
+    /// the touched accounts other than the sender and the recipient of the transaction and the
+    /// beneficiary of the block.
+    pub fn internal_accounts<T: Transaction>(
+        &self,
+        transactions: &[T],
+        senders: &[Address],
+        beneficiary: Address,
+    ) -> Vec<Vec<Address>> {
+        let mut accounts = vec![Vec::new(); transactions.len()];
+        for (index, touched) in &self.transactions {
+            let (sender, to) = (senders[*index], transactions[*index].to());
+            accounts[*index] = touched
+                .iter()
+                .map(|account| account.address)
+                .filter(|address| {
+                    *address != sender && Some(*address) != to && *address != beneficiary
+                })
+                .collect();
+        }
+        accounts
+    }
+
+    /// Returns the contracts deployed by the block.
+    ///
+    /// `nonces` holds the nonces of the accounts touched by the previous transactions, and is
+    /// updated with the ones touched by the block. `state_nonce` returns the nonce of the other
+    /// accounts before the block.
+    pub fn contract_creations<T: Transaction>(
+        &self,
+        transactions: &[T],
+        senders: &[Address],
+        nonces: &mut AddressMap<u64>,
+        mut state_nonce: impl FnMut(Address) -> ProviderResult<u64>,
+    ) -> ProviderResult<Vec<DeployedContract>> {
+        let mut creations = Vec::new();
+        for (index, touched) in &self.transactions {
+            let (index, creator) = (*index, senders[*index]);
+            let tx_creations = tx_contract_creations(
+                creator,
+                transactions[index].is_create(),
+                touched,
+                |address| match nonces.get(&address) {
+                    Some(nonce) => Ok(*nonce),
+                    None => state_nonce(address),
+                },
+            )?;
+            creations.extend(tx_creations.into_iter().map(|(address, factory)| DeployedContract {
+                transaction_index: index,
+                address,
+                creator,
+                factory,
+            }));
+            nonces.extend(touched.iter().map(|account| (account.address, account.nonce)));
+        }
+        Ok(creations)
+    }
+}
+
+/// State hook feeding a [`TouchedAccountsCollector`].
+#[derive(derive_more::Debug)]
+pub struct TouchedAccountsHook {
+    /// Hook the state changes are forwarded to.
+    #[debug(skip)]
+    inner: Box<dyn OnStateHook>,
+    touched: mpsc::Sender<(usize, Vec<TouchedAccount>)>,
+}
+
+impl OnStateHook for TouchedAccountsHook {
+    fn on_state(&mut self, source: StateChangeSource, state: &EvmState) {
+        if let StateChangeSource::Transaction(index) = source {
+            let _ = self.touched.send((index, touched_accounts(state)));
+        }
+        self.inner.on_state(source, state);
+    }
+}
+
+/// Account touched by a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TouchedAccount {
+    address: Address,
+    /// Nonce after the transaction.
+    nonce: u64,
+    /// Whether the account was created by the transaction.
+    created: bool,
+    /// Whether the account was created by the transaction and has code after it.
+    deployed: bool,
+}
+
+fn touched_accounts(state: &EvmState) -> Vec<TouchedAccount> {
+    state
+        .iter()
+        .filter(|(_, account)| account.is_touched())
+        .map(|(address, account)| TouchedAccount {
+            address: *address,
+            nonce: account.info.nonce,
+            created: account.is_created(),
+            deployed: account.is_created() &&
+                !account.is_selfdestructed() &&
+                !account.info.is_empty_code_hash(),
+        })
+        .collect()
+}
+
+/// Returns the contracts deployed by a transaction, with the contract that deployed them if the
+/// transaction didn't.
+///
+/// `prior_nonce` returns the nonce of an account before the transaction.
+fn tx_contract_creations(
+    sender: Address,
+    is_create: bool,
+    touched: &[TouchedAccount],
+    mut prior_nonce: impl FnMut(Address) -> ProviderResult<u64>,
+) -> ProviderResult<Vec<(Address, Option<Address>)>> {
+    let mut created = touched
+        .iter()
+        .filter(|account| account.created)
+        .map(|account| account.address)
+        .collect::<Vec<_>>();
+    let mut creations = Vec::with_capacity(created.len());
+    if created.is_empty() {
+        return Ok(creations)
+    }
+
+    let mut take_created = |address: Address| {
+        created.iter().position(|created| *created == address).map(|i| created.swap_remove(i))
+    };
+
+    // Every `CREATE` and `CREATE2` increments the nonce of the deploying account, and the address
+    // of a `CREATE` is derived from the nonce before the increment.
+    let mut create2_deployers = Vec::new();
+    for account in touched {
+        let first_nonce = if account.address == sender {
+            let nonce = prior_nonce(sender)?;
+            if is_create {
+                if let Some(contract) = take_created(sender.create(nonce)) {
+                    creations.push((contract, None));
+                }
+            }
+            // The first increment is the transaction's own.
+            nonce + 1
+        } else if account.created {
+            // Created contracts start with nonce 1, see EIP-161.
+            1
+        } else {
+            prior_nonce(account.address)?
+        };
+
+        let mut has_create2 = false;
+        for nonce in first_nonce..account.nonce {
+            match take_created(account.address.create(nonce)) {
+                Some(contract) => creations.push((contract, Some(account.address))),
+                None => has_create2 = true,
+            }
+        }
+        if has_create2 {
+            create2_deployers.push(account.address);
+        }
+    }
+
+    // `CREATE2` addresses don't depend on the nonce, so the remaining contracts can only be
+    // attributed if a single account deployed them.
+    let factory = match create2_deployers.as_slice() {
+        [factory] => Some(*factory),
+        _ => None,
+    };
+    creations.extend(created.into_iter().map(|contract| (contract, factory)));
+
+    let deployed = |address: &Address| {
+        touched.iter().any(|account| account.address == *address && account.deployed)
+    };
+    creations.retain(|(contract, _)| deployed(contract));
+
+    Ok(creations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, B256};
+
+    const SENDER: Address = address!("0x00000000000000000000000000000000000000aa");
+    const FACTORY: Address = address!("0x00000000000000000000000000000000000000bb");
+
+    fn touched(address: Address, nonce: u64, created: bool) -> TouchedAccount {
+        TouchedAccount { address, nonce, created, deployed: created }
+    }
+
+    fn create2(deployer: Address) -> Address {
+        deployer.create2_from_code(B256::with_last_byte(1), b"")
+    }
+
+    fn prior_nonces(address: Address) -> ProviderResult<u64> {
+        Ok(match address {
+            SENDER => 5,
+            FACTORY => 1,
+            _ => 0,
+        })
+    }
+
+    #[test]
+    fn top_level_create() {
+        let contract = SENDER.create(5);
+        let touched = [touched(SENDER, 6, false), touched(contract, 1, true)];
+        assert_eq!(
+            tx_contract_creations(SENDER, true, &touched, prior_nonces).unwrap(),
+            vec![(contract, None)]
+        );
+    }
+
+    #[test]
+    fn create_in_factory() {
+        let child = FACTORY.create(1);
+        let grandchild = child.create(1);
+        let touched = [
+            touched(SENDER, 6, false),
+            touched(FACTORY, 2, false),
+            touched(child, 2, true),
+            touched(grandchild, 1, true),
+        ];
+        let mut creations = tx_contract_creations(SENDER, false, &touched, prior_nonces).unwrap();
+        creations.sort();
+        let mut expected = vec![(child, Some(FACTORY)), (grandchild, Some(child))];
+        expected.sort();
+        assert_eq!(creations, expected);
+    }
+
+    #[test]
+    fn create2_in_factory() {
+        let contract = create2(FACTORY);
+        let touched =
+            [touched(SENDER, 6, false), touched(FACTORY, 2, false), touched(contract, 1, true)];
+        assert_eq!(
+            tx_contract_creations(SENDER, false, &touched, prior_nonces).unwrap(),
+            vec![(contract, Some(FACTORY))]
+        );
+
+        // Another deployer in the same transaction makes the factory ambiguous.
+        let other = FACTORY.create(1);
+        let other_contract = create2(other);
+        let touched = [
+            touched(SENDER, 6, false),
+            touched(FACTORY, 3, false),
+            touched(other, 2, true),
+            touched(contract, 1, true),
+            touched(other_contract, 1, true),
+        ];
+        let mut creations = tx_contract_creations(SENDER, false, &touched, prior_nonces).unwrap();
+        creations.sort();
+        let mut expected = vec![(other, Some(FACTORY)), (contract, None), (other_contract, None)];
+        expected.sort();
+        assert_eq!(creations, expected);
+    }
+
+    #[test]
+    fn without_code() {
+        let contract = FACTORY.create(1);
+        let touched = [
+            touched(SENDER, 6, false),
+            touched(FACTORY, 2, false),
+            TouchedAccount { address: contract, nonce: 1, created: true, deployed: false },
+        ];
+        assert!(tx_contract_creations(SENDER, false, &touched, prior_nonces).unwrap().is_empty());
+    }
+}
@@ -12,6 +12,7 @@ mod error;
 pub use error::{WalError, WalResult};
 
 use std::{
+    ops::RangeInclusive,
     path::Path,
     sync::{
         atomic::{AtomicU32, Ordering},
@@ -231,6 +232,22 @@ where
             .read_notification(file_id)
             .map(|entry| entry.map(|(notification, _)| notification))
     }
+
+    /// Returns the range of file IDs of the notifications in the WAL, in the order they were
+    /// committed.
+    ///
+    /// If the WAL is empty, returns `None`.
+    pub fn files_range(&self) -> WalResult<Option<RangeInclusive<u32>>> {
+        self.wal.storage.files_range()
+    }
+
+    /// Returns the notification with the given file ID, if it wasn't finalized yet.
+    pub fn read_notification(&self, file_id: u32) -> WalResult<Option<ExExNotification<N>>> {
+        self.wal
+            .storage
+            .read_notification(file_id)
+            .map(|entry| entry.map(|(notification, _)| notification))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,147 @@
+//! Export of the blocks, transactions, logs and ERC20 transfers to Parquet files for analytics.
+//!
+//! The history is exported by `reth export-parquet`, and the new blocks by [`ParquetExEx`]. Both
+//! write the same layout, see [`PartitionWriter`].
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
+    html_favicon_url = "https://avatars0.githubusercontent.com/u/97369466?s=256",
+    issue_tracker_base_url = "https://github.com/paradigmxyz/reth/issues/"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+mod tables;
+pub use tables::{Tables, TABLES};
+
+pub use reth_provider::{Erc20Transfer, TRANSFER_EVENT_TOPIC};
+
+mod writer;
+pub use writer::PartitionWriter;
+
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockNumHash;
+use futures::TryStreamExt;
+use reth_exex::{ExExContext, ExExEvent, ExExHead};
+use reth_node_api::{FullNodeComponents, NodeTypes};
+use reth_primitives::{Block, EthPrimitives, Receipt, RecoveredBlock};
+use reth_provider::{
+    BlockHashReader, BlockNumReader, BlockReader, ProviderError, ReceiptProvider,
+    TransactionVariant,
+};
+use reth_tracing::tracing::info;
+
+/// ExEx exporting the committed blocks to Parquet files.
+///
+/// The blocks are buffered in memory until their partition is complete. On startup, the export
+/// resumes after the last complete partition, and reverted blocks are removed from the buffer
+/// and from the written partitions.
+#[derive(Debug)]
+pub struct ParquetExEx<Node: FullNodeComponents> {
+    ctx: ExExContext<Node>,
+    writer: PartitionWriter,
+    /// The blocks of the current partition that weren't written yet, with their receipts.
+    pending: Vec<(RecoveredBlock<Block>, Vec<Receipt>)>,
+}
+
+impl<Node> ParquetExEx<Node>
+where
+    Node: FullNodeComponents<Types: NodeTypes<Primitives = EthPrimitives>>,
+{
+    /// Creates a new ExEx writing to `writer`, resuming after its last complete partition.
+    pub fn new(mut ctx: ExExContext<Node>, writer: PartitionWriter) -> eyre::Result<Self> {
+        // Partitions above the database head were reverted while the node was down
+        let best = ctx.provider().best_block_number()?;
+        for range in writer.remove_above(best)? {
+            info!(target: "exex::parquet", ?range, "Removed partition above the database head");
+        }
+
+        match writer.partitions()?.last() {
+            Some(range) => {
+                let number = *range.end();
+                let hash = ctx
+                    .provider()
+                    .block_hash(number)?
+                    .ok_or_else(|| ProviderError::HeaderNotFound(number.into()))?;
+                info!(target: "exex::parquet", dir = %writer.dir().display(), number, "Resuming export");
+                ctx.set_notifications_with_head(ExExHead::new(BlockNumHash::new(number, hash)));
+            }
+            None => {
+                info!(target: "exex::parquet", dir = %writer.dir().display(), "Starting export at the node head, use `reth export-parquet` for the history");
+                ctx.set_notifications_without_head();
+            }
+        }
+
+        Ok(Self { ctx, writer, pending: Vec::new() })
+    }
+
+    /// Exports the committed blocks until the node shuts down.
+    pub async fn run(mut self) -> eyre::Result<()> {
+        while let Some(notification) = self.ctx.notifications.try_next().await? {
+            if let Some(reverted_chain) = notification.reverted_chain() {
+                self.revert_to(reverted_chain.first().number() - 1)?;
+            }
+
+            if let Some(committed_chain) = notification.committed_chain() {
+                for (block, receipts) in committed_chain.blocks_and_receipts() {
+                    self.push(block.clone(), receipts.clone()).await?;
+                }
+                self.ctx
+                    .events
+                    .send(ExExEvent::FinishedHeight(committed_chain.tip().num_hash()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Buffers the block, writing its partition if it is the last block of it.
+    async fn push(
+        &mut self,
+        block: RecoveredBlock<Block>,
+        receipts: Vec<Receipt>,
+    ) -> eyre::Result<()> {
+        let number = block.number();
+        self.pending.push((block, receipts));
+
+        let partition = self.writer.partition_of(number);
+        if number == *partition.end() {
+            let range = self.pending[0].0.number()..=number;
+            let blocks = std::mem::take(&mut self.pending);
+            let writer = self.writer.clone();
+            tokio::task::spawn_blocking(move || {
+                writer.write(
+                    &range,
+                    blocks.iter().map(|(block, receipts)| (block, receipts.as_slice())),
+                )?;
+                info!(target: "exex::parquet", ?range, "Wrote partition");
+                eyre::Ok(())
+            })
+            .await??;
+        }
+        Ok(())
+    }
+
+    /// Drops the exported blocks above `fork_block`.
+    ///
+    /// If written partitions hold reverted blocks, they are removed and their blocks below the
+    /// fork are read back from the database into the buffer.
+    fn revert_to(&mut self, fork_block: u64) -> eyre::Result<()> {
+        self.pending.retain(|(block, _)| block.number() <= fork_block);
+
+        let removed = self.writer.remove_above(fork_block)?;
+        let Some(first) = removed.first().map(|range| *range.start()) else { return Ok(()) };
+        info!(target: "exex::parquet", ?removed, fork_block, "Removed reverted partitions");
+
+        // The removed partitions hold all the blocks above the fork, so the buffer is empty
+        let provider = self.ctx.provider();
+        for number in first..=fork_block {
+            let block = provider
+                .sealed_block_with_senders(number.into(), TransactionVariant::WithHash)?
+                .ok_or_else(|| ProviderError::HeaderNotFound(number.into()))?;
+            let receipts = provider.receipts_by_block(number.into())?.unwrap_or_default();
+            self.pending.push((block, receipts));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,391 @@
+//! Arrow schemas of the exported tables and their conversion from executed blocks.
+
+use alloy_consensus::{BlockHeader, Transaction, Typed2718};
+use arrow_array::{
+    builder::{
+        BinaryBuilder, BooleanBuilder, FixedSizeBinaryBuilder, StringBuilder, UInt32Builder,
+        UInt64Builder, UInt8Builder,
+    },
+    ArrayRef, RecordBatch,
+};
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+use reth_primitives::{Block, Receipt, RecoveredBlock};
+use reth_primitives_traits::{transaction::signed::is_impersonated_tx, SignedTransaction};
+use reth_provider::Erc20Transfer;
+use std::sync::Arc;
+
+/// The exported tables. `blocks` is written last, marking the partition as complete.
+pub const TABLES: [&str; 4] = ["transactions", "logs", "erc20_transfers", "blocks"];
+
+/// The record batches of the exported tables for a range of blocks, in the order of [`TABLES`].
+#[derive(Debug)]
+pub struct Tables(pub [RecordBatch; 4]);
+
+impl Tables {
+    /// Converts the blocks and their receipts to record batches.
+    pub fn new<'a>(
+        blocks: impl IntoIterator<Item = (&'a RecoveredBlock<Block>, &'a [Receipt])>,
+    ) -> Result<Self, ArrowError> {
+        let mut tables = TableBuilders::default();
+        for (block, receipts) in blocks {
+            tables.push_block(block, receipts)?;
+        }
+        tables.finish()
+    }
+}
+
+fn hash_type() -> DataType {
+    DataType::FixedSizeBinary(32)
+}
+
+fn address_type() -> DataType {
+    DataType::FixedSizeBinary(20)
+}
+
+fn blocks_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("number", DataType::UInt64, false),
+        Field::new("hash", hash_type(), false),
+        Field::new("parent_hash", hash_type(), false),
+        Field::new("timestamp", DataType::UInt64, false),
+        Field::new("miner", address_type(), false),
+        Field::new("gas_used", DataType::UInt64, false),
+        Field::new("gas_limit", DataType::UInt64, false),
+        Field::new("base_fee_per_gas", DataType::UInt64, true),
+        Field::new("transaction_count", DataType::UInt32, false),
+        Field::new("system_transaction_count", DataType::UInt32, false),
+    ])
+}
+
+fn transactions_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("transaction_index", DataType::UInt32, false),
+        Field::new("hash", hash_type(), false),
+        Field::new("from", address_type(), false),
+        Field::new("to", address_type(), true),
+        Field::new("is_system_tx", DataType::Boolean, false),
+        Field::new("tx_type", DataType::UInt8, false),
+        Field::new("nonce", DataType::UInt64, false),
+        Field::new("value", DataType::Utf8, false),
+        Field::new("gas_limit", DataType::UInt64, false),
+        Field::new("gas_used", DataType::UInt64, false),
+        Field::new("max_fee_per_gas", DataType::Utf8, false),
+        Field::new("max_priority_fee_per_gas", DataType::Utf8, true),
+        Field::new("input", DataType::Binary, false),
+        Field::new("success", DataType::Boolean, false),
+    ])
+}
+
+fn logs_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("transaction_index", DataType::UInt32, false),
+        Field::new("log_index", DataType::UInt32, false),
+        Field::new("transaction_hash", hash_type(), false),
+        Field::new("address", address_type(), false),
+        Field::new("topic0", hash_type(), true),
+        Field::new("topic1", hash_type(), true),
+        Field::new("topic2", hash_type(), true),
+        Field::new("topic3", hash_type(), true),
+        Field::new("data", DataType::Binary, false),
+    ])
+}
+
+fn erc20_transfers_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("transaction_index", DataType::UInt32, false),
+        Field::new("log_index", DataType::UInt32, false),
+        Field::new("transaction_hash", hash_type(), false),
+        Field::new("token", address_type(), false),
+        Field::new("from", address_type(), false),
+        Field::new("to", address_type(), false),
+        Field::new("value", DataType::Utf8, false),
+    ])
+}
+
+fn hash_builder() -> FixedSizeBinaryBuilder {
+    FixedSizeBinaryBuilder::new(32)
+}
+
+fn address_builder() -> FixedSizeBinaryBuilder {
+    FixedSizeBinaryBuilder::new(20)
+}
+
+/// Column builders of the exported tables.
+struct TableBuilders {
+    blocks: BlockColumns,
+    transactions: TransactionColumns,
+    logs: LogColumns,
+    transfers: TransferColumns,
+}
+
+impl Default for TableBuilders {
+    fn default() -> Self {
+        Self {
+            blocks: BlockColumns {
+                number: UInt64Builder::new(),
+                hash: hash_builder(),
+                parent_hash: hash_builder(),
+                timestamp: UInt64Builder::new(),
+                miner: address_builder(),
+                gas_used: UInt64Builder::new(),
+                gas_limit: UInt64Builder::new(),
+                base_fee_per_gas: UInt64Builder::new(),
+                transaction_count: UInt32Builder::new(),
+                system_transaction_count: UInt32Builder::new(),
+            },
+            transactions: TransactionColumns {
+                block_number: UInt64Builder::new(),
+                transaction_index: UInt32Builder::new(),
+                hash: hash_builder(),
+                from: address_builder(),
+                to: address_builder(),
+                is_system_tx: BooleanBuilder::new(),
+                tx_type: UInt8Builder::new(),
+                nonce: UInt64Builder::new(),
+                value: StringBuilder::new(),
+                gas_limit: UInt64Builder::new(),
+                gas_used: UInt64Builder::new(),
+                max_fee_per_gas: StringBuilder::new(),
+                max_priority_fee_per_gas: StringBuilder::new(),
+                input: BinaryBuilder::new(),
+                success: BooleanBuilder::new(),
+            },
+            logs: LogColumns {
+                block_number: UInt64Builder::new(),
+                transaction_index: UInt32Builder::new(),
+                log_index: UInt32Builder::new(),
+                transaction_hash: hash_builder(),
+                address: address_builder(),
+                topics: [hash_builder(), hash_builder(), hash_builder(), hash_builder()],
+                data: BinaryBuilder::new(),
+            },
+            transfers: TransferColumns {
+                block_number: UInt64Builder::new(),
+                transaction_index: UInt32Builder::new(),
+                log_index: UInt32Builder::new(),
+                transaction_hash: hash_builder(),
+                token: address_builder(),
+                from: address_builder(),
+                to: address_builder(),
+                value: StringBuilder::new(),
+            },
+        }
+    }
+}
+
+impl TableBuilders {
+    fn push_block(
+        &mut self,
+        block: &RecoveredBlock<Block>,
+        receipts: &[Receipt],
+    ) -> Result<(), ArrowError> {
+        let number = block.number();
+        let mut system_transaction_count = 0;
+        let mut log_index = 0u32;
+        let mut previous_cumulative_gas_used = 0;
+        for (index, ((sender, tx), receipt)) in
+            block.transactions_with_sender().zip(receipts).enumerate()
+        {
+            let index = index as u32;
+            let is_system_tx = is_impersonated_tx(tx.signature(), tx.gas_price()).is_some();
+            system_transaction_count += is_system_tx as u32;
+            let hash = tx.tx_hash();
+
+            let columns = &mut self.transactions;
+            columns.block_number.append_value(number);
+            columns.transaction_index.append_value(index);
+            columns.hash.append_value(hash)?;
+            columns.from.append_value(sender)?;
+            match tx.to() {
+                Some(to) => columns.to.append_value(to)?,
+                None => columns.to.append_null(),
+            }
+            columns.is_system_tx.append_value(is_system_tx);
+            columns.tx_type.append_value(tx.ty());
+            columns.nonce.append_value(tx.nonce());
+            columns.value.append_value(tx.value().to_string());
+            columns.gas_limit.append_value(tx.gas_limit());
+            columns
+                .gas_used
+                .append_value(receipt.cumulative_gas_used - previous_cumulative_gas_used);
+            previous_cumulative_gas_used = receipt.cumulative_gas_used;
+            columns.max_fee_per_gas.append_value(tx.max_fee_per_gas().to_string());
+            columns
+                .max_priority_fee_per_gas
+                .append_option(tx.max_priority_fee_per_gas().map(|fee| fee.to_string()));
+            columns.input.append_value(tx.input());
+            columns.success.append_value(receipt.success);
+
+            for log in &receipt.logs {
+                let columns = &mut self.logs;
+                columns.block_number.append_value(number);
+                columns.transaction_index.append_value(index);
+                columns.log_index.append_value(log_index);
+                columns.transaction_hash.append_value(hash)?;
+                columns.address.append_value(log.address)?;
+                for (position, topic) in columns.topics.iter_mut().enumerate() {
+                    match log.topics().get(position) {
+                        Some(value) => topic.append_value(value)?,
+                        None => topic.append_null(),
+                    }
+                }
+                columns.data.append_value(&log.data.data);
+
+                if let Some(transfer) = Erc20Transfer::decode(log) {
+                    let columns = &mut self.transfers;
+                    columns.block_number.append_value(number);
+                    columns.transaction_index.append_value(index);
+                    columns.log_index.append_value(log_index);
+                    columns.transaction_hash.append_value(hash)?;
+                    columns.token.append_value(transfer.token)?;
+                    columns.from.append_value(transfer.from)?;
+                    columns.to.append_value(transfer.to)?;
+                    columns.value.append_value(transfer.value.to_string());
+                }
+                log_index += 1;
+            }
+        }
+
+        let columns = &mut self.blocks;
+        columns.number.append_value(number);
+        columns.hash.append_value(block.hash())?;
+        columns.parent_hash.append_value(block.parent_hash())?;
+        columns.timestamp.append_value(block.timestamp());
+        columns.miner.append_value(block.beneficiary())?;
+        columns.gas_used.append_value(block.gas_used());
+        columns.gas_limit.append_value(block.gas_limit());
+        columns.base_fee_per_gas.append_option(block.base_fee_per_gas());
+        columns.transaction_count.append_value(block.body().transactions.len() as u32);
+        columns.system_transaction_count.append_value(system_transaction_count);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Tables, ArrowError> {
+        let Self { mut blocks, mut transactions, mut logs, mut transfers } = self;
+        let [topic0, topic1, topic2, topic3] = &mut logs.topics;
+        Ok(Tables([
+            batch(
+                transactions_schema(),
+                vec![
+                    Arc::new(transactions.block_number.finish()),
+                    Arc::new(transactions.transaction_index.finish()),
+                    Arc::new(transactions.hash.finish()),
+                    Arc::new(transactions.from.finish()),
+                    Arc::new(transactions.to.finish()),
+                    Arc::new(transactions.is_system_tx.finish()),
+                    Arc::new(transactions.tx_type.finish()),
+                    Arc::new(transactions.nonce.finish()),
+                    Arc::new(transactions.value.finish()),
+                    Arc::new(transactions.gas_limit.finish()),
+                    Arc::new(transactions.gas_used.finish()),
+                    Arc::new(transactions.max_fee_per_gas.finish()),
+                    Arc::new(transactions.max_priority_fee_per_gas.finish()),
+                    Arc::new(transactions.input.finish()),
+                    Arc::new(transactions.success.finish()),
+                ],
+            )?,
+            batch(
+                logs_schema(),
+                vec![
+                    Arc::new(logs.block_number.finish()),
+                    Arc::new(logs.transaction_index.finish()),
+                    Arc::new(logs.log_index.finish()),
+                    Arc::new(logs.transaction_hash.finish()),
+                    Arc::new(logs.address.finish()),
+                    Arc::new(topic0.finish()),
+                    Arc::new(topic1.finish()),
+                    Arc::new(topic2.finish()),
+                    Arc::new(topic3.finish()),
+                    Arc::new(logs.data.finish()),
+                ],
+            )?,
+            batch(
+                erc20_transfers_schema(),
+                vec![
+                    Arc::new(transfers.block_number.finish()),
+                    Arc::new(transfers.transaction_index.finish()),
+                    Arc::new(transfers.log_index.finish()),
+                    Arc::new(transfers.transaction_hash.finish()),
+                    Arc::new(transfers.token.finish()),
+                    Arc::new(transfers.from.finish()),
+                    Arc::new(transfers.to.finish()),
+                    Arc::new(transfers.value.finish()),
+                ],
+            )?,
+            batch(
+                blocks_schema(),
+                vec![
+                    Arc::new(blocks.number.finish()),
+                    Arc::new(blocks.hash.finish()),
+                    Arc::new(blocks.parent_hash.finish()),
+                    Arc::new(blocks.timestamp.finish()),
+                    Arc::new(blocks.miner.finish()),
+                    Arc::new(blocks.gas_used.finish()),
+                    Arc::new(blocks.gas_limit.finish()),
+                    Arc::new(blocks.base_fee_per_gas.finish()),
+                    Arc::new(blocks.transaction_count.finish()),
+                    Arc::new(blocks.system_transaction_count.finish()),
+                ],
+            )?,
+        ]))
+    }
+}
+
+fn batch(schema: Schema, columns: Vec<ArrayRef>) -> Result<RecordBatch, ArrowError> {
+    RecordBatch::try_new(Arc::new(schema), columns)
+}
+
+struct BlockColumns {
+    number: UInt64Builder,
+    hash: FixedSizeBinaryBuilder,
+    parent_hash: FixedSizeBinaryBuilder,
+    timestamp: UInt64Builder,
+    miner: FixedSizeBinaryBuilder,
+    gas_used: UInt64Builder,
+    gas_limit: UInt64Builder,
+    base_fee_per_gas: UInt64Builder,
+    transaction_count: UInt32Builder,
+    system_transaction_count: UInt32Builder,
+}
+
+struct TransactionColumns {
+    block_number: UInt64Builder,
+    transaction_index: UInt32Builder,
+    hash: FixedSizeBinaryBuilder,
+    from: FixedSizeBinaryBuilder,
+    to: FixedSizeBinaryBuilder,
+    is_system_tx: BooleanBuilder,
+    tx_type: UInt8Builder,
+    nonce: UInt64Builder,
+    value: StringBuilder,
+    gas_limit: UInt64Builder,
+    gas_used: UInt64Builder,
+    max_fee_per_gas: StringBuilder,
+    max_priority_fee_per_gas: StringBuilder,
+    input: BinaryBuilder,
+    success: BooleanBuilder,
+}
+
+struct LogColumns {
+    block_number: UInt64Builder,
+    transaction_index: UInt32Builder,
+    log_index: UInt32Builder,
+    transaction_hash: FixedSizeBinaryBuilder,
+    address: FixedSizeBinaryBuilder,
+    topics: [FixedSizeBinaryBuilder; 4],
+    data: BinaryBuilder,
+}
+
+struct TransferColumns {
+    block_number: UInt64Builder,
+    transaction_index: UInt32Builder,
+    log_index: UInt32Builder,
+    transaction_hash: FixedSizeBinaryBuilder,
+    token: FixedSizeBinaryBuilder,
+    from: FixedSizeBinaryBuilder,
+    to: FixedSizeBinaryBuilder,
+    value: StringBuilder,
+}
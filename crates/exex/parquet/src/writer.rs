@@ -0,0 +1,245 @@
+//! Parquet files of the exported tables, partitioned by block ranges.
+
+use crate::{Tables, TABLES};
+use parquet::{
+    arrow::ArrowWriter,
+    basic::{Compression, ZstdLevel},
+    errors::ParquetError,
+    file::properties::WriterProperties,
+};
+use reth_primitives::{Block, Receipt, RecoveredBlock};
+use std::{
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
+
+/// Writes the exported tables as Parquet files, one per table and block range, laid out as
+/// `<dir>/<table>/<first block>-<last block>.parquet`.
+///
+/// Every file is written atomically and the `blocks` table last, so a partition is complete if
+/// and only if its `blocks` file exists.
+#[derive(Debug, Clone)]
+pub struct PartitionWriter {
+    dir: PathBuf,
+    partition_size: u64,
+}
+
+impl PartitionWriter {
+    /// Creates a new writer to `dir`, partitioning the blocks in ranges of `partition_size`
+    /// blocks aligned to multiples of it.
+    pub fn new(dir: impl Into<PathBuf>, partition_size: u64) -> Self {
+        Self { dir: dir.into(), partition_size: partition_size.max(1) }
+    }
+
+    /// Returns the directory the partitions are written to.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the range of the partition holding the block.
+    pub const fn partition_of(&self, number: u64) -> RangeInclusive<u64> {
+        let first = number - number % self.partition_size;
+        first..=first + self.partition_size - 1
+    }
+
+    fn path(&self, table: &str, range: &RangeInclusive<u64>) -> PathBuf {
+        self.dir.join(table).join(format!("{:012}-{:012}.parquet", range.start(), range.end()))
+    }
+
+    /// Returns `true` if the blocks of `range` are all held by a complete partition.
+    pub fn is_written(&self, range: &RangeInclusive<u64>) -> eyre::Result<bool> {
+        Ok(self
+            .partitions()?
+            .iter()
+            .any(|partition| partition.start() <= range.start() && partition.end() >= range.end()))
+    }
+
+    /// Writes the blocks and their receipts as the partition `range`.
+    ///
+    /// Partitions overlapping `range`, e.g. a partial partition written by the ExEx when it
+    /// started in the middle of the range, are replaced. Partitions that also hold blocks outside
+    /// of `range` can't be replaced without losing them, so they are an error.
+    pub fn write<'a>(
+        &self,
+        range: &RangeInclusive<u64>,
+        blocks: impl IntoIterator<Item = (&'a RecoveredBlock<Block>, &'a [Receipt])>,
+    ) -> eyre::Result<()> {
+        let overlapping = self
+            .partitions()?
+            .into_iter()
+            .filter(|partition| {
+                partition.start() <= range.end() && partition.end() >= range.start()
+            })
+            .filter(|partition| partition != range)
+            .collect::<Vec<_>>();
+        if let Some(partition) = overlapping
+            .iter()
+            .find(|partition| partition.start() < range.start() || partition.end() > range.end())
+        {
+            eyre::bail!(
+                "partition {partition:?} in {} overlaps {range:?} partially, was it written with \
+                 another partition size?",
+                self.dir.display()
+            )
+        }
+
+        let Tables(batches) = Tables::new(blocks)?;
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::ZSTD(ZstdLevel::default()))
+            .build();
+        for (table, batch) in TABLES.iter().zip(batches) {
+            let path = self.path(table, range);
+            reth_fs_util::create_dir_all(self.dir.join(table))?;
+            reth_fs_util::atomic_write_file(&path, |file| {
+                let mut writer =
+                    ArrowWriter::try_new(file, batch.schema(), Some(properties.clone()))?;
+                writer.write(&batch)?;
+                writer.close()?;
+                Ok::<_, ParquetError>(())
+            })?;
+        }
+
+        for partition in &overlapping {
+            self.remove(partition)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the ranges of the complete partitions, in ascending order.
+    pub fn partitions(&self) -> eyre::Result<Vec<RangeInclusive<u64>>> {
+        let dir = self.dir.join(TABLES[TABLES.len() - 1]);
+        if !dir.exists() {
+            return Ok(Vec::new())
+        }
+
+        let mut partitions = Vec::new();
+        for entry in reth_fs_util::read_dir(&dir)? {
+            let name = entry?.file_name();
+            let Some((first, last)) = name
+                .to_str()
+                .and_then(|name| name.strip_suffix(".parquet"))
+                .and_then(|name| name.split_once('-'))
+            else {
+                continue
+            };
+            partitions.push(first.parse()?..=last.parse()?);
+        }
+        partitions.sort_unstable_by_key(|range| *range.start());
+        Ok(partitions)
+    }
+
+    /// Removes the partitions holding blocks above `number`, returning their ranges.
+    pub fn remove_above(&self, number: u64) -> eyre::Result<Vec<RangeInclusive<u64>>> {
+        let removed = self
+            .partitions()?
+            .into_iter()
+            .filter(|range| *range.end() > number)
+            .collect::<Vec<_>>();
+        for range in &removed {
+            self.remove(range)?;
+        }
+        Ok(removed)
+    }
+
+    /// Removes the files of the partition `range`.
+    fn remove(&self, range: &RangeInclusive<u64>) -> eyre::Result<()> {
+        // The `blocks` file goes first, so that an interrupted removal leaves an incomplete
+        // partition rather than a complete one with missing rows
+        for table in TABLES.iter().rev() {
+            let path = self.path(table, range);
+            if path.exists() {
+                reth_fs_util::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+
+    #[test]
+    fn writes_and_removes_partitions() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let blocks = random_block_range(
+            &mut rng,
+            0..=19,
+            BlockRangeParams { tx_count: 1..3, ..Default::default() },
+        )
+        .into_iter()
+        .map(|block| block.try_recover())
+        .collect::<Result<Vec<_>, _>>()?;
+        let receipts = blocks
+            .iter()
+            .map(|block| vec![Receipt::default(); block.body().transactions.len()])
+            .collect::<Vec<_>>();
+
+        let dir = tempfile::tempdir()?;
+        let writer = PartitionWriter::new(dir.path(), 10);
+        assert_eq!(writer.partition_of(15), 10..=19);
+        for range in [0..=9, 10..=19] {
+            let rows = blocks[*range.start() as usize..=*range.end() as usize]
+                .iter()
+                .zip(&receipts[*range.start() as usize..=*range.end() as usize])
+                .map(|(block, receipts)| (block, receipts.as_slice()));
+            writer.write(&range, rows)?;
+            assert!(writer.is_written(&range)?);
+        }
+        assert_eq!(writer.partitions()?, vec![0..=9, 10..=19]);
+        assert!(writer.is_written(&(12..=15))?);
+        assert!(!writer.is_written(&(15..=25))?);
+
+        let transactions = ParquetRecordBatchReaderBuilder::try_new(std::fs::File::open(
+            writer.path("transactions", &(0..=9)),
+        )?)?
+        .build()?
+        .map(|batch| batch.map(|batch| batch.num_rows()))
+        .sum::<Result<usize, _>>()?;
+        assert_eq!(
+            transactions,
+            blocks[..10].iter().map(|block| block.body().transactions.len()).sum::<usize>()
+        );
+
+        assert_eq!(writer.remove_above(12)?, vec![10..=19]);
+        assert_eq!(writer.partitions()?, vec![0..=9]);
+        assert!(!writer.path("logs", &(10..=19)).exists());
+        Ok(())
+    }
+
+    #[test]
+    fn replaces_overlapping_partitions() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let blocks = random_block_range(
+            &mut rng,
+            0..=19,
+            BlockRangeParams { tx_count: 0..1, ..Default::default() },
+        )
+        .into_iter()
+        .map(|block| block.try_recover())
+        .collect::<Result<Vec<_>, _>>()?;
+        let rows = |range: RangeInclusive<u64>| {
+            blocks[*range.start() as usize..=*range.end() as usize]
+                .iter()
+                .map(|block| (block, &[] as &[Receipt]))
+        };
+
+        let dir = tempfile::tempdir()?;
+        let writer = PartitionWriter::new(dir.path(), 10);
+        writer.write(&(5..=9), rows(5..=9))?;
+        writer.write(&(10..=13), rows(10..=13))?;
+        assert!(!writer.is_written(&(0..=9))?);
+
+        // The partial partitions written by the ExEx are replaced by the complete ones
+        writer.write(&(0..=9), rows(0..=9))?;
+        assert_eq!(writer.partitions()?, vec![0..=9, 10..=13]);
+        assert!(!writer.path("transactions", &(5..=9)).exists());
+
+        // A partition holding blocks outside of the range is kept
+        assert!(writer.write(&(10..=11), rows(10..=11)).is_err());
+        assert_eq!(writer.partitions()?, vec![0..=9, 10..=13]);
+        Ok(())
+    }
+}
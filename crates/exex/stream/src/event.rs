@@ -0,0 +1,164 @@
+//! Events sent to the stream subscribers.
+
+use alloy_consensus::{BlockHeader, Transaction};
+use alloy_eips::BlockNumHash;
+use alloy_primitives::{Address, U256};
+use reth_db_api::models::NativeTransferKind;
+use reth_exex::ExExNotification;
+use reth_hyperliquid_types::{
+    archive::{read_archive_block, EvmBlock},
+    PrecompilesCache, ReadPrecompileInput, ReadPrecompileResult,
+};
+use reth_primitives::{Block, EthPrimitives, Receipt, RecoveredBlock, SealedBlock};
+use reth_primitives_traits::{transaction::signed::is_impersonated_tx, SignedTransaction};
+use reth_provider::NativeBridgeTransfer;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The read precompile calls of a block as recorded by hl-node, keyed by precompile address.
+pub type ReadPrecompileCalls = Vec<(Address, Vec<(ReadPrecompileInput, ReadPrecompileResult)>)>;
+
+/// An [`ExExNotification`] as sent to the stream subscribers.
+///
+/// A reorg is sent as a single event holding both the reverted and the committed blocks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamEvent {
+    /// The position of the notification in the stream. Subscribing from `seq + 1` resumes the
+    /// stream after this event.
+    pub seq: u32,
+    /// The blocks removed from the canonical chain, highest first.
+    pub reverted: Vec<BlockNumHash>,
+    /// The blocks added to the canonical chain, lowest first.
+    pub committed: Vec<StreamBlock>,
+}
+
+impl StreamEvent {
+    /// Converts the notification stored in the WAL at `seq` into an event, looking up the read
+    /// precompile calls of the committed blocks in `calls`.
+    pub fn new(
+        seq: u32,
+        notification: &ExExNotification<EthPrimitives>,
+        calls: &ReadPrecompileCallsSource,
+    ) -> Self {
+        let reverted = notification
+            .reverted_chain()
+            .map(|chain| chain.blocks_iter().rev().map(|block| block.num_hash()).collect())
+            .unwrap_or_default();
+        let committed = notification
+            .committed_chain()
+            .map(|chain| {
+                chain
+                    .blocks_iter()
+                    .map(|block| {
+                        let receipts =
+                            chain.execution_outcome().receipts_by_block(block.number()).to_vec();
+                        StreamBlock {
+                            block: block.sealed_block().clone(),
+                            senders: block.senders().to_vec(),
+                            native_transfers: native_transfers(block, &receipts),
+                            receipts,
+                            system_tx_count: system_tx_count(block),
+                            read_precompile_calls: calls.get(block),
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { seq, reverted, committed }
+    }
+}
+
+/// A block committed to the canonical chain, along with the data indexers usually need to
+/// re-derive from the RPC.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamBlock {
+    /// The block as executed by the node, including the system transactions.
+    pub block: SealedBlock,
+    /// The recovered senders of the block transactions.
+    pub senders: Vec<Address>,
+    /// The receipts of the block transactions, including the system transactions.
+    pub receipts: Vec<Receipt>,
+    /// The number of system transactions at the start of the block body.
+    pub system_tx_count: usize,
+    /// The native token deposits and withdrawals between HyperCore and HyperEVM of the block.
+    pub native_transfers: Vec<NativeTransfer>,
+    /// The read precompile calls made by the block. `None` if they were neither seen by the
+    /// ingest loop recently nor found in the archive.
+    pub read_precompile_calls: Option<ReadPrecompileCalls>,
+}
+
+/// A native token transfer between HyperCore and HyperEVM, see [`NativeBridgeTransfer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeTransfer {
+    /// The index of the transaction in the block body.
+    pub transaction_index: usize,
+    /// The user the tokens were deposited to or withdrawn by.
+    pub user: Address,
+    /// The direction of the transfer.
+    pub kind: NativeTransferKind,
+    /// The amount of tokens transferred.
+    pub value: U256,
+}
+
+/// Returns the native token transfers made by the block transactions.
+fn native_transfers(block: &RecoveredBlock<Block>, receipts: &[Receipt]) -> Vec<NativeTransfer> {
+    block
+        .body()
+        .transactions
+        .iter()
+        .zip(receipts)
+        .enumerate()
+        .filter_map(|(transaction_index, (tx, receipt))| {
+            let NativeBridgeTransfer { user, kind, value } =
+                NativeBridgeTransfer::decode(tx, receipt)?;
+            Some(NativeTransfer { transaction_index, user, kind, value })
+        })
+        .collect()
+}
+
+/// Returns the number of system transactions, which the ingest loop prepends to the block body.
+fn system_tx_count(block: &RecoveredBlock<Block>) -> usize {
+    block
+        .body()
+        .transactions
+        .iter()
+        .take_while(|tx| is_impersonated_tx(tx.signature(), tx.gas_price()).is_some())
+        .count()
+}
+
+/// Source of the read precompile calls of the streamed blocks.
+///
+/// The calls of recently ingested blocks are shared by the ingest loop through `recent`, older
+/// ones are read from the archive.
+#[derive(Debug, Clone, Default)]
+pub struct ReadPrecompileCallsSource {
+    recent: PrecompilesCache,
+    ingest_dir: Option<PathBuf>,
+}
+
+impl ReadPrecompileCallsSource {
+    /// Creates a new source reading the calls from `recent`, then from the archive at
+    /// `ingest_dir`.
+    pub const fn new(recent: PrecompilesCache, ingest_dir: Option<PathBuf>) -> Self {
+        Self { recent, ingest_dir }
+    }
+
+    /// Returns the read precompile calls of the block, if available.
+    pub fn get(&self, block: &RecoveredBlock<Block>) -> Option<ReadPrecompileCalls> {
+        if let Some(calls) = self.recent.lock().get(&block.number()) {
+            return Some(calls.clone())
+        }
+
+        let archived = read_archive_block(self.ingest_dir.as_deref()?, block.number())?;
+        let EvmBlock::Reth115(archived_block) = &archived.block;
+        (archived_block.hash() == block.hash()).then_some(archived.read_precompile_calls)
+    }
+
+    /// Drops the calls of the blocks below `number` shared by the ingest loop.
+    pub fn prune_below(&self, number: u64) {
+        self.recent.lock().retain(|height, _| *height >= number);
+    }
+}
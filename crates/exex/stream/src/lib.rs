@@ -0,0 +1,157 @@
+//! ExEx serving the canonical chain notifications to external consumers over WebSocket and IPC.
+//!
+//! Every notification received by the ExEx is committed to its own [`Wal`] before being
+//! published, and the WAL file ID of the notification is the sequence number of the
+//! [`StreamEvent`] sent for it. Subscribers store the sequence number of the last event they
+//! processed and resume from the next one after a restart of either side, as long as it wasn't
+//! pruned from the WAL, see [`StreamConfig::retention_blocks`].
+//!
+//! The subscriptions are served by the `exex_subscribe` method, see [`ExExStreamApiServer`].
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
+    html_favicon_url = "https://avatars0.githubusercontent.com/u/97369466?s=256",
+    issue_tracker_base_url = "https://github.com/paradigmxyz/reth/issues/"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+mod event;
+pub use event::{
+    NativeTransfer, ReadPrecompileCalls, ReadPrecompileCallsSource, StreamBlock, StreamEvent,
+};
+
+mod rpc;
+pub use rpc::{ExExStreamApiServer, ExExStreamRpc, CURSOR_PRUNED_CODE};
+
+use alloy_eips::BlockNumHash;
+use futures::TryStreamExt;
+use jsonrpsee::server::{ServerBuilder, ServerHandle};
+use reth_exex::{ExExContext, ExExEvent, ExExHead, ExExNotification, Wal};
+use reth_node_api::{FullNodeComponents, NodeTypes};
+use reth_primitives::EthPrimitives;
+use reth_provider::BlockHashReader;
+use reth_tracing::tracing::{debug, info};
+use std::{net::SocketAddr, path::PathBuf};
+use tokio::sync::watch;
+
+/// Configuration of the streaming ExEx.
+#[derive(Debug, Clone)]
+pub struct StreamConfig {
+    /// The address to serve the stream on over WebSocket, if any.
+    pub ws_addr: Option<SocketAddr>,
+    /// The path of the IPC socket to serve the stream on, if any.
+    pub ipc_path: Option<String>,
+    /// The directory of the WAL backing the stream.
+    pub wal_dir: PathBuf,
+    /// The number of blocks below the tip kept in the WAL, and so replayable by subscribers.
+    pub retention_blocks: u64,
+    /// Source of the read precompile calls of the streamed blocks.
+    pub calls: ReadPrecompileCallsSource,
+}
+
+/// The streaming ExEx, see the crate docs.
+#[derive(Debug)]
+pub struct ExExStream<Node: FullNodeComponents> {
+    ctx: ExExContext<Node>,
+    wal: Wal,
+    /// The sequence number of the next notification committed to the WAL.
+    next_seq: u32,
+    tip: watch::Sender<Option<u32>>,
+    retention_blocks: u64,
+    calls: ReadPrecompileCallsSource,
+    /// The servers stop when their handles are dropped.
+    _servers: Vec<ServerHandle>,
+}
+
+impl<Node> ExExStream<Node>
+where
+    Node: FullNodeComponents<Types: NodeTypes<Primitives = EthPrimitives>>,
+{
+    /// Opens the WAL, starts the servers and resumes the notifications after the last one
+    /// committed to the WAL.
+    pub async fn new(mut ctx: ExExContext<Node>, config: StreamConfig) -> eyre::Result<Self> {
+        let wal = Wal::new(&config.wal_dir)?;
+        let files_range = wal.handle().files_range()?;
+        let last_seq = files_range.map(|range| *range.end());
+
+        // Resume after the last notification committed to the WAL, on top of its committed
+        // chain, or below its reverted chain if it has none
+        match last_seq.map(|seq| wal.handle().read_notification(seq)).transpose()?.flatten() {
+            Some(notification) => {
+                let head = match &notification {
+                    ExExNotification::ChainCommitted { new } |
+                    ExExNotification::ChainReorged { new, .. } => new.tip().num_hash(),
+                    ExExNotification::ChainReverted { old } => {
+                        let fork_block = old.fork_block();
+                        BlockNumHash::new(fork_block.number, fork_block.hash)
+                    }
+                };
+                info!(target: "exex::stream", ?head, seq = ?last_seq, "Resuming stream");
+                ctx.set_notifications_with_head(ExExHead::new(head));
+            }
+            None => ctx.set_notifications_without_head(),
+        }
+
+        let (tip, tip_rx) = watch::channel(last_seq);
+        let module = ExExStreamRpc::new(
+            wal.clone(),
+            tip_rx,
+            config.calls.clone(),
+            Box::new(ctx.task_executor().clone()),
+        )
+        .into_rpc();
+
+        let mut servers = Vec::new();
+        if let Some(addr) = config.ws_addr {
+            let server = ServerBuilder::default().ws_only().build(addr).await?;
+            info!(target: "exex::stream", addr = %server.local_addr()?, "Stream WS server started");
+            servers.push(server.start(module.clone()));
+        }
+        if let Some(path) = config.ipc_path {
+            let handle =
+                reth_ipc::server::Builder::default().build(path.clone()).start(module).await?;
+            info!(target: "exex::stream", %path, "Stream IPC server started");
+            servers.push(handle);
+        }
+
+        Ok(Self {
+            ctx,
+            wal,
+            next_seq: last_seq.map_or(0, |seq| seq + 1),
+            tip,
+            retention_blocks: config.retention_blocks,
+            calls: config.calls,
+            _servers: servers,
+        })
+    }
+
+    /// Commits the notifications to the WAL and publishes them to the subscribers.
+    pub async fn run(mut self) -> eyre::Result<()> {
+        while let Some(notification) = self.ctx.notifications.try_next().await? {
+            self.wal.commit(&notification)?;
+            let seq = self.next_seq;
+            self.next_seq += 1;
+            self.tip.send_replace(Some(seq));
+            debug!(target: "exex::stream", seq, "Published notification");
+
+            if let Some(committed_chain) = notification.committed_chain() {
+                let tip = committed_chain.tip().num_hash();
+                self.prune(tip.number)?;
+                self.ctx.events.send(ExExEvent::FinishedHeight(tip))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the notifications older than the retention window from the WAL.
+    fn prune(&self, tip: u64) -> eyre::Result<()> {
+        let Some(number) = tip.checked_sub(self.retention_blocks) else { return Ok(()) };
+        if let Some(hash) = self.ctx.provider().block_hash(number)? {
+            self.wal.finalize(BlockNumHash::new(number, hash))?;
+        }
+        self.calls.prune_below(number);
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+//! `exex` namespace serving the stream subscriptions.
+
+use crate::{ReadPrecompileCallsSource, StreamEvent};
+use jsonrpsee::{
+    core::SubscriptionResult, proc_macros::rpc, types::ErrorObject, PendingSubscriptionSink,
+    SubscriptionMessage, SubscriptionSink,
+};
+use reth_exex::Wal;
+use reth_tasks::TaskSpawner;
+use reth_tracing::tracing::{debug, warn};
+use tokio::sync::watch;
+
+/// Error code returned when the requested cursor was already pruned from the WAL.
+pub const CURSOR_PRUNED_CODE: i32 = -32001;
+
+/// The `exex` namespace of the stream server.
+#[rpc(server, namespace = "exex")]
+pub trait ExExStreamApi {
+    /// Subscribes to the canonical chain notifications, starting at the event with sequence
+    /// number `from`, or at the next event if `from` is omitted.
+    ///
+    /// Events still in the WAL are replayed before the live ones, so a subscriber that stores the
+    /// `seq` of the last event it processed resumes without gaps by subscribing from `seq + 1`.
+    #[subscription(name = "subscribe" => "subscription", unsubscribe = "unsubscribe", item = StreamEvent)]
+    async fn subscribe(&self, from: Option<u32>) -> SubscriptionResult;
+}
+
+/// Server implementation of [`ExExStreamApiServer`], reading the events from the WAL of the
+/// streaming ExEx.
+#[derive(Debug)]
+pub struct ExExStreamRpc {
+    wal: Wal,
+    tip: watch::Receiver<Option<u32>>,
+    calls: ReadPrecompileCallsSource,
+    task_spawner: Box<dyn TaskSpawner>,
+}
+
+impl ExExStreamRpc {
+    /// Creates a new server over `wal`, whose last committed sequence number is published to
+    /// `tip`.
+    pub fn new(
+        wal: Wal,
+        tip: watch::Receiver<Option<u32>>,
+        calls: ReadPrecompileCallsSource,
+        task_spawner: Box<dyn TaskSpawner>,
+    ) -> Self {
+        Self { wal, tip, calls, task_spawner }
+    }
+}
+
+#[async_trait::async_trait]
+impl ExExStreamApiServer for ExExStreamRpc {
+    async fn subscribe(
+        &self,
+        pending: PendingSubscriptionSink,
+        from: Option<u32>,
+    ) -> SubscriptionResult {
+        let next = self.tip.borrow().map_or(0, |tip| tip + 1);
+        let from = match from {
+            Some(from) => {
+                let first = self.wal.handle().files_range()?.map_or(next, |range| *range.start());
+                if from < first {
+                    pending
+                        .reject(ErrorObject::owned(
+                            CURSOR_PRUNED_CODE,
+                            format!("cursor {from} was pruned, the oldest available is {first}"),
+                            None::<()>,
+                        ))
+                        .await;
+                    return Ok(())
+                }
+                from
+            }
+            None => next,
+        };
+
+        let sink = pending.accept().await?;
+        let (wal, tip, calls) = (self.wal.clone(), self.tip.clone(), self.calls.clone());
+        self.task_spawner.spawn(Box::pin(async move {
+            if let Err(err) = pipe_from_wal(sink, wal, tip, calls, from).await {
+                warn!(target: "exex::stream", %err, "Stream subscription failed");
+            }
+        }));
+
+        Ok(())
+    }
+}
+
+/// Sends the events from `from` onwards to the sink, waiting for new ones to be committed once
+/// the subscriber caught up with the tip.
+async fn pipe_from_wal(
+    sink: SubscriptionSink,
+    wal: Wal,
+    mut tip: watch::Receiver<Option<u32>>,
+    calls: ReadPrecompileCallsSource,
+    mut next: u32,
+) -> eyre::Result<()> {
+    loop {
+        let current = *tip.borrow_and_update();
+        while current.is_some_and(|tip| next <= tip) {
+            let (wal, calls) = (wal.clone(), calls.clone());
+            let event = tokio::task::spawn_blocking(move || {
+                let notification = wal.handle().read_notification(next)?;
+                eyre::Ok(notification.map(|n| StreamEvent::new(next, &n, &calls)))
+            })
+            .await??;
+            let Some(event) = event else {
+                eyre::bail!("event {next} was pruned before it was sent")
+            };
+
+            if sink.send(SubscriptionMessage::from_json(&event)?).await.is_err() {
+                // connection dropped
+                return Ok(())
+            }
+            next += 1;
+        }
+
+        tokio::select! {
+            _ = sink.closed() => {
+                debug!(target: "exex::stream", next, "Subscriber disconnected");
+                return Ok(())
+            }
+            changed = tip.changed() => {
+                if changed.is_err() {
+                    // the ExEx stopped
+                    return Ok(())
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_execution_types::Chain;
+    use reth_exex::ExExNotification;
+    use reth_tasks::TokioTaskExecutor;
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+    use std::sync::Arc;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn subscribers_resume_from_cursor() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let blocks = random_block_range(&mut rng, 0..=3, BlockRangeParams::default())
+            .into_iter()
+            .map(|block| block.try_recover())
+            .collect::<Result<Vec<_>, _>>()?;
+        let notifications = blocks
+            .iter()
+            .map(|block| ExExNotification::ChainCommitted {
+                new: Arc::new(Chain::new(vec![block.clone()], Default::default(), None)),
+            })
+            .collect::<Vec<_>>();
+
+        let temp_dir = tempfile::tempdir()?;
+        let wal = Wal::new(&temp_dir)?;
+        for notification in &notifications[..3] {
+            wal.commit(notification)?;
+        }
+        let (tip, tip_rx) = watch::channel(Some(2));
+        let module = ExExStreamRpc::new(
+            wal.clone(),
+            tip_rx,
+            ReadPrecompileCallsSource::default(),
+            Box::new(TokioTaskExecutor::default()),
+        )
+        .into_rpc();
+
+        // The events still in the WAL are replayed before the live ones
+        let mut subscription = module.subscribe_unbounded("exex_subscribe", [Some(1u32)]).await?;
+        for seq in 1..=2 {
+            let (event, _) = subscription.next::<StreamEvent>().await.unwrap()?;
+            assert_eq!(event.seq, seq);
+            assert_eq!(event.committed[0].block.hash(), blocks[seq as usize].hash());
+        }
+        wal.commit(&notifications[3])?;
+        tip.send_replace(Some(3));
+        let (event, _) = subscription.next::<StreamEvent>().await.unwrap()?;
+        assert_eq!(event.seq, 3);
+        assert!(event.reverted.is_empty());
+
+        // Cursors finalized out of the WAL are rejected
+        wal.finalize(blocks[1].num_hash())?;
+        assert!(module.subscribe_unbounded("exex_subscribe", [Some(1u32)]).await.is_err());
+        let mut subscription = module.subscribe_unbounded("exex_subscribe", [Some(2u32)]).await?;
+        let (event, _) = subscription.next::<StreamEvent>().await.unwrap()?;
+        assert_eq!(event.seq, 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+//! Blocks as written by hl-node, both to the archive (`--ingest-dir`) and to the local hourly
+//! files (`--local-ingest-dir`).
+
+use crate::ReadPrecompileCalls;
+use alloy_primitives::{Bytes, Log};
+use reth_primitives::{SealedBlock, Transaction};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A block together with the receipts, system transactions and read precompile calls recorded
+/// by hl-node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockAndReceipts {
+    pub block: EvmBlock,
+    pub receipts: Vec<LegacyReceipt>,
+    #[serde(default)]
+    pub system_txs: Vec<SystemTx>,
+    #[serde(default)]
+    pub read_precompile_calls: ReadPrecompileCalls,
+}
+
+impl BlockAndReceipts {
+    /// Returns the block number.
+    pub fn number(&self) -> u64 {
+        match &self.block {
+            EvmBlock::Reth115(block) => block.header().number,
+        }
+    }
+
+    /// Returns the msgpack encoded system transactions and read precompile calls of the block, as
+    /// they are stored in its sidecar.
+    pub fn encode_sidecar(&self) -> Result<(Bytes, Bytes), rmp_serde::encode::Error> {
+        Ok((
+            rmp_serde::to_vec(&self.system_txs)?.into(),
+            rmp_serde::to_vec(&self.read_precompile_calls)?.into(),
+        ))
+    }
+}
+
+/// Decodes the msgpack encoded read precompile calls of a block sidecar.
+pub fn decode_read_precompile_calls(
+    bytes: &[u8],
+) -> Result<ReadPrecompileCalls, rmp_serde::decode::Error> {
+    rmp_serde::from_slice(bytes)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EvmBlock {
+    Reth115(SealedBlock),
+}
+
+/// Receipt of a transaction as recorded by hl-node.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LegacyReceipt {
+    pub tx_type: LegacyTxType,
+    pub success: bool,
+    pub cumulative_gas_used: u64,
+    pub logs: Vec<Log>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LegacyTxType {
+    Legacy = 0,
+    Eip2930 = 1,
+    Eip1559 = 2,
+    Eip4844 = 3,
+    Eip7702 = 4,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemTx {
+    pub tx: Transaction,
+    pub receipt: Option<LegacyReceipt>,
+}
+
+/// Returns the path of a block in the archive laid out as
+/// `<ingest_dir>/<millions>/<thousands>/<height>.rmp.lz4`.
+pub fn archive_block_path(ingest_dir: &Path, height: u64) -> PathBuf {
+    let f = ((height - 1) / 1_000_000) * 1_000_000;
+    let s = ((height - 1) / 1_000) * 1_000;
+    ingest_dir.join(f.to_string()).join(s.to_string()).join(format!("{height}.rmp.lz4"))
+}
+
+/// Reads a block from the archive, returning `None` if it is missing or can't be decoded.
+pub fn read_archive_block(ingest_dir: &Path, height: u64) -> Option<BlockAndReceipts> {
+    let file = std::fs::read(archive_block_path(ingest_dir, height)).ok()?;
+    let mut decoder = lz4_flex::frame::FrameDecoder::new(&file[..]);
+    let blocks: Vec<BlockAndReceipts> = rmp_serde::from_read(&mut decoder).ok()?;
+    blocks.into_iter().next()
+}
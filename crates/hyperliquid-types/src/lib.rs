@@ -1,9 +1,16 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    fmt::Debug,
+    sync::Arc,
+};
 
 use alloy_primitives::{Address, Bytes};
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 
+pub mod archive;
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Hash)]
 pub struct ReadPrecompileInput {
     pub input: Bytes,
@@ -18,5 +25,40 @@ pub enum ReadPrecompileResult {
     UnexpectedError,
 }
 
-pub type PrecompilesCache =
-    Arc<Mutex<BTreeMap<u64, Vec<(Address, Vec<(ReadPrecompileInput, ReadPrecompileResult)>)>>>>;
+/// Read precompile calls of a block with their results, grouped by precompile address.
+pub type ReadPrecompileCalls = Vec<(Address, Vec<(ReadPrecompileInput, ReadPrecompileResult)>)>;
+
+pub type PrecompilesCache = Arc<Mutex<BTreeMap<u64, ReadPrecompileCalls>>>;
+
+/// Read precompile results that take precedence over the recorded ones, keyed by precompile
+/// address and call input. Used to simulate calls against hypothetical HyperCore state.
+///
+/// An overridden result applies to all calls with its input, whatever their gas limit.
+pub type PrecompileOverrides = HashMap<Address, HashMap<Bytes, ReadPrecompileResult>>;
+
+/// Source of read precompile results for calls that are not recorded by the ingested blocks.
+pub trait ReadPrecompileProvider: Debug + Send + Sync {
+    /// Resolves a call of the read precompile at `address` as of the given block.
+    fn read(
+        &self,
+        block_number: u64,
+        address: Address,
+        input: &ReadPrecompileInput,
+    ) -> Result<ReadPrecompileResult, Box<dyn Error + Send + Sync>>;
+}
+
+/// Source of the read precompile calls recorded for the ingested blocks, e.g. their sidecars in the
+/// node's storage.
+pub trait ReadPrecompileCallsReader: Debug + Send + Sync {
+    /// Returns the read precompile calls of the block, or `None` if they weren't recorded.
+    fn read_precompile_calls(
+        &self,
+        block_number: u64,
+    ) -> Result<Option<ReadPrecompileCalls>, Box<dyn Error + Send + Sync>>;
+}
+
+/// Returns `true` if the address belongs to the HyperCore read precompiles, which start at
+/// `0x0000000000000000000000000000000000000800`.
+pub fn is_read_precompile(address: &Address) -> bool {
+    address[..18].iter().all(|byte| *byte == 0) && address[18] == 0x08
+}
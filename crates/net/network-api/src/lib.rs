@@ -33,7 +33,12 @@ pub use events::{
     PeerRequestSender,
 };
 
-use std::{future::Future, net::SocketAddr, sync::Arc, time::Instant};
+use std::{
+    future::Future,
+    net::SocketAddr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use reth_eth_wire_types::{capability::Capabilities, DisconnectReason, EthVersion, Status};
 use reth_network_peers::NodeRecord;
@@ -83,6 +88,21 @@ pub trait NetworkInfo: Send + Sync {
 
     /// Returns `true` when the node is undergoing the very first Pipeline sync.
     fn is_initially_syncing(&self) -> bool;
+
+    /// Returns the highest block number known to be available for sync, if any.
+    fn highest_block(&self) -> Option<alloy_primitives::BlockNumber> {
+        None
+    }
+
+    /// Returns `true` if the network is listening for incoming connections.
+    fn is_listening(&self) -> bool {
+        true
+    }
+
+    /// Returns the sources the node syncs blocks from instead of devp2p peers.
+    fn sync_sources(&self) -> Vec<SyncSource> {
+        Vec::new()
+    }
 }
 
 /// Provides general purpose information about Peers in the network.
@@ -268,6 +288,29 @@ impl std::fmt::Display for Direction {
     }
 }
 
+/// A source the node syncs blocks from instead of devp2p peers, e.g. a block archive.
+///
+/// See [`NetworkSyncUpdater::update_source_height`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyncSource {
+    /// Name of the source.
+    pub name: &'static str,
+    /// Highest block number available from the source.
+    pub highest_block: alloy_primitives::BlockNumber,
+    /// When the source last reported its highest block.
+    pub updated_at: Instant,
+}
+
+impl SyncSource {
+    /// Duration after which a source that didn't report its highest block is considered inactive.
+    pub const TIMEOUT: Duration = Duration::from_secs(60);
+
+    /// Returns `true` if the source reported its highest block within [`Self::TIMEOUT`].
+    pub fn is_active(&self) -> bool {
+        self.updated_at.elapsed() < Self::TIMEOUT
+    }
+}
+
 /// The status of the network being ran by the local node.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
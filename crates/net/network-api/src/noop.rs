@@ -3,35 +3,141 @@
 //! This is useful for wiring components together that don't require network but still need to be
 //! generic over it.
 
-use std::net::{IpAddr, SocketAddr};
+use std::{
+    fmt,
+    marker::PhantomData,
+    net::{IpAddr, SocketAddr},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Instant,
+};
 
+use alloy_primitives::{BlockNumber, B256};
 use alloy_rpc_types_admin::EthProtocolInfo;
 use enr::{secp256k1::SecretKey, Enr};
-use reth_eth_wire_types::{DisconnectReason, ProtocolVersion};
+use reth_eth_wire_types::{
+    DisconnectReason, EthNetworkPrimitives, NetworkPrimitives, ProtocolVersion,
+};
+use reth_ethereum_forks::Head;
+use reth_network_p2p::{
+    bodies::client::BodiesClient,
+    download::DownloadClient,
+    error::PeerRequestResult,
+    headers::client::{HeadersClient, HeadersRequest},
+    priority::Priority,
+    sync::{NetworkSyncUpdater, SyncState},
+    BlockClient,
+};
 use reth_network_peers::NodeRecord;
 use reth_network_types::{PeerKind, Reputation, ReputationChangeKind};
+use reth_tokio_util::{EventSender, EventStream};
+use tokio::sync::{mpsc, oneshot};
+use tokio_stream::wrappers::UnboundedReceiverStream;
 
-use crate::{NetworkError, NetworkInfo, NetworkStatus, PeerId, PeerInfo, Peers, PeersInfo};
+use crate::{
+    events::{NetworkPeersEvents, PeerEventStream},
+    BlockDownloaderProvider, DiscoveryEvent, NetworkError, NetworkEvent,
+    NetworkEventListenerProvider, NetworkInfo, NetworkStatus, PeerId, PeerInfo, PeerRequest, Peers,
+    PeersInfo, SyncSource,
+};
 
 /// A type that implements all network trait that does nothing.
 ///
-/// Intended for testing purposes where network is not used.
-#[derive(Debug, Clone, Default)]
+/// Intended for testing purposes where network is not used, and for nodes that never sync over
+/// devp2p. The sync state and the sync sources reported via [`NetworkSyncUpdater`] are tracked so
+/// that they can still be served over RPC.
 #[non_exhaustive]
-pub struct NoopNetwork;
+pub struct NoopNetwork<Net: NetworkPrimitives = EthNetworkPrimitives> {
+    chain_id: u64,
+    client_version: String,
+    secret_key: SecretKey,
+    is_syncing: Arc<AtomicBool>,
+    sync_sources: Arc<Mutex<Vec<SyncSource>>>,
+    event_sender: EventSender<NetworkEvent<PeerRequest<Net>>>,
+    _primitives: PhantomData<Net>,
+}
+
+impl<Net: NetworkPrimitives> NoopNetwork<Net> {
+    /// Creates a new [`NoopNetwork`] for mainnet.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            // mainnet
+            chain_id: 1,
+            client_version: "reth-test".to_string(),
+            secret_key: SecretKey::from_slice(&[0xcd; 32]).expect("valid secret key"),
+            is_syncing: Default::default(),
+            sync_sources: Default::default(),
+            event_sender: Default::default(),
+            _primitives: PhantomData,
+        }
+    }
+
+    /// Sets the chain id reported by the network.
+    pub const fn with_chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    /// Sets the secret key the node record and the ENR of the node are derived from.
+    pub const fn with_secret_key(mut self, secret_key: SecretKey) -> Self {
+        self.secret_key = secret_key;
+        self
+    }
+
+    /// Sets the client version reported by the network.
+    pub fn with_client_version(mut self, client_version: impl Into<String>) -> Self {
+        self.client_version = client_version.into();
+        self
+    }
+}
 
-impl NetworkInfo for NoopNetwork {
+impl Default for NoopNetwork {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Net: NetworkPrimitives> Clone for NoopNetwork<Net> {
+    fn clone(&self) -> Self {
+        Self {
+            chain_id: self.chain_id,
+            client_version: self.client_version.clone(),
+            secret_key: self.secret_key,
+            is_syncing: self.is_syncing.clone(),
+            sync_sources: self.sync_sources.clone(),
+            event_sender: self.event_sender.clone(),
+            _primitives: PhantomData,
+        }
+    }
+}
+
+impl<Net: NetworkPrimitives> fmt::Debug for NoopNetwork<Net> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NoopNetwork")
+            .field("chain_id", &self.chain_id)
+            .field("client_version", &self.client_version)
+            .field("is_syncing", &self.is_syncing)
+            .field("sync_sources", &self.sync_sources)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Net: NetworkPrimitives> NetworkInfo for NoopNetwork<Net> {
+    /// Returns the unspecified address with port 0, since nothing is listening.
     fn local_addr(&self) -> SocketAddr {
-        (IpAddr::from(std::net::Ipv4Addr::UNSPECIFIED), 30303).into()
+        (IpAddr::from(std::net::Ipv4Addr::UNSPECIFIED), 0).into()
     }
 
     async fn network_status(&self) -> Result<NetworkStatus, NetworkError> {
         #[allow(deprecated)]
         Ok(NetworkStatus {
-            client_version: "reth-test".to_string(),
+            client_version: self.client_version.clone(),
             protocol_version: ProtocolVersion::V5 as u64,
             eth_protocol_info: EthProtocolInfo {
-                network: 1,
+                network: self.chain_id,
                 difficulty: None,
                 genesis: Default::default(),
                 config: Default::default(),
@@ -41,35 +147,92 @@ impl NetworkInfo for NoopNetwork {
     }
 
     fn chain_id(&self) -> u64 {
-        // mainnet
-        1
+        self.chain_id
     }
 
     fn is_syncing(&self) -> bool {
-        false
+        self.is_syncing.load(Ordering::Relaxed)
     }
 
     fn is_initially_syncing(&self) -> bool {
         false
     }
+
+    fn is_listening(&self) -> bool {
+        false
+    }
+
+    fn highest_block(&self) -> Option<BlockNumber> {
+        self.sync_sources.lock().unwrap().iter().map(|source| source.highest_block).max()
+    }
+
+    fn sync_sources(&self) -> Vec<SyncSource> {
+        self.sync_sources.lock().unwrap().clone()
+    }
+}
+
+impl<Net: NetworkPrimitives> NetworkSyncUpdater for NoopNetwork<Net> {
+    fn update_sync_state(&self, state: SyncState) {
+        self.is_syncing.store(state.is_syncing(), Ordering::Relaxed);
+    }
+
+    fn update_status(&self, _head: Head) {}
+
+    fn update_source_height(&self, source: &'static str, number: BlockNumber) {
+        let mut sources = self.sync_sources.lock().unwrap();
+        let updated_at = Instant::now();
+        match sources.iter_mut().find(|existing| existing.name == source) {
+            Some(existing) => {
+                existing.highest_block = existing.highest_block.max(number);
+                existing.updated_at = updated_at;
+            }
+            None => sources.push(SyncSource { name: source, highest_block: number, updated_at }),
+        }
+    }
+}
+
+impl<Net: NetworkPrimitives> NetworkPeersEvents for NoopNetwork<Net> {
+    fn peer_events(&self) -> PeerEventStream {
+        PeerEventStream::new(futures::stream::pending::<crate::events::PeerEvent>())
+    }
 }
 
-impl PeersInfo for NoopNetwork {
+impl<Net: NetworkPrimitives> NetworkEventListenerProvider for NoopNetwork<Net> {
+    type Primitives = Net;
+
+    fn event_listener(&self) -> EventStream<NetworkEvent<PeerRequest<Self::Primitives>>> {
+        self.event_sender.new_listener()
+    }
+
+    fn discovery_listener(&self) -> UnboundedReceiverStream<DiscoveryEvent> {
+        let (_, rx) = mpsc::unbounded_channel();
+        UnboundedReceiverStream::new(rx)
+    }
+}
+
+impl<Net: NetworkPrimitives> BlockDownloaderProvider for NoopNetwork<Net> {
+    type Client = NoopBlockClient<Net>;
+
+    async fn fetch_client(&self) -> Result<Self::Client, oneshot::error::RecvError> {
+        Ok(NoopBlockClient::default())
+    }
+}
+
+impl<Net: NetworkPrimitives> PeersInfo for NoopNetwork<Net> {
     fn num_connected_peers(&self) -> usize {
         0
     }
 
     fn local_node_record(&self) -> NodeRecord {
-        NodeRecord::new(self.local_addr(), PeerId::random())
+        NodeRecord::from_secret_key(self.local_addr(), &self.secret_key)
     }
 
     fn local_enr(&self) -> Enr<SecretKey> {
-        let sk = SecretKey::from_slice(&[0xcd; 32]).unwrap();
-        Enr::builder().build(&sk).unwrap()
+        Enr::builder().build(&self.secret_key).expect("ENR without entries is valid")
     }
 }
 
-impl Peers for NoopNetwork {
+impl<Net: NetworkPrimitives> Peers for NoopNetwork<Net> {
     fn add_trusted_peer_id(&self, _peer: PeerId) {}
 
     fn add_peer_kind(
@@ -118,3 +281,93 @@ impl Peers for NoopNetwork {
         Ok(None)
     }
 }
+
+/// A [`BlockClient`] that never returns any headers or bodies.
+///
+/// Requests never resolve, since there are no peers to serve them: resolving them with an empty
+/// response would make the downloaders retry them in a busy loop.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct NoopBlockClient<Net = EthNetworkPrimitives> {
+    _primitives: PhantomData<Net>,
+}
+
+impl<Net> Default for NoopBlockClient<Net> {
+    fn default() -> Self {
+        Self { _primitives: PhantomData }
+    }
+}
+
+impl<Net: NetworkPrimitives> DownloadClient for NoopBlockClient<Net> {
+    fn report_bad_message(&self, _peer_id: PeerId) {}
+
+    fn num_connected_peers(&self) -> usize {
+        0
+    }
+}
+
+impl<Net: NetworkPrimitives> HeadersClient for NoopBlockClient<Net> {
+    type Header = Net::BlockHeader;
+    type Output = futures::future::Pending<PeerRequestResult<Vec<Net::BlockHeader>>>;
+
+    fn get_headers_with_priority(
+        &self,
+        _request: HeadersRequest,
+        _priority: Priority,
+    ) -> Self::Output {
+        futures::future::pending()
+    }
+}
+
+impl<Net: NetworkPrimitives> BodiesClient for NoopBlockClient<Net> {
+    type Body = Net::BlockBody;
+    type Output = futures::future::Pending<PeerRequestResult<Vec<Net::BlockBody>>>;
+
+    fn get_block_bodies_with_priority(
+        &self,
+        _hashes: Vec<B256>,
+        _priority: Priority,
+    ) -> Self::Output {
+        futures::future::pending()
+    }
+}
+
+impl<Net: NetworkPrimitives> BlockClient for NoopBlockClient<Net> {
+    type Block = Net::Block;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_sync_sources() {
+        let network = NoopNetwork::<EthNetworkPrimitives>::new();
+        assert_eq!(network.highest_block(), None);
+        assert!(network.sync_sources().is_empty());
+
+        network.update_source_height("archive", 100);
+        network.update_source_height("hl-node", 120);
+        network.update_source_height("archive", 90);
+        assert_eq!(network.highest_block(), Some(120));
+
+        let sources = network.sync_sources();
+        assert_eq!(
+            sources.iter().map(|source| (source.name, source.highest_block)).collect::<Vec<_>>(),
+            [("archive", 100), ("hl-node", 120)]
+        );
+        assert!(sources.iter().all(SyncSource::is_active));
+    }
+
+    #[test]
+    fn stable_node_identity() {
+        let network = NoopNetwork::<EthNetworkPrimitives>::new();
+        let record = network.local_node_record();
+        assert_eq!(network.local_node_record(), record);
+        assert_eq!(
+            record.id.as_slice(),
+            &network.local_enr().public_key().serialize_uncompressed()[1..]
+        );
+        assert!(!network.is_listening());
+    }
+}
@@ -1,6 +1,7 @@
 //! Traits used when interacting with the sync status of the network.
 
 use alloy_eips::eip2124::Head;
+use alloy_primitives::BlockNumber;
 
 /// A type that provides information about whether the node is currently syncing and the network is
 /// currently serving syncing related requests.
@@ -27,6 +28,13 @@ pub trait NetworkSyncUpdater: std::fmt::Debug + Send + Sync + 'static {
 
     /// Updates the status of the p2p node
     fn update_status(&self, head: Head);
+
+    /// Notifies about the highest block number available from a source the node syncs blocks from
+    /// instead of devp2p peers, e.g. a block archive.
+    ///
+    /// The highest block of all sources is reported as `highestBlock` by `eth_syncing`, and the
+    /// sources that reported recently are counted by `net_peerCount`.
+    fn update_source_height(&self, _source: &'static str, _number: BlockNumber) {}
 }
 
 /// The state the network is currently in when it comes to synchronization.
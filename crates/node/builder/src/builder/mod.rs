@@ -16,7 +16,7 @@ use reth_cli_util::get_secret_key;
 use reth_db_api::{database::Database, database_metrics::DatabaseMetrics};
 use reth_engine_tree::tree::TreeConfig;
 use reth_exex::ExExContext;
-use reth_hyperliquid_types::PrecompilesCache;
+use reth_hyperliquid_types::{PrecompilesCache, ReadPrecompileProvider};
 use reth_network::{
     transactions::TransactionsManagerConfig, NetworkBuilder, NetworkConfig, NetworkConfigBuilder,
     NetworkHandle, NetworkManager, NetworkPrimitives,
@@ -192,6 +192,11 @@ impl<DB, ChainSpec> NodeBuilder<DB, ChainSpec> {
     pub fn config_mut(&mut self) -> &mut NodeConfig<ChainSpec> {
         &mut self.config
     }
+
+    /// Returns a reference to the node builder's database.
+    pub const fn db(&self) -> &DB {
+        &self.database
+    }
 }
 
 impl<DB, ChainSpec: EthChainSpec> NodeBuilder<DB, ChainSpec> {
@@ -291,6 +296,11 @@ impl<DB, ChainSpec> WithLaunchContext<NodeBuilder<DB, ChainSpec>> {
     pub const fn config(&self) -> &NodeConfig<ChainSpec> {
         self.builder.config()
     }
+
+    /// Returns a reference to the node builder's database.
+    pub const fn db(&self) -> &DB {
+        self.builder.db()
+    }
 }
 
 impl<DB, ChainSpec> WithLaunchContext<NodeBuilder<DB, ChainSpec>>
@@ -483,6 +493,14 @@ where
         }
     }
 
+    /// Add read precompile provider <hyperliquid>
+    pub fn add_read_precompile_provider(self, provider: Arc<dyn ReadPrecompileProvider>) -> Self {
+        Self {
+            builder: self.builder.add_read_precompile_provider(provider),
+            task_executor: self.task_executor,
+        }
+    }
+
     /// Sets the hook that is run to configure the rpc modules.
     pub fn extend_rpc_modules<F>(self, hook: F) -> Self
     where
@@ -744,7 +762,7 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
     }
 
     /// Get the network secret from the given data dir
-    fn network_secret(&self, data_dir: &ChainPath<DataDirPath>) -> eyre::Result<SecretKey> {
+    pub fn network_secret(&self, data_dir: &ChainPath<DataDirPath>) -> eyre::Result<SecretKey> {
         let network_secret_path =
             self.config().network.p2p_secret_key.clone().unwrap_or_else(|| data_dir.p2p_secret());
         let secret_key = get_secret_key(&network_secret_path)?;
@@ -13,11 +13,11 @@ use crate::{
     AddOns, FullNode,
 };
 use reth_exex::ExExContext;
-use reth_hyperliquid_types::PrecompilesCache;
+use reth_hyperliquid_types::{PrecompilesCache, ReadPrecompileProvider};
 use reth_node_api::{FullNodeComponents, FullNodeTypes, NodeAddOns, NodeTypes};
 use reth_node_core::node_config::NodeConfig;
 use reth_tasks::TaskExecutor;
-use std::{fmt, future::Future};
+use std::{fmt, future::Future, sync::Arc};
 
 /// A node builder that also has the configured types.
 pub struct NodeBuilderWithTypes<T: FullNodeTypes> {
@@ -148,9 +148,12 @@ impl<T: FullNodeTypes, C: NodeComponents<T>> Clone for NodeAdapter<T, C> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct HyperliquidSharedState {
     pub precompiles_cache: PrecompilesCache,
+    /// Resolves read precompile calls missing from the ingested blocks, for EVMs that don't
+    /// execute ingested blocks.
+    pub read_precompile_provider: Option<Arc<dyn ReadPrecompileProvider>>,
 }
 
 /// A fully type configured node builder.
@@ -307,7 +310,18 @@ where
 
     /// Add state
     pub fn add_precompiles_cache(mut self, precompiles_cache: PrecompilesCache) -> Self {
-        self.shared_state = Some(HyperliquidSharedState { precompiles_cache });
+        self.shared_state.get_or_insert_with(Default::default).precompiles_cache =
+            precompiles_cache;
+        self
+    }
+
+    /// Add the provider resolving read precompile calls missing from the ingested blocks
+    pub fn add_read_precompile_provider(
+        mut self,
+        provider: Arc<dyn ReadPrecompileProvider>,
+    ) -> Self {
+        self.shared_state.get_or_insert_with(Default::default).read_precompile_provider =
+            Some(provider);
         self
     }
 }
@@ -10,6 +10,8 @@ use crate::{
 use reth_consensus::{ConsensusError, FullConsensus};
 use reth_evm::{execute::BlockExecutorProvider, ConfigureEvmFor};
 use reth_network::NetworkPrimitives;
+use reth_network_api::FullNetwork;
+use reth_network_p2p::BlockClient;
 use reth_node_api::{BlockTy, BodyTy, HeaderTy, PrimitivesTy, TxTy};
 use reth_transaction_pool::{PoolTransaction, TransactionPool};
 use std::{future::Future, marker::PhantomData};
@@ -313,6 +315,7 @@ where
             BlockBody = BodyTy<Node::Types>,
             Block = BlockTy<Node::Types>,
         >,
+        Network: FullNetwork<Client: BlockClient<Block = BlockTy<Node::Types>>>,
     >,
     PayloadB: PayloadServiceBuilder<Node, PoolB::Pool>,
     ExecB: ExecutorBuilder<Node>,
@@ -320,7 +323,7 @@ where
 {
     type Components = Components<
         Node,
-        NetworkB::Primitives,
+        NetworkB::Network,
         PoolB::Pool,
         ExecB::EVM,
         ExecB::Executor,
@@ -395,16 +398,12 @@ pub trait NodeComponentsBuilder<Node: FullNodeTypes>: Send {
     ) -> impl Future<Output = eyre::Result<Self::Components>> + Send;
 }
 
-impl<Node, N, F, Fut, Pool, EVM, Executor, Cons, Payload> NodeComponentsBuilder<Node> for F
+impl<Node, Net, F, Fut, Pool, EVM, Executor, Cons, Payload> NodeComponentsBuilder<Node> for F
 where
-    N: NetworkPrimitives<
-        BlockHeader = HeaderTy<Node::Types>,
-        BlockBody = BodyTy<Node::Types>,
-        Block = BlockTy<Node::Types>,
-    >,
+    Net: FullNetwork<Client: BlockClient<Block = BlockTy<Node::Types>>>,
     Node: FullNodeTypes,
     F: FnOnce(&BuilderContext<Node>) -> Fut + Send,
-    Fut: Future<Output = eyre::Result<Components<Node, N, Pool, EVM, Executor, Cons, Payload>>>
+    Fut: Future<Output = eyre::Result<Components<Node, Net, Pool, EVM, Executor, Cons, Payload>>>
         + Send,
     Pool: TransactionPool<Transaction: PoolTransaction<Consensus = TxTy<Node::Types>>>
         + Unpin
@@ -415,7 +414,7 @@ where
         FullConsensus<PrimitivesTy<Node::Types>, Error = ConsensusError> + Clone + Unpin + 'static,
     Payload: PayloadBuilderFor<Node::Types> + Unpin + 'static,
 {
-    type Components = Components<Node, N, Pool, EVM, Executor, Cons, Payload>;
+    type Components = Components<Node, Net, Pool, EVM, Executor, Cons, Payload>;
 
     fn build_components(
         self,
@@ -26,11 +26,9 @@ use reth_payload_builder::PayloadBuilderHandle;
 use crate::{ConfigureEvm, FullNodeTypes};
 use reth_consensus::{ConsensusError, FullConsensus};
 use reth_evm::{execute::BlockExecutorProvider, ConfigureEvmFor};
-use reth_network::{NetworkHandle, NetworkPrimitives};
 use reth_network_api::FullNetwork;
 use reth_node_api::{
-    BlockTy, BodyTy, HeaderTy, NodeTypes, NodeTypesWithEngine, PayloadBuilderFor, PrimitivesTy,
-    TxTy,
+    BlockTy, NodeTypes, NodeTypesWithEngine, PayloadBuilderFor, PrimitivesTy, TxTy,
 };
 use reth_transaction_pool::{PoolTransaction, TransactionPool};
 
@@ -90,15 +88,7 @@ pub trait NodeComponents<T: FullNodeTypes>: Clone + Unpin + Send + Sync + 'stati
 ///
 /// This provides access to all the components of the node.
 #[derive(Debug)]
-pub struct Components<
-    Node: FullNodeTypes,
-    N: NetworkPrimitives,
-    Pool,
-    EVM,
-    Executor,
-    Consensus,
-    Payload,
-> {
+pub struct Components<Node: FullNodeTypes, Net, Pool, EVM, Executor, Consensus, Payload> {
     /// The transaction pool of the node.
     pub transaction_pool: Pool,
     /// The node's EVM configuration, defining settings for the Ethereum Virtual Machine.
@@ -108,22 +98,18 @@ pub struct Components<
     /// The consensus implementation of the node.
     pub consensus: Consensus,
     /// The network implementation of the node.
-    pub network: NetworkHandle<N>,
+    pub network: Net,
     /// The payload builder.
     pub payload_builder: Payload,
     /// The handle to the payload builder service.
     pub payload_builder_handle: PayloadBuilderHandle<<Node::Types as NodeTypesWithEngine>::Engine>,
 }
 
-impl<Node, Pool, EVM, Executor, Cons, N, Payload> NodeComponents<Node>
-    for Components<Node, N, Pool, EVM, Executor, Cons, Payload>
+impl<Node, Pool, EVM, Executor, Cons, Net, Payload> NodeComponents<Node>
+    for Components<Node, Net, Pool, EVM, Executor, Cons, Payload>
 where
     Node: FullNodeTypes,
-    N: NetworkPrimitives<
-        BlockHeader = HeaderTy<Node::Types>,
-        BlockBody = BodyTy<Node::Types>,
-        Block = BlockTy<Node::Types>,
-    >,
+    Net: FullNetwork<Client: BlockClient<Block = BlockTy<Node::Types>>>,
     Pool: TransactionPool<Transaction: PoolTransaction<Consensus = TxTy<Node::Types>>>
         + Unpin
         + 'static,
@@ -137,7 +123,7 @@ where
     type Evm = EVM;
     type Executor = Executor;
     type Consensus = Cons;
-    type Network = NetworkHandle<N>;
+    type Network = Net;
     type PayloadBuilder = Payload;
 
     fn pool(&self) -> &Self::Pool {
@@ -171,10 +157,10 @@ where
     }
 }
 
-impl<Node, N, Pool, EVM, Executor, Cons, Payload> Clone
-    for Components<Node, N, Pool, EVM, Executor, Cons, Payload>
+impl<Node, Net, Pool, EVM, Executor, Cons, Payload> Clone
+    for Components<Node, Net, Pool, EVM, Executor, Cons, Payload>
 where
-    N: NetworkPrimitives,
+    Net: Clone,
     Node: FullNodeTypes,
     Pool: TransactionPool,
     EVM: ConfigureEvm,
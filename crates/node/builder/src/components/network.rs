@@ -2,8 +2,11 @@
 
 use std::future::Future;
 
+use reth_chainspec::EthChainSpec;
 use reth_network::{NetworkHandle, NetworkPrimitives};
+use reth_network_api::{noop::NoopNetwork, FullNetwork};
 use reth_transaction_pool::TransactionPool;
+use tracing::info;
 
 use crate::{BuilderContext, FullNodeTypes};
 
@@ -12,12 +15,15 @@ pub trait NetworkBuilder<Node: FullNodeTypes, Pool: TransactionPool>: Send {
     /// The primitive types to use for the network.
     type Primitives: NetworkPrimitives;
 
+    /// The network built.
+    type Network: FullNetwork;
+
     /// Launches the network implementation and returns the handle to it.
     fn build_network(
         self,
         ctx: &BuilderContext<Node>,
         pool: Pool,
-    ) -> impl Future<Output = eyre::Result<NetworkHandle<Self::Primitives>>> + Send;
+    ) -> impl Future<Output = eyre::Result<Self::Network>> + Send;
 }
 
 impl<Node, P, F, Fut, Pool> NetworkBuilder<Node, Pool> for F
@@ -29,6 +35,7 @@ where
     Fut: Future<Output = eyre::Result<NetworkHandle<P>>> + Send,
 {
     type Primitives = P;
+    type Network = NetworkHandle<P>;
 
     fn build_network(
         self,
@@ -38,3 +45,43 @@ where
         self(ctx, pool)
     }
 }
+
+/// A [`NetworkBuilder`] that doesn't launch any p2p networking.
+///
+/// This is intended for nodes that receive all blocks through the engine API and never sync over
+/// devp2p: no discovery is started, no `RLPx` listener is bound and transactions are not gossiped.
+/// The returned [`NoopNetwork`] still tracks the sync state so `eth_syncing` and the `net_` and
+/// `admin_` namespaces keep answering.
+#[derive(Debug)]
+pub struct NoopNetworkBuilder<N = reth_network::EthNetworkPrimitives> {
+    _primitives: std::marker::PhantomData<N>,
+}
+
+impl<N> Default for NoopNetworkBuilder<N> {
+    fn default() -> Self {
+        Self { _primitives: Default::default() }
+    }
+}
+
+impl<Node, Pool, N> NetworkBuilder<Node, Pool> for NoopNetworkBuilder<N>
+where
+    Node: FullNodeTypes,
+    Pool: TransactionPool,
+    N: NetworkPrimitives,
+{
+    type Primitives = N;
+    type Network = NoopNetwork<N>;
+
+    async fn build_network(
+        self,
+        ctx: &BuilderContext<Node>,
+        _pool: Pool,
+    ) -> eyre::Result<Self::Network> {
+        info!(target: "reth::cli", "P2P networking disabled");
+        let secret_key = ctx.network_secret(&ctx.config().datadir())?;
+        Ok(NoopNetwork::new()
+            .with_chain_id(ctx.chain_spec().chain_id())
+            .with_secret_key(secret_key)
+            .with_client_version(ctx.config().network.identity.clone()))
+    }
+}
@@ -20,7 +20,8 @@ use reth_engine_local::MiningMode;
 use reth_engine_tree::tree::{InvalidBlockHook, InvalidBlockHooks, NoopInvalidBlockHook};
 use reth_evm::noop::NoopBlockExecutorProvider;
 use reth_fs_util as fs;
-use reth_invalid_block_hooks::InvalidBlockWitnessHook;
+use reth_invalid_block_hooks::{InvalidBlockBundleHook, InvalidBlockWitnessHook};
+use reth_network_api::NetworkInfo;
 use reth_network_p2p::headers::client::HeadersClient;
 use reth_node_api::{FullNodeTypes, NodeTypes, NodeTypesWithDB, NodeTypesWithDBAdapter};
 use reth_node_core::{
@@ -35,6 +36,7 @@ use reth_node_core::{
 };
 use reth_node_metrics::{
     chain::ChainSpecInfo,
+    health::{DeferredHealthChecker, HeadFreshness, HealthChecker, HealthStatus},
     hooks::Hooks,
     recorder::install_prometheus_recorder,
     server::{MetricServer, MetricServerConfig},
@@ -43,8 +45,9 @@ use reth_node_metrics::{
 use reth_primitives::Head;
 use reth_provider::{
     providers::{NodeTypesForProvider, ProviderNodeTypes, StaticFileProvider},
-    BlockHashReader, BlockNumReader, ChainSpecProvider, ProviderError, ProviderFactory,
-    ProviderResult, StageCheckpointReader, StateProviderFactory, StaticFileProviderFactory,
+    BlockHashReader, BlockNumReader, BlockReaderIdExt, ChainSpecProvider, ProviderError,
+    ProviderFactory, ProviderResult, StageCheckpointReader, StateProviderFactory,
+    StaticFileProviderFactory,
 };
 use reth_prune::{PruneModes, PrunerBuilder};
 use reth_rpc_api::clients::EthApiClient;
@@ -69,12 +72,23 @@ pub struct LaunchContext {
     pub task_executor: TaskExecutor,
     /// The data directory for the node.
     pub data_dir: ChainPath<DataDirPath>,
+    /// Probe of the `/health` endpoint of the metrics server, installed once the node components
+    /// are built.
+    pub health: DeferredHealthChecker,
+    /// Probe of the `/ready` endpoint of the metrics server, installed once the node components
+    /// are built.
+    pub readiness: DeferredHealthChecker,
 }
 
 impl LaunchContext {
     /// Create a new instance of the default node launcher.
-    pub const fn new(task_executor: TaskExecutor, data_dir: ChainPath<DataDirPath>) -> Self {
-        Self { task_executor, data_dir }
+    pub fn new(task_executor: TaskExecutor, data_dir: ChainPath<DataDirPath>) -> Self {
+        Self {
+            task_executor,
+            data_dir,
+            health: DeferredHealthChecker::default(),
+            readiness: DeferredHealthChecker::default(),
+        }
     }
 
     /// Attaches a database to the launch context.
@@ -521,7 +535,9 @@ where
                         }
                     })
                     .build(),
-            );
+            )
+            .with_health_checker(self.inner.health.checker())
+            .with_readiness_checker(self.inner.readiness.checker());
 
             MetricServer::new(config).serve().await?;
         }
@@ -723,6 +739,42 @@ where
         &self.right().db_provider_container.provider_factory
     }
 
+    /// Installs the probes of the `/health` and `/ready` endpoints of the metrics server.
+    ///
+    /// Convenience function to [`Self::install_health_checks`]
+    pub fn with_health_checks(self) -> Self {
+        self.install_health_checks();
+        self
+    }
+
+    /// Installs the probes of the `/health` and `/ready` endpoints of the metrics server, which
+    /// report the node as unhealthy until then.
+    pub fn install_health_checks(&self) {
+        // check the in-memory canonical head, the persisted blocks lag behind it
+        let health = HealthChecker::new({
+            let freshness = HeadFreshness::new(self.node_config().metrics_max_head_lag);
+            let provider = self.blockchain_db().clone();
+            move || match provider.latest_header() {
+                Ok(Some(head)) => freshness.check(head.number(), head.timestamp()),
+                Ok(None) => HealthStatus::Unhealthy("head header not found".to_string()),
+                Err(err) => HealthStatus::Unhealthy(format!("failed to read head: {err}")),
+            }
+        });
+        self.inner.health.install(health.clone());
+        self.inner.readiness.install(HealthChecker::new({
+            let network = self.components().network().clone();
+            move || match health.check() {
+                HealthStatus::Healthy if network.is_syncing() => {
+                    HealthStatus::Unhealthy(match network.highest_block() {
+                        Some(highest) => format!("syncing to block {highest}"),
+                        None => "syncing".to_string(),
+                    })
+                }
+                status => status,
+            }
+        }));
+    }
+
     /// Returns the max block that the node should run to, looking it up from the network if
     /// necessary
     pub async fn max_block<C>(&self, client: C) -> eyre::Result<Option<BlockNumber>>
@@ -829,8 +881,9 @@ where
             .block_number;
 
         // Skip the first stage as we've already retrieved it and comparing all other checkpoints
-        // against it.
-        for stage_id in StageId::ALL.iter().skip(1) {
+        // against it. Optional stages are only checked if they're enabled.
+        let optional_stages = self.toml_config().stages.enabled_optional_stages();
+        for stage_id in StageId::ALL.iter().skip(1).chain(&optional_stages) {
             let stage_checkpoint = self
                 .blockchain_db()
                 .get_stage_checkpoint(*stage_id)?
@@ -902,6 +955,12 @@ where
                         output_directory,
                         healthy_node_rpc_client.clone(),
                     )),
+                    InvalidBlockHookType::Bundle => Box::new(InvalidBlockBundleHook::new(
+                        self.blockchain_db().clone(),
+                        self.components().block_executor().clone(),
+                        output_directory,
+                        self.node_config().ingest_dir.clone(),
+                    )),
                     InvalidBlockHookType::PreState | InvalidBlockHookType::Opcode => {
                         eyre::bail!("invalid block hook {hook:?} is not implemented yet")
                     }
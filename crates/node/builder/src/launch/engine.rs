@@ -56,7 +56,7 @@ pub struct EngineNodeLauncher {
 
 impl EngineNodeLauncher {
     /// Create a new instance of the ethereum node launcher.
-    pub const fn new(
+    pub fn new(
         task_executor: TaskExecutor,
         data_dir: ChainPath<DataDirPath>,
         engine_tree_config: TreeConfig,
@@ -128,7 +128,9 @@ where
             .with_blockchain_db::<T, _>(move |provider_factory| {
                 Ok(BlockchainProvider::new(provider_factory)?)
             })?
-            .with_components(components_builder, on_component_initialized, shared_state.clone()).await?;
+            .with_components(components_builder, on_component_initialized, shared_state.clone()).await?
+            // the health checks of the metrics server read the canonical head and the sync state
+            .with_health_checks();
 
         // spawn exexs
         let exex_manager_handle = ExExLauncher::new(
@@ -9,6 +9,7 @@ use std::{
 use crate::{BeaconConsensusEngineEvent, BeaconConsensusEngineHandle, EthApiBuilderCtx};
 use alloy_rpc_types::engine::{ClientVersionV1, ExecutionData};
 use futures::TryFutureExt;
+use jsonrpsee::server::RpcServiceBuilder;
 use reth_chainspec::EthereumHardforks;
 use reth_node_api::{
     AddOnsContext, BlockTy, EngineTypes, EngineValidator, FullNodeComponents, NodeAddOns,
@@ -22,13 +23,14 @@ use reth_payload_builder::PayloadStore;
 use reth_provider::ChainSpecProvider;
 use reth_rpc::{
     eth::{EthApiTypes, FullEthApiServer},
-    EthApi,
+    EthApi, SlowQueryApi,
 };
-use reth_rpc_api::{eth::helpers::AddDevSigners, IntoEngineApiRpcModule};
+use reth_rpc_api::{eth::helpers::AddDevSigners, IntoEngineApiRpcModule, SlowQueryApiServer};
 use reth_rpc_builder::{
     auth::{AuthRpcModule, AuthServerHandle},
     config::RethRpcServerConfig,
-    RpcModuleBuilder, RpcRegistryInner, RpcServerHandle, TransportRpcModules,
+    slow_query::SlowQueryLayer,
+    RethRpcModule, RpcModuleBuilder, RpcRegistryInner, RpcServerHandle, TransportRpcModules,
 };
 use reth_rpc_engine_api::{capabilities::EngineCapabilities, EngineApi};
 use reth_tasks::TaskExecutor;
@@ -467,6 +469,12 @@ where
             registry.eth_api().with_dev_accounts();
         }
 
+        let slow_queries = SlowQueryLayer::new(config.rpc.slow_query_config());
+        modules.merge_if_module_configured(
+            RethRpcModule::Admin,
+            SlowQueryApi::new(slow_queries.log().clone()).into_rpc(),
+        )?;
+
         let mut registry = RpcRegistry { registry };
         let ctx = RpcContext {
             node: node.clone(),
@@ -481,7 +489,10 @@ where
         ext(ctx.modules, ctx.auth_module)?;
         extend_rpc_modules.extend_rpc_modules(ctx)?;
 
-        let server_config = config.rpc.rpc_server_config();
+        let server_config = config
+            .rpc
+            .rpc_server_config()
+            .set_rpc_middleware(RpcServiceBuilder::new().layer(slow_queries));
         let cloned_modules = modules.clone();
         let launch_rpc = server_config.start(&cloned_modules).map_ok(|handle| {
             if let Some(path) = handle.ipc_endpoint() {
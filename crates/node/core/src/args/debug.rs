@@ -280,6 +280,8 @@ pub enum InvalidBlockHookType {
     PreState,
     /// An opcode trace value enum
     Opcode,
+    /// A replay bundle value enum
+    Bundle,
 }
 
 impl FromStr for InvalidBlockHookType {
@@ -290,6 +292,7 @@ impl FromStr for InvalidBlockHookType {
             "witness" => Self::Witness,
             "prestate" => Self::PreState,
             "opcode" => Self::Opcode,
+            "bundle" => Self::Bundle,
             _ => return Err(ParseError::VariantNotFound),
         })
     }
@@ -82,6 +82,45 @@ pub struct PruningArgs {
     #[arg(long = "prune.storagehistory.before", value_name = "BLOCK_NUMBER", conflicts_with_all = &["storage_history_full", "storage_history_distance"])]
     pub storage_history_before: Option<BlockNumber>,
 
+    // Address Transactions
+    /// Prunes all address transactions index data.
+    #[arg(long = "prune.addresstransactions.full", conflicts_with_all = &["address_transactions_distance", "address_transactions_before"])]
+    pub address_transactions_full: bool,
+    /// Prune address transactions index data before the `head-N` block number. In other words,
+    /// keep last N + 1 blocks.
+    #[arg(long = "prune.addresstransactions.distance", value_name = "BLOCKS", conflicts_with_all = &["address_transactions_full", "address_transactions_before"])]
+    pub address_transactions_distance: Option<u64>,
+    /// Prune address transactions index data before the specified block number. The specified
+    /// block number is not pruned.
+    #[arg(long = "prune.addresstransactions.before", value_name = "BLOCK_NUMBER", conflicts_with_all = &["address_transactions_full", "address_transactions_distance"])]
+    pub address_transactions_before: Option<BlockNumber>,
+
+    // Log Index
+    /// Prunes all log index data.
+    #[arg(long = "prune.logindex.full", conflicts_with_all = &["log_index_distance", "log_index_before"])]
+    pub log_index_full: bool,
+    /// Prune log index data before the `head-N` block number. In other words, keep last N + 1
+    /// blocks.
+    #[arg(long = "prune.logindex.distance", value_name = "BLOCKS", conflicts_with_all = &["log_index_full", "log_index_before"])]
+    pub log_index_distance: Option<u64>,
+    /// Prune log index data before the specified block number. The specified block number is not
+    /// pruned.
+    #[arg(long = "prune.logindex.before", value_name = "BLOCK_NUMBER", conflicts_with_all = &["log_index_full", "log_index_distance"])]
+    pub log_index_before: Option<BlockNumber>,
+
+    // Token Transfers
+    /// Prunes all token transfers index data.
+    #[arg(long = "prune.tokentransfers.full", conflicts_with_all = &["token_transfers_distance", "token_transfers_before"])]
+    pub token_transfers_full: bool,
+    /// Prune token transfers index data before the `head-N` block number. In other words, keep
+    /// last N + 1 blocks.
+    #[arg(long = "prune.tokentransfers.distance", value_name = "BLOCKS", conflicts_with_all = &["token_transfers_full", "token_transfers_before"])]
+    pub token_transfers_distance: Option<u64>,
+    /// Prune token transfers index data before the specified block number. The specified block
+    /// number is not pruned.
+    #[arg(long = "prune.tokentransfers.before", value_name = "BLOCK_NUMBER", conflicts_with_all = &["token_transfers_full", "token_transfers_distance"])]
+    pub token_transfers_before: Option<BlockNumber>,
+
     // Receipts Log Filter
     /// Configure receipts log filter. Format:
     /// <`address`>:<`prune_mode`>[,<`address`>:<`prune_mode`>...] Where <`prune_mode`> can be
@@ -111,6 +150,9 @@ impl PruningArgs {
                         .or(Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE))),
                     account_history: Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE)),
                     storage_history: Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE)),
+                    address_transactions: Some(PruneMode::Full),
+                    log_index: Some(PruneMode::Full),
+                    token_transfers: Some(PruneMode::Full),
                     receipts_log_filter: ReceiptsLogPruneConfig(
                         chain_spec
                             .deposit_contract()
@@ -141,6 +183,15 @@ impl PruningArgs {
         if let Some(mode) = self.storage_history_prune_mode() {
             config.segments.storage_history = Some(mode);
         }
+        if let Some(mode) = self.address_transactions_prune_mode() {
+            config.segments.address_transactions = Some(mode);
+        }
+        if let Some(mode) = self.log_index_prune_mode() {
+            config.segments.log_index = Some(mode);
+        }
+        if let Some(mode) = self.token_transfers_prune_mode() {
+            config.segments.token_transfers = Some(mode);
+        }
 
         Some(config)
     }
@@ -203,6 +254,42 @@ impl PruningArgs {
             None
         }
     }
+
+    const fn address_transactions_prune_mode(&self) -> Option<PruneMode> {
+        if self.address_transactions_full {
+            Some(PruneMode::Full)
+        } else if let Some(distance) = self.address_transactions_distance {
+            Some(PruneMode::Distance(distance))
+        } else if let Some(block_number) = self.address_transactions_before {
+            Some(PruneMode::Before(block_number))
+        } else {
+            None
+        }
+    }
+
+    const fn log_index_prune_mode(&self) -> Option<PruneMode> {
+        if self.log_index_full {
+            Some(PruneMode::Full)
+        } else if let Some(distance) = self.log_index_distance {
+            Some(PruneMode::Distance(distance))
+        } else if let Some(block_number) = self.log_index_before {
+            Some(PruneMode::Before(block_number))
+        } else {
+            None
+        }
+    }
+
+    const fn token_transfers_prune_mode(&self) -> Option<PruneMode> {
+        if self.token_transfers_full {
+            Some(PruneMode::Full)
+        } else if let Some(distance) = self.token_transfers_distance {
+            Some(PruneMode::Distance(distance))
+        } else if let Some(block_number) = self.token_transfers_before {
+            Some(PruneMode::Before(block_number))
+        } else {
+            None
+        }
+    }
 }
 
 pub(crate) fn parse_receipts_log_filter(
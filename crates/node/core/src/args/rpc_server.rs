@@ -5,6 +5,7 @@ use std::{
     ffi::OsStr,
     net::{IpAddr, Ipv4Addr},
     path::PathBuf,
+    time::Duration,
 };
 
 use alloy_primitives::Address;
@@ -13,6 +14,7 @@ use clap::{
     builder::{PossibleValue, RangedU64ValueParser, TypedValueParser},
     Arg, Args, Command,
 };
+use humantime::parse_duration;
 use rand::Rng;
 use reth_rpc_server_types::{constants, RethRpcModule, RpcModuleSelection};
 
@@ -59,6 +61,25 @@ pub struct RpcServerArgs {
     #[arg(long = "http.corsdomain")]
     pub http_corsdomain: Option<String>,
 
+    /// Enable the EIP-1767 GraphQL endpoint on the HTTP server, requires `--http`.
+    ///
+    /// Queries are served under `/graphql`, and a GraphiQL user interface under `/graphql/ui`.
+    /// If API keys are enabled, the key is sent in the `X-API-Key` header or as path prefix, e.g.
+    /// `/<key>/graphql`, and queries are charged as calls of `graphql_query`.
+    #[arg(long)]
+    pub graphql: bool,
+
+    /// Maximum complexity of a GraphQL query.
+    ///
+    /// Every requested field counts as one, the fields requested for each block of a block range
+    /// are counted once per block.
+    #[arg(long = "graphql.max-complexity", value_name = "COUNT", default_value_t = constants::DEFAULT_GRAPHQL_MAX_COMPLEXITY)]
+    pub graphql_max_complexity: usize,
+
+    /// Maximum depth of a GraphQL query.
+    #[arg(long = "graphql.max-depth", value_name = "COUNT", default_value_t = constants::DEFAULT_GRAPHQL_MAX_DEPTH)]
+    pub graphql_max_depth: usize,
+
     /// Enable the WS-RPC server
     #[arg(long)]
     pub ws: bool,
@@ -120,6 +141,36 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.jwtsecret", value_name = "HEX", global = true, required = false)]
     pub rpc_jwtsecret: Option<JwtSecret>,
 
+    /// Path to a TOML file with the API keys required by the HTTP and WS servers.
+    ///
+    /// Clients pass their key in the `x-api-key` header or as the URL path. The file configures
+    /// per-key request and compute unit quotas, method weights and method allow/deny lists, and
+    /// is reloaded when it changes.
+    #[arg(long = "rpc.api-keys", value_name = "PATH")]
+    pub rpc_api_keys: Option<PathBuf>,
+
+    /// Log RPC calls that take at least this long, e.g. `500ms`.
+    ///
+    /// Slow calls are logged with their parameters, caller and database read stats, and the most
+    /// recent ones are returned by `admin_slowQueries`.
+    #[arg(long = "rpc.slow-query-threshold", value_name = "DURATION", value_parser = parse_duration)]
+    pub rpc_slow_query_threshold: Option<Duration>,
+
+    /// Log RPC calls with responses of at least this many bytes.
+    #[arg(long = "rpc.slow-query-response-size", value_name = "BYTES")]
+    pub rpc_slow_query_response_size: Option<usize>,
+
+    /// Number of recent slow RPC calls returned by `admin_slowQueries`.
+    #[arg(long = "rpc.slow-query-log-size", value_name = "COUNT", default_value_t = constants::DEFAULT_SLOW_QUERY_LOG_SIZE)]
+    pub rpc_slow_query_log_size: usize,
+
+    /// Comma separated addresses of reverse proxies in front of the HTTP and WS servers.
+    ///
+    /// The caller of a request is the rightmost address in the `X-Forwarded-For` header that isn't
+    /// one of these proxies, or the `X-Real-IP` header.
+    #[arg(long = "rpc.trusted-proxies", value_name = "IP", value_delimiter = ',')]
+    pub rpc_trusted_proxies: Vec<IpAddr>,
+
     /// Set the maximum RPC request payload size for both HTTP and WS in megabytes.
     #[arg(long = "rpc.max-request-size", alias = "rpc-max-request-size", default_value_t = RPC_DEFAULT_MAX_REQUEST_SIZE_MB.into())]
     pub rpc_max_request_size: MaxU32,
@@ -145,6 +196,20 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.max-tracing-requests", alias = "rpc-max-tracing-requests", value_name = "COUNT", default_value_t = constants::default_max_tracing_requests())]
     pub rpc_max_tracing_requests: usize,
 
+    /// Directory of the trace cache, which is disabled if not set.
+    ///
+    /// Results of `debug_traceBlockByNumber`, `debug_traceBlockByHash`, `trace_block`,
+    /// `trace_replayBlockTransactions` and `ots_traceTransaction` are cached on disk by block hash
+    /// and call parameters, and removed when the block is unwound.
+    #[arg(long = "rpc.trace-cache-dir", value_name = "PATH")]
+    pub rpc_trace_cache_dir: Option<PathBuf>,
+
+    /// Maximum size of the trace cache in megabytes.
+    ///
+    /// The least recently used results are evicted once the cache exceeds this size.
+    #[arg(long = "rpc.trace-cache-max-size", value_name = "MB", default_value_t = constants::DEFAULT_TRACE_CACHE_MAX_SIZE_MB)]
+    pub rpc_trace_cache_max_size: u64,
+
     /// Maximum number of blocks that could be scanned per filter request. (0 = entire chain)
     #[arg(long = "rpc.max-blocks-per-filter", alias = "rpc-max-blocks-per-filter", value_name = "COUNT", default_value_t = ZeroAsNoneU64::new(constants::DEFAULT_MAX_BLOCKS_PER_FILTER))]
     pub rpc_max_blocks_per_filter: ZeroAsNoneU64,
@@ -305,6 +370,9 @@ impl Default for RpcServerArgs {
             http_port: constants::DEFAULT_HTTP_RPC_PORT,
             http_api: None,
             http_corsdomain: None,
+            graphql: false,
+            graphql_max_complexity: constants::DEFAULT_GRAPHQL_MAX_COMPLEXITY,
+            graphql_max_depth: constants::DEFAULT_GRAPHQL_MAX_DEPTH,
             ws: false,
             ws_addr: Ipv4Addr::LOCALHOST.into(),
             ws_port: constants::DEFAULT_WS_RPC_PORT,
@@ -318,11 +386,18 @@ impl Default for RpcServerArgs {
             auth_ipc: false,
             auth_ipc_path: constants::DEFAULT_ENGINE_API_IPC_ENDPOINT.to_string(),
             rpc_jwtsecret: None,
+            rpc_api_keys: None,
+            rpc_slow_query_threshold: None,
+            rpc_slow_query_response_size: None,
+            rpc_slow_query_log_size: constants::DEFAULT_SLOW_QUERY_LOG_SIZE,
+            rpc_trusted_proxies: Vec::new(),
             rpc_max_request_size: RPC_DEFAULT_MAX_REQUEST_SIZE_MB.into(),
             rpc_max_response_size: RPC_DEFAULT_MAX_RESPONSE_SIZE_MB.into(),
             rpc_max_subscriptions_per_connection: RPC_DEFAULT_MAX_SUBS_PER_CONN.into(),
             rpc_max_connections: RPC_DEFAULT_MAX_CONNECTIONS.into(),
             rpc_max_tracing_requests: constants::default_max_tracing_requests(),
+            rpc_trace_cache_dir: None,
+            rpc_trace_cache_max_size: constants::DEFAULT_TRACE_CACHE_MAX_SIZE_MB,
             rpc_max_blocks_per_filter: constants::DEFAULT_MAX_BLOCKS_PER_FILTER.into(),
             rpc_max_logs_per_response: (constants::DEFAULT_MAX_LOGS_PER_RESPONSE as u64).into(),
             rpc_gas_cap: constants::gas_oracle::RPC_DEFAULT_GAS_CAP,
@@ -50,4 +50,20 @@ pub enum StageEnum {
     ///
     /// Manages historical data related to storage.
     StorageHistory,
+    /// The address transactions stage within the pipeline.
+    ///
+    /// Indexes the transactions that touched each address.
+    AddressTransactions,
+    /// The log index stage within the pipeline.
+    ///
+    /// Indexes the positions of the logs of each address and topic.
+    LogIndex,
+    /// The token transfers stage within the pipeline.
+    ///
+    /// Indexes the ERC20 token transfers of each token and holder.
+    TokenTransfers,
+    /// The contract creators stage within the pipeline.
+    ///
+    /// Indexes the contracts created by each transaction.
+    ContractCreators,
 }
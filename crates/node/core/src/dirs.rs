@@ -355,6 +355,13 @@ impl<D> ChainPath<D> {
     pub fn exex_wal(&self) -> PathBuf {
         self.data_dir().join("exex/wal")
     }
+
+    /// Returns the path to the WAL directory of the streaming ExEx for this chain.
+    ///
+    /// `<DIR>/<CHAIN_ID>/exex/stream`
+    pub fn exex_stream(&self) -> PathBuf {
+        self.data_dir().join("exex/stream")
+    }
 }
 
 impl<D> AsRef<Path> for ChainPath<D> {
@@ -28,6 +28,7 @@ use std::{
     net::SocketAddr,
     path::{Path, PathBuf},
     sync::Arc,
+    time::Duration,
 };
 use tracing::*;
 
@@ -40,6 +41,9 @@ pub const DEFAULT_MEMORY_BLOCK_BUFFER_TARGET: u64 = 2;
 /// Default size of cross-block cache in megabytes.
 pub const DEFAULT_CROSS_BLOCK_CACHE_SIZE_MB: u64 = 4 * 1024;
 
+/// Default maximum lag of the head block timestamp before the node reports unhealthy.
+pub const DEFAULT_MAX_HEAD_LAG: Duration = Duration::from_secs(60);
+
 /// This includes all necessary configuration to launch the node.
 /// The individual configuration options can be overwritten before launching the node.
 ///
@@ -103,6 +107,10 @@ pub struct NodeConfig<ChainSpec> {
     /// The metrics will be served at the given interface and port.
     pub metrics: Option<SocketAddr>,
 
+    /// Maximum lag of the head block timestamp behind the wall clock before the `/health` and
+    /// `/ready` endpoints of the metrics server report unhealthy.
+    pub metrics_max_head_lag: Duration,
+
     /// Add a new instance of a node.
     ///
     /// Configures the ports of the node to avoid conflicts with the defaults.
@@ -169,6 +177,7 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
             config: None,
             chain,
             metrics: None,
+            metrics_max_head_lag: DEFAULT_MAX_HEAD_LAG,
             instance: 1,
             network: NetworkArgs::default(),
             rpc: RpcServerArgs::default(),
@@ -463,6 +472,7 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
             datadir: self.datadir,
             config: self.config,
             metrics: self.metrics,
+            metrics_max_head_lag: self.metrics_max_head_lag,
             instance: self.instance,
             network: self.network,
             rpc: self.rpc,
@@ -491,6 +501,7 @@ impl<ChainSpec> Clone for NodeConfig<ChainSpec> {
             chain: self.chain.clone(),
             config: self.config.clone(),
             metrics: self.metrics,
+            metrics_max_head_lag: self.metrics_max_head_lag,
             instance: self.instance,
             network: self.network.clone(),
             rpc: self.rpc.clone(),
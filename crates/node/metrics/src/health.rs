@@ -0,0 +1,153 @@
+//! Health and readiness probes served next to the metrics endpoint.
+//!
+//! Load balancers poll `/health` and `/ready` to route around nodes whose canonical head has
+//! fallen behind the chain, or that are still syncing.
+
+use std::{
+    fmt,
+    sync::{Arc, OnceLock},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The outcome of a [`HealthCheck`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HealthStatus {
+    /// The node is healthy.
+    Healthy,
+    /// The node is unhealthy for the contained reason.
+    Unhealthy(String),
+}
+
+impl HealthStatus {
+    /// Returns `true` if the status is [`HealthStatus::Healthy`].
+    pub const fn is_healthy(&self) -> bool {
+        matches!(self, Self::Healthy)
+    }
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Healthy => f.write_str("ok"),
+            Self::Unhealthy(reason) => f.write_str(reason),
+        }
+    }
+}
+
+/// A probe that is evaluated for every `/health` and `/ready` request.
+pub trait HealthCheck: Fn() -> HealthStatus + Send + Sync + 'static {}
+impl<T: 'static + Fn() -> HealthStatus + Send + Sync> HealthCheck for T {}
+
+/// Shared handle to a [`HealthCheck`].
+#[derive(Clone)]
+pub struct HealthChecker {
+    inner: Arc<dyn HealthCheck<Output = HealthStatus>>,
+}
+
+impl HealthChecker {
+    /// Creates a new [`HealthChecker`] from the given probe.
+    pub fn new(check: impl HealthCheck) -> Self {
+        Self { inner: Arc::new(check) }
+    }
+
+    /// Evaluates the probe.
+    pub fn check(&self) -> HealthStatus {
+        (self.inner)()
+    }
+}
+
+impl fmt::Debug for HealthChecker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HealthChecker").finish_non_exhaustive()
+    }
+}
+
+/// A [`HealthChecker`] whose probe is installed after the metrics server was started, once the node
+/// components it reads are built.
+///
+/// Reports the node as unhealthy until the probe is installed.
+#[derive(Debug, Clone, Default)]
+pub struct DeferredHealthChecker {
+    probe: Arc<OnceLock<HealthChecker>>,
+}
+
+impl DeferredHealthChecker {
+    /// Installs the probe. Only the first installed probe is used.
+    pub fn install(&self, probe: HealthChecker) {
+        let _ = self.probe.set(probe);
+    }
+
+    /// Returns a [`HealthChecker`] evaluating the installed probe.
+    pub fn checker(&self) -> HealthChecker {
+        let probe = self.probe.clone();
+        HealthChecker::new(move || match probe.get() {
+            Some(probe) => probe.check(),
+            None => HealthStatus::Unhealthy("node is starting".to_string()),
+        })
+    }
+}
+
+/// Reports the node as unhealthy if the canonical head timestamp lags behind the wall clock by
+/// more than the configured threshold.
+#[derive(Debug, Clone, Copy)]
+pub struct HeadFreshness {
+    max_lag: Duration,
+}
+
+impl HeadFreshness {
+    /// Creates a new [`HeadFreshness`] check with the given maximum head lag.
+    pub const fn new(max_lag: Duration) -> Self {
+        Self { max_lag }
+    }
+
+    /// Returns the configured maximum head lag.
+    pub const fn max_lag(&self) -> Duration {
+        self.max_lag
+    }
+
+    /// Checks the head block timestamp (in seconds) against the current wall clock.
+    pub fn check(&self, head_number: u64, head_timestamp: u64) -> HealthStatus {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        self.check_at(head_number, head_timestamp, now)
+    }
+
+    /// Checks the head block timestamp against the given wall clock time, both in seconds.
+    pub fn check_at(&self, head_number: u64, head_timestamp: u64, now: u64) -> HealthStatus {
+        let lag = now.saturating_sub(head_timestamp);
+        if lag > self.max_lag.as_secs() {
+            HealthStatus::Unhealthy(format!(
+                "head block {head_number} is {lag}s behind wall clock (max {}s)",
+                self.max_lag.as_secs()
+            ))
+        } else {
+            HealthStatus::Healthy
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn head_freshness() {
+        let check = HeadFreshness::new(Duration::from_secs(30));
+        assert!(check.check_at(1, 1_000, 1_000).is_healthy());
+        assert!(check.check_at(1, 1_000, 1_030).is_healthy());
+        assert!(!check.check_at(1, 1_000, 1_031).is_healthy());
+        // head timestamps slightly in the future are not lagging
+        assert!(check.check_at(1, 1_010, 1_000).is_healthy());
+    }
+
+    #[test]
+    fn deferred_health_checker() {
+        let deferred = DeferredHealthChecker::default();
+        let checker = deferred.checker();
+        assert!(!checker.check().is_healthy());
+
+        deferred.install(HealthChecker::new(|| HealthStatus::Healthy));
+        assert!(checker.check().is_healthy());
+        deferred.install(HealthChecker::new(|| HealthStatus::Unhealthy("ignored".to_string())));
+        assert!(checker.check().is_healthy());
+    }
+}
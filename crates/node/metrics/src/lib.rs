@@ -8,6 +8,7 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 pub mod chain;
+pub mod health;
 /// The metrics hooks for prometheus.
 pub mod hooks;
 pub mod recorder;
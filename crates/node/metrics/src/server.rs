@@ -1,11 +1,12 @@
 use crate::{
     chain::ChainSpecInfo,
+    health::{HealthChecker, HealthStatus},
     hooks::{Hook, Hooks},
     recorder::install_prometheus_recorder,
     version::VersionInfo,
 };
 use eyre::WrapErr;
-use http::{header::CONTENT_TYPE, HeaderValue, Response};
+use http::{header::CONTENT_TYPE, HeaderValue, Request, Response, StatusCode};
 use metrics::describe_gauge;
 use metrics_process::Collector;
 use reth_metrics::metrics::Unit;
@@ -20,6 +21,8 @@ pub struct MetricServerConfig {
     chain_spec_info: ChainSpecInfo,
     task_executor: TaskExecutor,
     hooks: Hooks,
+    health: Option<HealthChecker>,
+    readiness: Option<HealthChecker>,
 }
 
 impl MetricServerConfig {
@@ -31,7 +34,31 @@ impl MetricServerConfig {
         task_executor: TaskExecutor,
         hooks: Hooks,
     ) -> Self {
-        Self { listen_addr, hooks, task_executor, version_info, chain_spec_info }
+        Self {
+            listen_addr,
+            hooks,
+            task_executor,
+            version_info,
+            chain_spec_info,
+            health: None,
+            readiness: None,
+        }
+    }
+
+    /// Serves `/health` using the given [`HealthChecker`].
+    ///
+    /// Without a checker the endpoint always reports healthy.
+    pub fn with_health_checker(mut self, health: HealthChecker) -> Self {
+        self.health = Some(health);
+        self
+    }
+
+    /// Serves `/ready` using the given [`HealthChecker`].
+    ///
+    /// Without a checker the endpoint reports the same status as `/health`.
+    pub fn with_readiness_checker(mut self, readiness: HealthChecker) -> Self {
+        self.readiness = Some(readiness);
+        self
     }
 }
 
@@ -49,13 +76,22 @@ impl MetricServer {
 
     /// Spawns the metrics server
     pub async fn serve(&self) -> eyre::Result<()> {
-        let MetricServerConfig { listen_addr, hooks, task_executor, version_info, chain_spec_info } =
-            &self.config;
+        let MetricServerConfig {
+            listen_addr,
+            hooks,
+            task_executor,
+            version_info,
+            chain_spec_info,
+            health,
+            readiness,
+        } = &self.config;
 
         let hooks = hooks.clone();
         self.start_endpoint(
             *listen_addr,
             Arc::new(move || hooks.iter().for_each(|hook| hook())),
+            health.clone(),
+            readiness.clone(),
             task_executor.clone(),
         )
         .await
@@ -78,6 +114,8 @@ impl MetricServer {
         &self,
         listen_addr: SocketAddr,
         hook: Arc<F>,
+        health: Option<HealthChecker>,
+        readiness: Option<HealthChecker>,
         task_executor: TaskExecutor,
     ) -> eyre::Result<()> {
         let listener = tokio::net::TcpListener::bind(listen_addr)
@@ -101,10 +139,17 @@ impl MetricServer {
 
                 let handle = install_prometheus_recorder();
                 let hook = hook.clone();
-                let service = tower::service_fn(move |_| {
-                    (hook)();
-                    let metrics = handle.handle().render();
-                    let mut response = Response::new(metrics);
+                let health = health.clone();
+                let readiness = readiness.clone();
+                let service = tower::service_fn(move |req: Request<_>| {
+                    let mut response = match req.uri().path() {
+                        "/health" => health_response(health.as_ref()),
+                        "/ready" => health_response(readiness.as_ref().or(health.as_ref())),
+                        _ => {
+                            (hook)();
+                            Response::new(handle.handle().render())
+                        }
+                    };
                     response
                         .headers_mut()
                         .insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
@@ -127,6 +172,15 @@ impl MetricServer {
     }
 }
 
+/// Evaluates the health checker, responding with `503 Service Unavailable` if it fails.
+fn health_response(health: Option<&HealthChecker>) -> Response<String> {
+    let status = health.map(HealthChecker::check).unwrap_or(HealthStatus::Healthy);
+    let code = if status.is_healthy() { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+    let mut response = Response::new(status.to_string());
+    *response.status_mut() = code;
+    response
+}
+
 fn describe_db_metrics() {
     describe_gauge!("db.table_size", Unit::Bytes, "The size of a database table (in bytes)");
     describe_gauge!("db.table_pages", "The number of database pages for a table");
@@ -253,4 +307,42 @@ mod tests {
         assert!(body.contains("reth_process_cpu_seconds_total"));
         assert!(body.contains("reth_process_start_time_seconds"));
     }
+
+    #[tokio::test]
+    async fn test_health_endpoints() {
+        let chain_spec_info = ChainSpecInfo { name: "test".to_string() };
+        let version_info = VersionInfo {
+            version: "test",
+            build_timestamp: "test",
+            cargo_features: "test",
+            git_sha: "test",
+            target_triple: "test",
+            build_profile: "test",
+        };
+
+        let tasks = TaskManager::current();
+        let executor = tasks.executor();
+
+        let hooks = Hooks::builder().build();
+
+        let listen_addr = get_random_available_addr();
+        let config =
+            MetricServerConfig::new(listen_addr, version_info, chain_spec_info, executor, hooks)
+                .with_health_checker(HealthChecker::new(|| HealthStatus::Healthy))
+                .with_readiness_checker(HealthChecker::new(|| {
+                    HealthStatus::Unhealthy("node is syncing".to_string())
+                }));
+
+        MetricServer::new(config).serve().await.unwrap();
+
+        let response =
+            Client::new().get(format!("http://{listen_addr}/health")).send().await.unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        assert_eq!(response.text().await.unwrap(), "ok");
+
+        let response =
+            Client::new().get(format!("http://{listen_addr}/ready")).send().await.unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.text().await.unwrap(), "node is syncing");
+    }
 }
@@ -733,6 +733,7 @@ where
         + 'static,
 {
     type Primitives = OpNetworkPrimitives;
+    type Network = NetworkHandle<Self::Primitives>;
 
     async fn build_network(
         self,
@@ -520,6 +520,8 @@ impl<Txs> OpBuilder<'_, Txs> {
                 )),
                 execution_output: Arc::new(execution_outcome),
                 hashed_state: Arc::new(hashed_state),
+                internal_accounts: Default::default(),
+                contract_creations: Default::default(),
             },
             trie: Arc::new(trie_output),
         };
@@ -6,8 +6,9 @@ use reth_db_api::{table::Value, transaction::DbTxMut};
 use reth_exex_types::FinishedExExHeight;
 use reth_primitives_traits::NodePrimitives;
 use reth_provider::{
-    providers::StaticFileProvider, BlockReader, DBProvider, DatabaseProviderFactory,
-    NodePrimitivesProvider, PruneCheckpointWriter, StaticFileProviderFactory,
+    providers::StaticFileProvider, AddressTransactionsReader, BlockReader, DBProvider,
+    DatabaseProviderFactory, LogIndexReader, NodePrimitivesProvider, PruneCheckpointWriter,
+    StaticFileProviderFactory, TokenTransfersReader,
 };
 use reth_prune_types::PruneModes;
 use std::time::Duration;
@@ -81,6 +82,9 @@ impl PrunerBuilder {
         PF: DatabaseProviderFactory<
                 ProviderRW: PruneCheckpointWriter
                                 + BlockReader<Transaction: Encodable2718>
+                                + AddressTransactionsReader
+                                + LogIndexReader
+                                + TokenTransfersReader
                                 + StaticFileProviderFactory<
                     Primitives: NodePrimitives<SignedTx: Value, Receipt: Value>,
                 >,
@@ -110,6 +114,9 @@ impl PrunerBuilder {
         Provider: StaticFileProviderFactory<Primitives: NodePrimitives<SignedTx: Value, Receipt: Value>>
             + DBProvider<Tx: DbTxMut>
             + BlockReader<Transaction: Encodable2718>
+            + AddressTransactionsReader
+            + LogIndexReader
+            + TokenTransfersReader
             + PruneCheckpointWriter,
     {
         let segments = SegmentSet::<Provider>::from_components(static_file_provider, self.segments);
@@ -9,14 +9,15 @@ use reth_provider::{errors::provider::ProviderResult, BlockReader, PruneCheckpoi
 use reth_prune_types::{PruneCheckpoint, PruneMode, PrunePurpose, PruneSegment, SegmentOutput};
 pub use set::SegmentSet;
 pub use static_file::{
-    Headers as StaticFileHeaders, Receipts as StaticFileReceipts,
-    Transactions as StaticFileTransactions,
+    AccountChangeSets as StaticFileAccountChangeSets, Headers as StaticFileHeaders,
+    HlSidecars as StaticFileHlSidecars, Receipts as StaticFileReceipts,
+    StorageChangeSets as StaticFileStorageChangeSets, Transactions as StaticFileTransactions,
 };
 use std::{fmt::Debug, ops::RangeInclusive};
 use tracing::error;
 pub use user::{
-    AccountHistory, Receipts as UserReceipts, ReceiptsByLogs, SenderRecovery, StorageHistory,
-    TransactionLookup,
+    AccountHistory, AddressTransactions, LogIndex, Receipts as UserReceipts, ReceiptsByLogs,
+    SenderRecovery, StorageHistory, TokenTransfers, TransactionLookup,
 };
 
 /// A segment represents a pruning of some portion of the data.
@@ -1,17 +1,20 @@
 use crate::segments::{
-    AccountHistory, ReceiptsByLogs, Segment, SenderRecovery, StorageHistory, TransactionLookup,
-    UserReceipts,
+    AccountHistory, AddressTransactions, LogIndex, ReceiptsByLogs, Segment, SenderRecovery,
+    StorageHistory, TokenTransfers, TransactionLookup, UserReceipts,
 };
 use alloy_eips::eip2718::Encodable2718;
 use reth_db_api::{table::Value, transaction::DbTxMut};
 use reth_primitives_traits::NodePrimitives;
 use reth_provider::{
-    providers::StaticFileProvider, BlockReader, DBProvider, PruneCheckpointWriter,
-    StaticFileProviderFactory,
+    providers::StaticFileProvider, AddressTransactionsReader, BlockReader, DBProvider,
+    LogIndexReader, PruneCheckpointWriter, StaticFileProviderFactory, TokenTransfersReader,
 };
 use reth_prune_types::PruneModes;
 
-use super::{StaticFileHeaders, StaticFileReceipts, StaticFileTransactions};
+use super::{
+    StaticFileAccountChangeSets, StaticFileHeaders, StaticFileHlSidecars, StaticFileReceipts,
+    StaticFileStorageChangeSets, StaticFileTransactions,
+};
 
 /// Collection of [`Segment`]. Thread-safe, allocated on the heap.
 #[derive(Debug)]
@@ -50,7 +53,10 @@ where
     Provider: StaticFileProviderFactory<Primitives: NodePrimitives<SignedTx: Value, Receipt: Value>>
         + DBProvider<Tx: DbTxMut>
         + PruneCheckpointWriter
-        + BlockReader<Transaction: Encodable2718>,
+        + BlockReader<Transaction: Encodable2718>
+        + AddressTransactionsReader
+        + LogIndexReader
+        + TokenTransfersReader,
 {
     /// Creates a [`SegmentSet`] from an existing components, such as [`StaticFileProvider`] and
     /// [`PruneModes`].
@@ -64,6 +70,9 @@ where
             receipts,
             account_history,
             storage_history,
+            address_transactions,
+            log_index,
+            token_transfers,
             receipts_log_filter,
         } = prune_modes;
 
@@ -73,11 +82,23 @@ where
             // Static file transactions
             .segment(StaticFileTransactions::new(static_file_provider.clone()))
             // Static file receipts
-            .segment(StaticFileReceipts::new(static_file_provider))
+            .segment(StaticFileReceipts::new(static_file_provider.clone()))
+            // Static file account changesets
+            .segment(StaticFileAccountChangeSets::new(static_file_provider.clone()))
+            // Static file storage changesets
+            .segment(StaticFileStorageChangeSets::new(static_file_provider.clone()))
+            // Static file Hyperliquid sidecars
+            .segment(StaticFileHlSidecars::new(static_file_provider))
             // Account history
             .segment_opt(account_history.map(AccountHistory::new))
             // Storage history
             .segment_opt(storage_history.map(StorageHistory::new))
+            // Address transactions
+            .segment_opt(address_transactions.map(AddressTransactions::new))
+            // Log index, pruned before the receipts it's read from
+            .segment_opt(log_index.map(LogIndex::new))
+            // Token transfers, pruned before the receipts they're read from
+            .segment_opt(token_transfers.map(TokenTransfers::new))
             // User receipts
             .segment_opt(receipts.map(UserReceipts::new))
             // Receipts by logs
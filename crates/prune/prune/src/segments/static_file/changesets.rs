@@ -0,0 +1,317 @@
+use crate::{
+    db_ext::DbTxPruneExt,
+    segments::{PruneInput, Segment},
+    PrunerError,
+};
+use reth_db_api::{models::BlockNumberAddress, tables, transaction::DbTxMut};
+use reth_provider::{providers::StaticFileProvider, DBProvider, StaticFileProviderFactory};
+use reth_prune_types::{
+    PruneMode, PrunePurpose, PruneSegment, SegmentOutput, SegmentOutputCheckpoint,
+};
+use reth_static_file_types::StaticFileSegment;
+use tracing::trace;
+
+#[derive(Debug)]
+pub struct AccountChangeSets<N> {
+    static_file_provider: StaticFileProvider<N>,
+}
+
+impl<N> AccountChangeSets<N> {
+    pub const fn new(static_file_provider: StaticFileProvider<N>) -> Self {
+        Self { static_file_provider }
+    }
+}
+
+impl<Provider: StaticFileProviderFactory + DBProvider<Tx: DbTxMut>> Segment<Provider>
+    for AccountChangeSets<Provider::Primitives>
+{
+    fn segment(&self) -> PruneSegment {
+        PruneSegment::AccountChangeSets
+    }
+
+    fn mode(&self) -> Option<PruneMode> {
+        self.static_file_provider
+            .get_highest_static_file_block(StaticFileSegment::AccountChangeSets)
+            .map(PruneMode::before_inclusive)
+    }
+
+    fn purpose(&self) -> PrunePurpose {
+        PrunePurpose::StaticFile
+    }
+
+    fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
+        let range = match input.get_next_block_range() {
+            Some(range) => range,
+            None => {
+                trace!(target: "pruner", "No account changesets to prune");
+                return Ok(SegmentOutput::done())
+            }
+        };
+        let range_end = *range.end();
+
+        let mut limiter = input.limiter;
+
+        let mut last_pruned_block = None;
+        let (pruned, done) =
+            provider.tx_ref().prune_table_with_range::<tables::AccountChangeSets>(
+                range,
+                &mut limiter,
+                |_| false,
+                |(block_number, _)| last_pruned_block = Some(block_number),
+            )?;
+        trace!(target: "pruner", %pruned, %done, "Pruned account changesets");
+
+        let last_pruned_block = last_pruned_block
+            // If there's more account changesets to prune, set the checkpoint block number to
+            // previous, so we could finish pruning its account changesets on the next run.
+            .map(|block_number| if done { block_number } else { block_number.saturating_sub(1) })
+            .unwrap_or(range_end);
+
+        let progress = limiter.progress(done);
+
+        Ok(SegmentOutput {
+            progress,
+            pruned,
+            checkpoint: Some(SegmentOutputCheckpoint {
+                block_number: Some(last_pruned_block),
+                tx_number: None,
+            }),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct StorageChangeSets<N> {
+    static_file_provider: StaticFileProvider<N>,
+}
+
+impl<N> StorageChangeSets<N> {
+    pub const fn new(static_file_provider: StaticFileProvider<N>) -> Self {
+        Self { static_file_provider }
+    }
+}
+
+impl<Provider: StaticFileProviderFactory + DBProvider<Tx: DbTxMut>> Segment<Provider>
+    for StorageChangeSets<Provider::Primitives>
+{
+    fn segment(&self) -> PruneSegment {
+        PruneSegment::StorageChangeSets
+    }
+
+    fn mode(&self) -> Option<PruneMode> {
+        self.static_file_provider
+            .get_highest_static_file_block(StaticFileSegment::StorageChangeSets)
+            .map(PruneMode::before_inclusive)
+    }
+
+    fn purpose(&self) -> PrunePurpose {
+        PrunePurpose::StaticFile
+    }
+
+    fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
+        let range = match input.get_next_block_range() {
+            Some(range) => range,
+            None => {
+                trace!(target: "pruner", "No storage changesets to prune");
+                return Ok(SegmentOutput::done())
+            }
+        };
+        let range_end = *range.end();
+
+        let mut limiter = input.limiter;
+
+        let mut last_pruned_block = None;
+        let (pruned, done) =
+            provider.tx_ref().prune_table_with_range::<tables::StorageChangeSets>(
+                BlockNumberAddress::range(range),
+                &mut limiter,
+                |_| false,
+                |(key, _)| last_pruned_block = Some(key.block_number()),
+            )?;
+        trace!(target: "pruner", %pruned, %done, "Pruned storage changesets");
+
+        let last_pruned_block = last_pruned_block
+            // If there's more storage changesets to prune, set the checkpoint block number to
+            // previous, so we could finish pruning its storage changesets on the next run.
+            .map(|block_number| if done { block_number } else { block_number.saturating_sub(1) })
+            .unwrap_or(range_end);
+
+        let progress = limiter.progress(done);
+
+        Ok(SegmentOutput {
+            progress,
+            pruned,
+            checkpoint: Some(SegmentOutputCheckpoint {
+                block_number: Some(last_pruned_block),
+                tx_number: None,
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountChangeSets, StorageChangeSets};
+    use crate::segments::{PruneInput, PruneLimiter, Segment, SegmentOutput};
+    use alloy_primitives::{Address, BlockNumber, B256, U256};
+    use assert_matches::assert_matches;
+    use reth_db_api::tables;
+    use reth_primitives_traits::{Account, StorageEntry};
+    use reth_provider::{
+        DatabaseProviderFactory, PruneCheckpointReader, PruneCheckpointWriter,
+        StaticFileProviderFactory,
+    };
+    use reth_prune_types::{
+        PruneCheckpoint, PruneInterruptReason, PruneMode, PruneProgress, PruneSegment,
+    };
+    use reth_stages::test_utils::TestStageDB;
+
+    /// Inserts changesets of two accounts with one storage slot each for blocks `0..10`.
+    fn setup() -> TestStageDB {
+        let db = TestStageDB::default();
+        let changesets = (0..10).map(|_| {
+            [Address::with_last_byte(1), Address::with_last_byte(2)]
+                .into_iter()
+                .map(|address| {
+                    (
+                        address,
+                        Account::default(),
+                        vec![StorageEntry { key: B256::with_last_byte(1), value: U256::from(1) }],
+                    )
+                })
+                .collect()
+        });
+        db.insert_changesets(changesets, None).expect("insert changesets");
+        db
+    }
+
+    #[test]
+    fn prune_account_changesets() {
+        let db = setup();
+        assert_eq!(db.table::<tables::AccountChangeSets>().unwrap().len(), 20);
+
+        let test_prune = |to_block: BlockNumber,
+                          limiter: PruneLimiter,
+                          expected_result: (PruneProgress, usize),
+                          expected_checkpoint: BlockNumber| {
+            let segment = AccountChangeSets::new(db.factory.static_file_provider());
+            let prune_mode = PruneMode::Before(to_block);
+            let input = PruneInput {
+                previous_checkpoint: db
+                    .factory
+                    .provider()
+                    .unwrap()
+                    .get_prune_checkpoint(PruneSegment::AccountChangeSets)
+                    .unwrap(),
+                to_block,
+                limiter,
+            };
+
+            let provider = db.factory.database_provider_rw().unwrap();
+            let result = segment.prune(&provider, input).unwrap();
+            assert_matches!(
+                result,
+                SegmentOutput { progress, pruned, checkpoint: Some(_) }
+                    if (progress, pruned) == expected_result
+            );
+            provider
+                .save_prune_checkpoint(
+                    PruneSegment::AccountChangeSets,
+                    result.checkpoint.unwrap().as_prune_checkpoint(prune_mode),
+                )
+                .unwrap();
+            provider.commit().expect("commit");
+
+            assert_eq!(
+                db.factory
+                    .provider()
+                    .unwrap()
+                    .get_prune_checkpoint(PruneSegment::AccountChangeSets)
+                    .unwrap(),
+                Some(PruneCheckpoint {
+                    block_number: Some(expected_checkpoint),
+                    tx_number: None,
+                    prune_mode
+                })
+            );
+        };
+
+        // The limit is reached in the middle of block 2, so it is pruned again on the next run.
+        test_prune(
+            4,
+            PruneLimiter::default().set_deleted_entries_limit(5),
+            (PruneProgress::HasMoreData(PruneInterruptReason::DeletedEntriesLimitReached), 5),
+            1,
+        );
+        test_prune(4, PruneLimiter::default(), (PruneProgress::Finished, 5), 4);
+
+        let changesets = db.table::<tables::AccountChangeSets>().unwrap();
+        assert_eq!(changesets.len(), 10);
+        assert!(changesets.iter().all(|(block_number, _)| *block_number > 4));
+    }
+
+    #[test]
+    fn prune_storage_changesets() {
+        let db = setup();
+        assert_eq!(db.table::<tables::StorageChangeSets>().unwrap().len(), 20);
+
+        let test_prune = |to_block: BlockNumber,
+                          limiter: PruneLimiter,
+                          expected_result: (PruneProgress, usize),
+                          expected_checkpoint: BlockNumber| {
+            let segment = StorageChangeSets::new(db.factory.static_file_provider());
+            let prune_mode = PruneMode::Before(to_block);
+            let input = PruneInput {
+                previous_checkpoint: db
+                    .factory
+                    .provider()
+                    .unwrap()
+                    .get_prune_checkpoint(PruneSegment::StorageChangeSets)
+                    .unwrap(),
+                to_block,
+                limiter,
+            };
+
+            let provider = db.factory.database_provider_rw().unwrap();
+            let result = segment.prune(&provider, input).unwrap();
+            assert_matches!(
+                result,
+                SegmentOutput { progress, pruned, checkpoint: Some(_) }
+                    if (progress, pruned) == expected_result
+            );
+            provider
+                .save_prune_checkpoint(
+                    PruneSegment::StorageChangeSets,
+                    result.checkpoint.unwrap().as_prune_checkpoint(prune_mode),
+                )
+                .unwrap();
+            provider.commit().expect("commit");
+
+            assert_eq!(
+                db.factory
+                    .provider()
+                    .unwrap()
+                    .get_prune_checkpoint(PruneSegment::StorageChangeSets)
+                    .unwrap(),
+                Some(PruneCheckpoint {
+                    block_number: Some(expected_checkpoint),
+                    tx_number: None,
+                    prune_mode
+                })
+            );
+        };
+
+        // The limit is reached in the middle of block 2, so it is pruned again on the next run.
+        test_prune(
+            4,
+            PruneLimiter::default().set_deleted_entries_limit(5),
+            (PruneProgress::HasMoreData(PruneInterruptReason::DeletedEntriesLimitReached), 5),
+            1,
+        );
+        test_prune(4, PruneLimiter::default(), (PruneProgress::Finished, 5), 4);
+
+        let changesets = db.table::<tables::StorageChangeSets>().unwrap();
+        assert_eq!(changesets.len(), 10);
+        assert!(changesets.iter().all(|(key, _)| key.block_number() > 4));
+    }
+}
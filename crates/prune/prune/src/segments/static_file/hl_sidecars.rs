@@ -0,0 +1,80 @@
+use crate::{
+    db_ext::DbTxPruneExt,
+    segments::{PruneInput, Segment},
+    PrunerError,
+};
+use reth_db_api::{tables, transaction::DbTxMut};
+use reth_provider::{providers::StaticFileProvider, DBProvider, StaticFileProviderFactory};
+use reth_prune_types::{
+    PruneMode, PrunePurpose, PruneSegment, SegmentOutput, SegmentOutputCheckpoint,
+};
+use reth_static_file_types::StaticFileSegment;
+use tracing::trace;
+
+#[derive(Debug)]
+pub struct HlSidecars<N> {
+    static_file_provider: StaticFileProvider<N>,
+}
+
+impl<N> HlSidecars<N> {
+    pub const fn new(static_file_provider: StaticFileProvider<N>) -> Self {
+        Self { static_file_provider }
+    }
+}
+
+impl<Provider: StaticFileProviderFactory + DBProvider<Tx: DbTxMut>> Segment<Provider>
+    for HlSidecars<Provider::Primitives>
+{
+    fn segment(&self) -> PruneSegment {
+        PruneSegment::HlSidecars
+    }
+
+    fn mode(&self) -> Option<PruneMode> {
+        self.static_file_provider
+            .get_highest_static_file_block(StaticFileSegment::HlSidecars)
+            .map(PruneMode::before_inclusive)
+    }
+
+    fn purpose(&self) -> PrunePurpose {
+        PrunePurpose::StaticFile
+    }
+
+    fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
+        let range = match input.get_next_block_range() {
+            Some(range) => range,
+            None => {
+                trace!(target: "pruner", "No Hyperliquid sidecars to prune");
+                return Ok(SegmentOutput::done())
+            }
+        };
+        let range_end = *range.end();
+
+        let mut limiter = input.limiter;
+
+        let mut last_pruned_block = None;
+        let (pruned, done) = provider.tx_ref().prune_table_with_range::<tables::HlSidecars>(
+            range,
+            &mut limiter,
+            |_| false,
+            |(block_number, _)| last_pruned_block = Some(block_number),
+        )?;
+        trace!(target: "pruner", %pruned, %done, "Pruned Hyperliquid sidecars");
+
+        let last_pruned_block = last_pruned_block
+            // If there's more sidecars to prune, set the checkpoint block number to previous, so
+            // we could finish pruning its sidecars on the next run.
+            .map(|block_number| if done { block_number } else { block_number.saturating_sub(1) })
+            .unwrap_or(range_end);
+
+        let progress = limiter.progress(done);
+
+        Ok(SegmentOutput {
+            progress,
+            pruned,
+            checkpoint: Some(SegmentOutputCheckpoint {
+                block_number: Some(last_pruned_block),
+                tx_number: None,
+            }),
+        })
+    }
+}
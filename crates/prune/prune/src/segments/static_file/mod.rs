@@ -1,7 +1,11 @@
+mod changesets;
 mod headers;
+mod hl_sidecars;
 mod receipts;
 mod transactions;
 
+pub use changesets::{AccountChangeSets, StorageChangeSets};
 pub use headers::Headers;
+pub use hl_sidecars::HlSidecars;
 pub use receipts::Receipts;
 pub use transactions::Transactions;
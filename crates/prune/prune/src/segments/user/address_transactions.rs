@@ -0,0 +1,214 @@
+use crate::{
+    segments::{user::history::prune_history_indices, PruneInput, Segment},
+    PruneLimiter, PrunerError,
+};
+use reth_db_api::{models::ShardedKey, tables, transaction::DbTxMut};
+use reth_provider::{AddressTransactionsReader, BlockReader, DBProvider};
+use reth_prune_types::{
+    PruneMode, PrunePurpose, PruneSegment, SegmentOutput, SegmentOutputCheckpoint,
+};
+use tracing::{instrument, trace};
+
+#[derive(Debug)]
+pub struct AddressTransactions {
+    mode: PruneMode,
+}
+
+impl AddressTransactions {
+    pub const fn new(mode: PruneMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl<Provider> Segment<Provider> for AddressTransactions
+where
+    Provider: DBProvider<Tx: DbTxMut> + BlockReader + AddressTransactionsReader,
+{
+    fn segment(&self) -> PruneSegment {
+        PruneSegment::AddressTransactions
+    }
+
+    fn mode(&self) -> Option<PruneMode> {
+        Some(self.mode)
+    }
+
+    fn purpose(&self) -> PrunePurpose {
+        PrunePurpose::User
+    }
+
+    #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
+    fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
+        let (start, end) = match input.get_next_tx_num_range(provider)? {
+            Some(range) => range,
+            None => {
+                trace!(target: "pruner", "No address transactions to prune");
+                return Ok(SegmentOutput::done())
+            }
+        }
+        .into_inner();
+
+        if input.limiter.is_limit_reached() {
+            return Ok(SegmentOutput::not_done(
+                input.limiter.interrupt_reason(),
+                input.previous_checkpoint.map(SegmentOutputCheckpoint::from_prune_checkpoint),
+            ))
+        }
+        let tx_range_end = Some(end)
+            .min(input.limiter.deleted_entries_limit_left().map(|left| start + left as u64 - 1))
+            .unwrap();
+        let done = tx_range_end == end;
+
+        let block_of = |tx_number| {
+            provider
+                .transaction_block(tx_number)?
+                .ok_or(PrunerError::InconsistentData("Block for transaction is not found"))
+        };
+        let (start_block, end_block) = (block_of(start)?, block_of(tx_range_end)?);
+
+        // Collect the addresses touched by the transactions in range alongside the highest
+        // transaction number to prune for each of them. Addresses that were only touched through
+        // logs of already pruned receipts can't be found anymore, so their entries are left in
+        // place.
+        let highest_sharded_keys = provider
+            .touched_addresses_with_range(start_block..=end_block)?
+            .into_iter()
+            .filter_map(|(address, tx_numbers)| {
+                tx_numbers
+                    .into_iter()
+                    .rev()
+                    .find(|tx_number| (start..=tx_range_end).contains(tx_number))
+                    .map(|tx_number| ShardedKey::new(address, tx_number))
+            });
+        let outcomes = prune_history_indices::<Provider, tables::AddressTransactions, _>(
+            provider,
+            highest_sharded_keys,
+            |a, b| a.key == b.key,
+        )?;
+        // The internal call accounts of pruned transactions aren't needed anymore.
+        provider.tx_ref().prune_table_with_range::<tables::TransactionInternalAccounts>(
+            start..=tx_range_end,
+            &mut PruneLimiter::default(),
+            |_| false,
+            |_| {},
+        )?;
+        trace!(target: "pruner", ?outcomes, %done, "Pruned address transactions");
+
+        let mut limiter = input.limiter;
+        limiter.increment_deleted_entries_count_by((tx_range_end - start + 1) as usize);
+
+        // If the last block wasn't pruned fully, set the checkpoint block number to previous, so we
+        // could finish pruning its transactions on the next run.
+        let last_pruned_block = if done ||
+            provider
+                .block_body_indices(end_block)?
+                .is_some_and(|body| body.last_tx_num() == tx_range_end)
+        {
+            Some(end_block)
+        } else {
+            end_block.checked_sub(1)
+        };
+
+        let progress = limiter.progress(done);
+
+        Ok(SegmentOutput {
+            progress,
+            pruned: outcomes.deleted,
+            checkpoint: Some(SegmentOutputCheckpoint {
+                block_number: last_pruned_block,
+                tx_number: Some(tx_range_end),
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::segments::{AddressTransactions, PruneInput, PruneLimiter, Segment};
+    use alloy_primitives::{Address, TxNumber};
+    use reth_db_api::tables;
+    use reth_primitives_traits::SignedTransaction;
+    use reth_provider::{
+        AddressTransactionsReader, BlockBodyIndicesProvider, DatabaseProviderFactory,
+        HistoryWriter, PruneCheckpointReader,
+    };
+    use reth_prune_types::{PruneCheckpoint, PruneMode, PruneSegment};
+    use reth_stages::test_utils::{StorageKind, TestStageDB};
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn prune() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            0..=20,
+            BlockRangeParams { parent: None, tx_count: 1..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Static).expect("insert blocks");
+
+        let mut senders = BTreeMap::<Address, Vec<TxNumber>>::new();
+        for (tx_number, tx) in
+            blocks.iter().flat_map(|block| block.body().transactions.iter()).enumerate()
+        {
+            senders
+                .entry(tx.recover_signer().expect("recover signer"))
+                .or_default()
+                .push(tx_number as TxNumber);
+        }
+        let provider = db.factory.database_provider_rw().unwrap();
+        provider.insert_address_transactions_index(senders.clone()).unwrap();
+        provider.commit().expect("commit");
+        assert!(!db.table::<tables::AddressTransactions>().unwrap().is_empty());
+
+        let to_block = 10;
+        let last_pruned_tx = db
+            .factory
+            .provider()
+            .unwrap()
+            .block_body_indices(to_block)
+            .unwrap()
+            .unwrap()
+            .last_tx_num();
+
+        let segment = AddressTransactions::new(PruneMode::Before(to_block + 1));
+        let input = PruneInput {
+            previous_checkpoint: None,
+            to_block,
+            limiter: PruneLimiter::default().set_deleted_entries_limit(usize::MAX),
+        };
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = segment.prune(&provider, input).unwrap();
+        assert!(result.progress.is_finished());
+        segment
+            .save_checkpoint(
+                &provider,
+                result.checkpoint.unwrap().as_prune_checkpoint(PruneMode::Before(to_block + 1)),
+            )
+            .unwrap();
+        provider.commit().expect("commit");
+
+        assert_eq!(
+            db.factory.provider().unwrap().get_prune_checkpoint(PruneSegment::AddressTransactions),
+            Ok(Some(PruneCheckpoint {
+                block_number: Some(to_block),
+                tx_number: Some(last_pruned_tx),
+                prune_mode: PruneMode::Before(to_block + 1)
+            }))
+        );
+
+        let provider = db.factory.provider().unwrap();
+        for (address, tx_numbers) in senders {
+            let expected = tx_numbers
+                .into_iter()
+                .filter(|tx_number| *tx_number > last_pruned_tx)
+                .collect::<Vec<_>>();
+            let mut found =
+                provider.address_transactions_before(address, TxNumber::MAX, usize::MAX).unwrap();
+            found.reverse();
+            assert_eq!(found, expected);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    segments::{user::history::prune_history_indices, PruneInput, Segment},
+    PrunerError,
+};
+use reth_db_api::{
+    models::{LogPosition, ShardedKey},
+    tables,
+    transaction::DbTxMut,
+};
+use reth_provider::{DBProvider, LogIndexEntries, LogIndexReader};
+use reth_prune_types::{
+    PruneMode, PrunePurpose, PruneSegment, SegmentOutput, SegmentOutputCheckpoint,
+};
+use tracing::{instrument, trace};
+
+#[derive(Debug)]
+pub struct LogIndex {
+    mode: PruneMode,
+}
+
+impl LogIndex {
+    pub const fn new(mode: PruneMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl<Provider> Segment<Provider> for LogIndex
+where
+    Provider: DBProvider<Tx: DbTxMut> + LogIndexReader,
+{
+    fn segment(&self) -> PruneSegment {
+        PruneSegment::LogIndex
+    }
+
+    fn mode(&self) -> Option<PruneMode> {
+        Some(self.mode)
+    }
+
+    fn purpose(&self) -> PrunePurpose {
+        PrunePurpose::User
+    }
+
+    #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
+    fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
+        let (start, end) = match input.get_next_block_range() {
+            Some(range) => range,
+            None => {
+                trace!(target: "pruner", "No log index to prune");
+                return Ok(SegmentOutput::done())
+            }
+        }
+        .into_inner();
+
+        if input.limiter.is_limit_reached() {
+            return Ok(SegmentOutput::not_done(
+                input.limiter.interrupt_reason(),
+                input.previous_checkpoint.map(SegmentOutputCheckpoint::from_prune_checkpoint),
+            ))
+        }
+        let range_end = Some(end)
+            .min(input.limiter.deleted_entries_limit_left().map(|left| start + left as u64 - 1))
+            .unwrap();
+        let done = range_end == end;
+
+        // The indexed addresses and topics are found through the receipts of the blocks in range.
+        // Entries of blocks whose receipts were already pruned can't be found anymore, so they are
+        // left in place.
+        let LogIndexEntries { addresses, topics } =
+            provider.log_index_entries_with_range(start..=range_end)?;
+        // The index stores log positions, so everything up to the last position of the last block
+        // in range is pruned.
+        let last_position = *LogPosition::range(start..=range_end).end();
+        let address_outcomes = prune_history_indices::<Provider, tables::LogAddressIndex, _>(
+            provider,
+            addresses.into_keys().map(|address| ShardedKey::new(address, last_position)),
+            |a, b| a.key == b.key,
+        )?;
+        let topic_outcomes = prune_history_indices::<Provider, tables::LogTopicIndex, _>(
+            provider,
+            topics.into_keys().map(|key| ShardedKey::new(key, last_position)),
+            |a, b| a.key == b.key,
+        )?;
+        trace!(target: "pruner", ?address_outcomes, ?topic_outcomes, %done, "Pruned log index");
+
+        let mut limiter = input.limiter;
+        limiter.increment_deleted_entries_count_by((range_end - start + 1) as usize);
+
+        let progress = limiter.progress(done);
+
+        Ok(SegmentOutput {
+            progress,
+            pruned: address_outcomes.deleted + topic_outcomes.deleted,
+            checkpoint: Some(SegmentOutputCheckpoint {
+                block_number: Some(range_end),
+                tx_number: None,
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::segments::{LogIndex, PruneInput, PruneLimiter, Segment};
+    use reth_db_api::{models::LogPosition, tables};
+    use reth_provider::{
+        DatabaseProviderFactory, HistoryWriter, LogIndexReader, PruneCheckpointReader,
+    };
+    use reth_prune_types::{PruneCheckpoint, PruneMode, PruneSegment};
+    use reth_stages::test_utils::{StorageKind, TestStageDB};
+    use reth_testing_utils::generators::{
+        self, random_block_range, random_receipt, BlockRangeParams,
+    };
+
+    #[test]
+    fn prune() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            0..=20,
+            BlockRangeParams { parent: None, tx_count: 1..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Static).expect("insert blocks");
+
+        let mut receipts = Vec::new();
+        let mut tx_num = 0u64;
+        for block in &blocks {
+            let mut block_receipts = Vec::new();
+            for transaction in &block.body().transactions {
+                block_receipts.push((tx_num, random_receipt(&mut rng, transaction, Some(1))));
+                tx_num += 1;
+            }
+            receipts.push((block.number, block_receipts));
+        }
+        db.insert_receipts_by_block(receipts, StorageKind::Static).expect("insert receipts");
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        let entries = provider.log_index_entries_with_range(0..=20).unwrap();
+        provider.insert_log_index(entries.clone()).unwrap();
+        provider.commit().expect("commit");
+        assert!(!db.table::<tables::LogAddressIndex>().unwrap().is_empty());
+        assert!(!db.table::<tables::LogTopicIndex>().unwrap().is_empty());
+
+        let to_block = 10;
+        let segment = LogIndex::new(PruneMode::Before(to_block + 1));
+        let input = PruneInput {
+            previous_checkpoint: None,
+            to_block,
+            limiter: PruneLimiter::default().set_deleted_entries_limit(usize::MAX),
+        };
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = segment.prune(&provider, input).unwrap();
+        assert!(result.progress.is_finished());
+        segment
+            .save_checkpoint(
+                &provider,
+                result.checkpoint.unwrap().as_prune_checkpoint(PruneMode::Before(to_block + 1)),
+            )
+            .unwrap();
+        provider.commit().expect("commit");
+
+        assert_eq!(
+            db.factory.provider().unwrap().get_prune_checkpoint(PruneSegment::LogIndex),
+            Ok(Some(PruneCheckpoint {
+                block_number: Some(to_block),
+                tx_number: None,
+                prune_mode: PruneMode::Before(to_block + 1)
+            }))
+        );
+
+        let provider = db.factory.provider().unwrap();
+        let unpruned = |positions: Vec<LogPosition>| {
+            positions.into_iter().filter(|p| p.block_number > to_block).collect::<Vec<_>>()
+        };
+        for (address, positions) in entries.addresses {
+            assert_eq!(
+                provider.log_address_positions(address, 0..=20).unwrap(),
+                unpruned(positions)
+            );
+        }
+        for (key, positions) in entries.topics {
+            assert_eq!(provider.log_topic_positions(key, 0..=20).unwrap(), unpruned(positions));
+        }
+    }
+}
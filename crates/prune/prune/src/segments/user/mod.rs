@@ -1,14 +1,20 @@
 mod account_history;
+mod address_transactions;
 mod history;
+mod log_index;
 mod receipts;
 mod receipts_by_logs;
 mod sender_recovery;
 mod storage_history;
+mod token_transfers;
 mod transaction_lookup;
 
 pub use account_history::AccountHistory;
+pub use address_transactions::AddressTransactions;
+pub use log_index::LogIndex;
 pub use receipts::Receipts;
 pub use receipts_by_logs::ReceiptsByLogs;
 pub use sender_recovery::SenderRecovery;
 pub use storage_history::StorageHistory;
+pub use token_transfers::TokenTransfers;
 pub use transaction_lookup::TransactionLookup;
@@ -0,0 +1,217 @@
+use crate::{
+    segments::{PruneInput, Segment},
+    PrunerError,
+};
+use reth_db_api::{
+    cursor::{DbCursorRO, DbCursorRW},
+    table::Table,
+    tables,
+    transaction::DbTxMut,
+};
+use reth_provider::{DBProvider, TokenTransfersReader};
+use reth_prune_types::{
+    PruneMode, PrunePurpose, PruneSegment, SegmentOutput, SegmentOutputCheckpoint,
+};
+use tracing::{instrument, trace};
+
+#[derive(Debug)]
+pub struct TokenTransfers {
+    mode: PruneMode,
+}
+
+impl TokenTransfers {
+    pub const fn new(mode: PruneMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl<Provider> Segment<Provider> for TokenTransfers
+where
+    Provider: DBProvider<Tx: DbTxMut> + TokenTransfersReader,
+{
+    fn segment(&self) -> PruneSegment {
+        PruneSegment::TokenTransfers
+    }
+
+    fn mode(&self) -> Option<PruneMode> {
+        Some(self.mode)
+    }
+
+    fn purpose(&self) -> PrunePurpose {
+        PrunePurpose::User
+    }
+
+    #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
+    fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
+        let (start, end) = match input.get_next_block_range() {
+            Some(range) => range,
+            None => {
+                trace!(target: "pruner", "No token transfers to prune");
+                return Ok(SegmentOutput::done())
+            }
+        }
+        .into_inner();
+
+        if input.limiter.is_limit_reached() {
+            return Ok(SegmentOutput::not_done(
+                input.limiter.interrupt_reason(),
+                input.previous_checkpoint.map(SegmentOutputCheckpoint::from_prune_checkpoint),
+            ))
+        }
+        let range_end = Some(end)
+            .min(input.limiter.deleted_entries_limit_left().map(|left| start + left as u64 - 1))
+            .unwrap();
+        let done = range_end == end;
+
+        // The transfers are found through the transactions and receipts of the blocks in range.
+        // Transfers of blocks whose receipts were already pruned can't be found anymore, so they
+        // are left in place.
+        let entries = provider.token_transfer_entries_with_range(start..=range_end)?;
+        let holder_transfers = entries.holder_transfers().map(|(key, _)| key).collect::<Vec<_>>();
+        let mut pruned =
+            delete_keys::<Provider, tables::HolderTokenTransfers>(provider, holder_transfers)?;
+        pruned += delete_keys::<Provider, tables::TokenBalanceDeltas>(
+            provider,
+            entries.balance_deltas.into_keys(),
+        )?;
+        pruned += delete_keys::<Provider, tables::TokenTransfers>(
+            provider,
+            entries.transfers.into_iter().map(|(key, _)| key),
+        )?;
+        pruned += delete_keys::<Provider, tables::NativeTransfers>(
+            provider,
+            entries.native_transfers.into_iter().map(|(key, _)| key),
+        )?;
+        trace!(target: "pruner", %pruned, %done, "Pruned token transfers");
+
+        let mut limiter = input.limiter;
+        limiter.increment_deleted_entries_count_by((range_end - start + 1) as usize);
+
+        let progress = limiter.progress(done);
+
+        Ok(SegmentOutput {
+            progress,
+            pruned,
+            checkpoint: Some(SegmentOutputCheckpoint {
+                block_number: Some(range_end),
+                tx_number: None,
+            }),
+        })
+    }
+}
+
+/// Deletes the entries of the table with the given keys, returning the number of deleted entries.
+fn delete_keys<Provider, T>(
+    provider: &Provider,
+    keys: impl IntoIterator<Item = T::Key>,
+) -> Result<usize, PrunerError>
+where
+    Provider: DBProvider<Tx: DbTxMut>,
+    T: Table,
+{
+    let mut cursor = provider.tx_ref().cursor_write::<T>()?;
+    let mut deleted = 0;
+    for key in keys {
+        if cursor.seek_exact(key)?.is_some() {
+            cursor.delete_current()?;
+            deleted += 1;
+        }
+    }
+    Ok(deleted)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::segments::{PruneInput, PruneLimiter, Segment, TokenTransfers};
+    use alloy_primitives::{Address, Log, LogData, U256};
+    use reth_db_api::tables;
+    use reth_provider::{
+        DatabaseProviderFactory, HistoryWriter, PruneCheckpointReader, TokenTransfersReader,
+        TRANSFER_EVENT_TOPIC,
+    };
+    use reth_prune_types::{PruneCheckpoint, PruneMode, PruneSegment};
+    use reth_stages::test_utils::{StorageKind, TestStageDB};
+    use reth_testing_utils::generators::{
+        self, random_block_range, random_receipt, BlockRangeParams,
+    };
+
+    #[test]
+    fn prune() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            0..=20,
+            BlockRangeParams { parent: None, tx_count: 1..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Static).expect("insert blocks");
+
+        let mut receipts = Vec::new();
+        let mut tx_num = 0u64;
+        for block in &blocks {
+            let mut block_receipts = Vec::new();
+            for transaction in &block.body().transactions {
+                let mut receipt = random_receipt(&mut rng, transaction, Some(0));
+                let from = Address::with_last_byte(block.number as u8 + 1);
+                receipt.logs.push(Log {
+                    address: Address::with_last_byte(0xff),
+                    data: LogData::new_unchecked(
+                        vec![TRANSFER_EVENT_TOPIC, from.into_word(), Address::ZERO.into_word()],
+                        U256::from(tx_num).to_be_bytes_vec().into(),
+                    ),
+                });
+                block_receipts.push((tx_num, receipt));
+                tx_num += 1;
+            }
+            receipts.push((block.number, block_receipts));
+        }
+        db.insert_receipts_by_block(receipts, StorageKind::Static).expect("insert receipts");
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        let entries = provider.token_transfer_entries_with_range(0..=20).unwrap();
+        assert_eq!(entries.transfers.len() as u64, tx_num);
+        provider.insert_token_transfers(entries).unwrap();
+        provider.commit().expect("commit");
+
+        let to_block = 10;
+        let segment = TokenTransfers::new(PruneMode::Before(to_block + 1));
+        let input = PruneInput {
+            previous_checkpoint: None,
+            to_block,
+            limiter: PruneLimiter::default().set_deleted_entries_limit(usize::MAX),
+        };
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = segment.prune(&provider, input).unwrap();
+        assert!(result.progress.is_finished());
+        segment
+            .save_checkpoint(
+                &provider,
+                result.checkpoint.unwrap().as_prune_checkpoint(PruneMode::Before(to_block + 1)),
+            )
+            .unwrap();
+        provider.commit().expect("commit");
+
+        assert_eq!(
+            db.factory.provider().unwrap().get_prune_checkpoint(PruneSegment::TokenTransfers),
+            Ok(Some(PruneCheckpoint {
+                block_number: Some(to_block),
+                tx_number: None,
+                prune_mode: PruneMode::Before(to_block + 1)
+            }))
+        );
+
+        let transfers = db.table::<tables::TokenTransfers>().unwrap();
+        assert!(!transfers.is_empty());
+        assert!(transfers.iter().all(|(key, _)| key.block_number > to_block));
+        let holder_transfers = db.table::<tables::HolderTokenTransfers>().unwrap();
+        assert_eq!(holder_transfers.len(), transfers.len());
+        assert!(holder_transfers.iter().all(|(key, _)| key.block_number > to_block));
+        assert!(db
+            .table::<tables::TokenBalanceDeltas>()
+            .unwrap()
+            .iter()
+            .all(|(key, _)| key.block_number > to_block));
+    }
+}
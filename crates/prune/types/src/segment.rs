@@ -26,15 +26,37 @@ pub enum PruneSegment {
     Headers,
     /// Prune segment responsible for the `Transactions` table.
     Transactions,
+    /// Prune segment responsible for the `AddressTransactions` table.
+    AddressTransactions,
+    /// Prune segment responsible for the `LogAddressIndex` and `LogTopicIndex` tables.
+    LogIndex,
+    /// Prune segment responsible for the `AccountChangeSets` table, after it was copied to static
+    /// files.
+    AccountChangeSets,
+    /// Prune segment responsible for the `StorageChangeSets` table, after it was copied to static
+    /// files.
+    StorageChangeSets,
+    /// Prune segment responsible for the `HlSidecars` table, after it was copied to static files.
+    HlSidecars,
+    /// Prune segment responsible for the `TokenTransfers`, `HolderTokenTransfers`,
+    /// `TokenBalanceDeltas` and `NativeTransfers` tables.
+    TokenTransfers,
 }
 
 impl PruneSegment {
     /// Returns minimum number of blocks to keep in the database for this segment.
     pub const fn min_blocks(&self, purpose: PrunePurpose) -> u64 {
         match self {
-            Self::SenderRecovery | Self::TransactionLookup | Self::Headers | Self::Transactions => {
-                0
-            }
+            Self::SenderRecovery |
+            Self::TransactionLookup |
+            Self::Headers |
+            Self::Transactions |
+            Self::AddressTransactions |
+            Self::LogIndex |
+            Self::AccountChangeSets |
+            Self::StorageChangeSets |
+            Self::HlSidecars |
+            Self::TokenTransfers => 0,
             Self::Receipts if purpose.is_static_file() => 0,
             Self::ContractLogs | Self::AccountHistory | Self::StorageHistory => {
                 MINIMUM_PRUNING_DISTANCE
@@ -46,6 +46,15 @@ pub struct PruneModes {
         )
     )]
     pub storage_history: Option<PruneMode>,
+    /// Address transactions index pruning configuration.
+    #[cfg_attr(any(test, feature = "serde"), serde(skip_serializing_if = "Option::is_none"))]
+    pub address_transactions: Option<PruneMode>,
+    /// Log index pruning configuration.
+    #[cfg_attr(any(test, feature = "serde"), serde(skip_serializing_if = "Option::is_none"))]
+    pub log_index: Option<PruneMode>,
+    /// Token transfers pruning configuration.
+    #[cfg_attr(any(test, feature = "serde"), serde(skip_serializing_if = "Option::is_none"))]
+    pub token_transfers: Option<PruneMode>,
     /// Receipts pruning configuration by retaining only those receipts that contain logs emitted
     /// by the specified addresses, discarding others. This setting is overridden by `receipts`.
     ///
@@ -68,6 +77,9 @@ impl PruneModes {
             receipts: Some(PruneMode::Full),
             account_history: Some(PruneMode::Full),
             storage_history: Some(PruneMode::Full),
+            address_transactions: Some(PruneMode::Full),
+            log_index: Some(PruneMode::Full),
+            token_transfers: Some(PruneMode::Full),
             receipts_log_filter: Default::default(),
         }
     }
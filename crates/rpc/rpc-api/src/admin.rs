@@ -1,6 +1,7 @@
 use alloy_rpc_types_admin::{NodeInfo, PeerInfo};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use reth_network_peers::{AnyNode, NodeRecord};
+use reth_rpc_server_types::SlowQuery;
 
 /// Admin namespace rpc interface that gives access to several non-standard RPC methods.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "admin"))]
@@ -46,3 +47,12 @@ pub trait AdminApi {
     #[method(name = "nodeInfo")]
     async fn node_info(&self) -> RpcResult<NodeInfo>;
 }
+
+/// Admin namespace rpc interface for the slow query log of the RPC server.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "admin"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "admin"))]
+pub trait SlowQueryApi {
+    /// Returns the most recent calls that exceeded the slow query thresholds, most recent first.
+    #[method(name = "slowQueries")]
+    fn slow_queries(&self) -> RpcResult<Vec<SlowQuery>>;
+}
@@ -0,0 +1,60 @@
+use alloy_primitives::Address;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_rpc_server_types::{
+    NativeTransfersPage, TokenBalanceDelta, TokenTransfersPage, TransferCursor,
+};
+
+/// Hyperliquid rpc interface, serving the token transfer indices of the node.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "hl"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "hl"))]
+pub trait HlApi {
+    /// Returns a page of the ERC20 token transfers sent or received by the address in the
+    /// inclusive block range, optionally only of the given token.
+    ///
+    /// The page starts at the cursor if given, which is returned by the previous page.
+    #[method(name = "getTokenTransfers")]
+    async fn get_token_transfers(
+        &self,
+        address: Address,
+        token: Option<Address>,
+        from_block: u64,
+        to_block: u64,
+        cursor: Option<TransferCursor>,
+    ) -> RpcResult<TokenTransfersPage>;
+
+    /// Returns a page of the transfers of the ERC20 token in the inclusive block range.
+    ///
+    /// The page starts at the cursor if given, which is returned by the previous page.
+    #[method(name = "getTokenTransfersByToken")]
+    async fn get_token_transfers_by_token(
+        &self,
+        token: Address,
+        from_block: u64,
+        to_block: u64,
+        cursor: Option<TransferCursor>,
+    ) -> RpcResult<TokenTransfersPage>;
+
+    /// Returns the tokens received and sent by the address in each block of the inclusive block
+    /// range in which its balance of the token changed.
+    #[method(name = "getTokenBalanceDeltas")]
+    async fn get_token_balance_deltas(
+        &self,
+        address: Address,
+        token: Address,
+        from_block: u64,
+        to_block: u64,
+    ) -> RpcResult<Vec<TokenBalanceDelta>>;
+
+    /// Returns a page of the native token deposits from HyperCore to the address and withdrawals
+    /// by the address to HyperCore in the inclusive block range.
+    ///
+    /// The page starts at the cursor if given, which is returned by the previous page.
+    #[method(name = "getNativeTransfers")]
+    async fn get_native_transfers(
+        &self,
+        address: Address,
+        from_block: u64,
+        to_block: u64,
+        cursor: Option<TransferCursor>,
+    ) -> RpcResult<NativeTransfersPage>;
+}
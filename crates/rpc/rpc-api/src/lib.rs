@@ -20,6 +20,7 @@ mod debug;
 mod engine;
 mod ganache;
 mod hardhat;
+mod hl;
 mod mev;
 mod miner;
 mod net;
@@ -37,9 +38,10 @@ pub use servers::*;
 /// Aggregates all server traits.
 pub mod servers {
     pub use crate::{
-        admin::AdminApiServer,
+        admin::{AdminApiServer, SlowQueryApiServer},
         debug::{DebugApiServer, DebugExecutionWitnessApiServer},
         engine::{EngineApiServer, EngineEthApiServer, IntoEngineApiRpcModule},
+        hl::HlApiServer,
         mev::{MevFullApiServer, MevSimApiServer},
         miner::MinerApiServer,
         net::NetApiServer,
@@ -65,12 +67,13 @@ pub use clients::*;
 #[cfg(feature = "client")]
 pub mod clients {
     pub use crate::{
-        admin::AdminApiClient,
+        admin::{AdminApiClient, SlowQueryApiClient},
         anvil::AnvilApiClient,
         debug::{DebugApiClient, DebugExecutionWitnessApiClient},
         engine::{EngineApiClient, EngineEthApiClient},
         ganache::GanacheApiClient,
         hardhat::HardhatApiClient,
+        hl::HlApiClient,
         mev::{MevFullApiClient, MevSimApiClient},
         miner::MinerApiClient,
         net::NetApiClient,
@@ -2,10 +2,11 @@ use alloy_eips::BlockId;
 use alloy_json_rpc::RpcObject;
 use alloy_primitives::{Address, Bytes, TxHash, B256};
 use alloy_rpc_types_trace::otterscan::{
-    BlockDetails, ContractCreator, InternalOperation, OtsBlockTransactions, TraceEntry,
-    TransactionsWithReceipts,
+    BlockDetails, ContractCreator, InternalOperation, OtsBlockTransactions, OtsTransactionReceipt,
+    TraceEntry,
 };
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_rpc_server_types::{ContractDeployment, TransactionsWithReceipts};
 
 /// Otterscan rpc interface.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "ots"))]
@@ -69,7 +70,7 @@ pub trait Otterscan<T: RpcObject, H: RpcObject> {
         address: Address,
         block_number: u64,
         page_size: usize,
-    ) -> RpcResult<TransactionsWithReceipts>;
+    ) -> RpcResult<TransactionsWithReceipts<T, OtsTransactionReceipt>>;
 
     /// Gets paginated inbound/outbound transaction calls for a certain address.
     #[method(name = "searchTransactionsAfter")]
@@ -78,7 +79,7 @@ pub trait Otterscan<T: RpcObject, H: RpcObject> {
         address: Address,
         block_number: u64,
         page_size: usize,
-    ) -> RpcResult<TransactionsWithReceipts>;
+    ) -> RpcResult<TransactionsWithReceipts<T, OtsTransactionReceipt>>;
 
     /// Gets the transaction hash for a certain sender address, given its nonce.
     #[method(name = "getTransactionBySenderAndNonce")]
@@ -91,4 +92,13 @@ pub trait Otterscan<T: RpcObject, H: RpcObject> {
     /// Gets the transaction hash and the address who created a contract.
     #[method(name = "getContractCreator")]
     async fn get_contract_creator(&self, address: Address) -> RpcResult<Option<ContractCreator>>;
+
+    /// Gets the contracts deployed in the given inclusive block range, in the order they were
+    /// deployed.
+    #[method(name = "getContractsDeployed")]
+    async fn get_contracts_deployed(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> RpcResult<Vec<ContractDeployment>>;
 }
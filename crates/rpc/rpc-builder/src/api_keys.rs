@@ -0,0 +1,847 @@
+//! API key authentication and per-key quotas for the public HTTP and WS servers.
+//!
+//! Keys are configured in a TOML file that is reloaded whenever it changes:
+//!
+//! ```toml
+//! # compute units charged for methods without an entry in `weights`
+//! default_weight = 1
+//!
+//! [weights]
+//! eth_call = 5
+//! eth_getLogs = 20
+//! # entries ending with `_` apply to the entire namespace
+//! debug_ = 100
+//!
+//! [[keys]]
+//! name = "alice"
+//! key = "3b0cfe4b21b9"
+//! requests_per_second = 100
+//! compute_units_per_second = 1000
+//! allowed_methods = ["eth_", "net_", "web3_"]
+//! denied_methods = ["eth_sendRawTransaction"]
+//! ```
+//!
+//! Clients pass their key either in the [`API_KEY_HEADER`] header or as the first segment of the
+//! URL path, e.g. `http://localhost:8545/3b0cfe4b21b9` or
+//! `http://localhost:8545/3b0cfe4b21b9/graphql`. Requests without a known key are rejected with
+//! `401 Unauthorized` by the [`ApiKeyAuthLayer`], every call of an authenticated request is then
+//! checked against the key's method lists and quotas by the [`ApiKeyQuotaLayer`]. GraphQL
+//! requests are checked as calls of `graphql_query`.
+
+use http::{StatusCode, Uri};
+use jsonrpsee::{
+    server::{middleware::rpc::RpcServiceT, HttpBody, HttpRequest, HttpResponse},
+    types::{ErrorObject, ErrorObjectOwned, Request},
+    MethodResponse,
+};
+use parking_lot::{Mutex, RwLock};
+use pin_project::pin_project;
+use reth_metrics::{metrics::Counter, Metrics};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    io,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, Weak},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+use tower::{Layer, Service};
+use tracing::{info, warn};
+
+/// The HTTP header clients can use to pass their API key.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// How often the API keys file is checked for changes.
+const RELOAD_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Error code returned for calls made with an API key that is no longer configured.
+const INVALID_API_KEY_CODE: i32 = -32000;
+
+/// Error code returned for methods the API key is not allowed to call, see EIP-1474.
+const METHOD_NOT_SUPPORTED_CODE: i32 = -32004;
+
+/// Error code returned when the API key exceeded its quota, see EIP-1474.
+const LIMIT_EXCEEDED_CODE: i32 = -32005;
+
+/// Errors that can occur when loading the API keys file.
+#[derive(Debug, thiserror::Error)]
+pub enum ApiKeysError {
+    /// Failed to read the API keys file.
+    #[error("failed to read API keys file {}: {error}", path.display())]
+    Read {
+        /// Path of the API keys file.
+        path: PathBuf,
+        /// IO error.
+        error: io::Error,
+    },
+    /// Failed to parse the API keys file.
+    #[error("failed to parse API keys file {}: {error}", path.display())]
+    Parse {
+        /// Path of the API keys file.
+        path: PathBuf,
+        /// TOML error.
+        error: toml::de::Error,
+    },
+    /// An API key is empty.
+    #[error("API key `{0}` is empty")]
+    EmptyKey(String),
+    /// The same name is used by multiple API keys.
+    #[error("API key name `{0}` is used more than once")]
+    DuplicateName(String),
+    /// The same key is configured multiple times.
+    #[error("API key `{0}` is configured more than once")]
+    DuplicateKey(String),
+}
+
+/// The content of the API keys file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ApiKeysConfig {
+    /// Compute units charged for methods without an entry in [`Self::weights`].
+    pub default_weight: u64,
+    /// Compute units charged per method.
+    ///
+    /// Entries ending with `_` apply to all methods of that namespace, e.g. `debug_`.
+    pub weights: HashMap<String, u64>,
+    /// The configured API keys.
+    pub keys: Vec<ApiKeyConfig>,
+}
+
+impl Default for ApiKeysConfig {
+    fn default() -> Self {
+        Self { default_weight: 1, weights: HashMap::new(), keys: Vec::new() }
+    }
+}
+
+impl ApiKeysConfig {
+    /// Reads and validates the API keys file at the given path.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, ApiKeysError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| ApiKeysError::Read { path: path.to_path_buf(), error })?;
+        let config: Self = toml::from_str(&content)
+            .map_err(|error| ApiKeysError::Parse { path: path.to_path_buf(), error })?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Ensures that all keys are non-empty and that names and keys are unique.
+    pub fn validate(&self) -> Result<(), ApiKeysError> {
+        let mut names = HashSet::new();
+        let mut keys = HashSet::new();
+        for key in &self.keys {
+            if key.key.is_empty() {
+                return Err(ApiKeysError::EmptyKey(key.name.clone()))
+            }
+            if !names.insert(key.name.as_str()) {
+                return Err(ApiKeysError::DuplicateName(key.name.clone()))
+            }
+            if !keys.insert(key.key.as_str()) {
+                return Err(ApiKeysError::DuplicateKey(key.name.clone()))
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Configuration of a single API key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyConfig {
+    /// Name of the key, used as the metrics label instead of the key itself.
+    pub name: String,
+    /// The secret clients authenticate with.
+    pub key: String,
+    /// Maximum number of calls per second, unlimited if not set.
+    #[serde(default)]
+    pub requests_per_second: Option<u64>,
+    /// Maximum number of compute units per second, unlimited if not set.
+    ///
+    /// Calls weighing more than this are always rejected.
+    #[serde(default)]
+    pub compute_units_per_second: Option<u64>,
+    /// Methods or namespaces (e.g. `eth_`) this key may call, all methods if empty.
+    #[serde(default)]
+    pub allowed_methods: Vec<String>,
+    /// Methods or namespaces this key may not call, takes precedence over `allowed_methods`.
+    #[serde(default)]
+    pub denied_methods: Vec<String>,
+}
+
+/// The API keys shared by the HTTP and RPC middleware of all servers.
+#[derive(Debug, Clone)]
+pub struct ApiKeys {
+    inner: Arc<ApiKeysInner>,
+}
+
+impl ApiKeys {
+    /// Creates a new set of API keys from the given config.
+    pub fn new(config: ApiKeysConfig) -> Self {
+        Self::with_path(config, None)
+    }
+
+    /// Loads the API keys from the given file.
+    ///
+    /// See also [`Self::spawn_reload_task`].
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, ApiKeysError> {
+        let path = path.as_ref();
+        let config = ApiKeysConfig::from_path(path)?;
+        Ok(Self::with_path(config, Some(path.to_path_buf())))
+    }
+
+    fn with_path(config: ApiKeysConfig, path: Option<PathBuf>) -> Self {
+        let inner =
+            ApiKeysInner { path, state: Default::default(), metrics: ApiKeysMetrics::default() };
+        inner.apply(config);
+        Self { inner: Arc::new(inner) }
+    }
+
+    /// Replaces the configured keys and weights.
+    ///
+    /// Keys that remain configured keep their current quota usage.
+    pub fn update(&self, config: ApiKeysConfig) {
+        self.inner.apply(config);
+    }
+
+    /// Returns true if the given key is configured.
+    pub fn contains(&self, key: &str) -> bool {
+        self.inner.state.read().keys.contains_key(key)
+    }
+
+    /// Spawns a task that reloads the API keys file whenever it is modified.
+    ///
+    /// The task exits once all clones of this instance are dropped. Does nothing if the keys were
+    /// not loaded from a file.
+    pub fn spawn_reload_task(&self) {
+        let Some(path) = self.inner.path.clone() else { return };
+        let inner = Arc::downgrade(&self.inner);
+        tokio::spawn(reload_on_change(inner, path));
+    }
+
+    /// Returns the HTTP middleware that authenticates requests.
+    pub fn auth_layer(&self) -> ApiKeyAuthLayer {
+        ApiKeyAuthLayer { api_keys: self.clone() }
+    }
+
+    /// Returns the RPC middleware that enforces the method lists and quotas of the keys.
+    pub fn quota_layer(&self) -> ApiKeyQuotaLayer {
+        ApiKeyQuotaLayer { api_keys: Some(self.clone()) }
+    }
+
+    /// Checks whether the key may call the given method and charges its quota.
+    fn check(&self, key: &str, method: &str) -> Result<(), ErrorObjectOwned> {
+        let state = self.inner.state.read();
+        let Some(api_key) = state.keys.get(key) else {
+            return Err(ErrorObject::owned(INVALID_API_KEY_CODE, "invalid API key", None::<()>))
+        };
+
+        if !api_key.is_method_allowed(method) {
+            api_key.metrics.denied_total.increment(1);
+            return Err(ErrorObject::owned(
+                METHOD_NOT_SUPPORTED_CODE,
+                format!("method {method} is not available for this API key"),
+                None::<()>,
+            ))
+        }
+
+        let weight = state.weights.weight(method);
+        if !api_key.quota.lock().try_acquire(weight, Instant::now()) {
+            api_key.metrics.rate_limited_total.increment(1);
+            return Err(ErrorObject::owned(
+                LIMIT_EXCEEDED_CODE,
+                "API key quota exceeded",
+                None::<()>,
+            ))
+        }
+
+        api_key.metrics.requests_total.increment(1);
+        api_key.metrics.compute_units_total.increment(weight);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct ApiKeysInner {
+    /// The file the keys were loaded from, if any.
+    path: Option<PathBuf>,
+    /// The currently configured keys.
+    state: RwLock<ApiKeysState>,
+    /// Metrics that are not specific to a key.
+    metrics: ApiKeysMetrics,
+}
+
+impl ApiKeysInner {
+    fn apply(&self, config: ApiKeysConfig) {
+        let now = Instant::now();
+        let mut state = self.state.write();
+        let keys = config
+            .keys
+            .into_iter()
+            .map(|config| {
+                let mut quota = Quota::new(&config, now);
+                if let Some(existing) = state.keys.get(&config.key) {
+                    quota.carry_over(&existing.quota.lock(), now);
+                }
+                let api_key = ApiKey {
+                    metrics: ApiKeyMetrics::new_with_labels(&[("key", config.name.clone())]),
+                    allowed_methods: config.allowed_methods,
+                    denied_methods: config.denied_methods,
+                    quota: Mutex::new(quota),
+                };
+                (config.key, Arc::new(api_key))
+            })
+            .collect();
+
+        *state = ApiKeysState {
+            keys,
+            weights: MethodWeights { default: config.default_weight, weights: config.weights },
+        };
+    }
+}
+
+/// Polls the modification time of the API keys file and reloads it on change.
+async fn reload_on_change(inner: Weak<ApiKeysInner>, path: PathBuf) {
+    let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+
+    let mut last_modified = modified(&path);
+    let mut interval = tokio::time::interval(RELOAD_INTERVAL);
+    loop {
+        interval.tick().await;
+        let Some(inner) = inner.upgrade() else { break };
+
+        let current = modified(&path);
+        if current == last_modified {
+            continue
+        }
+        last_modified = current;
+
+        match ApiKeysConfig::from_path(&path) {
+            Ok(config) => {
+                let keys = config.keys.len();
+                inner.apply(config);
+                inner.metrics.reloads_total.increment(1);
+                info!(target: "rpc::api_keys", ?path, keys, "Reloaded API keys");
+            }
+            Err(err) => {
+                inner.metrics.reload_failures_total.increment(1);
+                warn!(target: "rpc::api_keys", %err, "Failed to reload API keys, keeping the previous keys");
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct ApiKeysState {
+    /// API keys by their secret.
+    keys: HashMap<String, Arc<ApiKey>>,
+    /// Compute units per method.
+    weights: MethodWeights,
+}
+
+#[derive(Debug)]
+struct ApiKey {
+    /// Methods the key may call, all if empty.
+    allowed_methods: Vec<String>,
+    /// Methods the key may not call.
+    denied_methods: Vec<String>,
+    /// Remaining quota of the key.
+    quota: Mutex<Quota>,
+    /// Usage metrics of the key.
+    metrics: ApiKeyMetrics,
+}
+
+impl ApiKey {
+    fn is_method_allowed(&self, method: &str) -> bool {
+        (self.allowed_methods.is_empty() || matches_method(&self.allowed_methods, method)) &&
+            !matches_method(&self.denied_methods, method)
+    }
+}
+
+/// Returns true if any of the patterns is either the method itself or its namespace, e.g. `eth_`.
+fn matches_method(patterns: &[String], method: &str) -> bool {
+    patterns.iter().any(|pattern| {
+        pattern == method || (pattern.ends_with('_') && method.starts_with(pattern.as_str()))
+    })
+}
+
+#[derive(Debug, Default)]
+struct MethodWeights {
+    /// Weight of methods without an entry.
+    default: u64,
+    /// Weights by method or namespace.
+    weights: HashMap<String, u64>,
+}
+
+impl MethodWeights {
+    /// Returns the weight of the method, falling back to the weight of its namespace.
+    fn weight(&self, method: &str) -> u64 {
+        if let Some(weight) = self.weights.get(method) {
+            return *weight
+        }
+        method
+            .find('_')
+            .and_then(|idx| self.weights.get(&method[..=idx]))
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+/// The request and compute unit quotas of a key.
+#[derive(Debug)]
+struct Quota {
+    requests: Option<TokenBucket>,
+    compute_units: Option<TokenBucket>,
+}
+
+impl Quota {
+    fn new(config: &ApiKeyConfig, now: Instant) -> Self {
+        Self {
+            requests: config.requests_per_second.map(|rate| TokenBucket::new(rate, now)),
+            compute_units: config.compute_units_per_second.map(|rate| TokenBucket::new(rate, now)),
+        }
+    }
+
+    /// Takes over the usage of a previous quota of the same key.
+    fn carry_over(&mut self, previous: &Self, now: Instant) {
+        if let (Some(bucket), Some(previous)) = (&mut self.requests, &previous.requests) {
+            bucket.carry_over(previous, now);
+        }
+        if let (Some(bucket), Some(previous)) = (&mut self.compute_units, &previous.compute_units) {
+            bucket.carry_over(previous, now);
+        }
+    }
+
+    /// Charges one request of the given weight, returns false if either quota is exhausted.
+    fn try_acquire(&mut self, weight: u64, now: Instant) -> bool {
+        let requests_available = self.requests.as_mut().is_none_or(|b| b.refill(now) >= 1.0);
+        let compute_units_available =
+            self.compute_units.as_mut().is_none_or(|b| b.refill(now) >= weight as f64);
+        if !requests_available || !compute_units_available {
+            return false
+        }
+
+        if let Some(bucket) = &mut self.requests {
+            bucket.available -= 1.0;
+        }
+        if let Some(bucket) = &mut self.compute_units {
+            bucket.available -= weight as f64;
+        }
+        true
+    }
+}
+
+/// A token bucket that refills at a constant rate and holds at most one second worth of tokens.
+#[derive(Debug, Clone)]
+struct TokenBucket {
+    /// Tokens added per second.
+    rate: f64,
+    /// Currently available tokens.
+    available: f64,
+    /// Last time the bucket was refilled.
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: u64, now: Instant) -> Self {
+        Self { rate: rate as f64, available: rate as f64, updated_at: now }
+    }
+
+    /// Adds the tokens accumulated since the last refill and returns the available tokens.
+    fn refill(&mut self, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.available = (self.available + elapsed * self.rate).min(self.rate);
+        self.updated_at = now;
+        self.available
+    }
+
+    fn carry_over(&mut self, previous: &Self, now: Instant) {
+        let mut previous = previous.clone();
+        self.available = previous.refill(now).min(self.rate);
+    }
+}
+
+/// The authenticated API key of a request, stored in the request extensions.
+#[derive(Debug, Clone)]
+struct AuthenticatedApiKey(Arc<str>);
+
+/// HTTP middleware that rejects requests without a configured API key.
+///
+/// The key is read from the [`API_KEY_HEADER`] header or the first segment of the URL path. Keys
+/// passed in the path are stripped from the request before it is forwarded.
+#[derive(Debug, Clone)]
+pub struct ApiKeyAuthLayer {
+    api_keys: ApiKeys,
+}
+
+impl<S> Layer<S> for ApiKeyAuthLayer {
+    type Service = ApiKeyAuthService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ApiKeyAuthService { api_keys: self.api_keys.clone(), inner }
+    }
+}
+
+/// The [`Service`] created by the [`ApiKeyAuthLayer`].
+#[derive(Debug, Clone)]
+pub struct ApiKeyAuthService<S> {
+    api_keys: ApiKeys,
+    inner: S,
+}
+
+impl<S> Service<HttpRequest> for ApiKeyAuthService<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse>,
+{
+    type Response = HttpResponse;
+    type Error = S::Error;
+    type Future = ApiKeyAuthFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: HttpRequest) -> Self::Future {
+        let Some(key) = take_api_key(&mut req) else {
+            self.api_keys.inner.metrics.unauthorized_total.increment(1);
+            return ApiKeyAuthFuture::unauthorized("missing API key")
+        };
+        if !self.api_keys.contains(&key) {
+            self.api_keys.inner.metrics.unauthorized_total.increment(1);
+            return ApiKeyAuthFuture::unauthorized("invalid API key")
+        }
+
+        req.extensions_mut().insert(AuthenticatedApiKey(key.into()));
+        ApiKeyAuthFuture::future(self.inner.call(req))
+    }
+}
+
+/// Returns the API key of the request.
+///
+/// If the key is passed as the first segment of the URL path, it is removed from the path, e.g.
+/// `/<key>/graphql` becomes `/graphql`.
+fn take_api_key(req: &mut HttpRequest) -> Option<String> {
+    if let Some(key) = req.headers().get(API_KEY_HEADER) {
+        return key.to_str().ok().map(str::to_string)
+    }
+
+    let path = req.uri().path().trim_start_matches('/');
+    let (key, rest) = path.split_once('/').unwrap_or((path, ""));
+    if key.is_empty() {
+        return None
+    }
+    let key = key.to_string();
+    let rest = rest.trim_end_matches('/');
+
+    let mut parts = req.uri().clone().into_parts();
+    let path_and_query = match req.uri().query() {
+        Some(query) => format!("/{rest}?{query}").parse().ok()?,
+        None => format!("/{rest}").parse().ok()?,
+    };
+    parts.path_and_query = Some(path_and_query);
+    *req.uri_mut() = Uri::from_parts(parts).ok()?;
+
+    Some(key)
+}
+
+/// Response future of the [`ApiKeyAuthService`].
+#[pin_project]
+#[allow(missing_debug_implementations)]
+pub struct ApiKeyAuthFuture<F> {
+    #[pin]
+    kind: AuthFutureKind<F>,
+}
+
+impl<F> ApiKeyAuthFuture<F> {
+    const fn future(future: F) -> Self {
+        Self { kind: AuthFutureKind::Future { future } }
+    }
+
+    fn unauthorized(reason: &'static str) -> Self {
+        let response = http::Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(HttpBody::new(reason.to_string()))
+            .expect("valid response");
+        Self { kind: AuthFutureKind::Unauthorized { response: Some(response) } }
+    }
+}
+
+#[pin_project(project = AuthFutureKindProj)]
+enum AuthFutureKind<F> {
+    Future {
+        #[pin]
+        future: F,
+    },
+    Unauthorized {
+        response: Option<HttpResponse>,
+    },
+}
+
+impl<F, E> Future for ApiKeyAuthFuture<F>
+where
+    F: Future<Output = Result<HttpResponse, E>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project().kind.project() {
+            AuthFutureKindProj::Future { future } => future.poll(cx),
+            AuthFutureKindProj::Unauthorized { response } => {
+                Poll::Ready(Ok(response.take().expect("polled after completion")))
+            }
+        }
+    }
+}
+
+/// RPC middleware that enforces the method lists and quotas of the authenticated API key.
+///
+/// This is a no-op if no API keys are configured.
+#[derive(Debug, Clone, Default)]
+pub struct ApiKeyQuotaLayer {
+    api_keys: Option<ApiKeys>,
+}
+
+impl<S> Layer<S> for ApiKeyQuotaLayer {
+    type Service = ApiKeyQuotaService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ApiKeyQuotaService { api_keys: self.api_keys.clone(), inner }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that charges every call to the quota of its API key.
+#[derive(Debug, Clone)]
+pub struct ApiKeyQuotaService<S> {
+    api_keys: Option<ApiKeys>,
+    inner: S,
+}
+
+impl<'a, S> RpcServiceT<'a> for ApiKeyQuotaService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = ApiKeyQuotaFuture<S::Future>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        let Some(api_keys) = &self.api_keys else {
+            return ApiKeyQuotaFuture::Future { future: self.inner.call(req) }
+        };
+
+        let res = match req.extensions().get::<AuthenticatedApiKey>() {
+            Some(key) => api_keys.check(&key.0, req.method_name()),
+            None => Err(ErrorObject::owned(INVALID_API_KEY_CODE, "missing API key", None::<()>)),
+        };
+        match res {
+            Ok(()) => ApiKeyQuotaFuture::Future { future: self.inner.call(req) },
+            Err(err) => {
+                ApiKeyQuotaFuture::Rejected { response: Some(MethodResponse::error(req.id, err)) }
+            }
+        }
+    }
+}
+
+/// Response future of the [`ApiKeyQuotaService`].
+#[pin_project(project = ApiKeyQuotaFutureProj)]
+pub enum ApiKeyQuotaFuture<F> {
+    /// The call was admitted.
+    Future {
+        /// The inner call.
+        #[pin]
+        future: F,
+    },
+    /// The call was rejected.
+    Rejected {
+        /// The error response.
+        response: Option<MethodResponse>,
+    },
+}
+
+impl<F> std::fmt::Debug for ApiKeyQuotaFuture<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ApiKeyQuotaFuture")
+    }
+}
+
+impl<F: Future<Output = MethodResponse>> Future for ApiKeyQuotaFuture<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project() {
+            ApiKeyQuotaFutureProj::Future { future } => future.poll(cx),
+            ApiKeyQuotaFutureProj::Rejected { response } => {
+                Poll::Ready(response.take().expect("polled after completion"))
+            }
+        }
+    }
+}
+
+/// Metrics of the API key middleware.
+#[derive(Metrics)]
+#[metrics(scope = "rpc_server.api_keys")]
+struct ApiKeysMetrics {
+    /// The number of requests rejected for a missing or unknown API key
+    unauthorized_total: Counter,
+    /// The number of times the API keys file was reloaded
+    reloads_total: Counter,
+    /// The number of times reloading the API keys file failed
+    reload_failures_total: Counter,
+}
+
+/// Usage metrics of a single API key.
+#[derive(Metrics)]
+#[metrics(scope = "rpc_server.api_key")]
+struct ApiKeyMetrics {
+    /// The number of admitted calls
+    requests_total: Counter,
+    /// The number of compute units charged
+    compute_units_total: Counter,
+    /// The number of calls rejected because the quota was exceeded
+    rate_limited_total: Counter,
+    /// The number of calls rejected because the method is not allowed
+    denied_total: Counter,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+default_weight = 2
+
+[weights]
+eth_call = 5
+debug_ = 100
+
+[[keys]]
+name = "alice"
+key = "alice-key"
+requests_per_second = 2
+
+[[keys]]
+name = "bob"
+key = "bob-key"
+compute_units_per_second = 10
+allowed_methods = ["eth_"]
+denied_methods = ["eth_sendRawTransaction"]
+"#;
+
+    fn api_keys() -> ApiKeys {
+        let config: ApiKeysConfig = toml::from_str(CONFIG).unwrap();
+        config.validate().unwrap();
+        ApiKeys::new(config)
+    }
+
+    #[test]
+    fn parse_config() {
+        let config: ApiKeysConfig = toml::from_str(CONFIG).unwrap();
+        assert_eq!(config.default_weight, 2);
+        assert_eq!(config.weights.get("debug_"), Some(&100));
+        assert_eq!(config.keys.len(), 2);
+        assert_eq!(config.keys[0].requests_per_second, Some(2));
+        assert_eq!(config.keys[0].compute_units_per_second, None);
+        assert!(config.keys[0].allowed_methods.is_empty());
+
+        let empty: ApiKeysConfig = toml::from_str("").unwrap();
+        assert_eq!(empty, ApiKeysConfig::default());
+    }
+
+    #[test]
+    fn reject_duplicate_keys() {
+        let mut config: ApiKeysConfig = toml::from_str(CONFIG).unwrap();
+        config.keys[1].key = config.keys[0].key.clone();
+        assert!(matches!(config.validate(), Err(ApiKeysError::DuplicateKey(_))));
+    }
+
+    #[test]
+    fn method_weights() {
+        let config: ApiKeysConfig = toml::from_str(CONFIG).unwrap();
+        let weights = MethodWeights { default: config.default_weight, weights: config.weights };
+        assert_eq!(weights.weight("eth_call"), 5);
+        assert_eq!(weights.weight("debug_traceTransaction"), 100);
+        assert_eq!(weights.weight("eth_blockNumber"), 2);
+    }
+
+    #[test]
+    fn method_lists() {
+        let api_keys = api_keys();
+        assert!(api_keys.check("bob-key", "eth_blockNumber").is_ok());
+        assert_eq!(
+            api_keys.check("bob-key", "debug_traceTransaction").unwrap_err().code(),
+            METHOD_NOT_SUPPORTED_CODE
+        );
+        assert_eq!(
+            api_keys.check("bob-key", "eth_sendRawTransaction").unwrap_err().code(),
+            METHOD_NOT_SUPPORTED_CODE
+        );
+        assert_eq!(
+            api_keys.check("unknown", "eth_blockNumber").unwrap_err().code(),
+            INVALID_API_KEY_CODE
+        );
+    }
+
+    #[test]
+    fn quotas() {
+        let api_keys = api_keys();
+        assert!(api_keys.check("alice-key", "eth_blockNumber").is_ok());
+        assert!(api_keys.check("alice-key", "debug_traceTransaction").is_ok());
+        assert_eq!(
+            api_keys.check("alice-key", "eth_blockNumber").unwrap_err().code(),
+            LIMIT_EXCEEDED_CODE
+        );
+
+        // 5 + 2 compute units fit, another 5 don't
+        assert!(api_keys.check("bob-key", "eth_call").is_ok());
+        assert!(api_keys.check("bob-key", "eth_blockNumber").is_ok());
+        assert_eq!(api_keys.check("bob-key", "eth_call").unwrap_err().code(), LIMIT_EXCEEDED_CODE);
+    }
+
+    #[test]
+    fn token_bucket_refill() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(10, now);
+        bucket.available = 0.0;
+        assert_eq!(bucket.refill(now + Duration::from_millis(500)), 5.0);
+        assert_eq!(bucket.refill(now + Duration::from_secs(10)), 10.0);
+    }
+
+    #[test]
+    fn update_keeps_usage() {
+        let api_keys = api_keys();
+        assert!(api_keys.check("alice-key", "eth_blockNumber").is_ok());
+        assert!(api_keys.check("alice-key", "eth_blockNumber").is_ok());
+
+        let mut config: ApiKeysConfig = toml::from_str(CONFIG).unwrap();
+        config.keys.remove(1);
+        api_keys.update(config);
+        assert!(!api_keys.contains("bob-key"));
+        assert_eq!(
+            api_keys.check("alice-key", "eth_blockNumber").unwrap_err().code(),
+            LIMIT_EXCEEDED_CODE
+        );
+    }
+
+    #[test]
+    fn api_key_from_path() {
+        let mut req =
+            http::Request::builder().uri("/alice-key?foo=bar").body(HttpBody::empty()).unwrap();
+        assert_eq!(take_api_key(&mut req).as_deref(), Some("alice-key"));
+        assert_eq!(req.uri(), "/?foo=bar");
+
+        let mut req = http::Request::builder()
+            .uri("/")
+            .header(API_KEY_HEADER, "bob-key")
+            .body(HttpBody::empty())
+            .unwrap();
+        assert_eq!(take_api_key(&mut req).as_deref(), Some("bob-key"));
+
+        let mut req =
+            http::Request::builder().uri("/alice-key/graphql").body(HttpBody::empty()).unwrap();
+        assert_eq!(take_api_key(&mut req).as_deref(), Some("alice-key"));
+        assert_eq!(req.uri(), "/graphql");
+
+        let mut req = http::Request::builder().uri("/").body(HttpBody::empty()).unwrap();
+        assert_eq!(take_api_key(&mut req), None);
+    }
+}
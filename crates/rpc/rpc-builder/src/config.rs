@@ -3,15 +3,16 @@ use std::{net::SocketAddr, path::PathBuf};
 use jsonrpsee::server::ServerBuilder;
 use reth_node_core::{args::RpcServerArgs, utils::get_or_create_jwt_secret_from_path};
 use reth_rpc::ValidationApiConfig;
-use reth_rpc_eth_types::{EthConfig, EthStateCacheConfig, GasPriceOracleConfig};
+use reth_rpc_eth_types::{EthConfig, EthStateCacheConfig, GasPriceOracleConfig, TraceCacheConfig};
+use reth_rpc_graphql::GraphQlConfig;
 use reth_rpc_layer::{JwtError, JwtSecret};
 use reth_rpc_server_types::RpcModuleSelection;
 use tower::layer::util::Identity;
 use tracing::{debug, warn};
 
 use crate::{
-    auth::AuthServerConfig, error::RpcError, IpcServerBuilder, RpcModuleConfig, RpcServerConfig,
-    TransportRpcModuleConfig,
+    auth::AuthServerConfig, error::RpcError, slow_query::SlowQueryConfig, IpcServerBuilder,
+    RpcModuleConfig, RpcServerConfig, TransportRpcModuleConfig,
 };
 
 /// A trait that provides a configured RPC server.
@@ -43,6 +44,12 @@ pub trait RethRpcServerConfig {
     /// Extracts the gas price oracle config from the args.
     fn gas_price_oracle_config(&self) -> GasPriceOracleConfig;
 
+    /// Returns the trace cache configuration, if enabled.
+    fn trace_cache_config(&self) -> Option<TraceCacheConfig>;
+
+    /// Returns the GraphQL configuration, if enabled.
+    fn graphql_config(&self) -> Option<GraphQlConfig>;
+
     /// Creates the [`TransportRpcModuleConfig`] from cli args.
     ///
     /// This sets all the api modules, and configures additional settings like gas price oracle
@@ -58,6 +65,9 @@ pub trait RethRpcServerConfig {
     /// Creates the [`RpcServerConfig`] from cli args.
     fn rpc_server_config(&self) -> RpcServerConfig;
 
+    /// Returns the slow query log configuration.
+    fn slow_query_config(&self) -> SlowQueryConfig;
+
     /// Creates the [`AuthServerConfig`] from cli args.
     fn auth_server_config(&self, jwt_secret: JwtSecret) -> Result<AuthServerConfig, RpcError>;
 
@@ -133,9 +143,26 @@ impl RethRpcServerConfig for RpcServerArgs {
         self.gas_price_oracle.gas_price_oracle_config()
     }
 
+    fn trace_cache_config(&self) -> Option<TraceCacheConfig> {
+        self.rpc_trace_cache_dir.clone().map(|dir| TraceCacheConfig {
+            dir,
+            max_size: self.rpc_trace_cache_max_size.saturating_mul(1024 * 1024),
+        })
+    }
+
+    fn graphql_config(&self) -> Option<GraphQlConfig> {
+        self.graphql.then_some(GraphQlConfig {
+            max_complexity: self.graphql_max_complexity,
+            max_depth: self.graphql_max_depth,
+        })
+    }
+
     fn transport_rpc_module_config(&self) -> TransportRpcModuleConfig {
-        let mut config = TransportRpcModuleConfig::default()
-            .with_config(RpcModuleConfig::new(self.eth_config(), self.flashbots_config()));
+        let mut config = TransportRpcModuleConfig::default().with_config(
+            RpcModuleConfig::new(self.eth_config(), self.flashbots_config())
+                .with_trace_cache(self.trace_cache_config())
+                .with_graphql(self.graphql_config()),
+        );
 
         if self.http {
             config = config.with_http(
@@ -177,7 +204,10 @@ impl RethRpcServerConfig for RpcServerArgs {
     }
 
     fn rpc_server_config(&self) -> RpcServerConfig {
-        let mut config = RpcServerConfig::default().with_jwt_secret(self.rpc_secret_key());
+        let mut config = RpcServerConfig::default()
+            .with_jwt_secret(self.rpc_secret_key())
+            .with_api_keys(self.rpc_api_keys.clone())
+            .with_trusted_proxies(self.rpc_trusted_proxies.clone());
 
         if self.http_api.is_some() && !self.http {
             warn!(
@@ -208,6 +238,14 @@ impl RethRpcServerConfig for RpcServerArgs {
         config
     }
 
+    fn slow_query_config(&self) -> SlowQueryConfig {
+        SlowQueryConfig {
+            duration_threshold: self.rpc_slow_query_threshold,
+            response_size_threshold: self.rpc_slow_query_response_size,
+            log_size: self.rpc_slow_query_log_size,
+        }
+    }
+
     fn auth_server_config(&self, jwt_secret: JwtSecret) -> Result<AuthServerConfig, RpcError> {
         let address = SocketAddr::new(self.auth_addr, self.auth_port);
 
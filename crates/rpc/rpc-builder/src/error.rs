@@ -1,4 +1,4 @@
-use crate::{cors::CorsDomainError, RethRpcModule};
+use crate::{api_keys::ApiKeysError, cors::CorsDomainError, RethRpcModule};
 use reth_ipc::server::IpcServerStartError;
 use std::{
     collections::HashSet,
@@ -70,6 +70,9 @@ pub enum RpcError {
     /// Thrown when IPC server fails to start.
     #[error(transparent)]
     IpcServerError(#[from] IpcServerStartError),
+    /// Failed to load the API keys.
+    #[error(transparent)]
+    ApiKeys(#[from] ApiKeysError),
     /// Custom error.
     #[error("{0}")]
     Custom(String),
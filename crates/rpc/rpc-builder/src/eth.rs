@@ -6,7 +6,7 @@ use reth_rpc_eth_api::EthApiTypes;
 use reth_rpc_eth_types::{
     cache::cache_new_blocks_task, EthApiBuilderCtx, EthConfig, EthStateCache,
 };
-use reth_tasks::TaskSpawner;
+use reth_tasks::{pool::BlockingTaskGuard, TaskSpawner};
 
 /// Alias for `eth` namespace API builder.
 pub type DynEthApiBuilder<Provider, Pool, EvmConfig, Network, Tasks, EthApi> =
@@ -38,7 +38,8 @@ where
 {
     /// Returns a new instance with handlers for `eth` namespace.
     ///
-    /// This will spawn all necessary tasks for the handlers.
+    /// This will spawn all necessary tasks for the handlers. Traced subscriptions share the given
+    /// guard with the tracing handlers.
     #[allow(clippy::too_many_arguments)]
     pub fn bootstrap<EvmConfig, Tasks, Pool, Network>(
         provider: Provider,
@@ -48,6 +49,7 @@ where
         config: EthConfig,
         executor: Tasks,
         eth_api_builder: DynEthApiBuilder<Provider, Pool, EvmConfig, Network, Tasks, EthApi>,
+        blocking_task_guard: BlockingTaskGuard,
     ) -> Self
     where
         EvmConfig: ConfigureEvm<Header = Provider::Header>,
@@ -71,7 +73,11 @@ where
         let filter =
             EthFilter::new(api.clone(), ctx.config.filter_config(), Box::new(ctx.executor.clone()));
 
-        let pubsub = EthPubSub::with_spawner(api.clone(), Box::new(ctx.executor.clone()));
+        let pubsub = EthPubSub::with_spawner(
+            api.clone(),
+            Box::new(ctx.executor.clone()),
+            blocking_task_guard,
+        );
 
         Self { api, cache: ctx.cache, filter, pubsub }
     }
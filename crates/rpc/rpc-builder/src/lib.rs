@@ -22,7 +22,10 @@
 //! use reth_evm_ethereum::EthEvmConfig;
 //! use reth_network_api::{NetworkInfo, Peers};
 //! use reth_primitives::{Header, PooledTransaction, TransactionSigned};
-//! use reth_provider::{AccountReader, CanonStateSubscriptions, ChangeSetReader, FullRpcProvider};
+//! use reth_provider::{
+//!     AccountReader, AddressTransactionsReader, CanonStateSubscriptions, ChangeSetReader,
+//!     ContractCreatorsReader, FullRpcProvider, LogIndexReader, TokenTransfersReader,
+//! };
 //! use reth_rpc::EthApi;
 //! use reth_rpc_builder::{
 //!     RethRpcModule, RpcModuleBuilder, RpcServerConfig, ServerBuilder, TransportRpcModuleConfig,
@@ -46,6 +49,10 @@
 //!             Header = reth_primitives::Header,
 //!         > + AccountReader
 //!         + ChangeSetReader
+//!         + AddressTransactionsReader
+//!         + LogIndexReader
+//!         + ContractCreatorsReader
+//!         + TokenTransfersReader
 //!         + CanonStateSubscriptions<Primitives = reth_primitives::EthPrimitives>,
 //!     Pool: TransactionPool<
 //!             Transaction: PoolTransaction<
@@ -92,7 +99,10 @@
 //! use reth_evm_ethereum::EthEvmConfig;
 //! use reth_network_api::{NetworkInfo, Peers};
 //! use reth_primitives::{Header, PooledTransaction, TransactionSigned};
-//! use reth_provider::{AccountReader, CanonStateSubscriptions, ChangeSetReader, FullRpcProvider};
+//! use reth_provider::{
+//!     AccountReader, AddressTransactionsReader, CanonStateSubscriptions, ChangeSetReader,
+//!     ContractCreatorsReader, FullRpcProvider, LogIndexReader, TokenTransfersReader,
+//! };
 //! use reth_rpc::EthApi;
 //! use reth_rpc_api::{EngineApiServer, IntoEngineApiRpcModule};
 //! use reth_rpc_builder::{
@@ -121,6 +131,10 @@
 //!             Header = reth_primitives::Header,
 //!         > + AccountReader
 //!         + ChangeSetReader
+//!         + AddressTransactionsReader
+//!         + LogIndexReader
+//!         + ContractCreatorsReader
+//!         + TokenTransfersReader
 //!         + CanonStateSubscriptions<Primitives = reth_primitives::EthPrimitives>,
 //!     Pool: TransactionPool<
 //!             Transaction: PoolTransaction<
@@ -174,12 +188,19 @@
 use std::{
     collections::HashMap,
     fmt::Debug,
-    net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+    net::{IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4},
+    path::PathBuf,
     sync::Arc,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use crate::{auth::AuthRpcModule, error::WsHttpSamePortError, metrics::RpcRequestMetrics};
+use crate::{
+    api_keys::{ApiKeyAuthLayer, ApiKeyQuotaLayer, ApiKeyQuotaService, ApiKeys},
+    auth::AuthRpcModule,
+    error::WsHttpSamePortError,
+    metrics::RpcRequestMetrics,
+    slow_query::RpcCallerLayer,
+};
 use alloy_provider::{fillers::RecommendedFillers, Provider, ProviderBuilder};
 use error::{ConflictingModules, RpcError, ServerKind};
 use eth::DynEthApiBuilder;
@@ -198,25 +219,34 @@ use reth_evm::{execute::BlockExecutorProvider, ConfigureEvm};
 use reth_network_api::{noop::NoopNetwork, NetworkInfo, Peers};
 use reth_primitives::NodePrimitives;
 use reth_provider::{
-    AccountReader, BlockReader, CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader,
-    FullRpcProvider, ProviderBlock, ProviderHeader, ProviderReceipt, StateProviderFactory,
+    AccountReader, AddressTransactionsReader, BlockReader, CanonStateSubscriptions,
+    ChainSpecProvider, ChangeSetReader, ContractCreatorsReader, FullRpcProvider, LogIndexReader,
+    ProviderBlock, ProviderHeader, ProviderReceipt, StateProviderFactory, TokenTransfersReader,
 };
 use reth_rpc::{
-    AdminApi, DebugApi, EngineEthApi, EthBundle, MinerApi, NetApi, OtterscanApi, RPCApi, RethApi,
-    TraceApi, TxPoolApi, ValidationApiConfig, Web3Api,
+    AdminApi, DebugApi, EngineEthApi, EthBundle, HlApi, MinerApi, NetApi, OtterscanApi, RPCApi,
+    RethApi, TraceApi, TxPoolApi, ValidationApiConfig, Web3Api,
 };
 use reth_rpc_api::servers::*;
 use reth_rpc_eth_api::{
     helpers::{Call, EthApiSpec, EthTransactions, LoadPendingBlock, TraceExt},
     EthApiServer, EthApiTypes, FullEthApiServer, RpcBlock, RpcHeader, RpcReceipt, RpcTransaction,
 };
-use reth_rpc_eth_types::{EthConfig, EthStateCache, EthSubscriptionIdProvider};
+use reth_rpc_eth_types::{
+    trace_cache::trace_cache_invalidation_task, EthConfig, EthStateCache,
+    EthSubscriptionIdProvider, TraceCache, TraceCacheConfig,
+};
+use reth_rpc_graphql::{
+    build_schema, graphql_module, EthGraphQlBackend, GraphQlConfig, GraphQlLayer,
+    RawTransactionForwarder,
+};
 use reth_rpc_layer::{AuthLayer, Claims, CompressionLayer, JwtAuthValidator, JwtSecret};
 use reth_tasks::{pool::BlockingTaskGuard, TaskSpawner, TokioTaskExecutor};
 use reth_transaction_pool::{noop::NoopTransactionPool, TransactionPool};
 use serde::{Deserialize, Serialize};
 use tower::Layer;
 use tower_http::cors::CorsLayer;
+use tracing::error;
 
 pub use cors::CorsDomainError;
 
@@ -252,6 +282,12 @@ use reth_rpc::eth::sim_bundle::EthSimBundle;
 // Rpc rate limiter
 pub mod rate_limiter;
 
+// API key authentication and quotas
+pub mod api_keys;
+
+// Rpc slow query log
+pub mod slow_query;
+
 /// Convenience function for starting a server in one step.
 #[allow(clippy::too_many_arguments)]
 pub async fn launch<Provider, Pool, Network, Tasks, EvmConfig, EthApi, BlockExecutor>(
@@ -286,7 +322,11 @@ where
             Block = <BlockExecutor::Primitives as NodePrimitives>::Block,
             Receipt = <BlockExecutor::Primitives as NodePrimitives>::Receipt,
             Header = <BlockExecutor::Primitives as NodePrimitives>::BlockHeader,
-        > + CanonStateSubscriptions<Primitives = BlockExecutor::Primitives>,
+        > + CanonStateSubscriptions<Primitives = BlockExecutor::Primitives>
+                      + AddressTransactionsReader
+                      + LogIndexReader
+                      + ContractCreatorsReader
+                      + TokenTransfersReader,
     >,
     BlockExecutor: BlockExecutorProvider,
 {
@@ -605,7 +645,11 @@ where
                 Block = <BlockExecutor::Primitives as NodePrimitives>::Block,
                 Receipt = <BlockExecutor::Primitives as NodePrimitives>::Receipt,
                 Header = <BlockExecutor::Primitives as NodePrimitives>::BlockHeader,
-            > + CanonStateSubscriptions<Primitives = BlockExecutor::Primitives>,
+            > + CanonStateSubscriptions<Primitives = BlockExecutor::Primitives>
+                          + AddressTransactionsReader
+                          + LogIndexReader
+                          + ContractCreatorsReader
+                          + TokenTransfersReader,
         >,
     {
         let Self { provider, pool, network, executor, evm_config, block_executor, consensus } =
@@ -706,7 +750,11 @@ where
                 Receipt = <BlockExecutor::Primitives as NodePrimitives>::Receipt,
                 Block = <BlockExecutor::Primitives as NodePrimitives>::Block,
                 Header = <BlockExecutor::Primitives as NodePrimitives>::BlockHeader,
-            > + CanonStateSubscriptions<Primitives = BlockExecutor::Primitives>,
+            > + CanonStateSubscriptions<Primitives = BlockExecutor::Primitives>
+                          + AddressTransactionsReader
+                          + LogIndexReader
+                          + ContractCreatorsReader
+                          + TokenTransfersReader,
         >,
         Pool: TransactionPool<Transaction = <EthApi::Pool as TransactionPool>::Transaction>,
     {
@@ -734,6 +782,9 @@ where
             modules.http = registry.maybe_module(http.as_ref());
             modules.ws = registry.maybe_module(ws.as_ref());
             modules.ipc = registry.maybe_module(ipc.as_ref());
+            if let Some(graphql) = registry.graphql_module() {
+                modules.merge_graphql(graphql).expect("No conflicting methods");
+            }
         }
 
         modules
@@ -753,6 +804,10 @@ pub struct RpcModuleConfig {
     eth: EthConfig,
     /// `flashbots` namespace settings
     flashbots: ValidationApiConfig,
+    /// Settings of the trace cache used by the `debug`, `trace` and `ots` namespaces
+    trace_cache: Option<TraceCacheConfig>,
+    /// Settings of the GraphQL endpoint of the http server, disabled if `None`
+    graphql: Option<GraphQlConfig>,
 }
 
 // === impl RpcModuleConfig ===
@@ -765,7 +820,19 @@ impl RpcModuleConfig {
 
     /// Returns a new RPC module config given the eth namespace config
     pub const fn new(eth: EthConfig, flashbots: ValidationApiConfig) -> Self {
-        Self { eth, flashbots }
+        Self { eth, flashbots, trace_cache: None, graphql: None }
+    }
+
+    /// Configures the trace cache, disabled if `None`
+    pub fn with_trace_cache(mut self, trace_cache: Option<TraceCacheConfig>) -> Self {
+        self.trace_cache = trace_cache;
+        self
+    }
+
+    /// Configures the GraphQL endpoint, disabled if `None`
+    pub const fn with_graphql(mut self, graphql: Option<GraphQlConfig>) -> Self {
+        self.graphql = graphql;
+        self
     }
 
     /// Get a reference to the eth namespace config
@@ -777,6 +844,16 @@ impl RpcModuleConfig {
     pub fn eth_mut(&mut self) -> &mut EthConfig {
         &mut self.eth
     }
+
+    /// Get a reference to the trace cache config, if enabled
+    pub const fn trace_cache(&self) -> Option<&TraceCacheConfig> {
+        self.trace_cache.as_ref()
+    }
+
+    /// Get a reference to the GraphQL config, if enabled
+    pub const fn graphql(&self) -> Option<&GraphQlConfig> {
+        self.graphql.as_ref()
+    }
 }
 
 /// Configures [`RpcModuleConfig`]
@@ -784,6 +861,8 @@ impl RpcModuleConfig {
 pub struct RpcModuleConfigBuilder {
     eth: Option<EthConfig>,
     flashbots: Option<ValidationApiConfig>,
+    trace_cache: Option<TraceCacheConfig>,
+    graphql: Option<GraphQlConfig>,
 }
 
 // === impl RpcModuleConfigBuilder ===
@@ -801,10 +880,27 @@ impl RpcModuleConfigBuilder {
         self
     }
 
+    /// Configures the trace cache
+    pub fn trace_cache(mut self, trace_cache: TraceCacheConfig) -> Self {
+        self.trace_cache = Some(trace_cache);
+        self
+    }
+
+    /// Configures the GraphQL endpoint
+    pub const fn graphql(mut self, graphql: GraphQlConfig) -> Self {
+        self.graphql = Some(graphql);
+        self
+    }
+
     /// Consumes the type and creates the [`RpcModuleConfig`]
     pub fn build(self) -> RpcModuleConfig {
-        let Self { eth, flashbots } = self;
-        RpcModuleConfig { eth: eth.unwrap_or_default(), flashbots: flashbots.unwrap_or_default() }
+        let Self { eth, flashbots, trace_cache, graphql } = self;
+        RpcModuleConfig {
+            eth: eth.unwrap_or_default(),
+            flashbots: flashbots.unwrap_or_default(),
+            trace_cache,
+            graphql,
+        }
     }
 
     /// Get a reference to the eth namespace config, if any
@@ -845,6 +941,10 @@ pub struct RpcRegistryInner<
     eth: EthHandlers<Provider, EthApi>,
     /// to put trace calls behind semaphore
     blocking_pool_guard: BlockingTaskGuard,
+    /// Cache of trace results, if enabled
+    trace_cache: Option<TraceCache>,
+    /// Settings of the GraphQL endpoint, if enabled
+    graphql: Option<GraphQlConfig>,
     /// Contains the [Methods] of a module
     modules: HashMap<RethRpcModule, Methods>,
 }
@@ -885,6 +985,24 @@ where
     {
         let blocking_pool_guard = BlockingTaskGuard::new(config.eth.max_tracing_requests);
 
+        let trace_cache = config.trace_cache.and_then(|config| match TraceCache::open(&config) {
+            Ok(cache) => {
+                let new_canonical_blocks = provider.canonical_state_stream();
+                let c = cache.clone();
+                executor.spawn_critical(
+                    "trace cache invalidation task",
+                    Box::pin(async move {
+                        trace_cache_invalidation_task(c, new_canonical_blocks).await;
+                    }),
+                );
+                Some(cache)
+            }
+            Err(err) => {
+                error!(target: "reth::rpc", dir = ?config.dir, %err, "Failed to open trace cache");
+                None
+            }
+        });
+
         let eth = EthHandlers::bootstrap(
             provider.clone(),
             pool.clone(),
@@ -893,6 +1011,7 @@ where
             config.eth,
             executor.clone(),
             eth_api_builder,
+            blocking_pool_guard.clone(),
         );
 
         Self {
@@ -904,6 +1023,8 @@ where
             consensus,
             modules: Default::default(),
             blocking_pool_guard,
+            trace_cache,
+            graphql: config.graphql,
             block_executor,
         }
     }
@@ -1034,13 +1155,28 @@ where
     /// If called outside of the tokio runtime. See also [`Self::eth_api`]
     pub fn register_ots(&mut self) -> &mut Self
     where
-        EthApi: TraceExt + EthTransactions,
+        EthApi: TraceExt
+            + EthTransactions<Provider: AddressTransactionsReader + ContractCreatorsReader>,
     {
         let otterscan_api = self.otterscan_api();
         self.modules.insert(RethRpcModule::Ots, otterscan_api.into_rpc().into());
         self
     }
 
+    /// Register Hl Namespace
+    ///
+    /// # Panics
+    ///
+    /// If called outside of the tokio runtime. See also [`Self::eth_api`]
+    pub fn register_hl(&mut self) -> &mut Self
+    where
+        EthApi: EthTransactions<Provider: TokenTransfersReader>,
+    {
+        let hl_api = HlApi::new(self.eth_api().clone());
+        self.modules.insert(RethRpcModule::Hl, hl_api.into_rpc().into());
+        self
+    }
+
     /// Register Debug Namespace
     ///
     /// # Panics
@@ -1106,7 +1242,7 @@ where
     /// If called outside of the tokio runtime. See also [`Self::eth_api`]
     pub fn otterscan_api(&self) -> OtterscanApi<EthApi> {
         let eth_api = self.eth_api().clone();
-        OtterscanApi::new(eth_api)
+        OtterscanApi::new(eth_api, self.trace_cache.clone())
     }
 }
 
@@ -1128,7 +1264,11 @@ where
     where
         EthApi: TraceExt,
     {
-        TraceApi::new(self.eth_api().clone(), self.blocking_pool_guard.clone())
+        TraceApi::new(
+            self.eth_api().clone(),
+            self.blocking_pool_guard.clone(),
+            self.trace_cache.clone(),
+        )
     }
 
     /// Instantiates [`EthBundle`] Api
@@ -1158,6 +1298,7 @@ where
             self.eth_api().clone(),
             self.blocking_pool_guard.clone(),
             self.block_executor.clone(),
+            self.trace_cache.clone(),
         )
     }
 
@@ -1195,7 +1336,11 @@ where
             Block = <BlockExecutor::Primitives as NodePrimitives>::Block,
             Receipt = <BlockExecutor::Primitives as NodePrimitives>::Receipt,
             Header = <BlockExecutor::Primitives as NodePrimitives>::BlockHeader,
-        > + CanonStateSubscriptions<Primitives = BlockExecutor::Primitives>,
+        > + CanonStateSubscriptions<Primitives = BlockExecutor::Primitives>
+                      + AddressTransactionsReader
+                      + LogIndexReader
+                      + ContractCreatorsReader
+                      + TokenTransfersReader,
     >,
     BlockExecutor: BlockExecutorProvider,
     Consensus: FullConsensus<BlockExecutor::Primitives, Error = ConsensusError> + Clone + 'static,
@@ -1217,6 +1362,31 @@ where
         AuthRpcModule { inner: module }
     }
 
+    /// Creates the [`RpcModule`] that serves the `eth_` handlers of this registry over GraphQL, if
+    /// enabled.
+    ///
+    /// See also [`TransportRpcModules::merge_graphql`].
+    pub fn graphql_module(&self) -> Option<RpcModule<()>> {
+        let config = self.graphql.as_ref()?;
+        let backend = EthGraphQlBackend::new(self.eth.api.clone(), self.eth.filter.clone());
+        Some(graphql_module(build_schema(Arc::new(backend), config)))
+    }
+
+    /// Same as [`Self::graphql_module`], but the transactions of the `sendRawTransaction`
+    /// mutation are forwarded with the given forwarder instead of being submitted to the pool.
+    ///
+    /// The returned module replaces the one installed by default, see
+    /// [`TransportRpcModules::replace_http`].
+    pub fn graphql_module_with_tx_forwarder(
+        &self,
+        tx_forwarder: Arc<dyn RawTransactionForwarder>,
+    ) -> Option<RpcModule<()>> {
+        let config = self.graphql.as_ref()?;
+        let backend = EthGraphQlBackend::new(self.eth.api.clone(), self.eth.filter.clone())
+            .with_tx_forwarder(tx_forwarder);
+        Some(graphql_module(build_schema(Arc::new(backend), config)))
+    }
+
     /// Helper function to create a [`RpcModule`] if it's not `None`
     fn maybe_module(&mut self, config: Option<&RpcModuleSelection>) -> Option<RpcModule<()>> {
         config.map(|config| self.module_for(config))
@@ -1238,6 +1408,9 @@ where
         modules.http = http;
         modules.ws = ws;
         modules.ipc = ipc;
+        if let Some(graphql) = self.graphql_module() {
+            modules.merge_graphql(graphql).expect("No conflicting methods");
+        }
         modules
     }
 
@@ -1285,6 +1458,7 @@ where
                             eth_api.clone(),
                             self.blocking_pool_guard.clone(),
                             self.block_executor.clone(),
+                            self.trace_cache.clone(),
                         )
                         .into_rpc()
                         .into(),
@@ -1308,11 +1482,13 @@ where
                         RethRpcModule::Net => {
                             NetApi::new(self.network.clone(), eth_api.clone()).into_rpc().into()
                         }
-                        RethRpcModule::Trace => {
-                            TraceApi::new(eth_api.clone(), self.blocking_pool_guard.clone())
-                                .into_rpc()
-                                .into()
-                        }
+                        RethRpcModule::Trace => TraceApi::new(
+                            eth_api.clone(),
+                            self.blocking_pool_guard.clone(),
+                            self.trace_cache.clone(),
+                        )
+                        .into_rpc()
+                        .into(),
                         RethRpcModule::Web3 => Web3Api::new(self.network.clone()).into_rpc().into(),
                         RethRpcModule::Txpool => TxPoolApi::new(
                             self.eth.api.pool().clone(),
@@ -1328,7 +1504,11 @@ where
                         )
                         .into_rpc()
                         .into(),
-                        RethRpcModule::Ots => OtterscanApi::new(eth_api.clone()).into_rpc().into(),
+                        RethRpcModule::Ots => {
+                            OtterscanApi::new(eth_api.clone(), self.trace_cache.clone())
+                                .into_rpc()
+                                .into()
+                        }
                         RethRpcModule::Reth => {
                             RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
                                 .into_rpc()
@@ -1344,6 +1524,7 @@ where
                                 .into_rpc()
                                 .into()
                         }
+                        RethRpcModule::Hl => HlApi::new(eth_api.clone()).into_rpc().into(),
                     })
                     .clone()
             })
@@ -1382,6 +1563,10 @@ pub struct RpcServerConfig<RpcMiddleware = Identity> {
     ipc_endpoint: Option<String>,
     /// JWT secret for authentication
     jwt_secret: Option<JwtSecret>,
+    /// File with the API keys required by the http and ws servers
+    api_keys_path: Option<PathBuf>,
+    /// Proxies whose forwarding headers are trusted to identify the caller
+    trusted_proxies: Vec<IpAddr>,
     /// Configurable RPC middleware
     rpc_middleware: RpcServiceBuilder<RpcMiddleware>,
 }
@@ -1401,6 +1586,8 @@ impl Default for RpcServerConfig<Identity> {
             ipc_server_config: None,
             ipc_endpoint: None,
             jwt_secret: None,
+            api_keys_path: None,
+            trusted_proxies: Vec::new(),
             rpc_middleware: RpcServiceBuilder::new(),
         }
     }
@@ -1464,6 +1651,8 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             ipc_server_config: self.ipc_server_config,
             ipc_endpoint: self.ipc_endpoint,
             jwt_secret: self.jwt_secret,
+            api_keys_path: self.api_keys_path,
+            trusted_proxies: self.trusted_proxies,
             rpc_middleware,
         }
     }
@@ -1537,6 +1726,21 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         self
     }
 
+    /// Configures the file with the API keys required by the http and ws servers.
+    ///
+    /// See [`api_keys`] for the file format.
+    pub fn with_api_keys(mut self, path: Option<PathBuf>) -> Self {
+        self.api_keys_path = path;
+        self
+    }
+
+    /// Configures the proxies whose `x-forwarded-for` and `x-real-ip` headers identify the caller
+    /// of a request, see [`RpcCallerLayer`].
+    pub fn with_trusted_proxies(mut self, proxies: Vec<IpAddr>) -> Self {
+        self.trusted_proxies = proxies;
+        self
+    }
+
     /// Returns true if any server is configured.
     ///
     /// If no server is configured, no server will be launched on [`RpcServerConfig::start`].
@@ -1571,6 +1775,16 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         jwt_secret.map(|secret| AuthLayer::new(JwtAuthValidator::new(secret)))
     }
 
+    /// Creates the [`ApiKeyAuthLayer`] if any
+    fn maybe_api_key_auth_layer(api_keys: Option<&ApiKeys>) -> Option<ApiKeyAuthLayer> {
+        api_keys.map(ApiKeys::auth_layer)
+    }
+
+    /// Creates the [`ApiKeyQuotaLayer`], which is a no-op if no API keys are configured
+    fn api_key_quota_layer(api_keys: Option<&ApiKeys>) -> ApiKeyQuotaLayer {
+        api_keys.map(ApiKeys::quota_layer).unwrap_or_default()
+    }
+
     /// Returns a [`CompressionLayer`] that adds compression support (gzip, deflate, brotli, zstd)
     /// based on the client's `Accept-Encoding` header
     fn maybe_compression_layer() -> Option<CompressionLayer> {
@@ -1584,8 +1798,8 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
     /// Returns the [`RpcServerHandle`] with the handle to the started servers.
     pub async fn start(self, modules: &TransportRpcModules) -> Result<RpcServerHandle, RpcError>
     where
-        RpcMiddleware: Layer<RpcRequestMetricsService<RpcService>> + Clone + Send + 'static,
-        for<'a> <RpcMiddleware as Layer<RpcRequestMetricsService<RpcService>>>::Service:
+        RpcMiddleware: Layer<MeteredRpcService> + Clone + Send + 'static,
+        for<'a> <RpcMiddleware as Layer<MeteredRpcService>>::Service:
             Send + Sync + 'static + RpcServiceT<'a>,
     {
         let mut http_handle = None;
@@ -1602,6 +1816,13 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             constants::DEFAULT_WS_RPC_PORT,
         )));
 
+        let api_keys = self.api_keys_path.as_ref().map(ApiKeys::from_path).transpose()?;
+        if let Some(api_keys) = &api_keys {
+            api_keys.spawn_reload_task();
+        }
+
+        let caller_layer = RpcCallerLayer::new(self.trusted_proxies.iter().copied());
+
         let metrics = modules.ipc.as_ref().map(RpcRequestMetrics::ipc).unwrap_or_default();
         let ipc_path =
             self.ipc_endpoint.clone().unwrap_or_else(|| constants::DEFAULT_IPC_ENDPOINT.into());
@@ -1642,17 +1863,23 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                         tower::ServiceBuilder::new()
                             .option_layer(Self::maybe_cors_layer(cors)?)
                             .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
-                            .option_layer(Self::maybe_compression_layer()),
+                            .option_layer(Self::maybe_api_key_auth_layer(api_keys.as_ref()))
+                            .layer(caller_layer.clone())
+                            .option_layer(Self::maybe_compression_layer())
+                            .option_layer(modules.graphql),
                     )
                     .set_rpc_middleware(
-                        self.rpc_middleware.clone().layer(
-                            modules
-                                .http
-                                .as_ref()
-                                .or(modules.ws.as_ref())
-                                .map(RpcRequestMetrics::same_port)
-                                .unwrap_or_default(),
-                        ),
+                        self.rpc_middleware
+                            .clone()
+                            .layer(
+                                modules
+                                    .http
+                                    .as_ref()
+                                    .or(modules.ws.as_ref())
+                                    .map(RpcRequestMetrics::same_port)
+                                    .unwrap_or_default(),
+                            )
+                            .layer(Self::api_key_quota_layer(api_keys.as_ref())),
                     )
                     .build(http_socket_addr)
                     .await
@@ -1690,12 +1917,15 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                 .set_http_middleware(
                     tower::ServiceBuilder::new()
                         .option_layer(Self::maybe_cors_layer(self.ws_cors_domains.clone())?)
-                        .option_layer(Self::maybe_jwt_layer(self.jwt_secret)),
+                        .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
+                        .option_layer(Self::maybe_api_key_auth_layer(api_keys.as_ref()))
+                        .layer(caller_layer.clone()),
                 )
                 .set_rpc_middleware(
                     self.rpc_middleware
                         .clone()
-                        .layer(modules.ws.as_ref().map(RpcRequestMetrics::ws).unwrap_or_default()),
+                        .layer(modules.ws.as_ref().map(RpcRequestMetrics::ws).unwrap_or_default())
+                        .layer(Self::api_key_quota_layer(api_keys.as_ref())),
                 )
                 .build(ws_socket_addr)
                 .await
@@ -1716,12 +1946,18 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                     tower::ServiceBuilder::new()
                         .option_layer(Self::maybe_cors_layer(self.ws_cors_domains.clone())?)
                         .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
-                        .option_layer(Self::maybe_compression_layer()),
+                        .option_layer(Self::maybe_api_key_auth_layer(api_keys.as_ref()))
+                        .layer(caller_layer.clone())
+                        .option_layer(Self::maybe_compression_layer())
+                        .option_layer(modules.graphql),
                 )
                 .set_rpc_middleware(
-                    self.rpc_middleware.clone().layer(
-                        modules.http.as_ref().map(RpcRequestMetrics::http).unwrap_or_default(),
-                    ),
+                    self.rpc_middleware
+                        .clone()
+                        .layer(
+                            modules.http.as_ref().map(RpcRequestMetrics::http).unwrap_or_default(),
+                        )
+                        .layer(Self::api_key_quota_layer(api_keys.as_ref())),
                 )
                 .build(http_socket_addr)
                 .await
@@ -1749,6 +1985,9 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
     }
 }
 
+/// The RPC service of the http and ws servers that is wrapped by the configurable RPC middleware.
+type MeteredRpcService = RpcRequestMetricsService<ApiKeyQuotaService<RpcService>>;
+
 /// Holds modules to be installed per transport type
 ///
 /// # Example
@@ -1914,6 +2153,8 @@ pub struct TransportRpcModules<Context = ()> {
     ws: Option<RpcModule<Context>>,
     /// rpcs module for ipc
     ipc: Option<RpcModule<Context>>,
+    /// GraphQL endpoint served next to the http module, which has the GraphQL method merged
+    graphql: Option<GraphQlLayer>,
 }
 
 // === impl TransportRpcModules ===
@@ -1947,6 +2188,20 @@ impl TransportRpcModules {
         self
     }
 
+    /// Merge the given GraphQL module, see [`graphql_module`], in the configured http methods and
+    /// serve the GraphQL endpoint on the http transport.
+    ///
+    /// Fails if any of the methods in other is present already.
+    ///
+    /// Returns [Ok(false)] if no http transport is configured.
+    pub fn merge_graphql(&mut self, graphql: RpcModule<()>) -> Result<bool, RegisterMethodError> {
+        let merged = self.merge_http(graphql)?;
+        if merged {
+            self.graphql = Some(GraphQlLayer::new());
+        }
+        Ok(merged)
+    }
+
     /// Returns the [`TransportRpcModuleConfig`] used to configure this instance.
     pub const fn module_config(&self) -> &TransportRpcModuleConfig {
         &self.config
@@ -0,0 +1,382 @@
+//! RPC middleware that logs slow queries.
+//!
+//! Calls that take longer than the configured duration or return more than the configured number
+//! of bytes are logged and recorded in a [`SlowQueryLog`], which is served by
+//! `admin_slowQueries`.
+
+use http::HeaderMap;
+use jsonrpsee::{
+    server::{middleware::rpc::RpcServiceT, HttpRequest},
+    types::Request,
+    MethodResponse,
+};
+use pin_project::pin_project;
+use reth_db_api::DatabaseReadStats;
+use reth_rpc_server_types::{constants::DEFAULT_SLOW_QUERY_LOG_SIZE, SlowQuery, SlowQueryLog};
+use serde_json::value::RawValue;
+use std::{
+    borrow::Cow,
+    future::Future,
+    net::{IpAddr, SocketAddr},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use tower::{Layer, Service};
+use tracing::warn;
+
+/// Maximum number of bytes of the parameters that are logged.
+const MAX_PARAMS_LEN: usize = 1024;
+
+/// Thresholds of the slow query log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlowQueryConfig {
+    /// Calls taking at least this long are logged.
+    pub duration_threshold: Option<Duration>,
+    /// Calls with responses of at least this many bytes are logged.
+    pub response_size_threshold: Option<usize>,
+    /// Number of slow queries kept for `admin_slowQueries`.
+    pub log_size: usize,
+}
+
+impl SlowQueryConfig {
+    /// Returns true if any threshold is configured.
+    pub const fn is_enabled(&self) -> bool {
+        self.duration_threshold.is_some() || self.response_size_threshold.is_some()
+    }
+
+    /// Returns true if a call exceeded any of the thresholds.
+    fn is_slow(&self, elapsed: Duration, response_bytes: usize) -> bool {
+        self.duration_threshold.is_some_and(|threshold| elapsed >= threshold) ||
+            self.response_size_threshold.is_some_and(|threshold| response_bytes >= threshold)
+    }
+}
+
+impl Default for SlowQueryConfig {
+    fn default() -> Self {
+        Self {
+            duration_threshold: None,
+            response_size_threshold: None,
+            log_size: DEFAULT_SLOW_QUERY_LOG_SIZE,
+        }
+    }
+}
+
+/// A [`Layer`] for the [`RpcServiceBuilder`](jsonrpsee::server::RpcServiceBuilder) that records
+/// slow queries.
+///
+/// This is a no-op if no threshold is configured.
+#[derive(Debug, Clone)]
+pub struct SlowQueryLayer {
+    config: SlowQueryConfig,
+    log: SlowQueryLog,
+}
+
+impl SlowQueryLayer {
+    /// Creates a new layer with an empty log.
+    pub fn new(config: SlowQueryConfig) -> Self {
+        let log = SlowQueryLog::new(if config.is_enabled() { config.log_size } else { 0 });
+        Self { config, log }
+    }
+
+    /// Returns the log the slow queries are recorded to.
+    pub const fn log(&self) -> &SlowQueryLog {
+        &self.log
+    }
+}
+
+impl<S> Layer<S> for SlowQueryLayer {
+    type Service = SlowQueryService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        SlowQueryService { config: self.config, log: self.log.clone(), inner }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that records slow queries.
+#[derive(Debug, Clone)]
+pub struct SlowQueryService<S> {
+    config: SlowQueryConfig,
+    log: SlowQueryLog,
+    inner: S,
+}
+
+impl<'a, S> RpcServiceT<'a> for SlowQueryService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = SlowQueryFuture<'a, S::Future>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        if !self.config.is_enabled() {
+            return SlowQueryFuture { fut: self.inner.call(req), call: None }
+        }
+
+        let call = SlowQueryCall {
+            config: self.config,
+            log: self.log.clone(),
+            started_at: Instant::now(),
+            started_at_unix: SystemTime::now(),
+            method: req.method.clone(),
+            params: req.params.clone(),
+            caller: req.extensions().get::<RpcCaller>().map(|caller| caller.0),
+            db_reads: Arc::default(),
+        };
+        SlowQueryFuture { fut: self.inner.call(req), call: Some(call) }
+    }
+}
+
+/// The state of a call that is checked against the thresholds once it completes.
+#[derive(Debug)]
+struct SlowQueryCall<'a> {
+    config: SlowQueryConfig,
+    log: SlowQueryLog,
+    started_at: Instant,
+    started_at_unix: SystemTime,
+    method: Cow<'a, str>,
+    params: Option<Cow<'a, RawValue>>,
+    caller: Option<IpAddr>,
+    /// The database reads of the call.
+    db_reads: Arc<DatabaseReadStats>,
+}
+
+impl SlowQueryCall<'_> {
+    /// Logs and records the call if it exceeded any threshold.
+    fn finish(self, response: &MethodResponse) {
+        let elapsed = self.started_at.elapsed();
+        let response_bytes = response.as_result().len();
+        if !self.config.is_slow(elapsed, response_bytes) {
+            return
+        }
+
+        let query = SlowQuery {
+            started_at: self
+                .started_at_unix
+                .duration_since(UNIX_EPOCH)
+                .map(|since| since.as_millis() as u64)
+                .unwrap_or_default(),
+            method: self.method.into_owned(),
+            params: truncate_params(self.params.as_deref().map_or("[]", RawValue::get)),
+            caller: self.caller.map(|caller| caller.to_string()),
+            duration_ms: elapsed.as_millis() as u64,
+            response_bytes,
+            success: response.is_success(),
+            db_read_transactions: self.db_reads.transactions(),
+            db_cursors: self.db_reads.cursors(),
+            db_gets: self.db_reads.gets(),
+        };
+
+        warn!(
+            target: "rpc::slow_query",
+            method = %query.method,
+            params = %query.params,
+            caller = ?query.caller,
+            ?elapsed,
+            response_bytes,
+            success = query.success,
+            db_read_transactions = query.db_read_transactions,
+            db_cursors = query.db_cursors,
+            db_gets = query.db_gets,
+            "Slow RPC call"
+        );
+        self.log.push(query);
+    }
+}
+
+/// Truncates the parameters to [`MAX_PARAMS_LEN`] bytes.
+fn truncate_params(params: &str) -> String {
+    if params.len() <= MAX_PARAMS_LEN {
+        return params.to_string()
+    }
+    let mut end = MAX_PARAMS_LEN;
+    while !params.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}...", &params[..end])
+}
+
+/// Response future of the [`SlowQueryService`].
+#[pin_project]
+pub struct SlowQueryFuture<'a, F> {
+    #[pin]
+    fut: F,
+    call: Option<SlowQueryCall<'a>>,
+}
+
+impl<F> std::fmt::Debug for SlowQueryFuture<'_, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SlowQueryFuture")
+    }
+}
+
+impl<F: Future<Output = MethodResponse>> Future for SlowQueryFuture<'_, F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        // The database reads of the call are recorded while it is polled, the blocking tasks it
+        // spawns carry the stats along
+        let db_reads = this.call.as_ref().map(|call| call.db_reads.clone());
+        let fut = this.fut;
+        let res = DatabaseReadStats::scope(db_reads, || fut.poll(cx));
+        if let Poll::Ready(response) = &res {
+            if let Some(call) = this.call.take() {
+                call.finish(response);
+            }
+        }
+        res
+    }
+}
+
+/// The address of the caller, stored in the request extensions.
+#[derive(Debug, Clone, Copy)]
+struct RpcCaller(IpAddr);
+
+impl RpcCaller {
+    /// Returns the address of the caller of a request received from `peer`.
+    ///
+    /// This is the address of the peer, unless the peer is one of the `trusted_proxies` or
+    /// unknown. Then the caller is the rightmost address in the `x-forwarded-for` header that isn't
+    /// a trusted proxy, as all addresses before it can be set by the caller, or the `x-real-ip`
+    /// header.
+    fn resolve(
+        peer: Option<IpAddr>,
+        headers: &HeaderMap,
+        trusted_proxies: &[IpAddr],
+    ) -> Option<Self> {
+        let peer = peer.map(|peer| peer.to_canonical());
+        if let Some(peer) = peer.filter(|peer| !trusted_proxies.contains(peer)) {
+            return Some(Self(peer))
+        }
+
+        let forwarded_for = headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .collect::<Vec<_>>();
+        let forwarded_for = forwarded_for
+            .iter()
+            .rev()
+            .map_while(|addr| addr.trim().parse::<IpAddr>().ok())
+            .map(|addr| addr.to_canonical())
+            .find(|addr| !trusted_proxies.contains(addr));
+        let real_ip = || {
+            headers
+                .get("x-real-ip")
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<IpAddr>().ok())
+                .map(|addr| addr.to_canonical())
+        };
+        forwarded_for.or_else(real_ip).or(peer).map(Self)
+    }
+}
+
+/// HTTP middleware that makes the caller address available to the [`SlowQueryService`].
+///
+/// The caller is the address of the peer of the connection, taken from the [`SocketAddr`] in the
+/// request extensions. The `x-forwarded-for` and `x-real-ip` headers are only used for connections
+/// from the configured trusted proxies, or if the peer is unknown. The jsonrpsee server doesn't
+/// expose the peer to the middleware, so it is unknown unless an outer layer inserted it.
+#[derive(Debug, Clone, Default)]
+pub struct RpcCallerLayer {
+    trusted_proxies: Arc<[IpAddr]>,
+}
+
+impl RpcCallerLayer {
+    /// Creates a new layer that trusts the forwarding headers set by the given proxies.
+    pub fn new(trusted_proxies: impl IntoIterator<Item = IpAddr>) -> Self {
+        Self {
+            trusted_proxies: trusted_proxies.into_iter().map(|addr| addr.to_canonical()).collect(),
+        }
+    }
+}
+
+impl<S> Layer<S> for RpcCallerLayer {
+    type Service = RpcCallerService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcCallerService { trusted_proxies: self.trusted_proxies.clone(), inner }
+    }
+}
+
+/// The [`Service`] created by the [`RpcCallerLayer`].
+#[derive(Debug, Clone)]
+pub struct RpcCallerService<S> {
+    trusted_proxies: Arc<[IpAddr]>,
+    inner: S,
+}
+
+impl<S> Service<HttpRequest> for RpcCallerService<S>
+where
+    S: Service<HttpRequest>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: HttpRequest) -> Self::Future {
+        let peer = req.extensions().get::<SocketAddr>().map(|peer| peer.ip());
+        if let Some(caller) = RpcCaller::resolve(peer, req.headers(), &self.trusted_proxies) {
+            req.extensions_mut().insert(caller);
+        }
+        self.inner.call(req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thresholds() {
+        let config = SlowQueryConfig {
+            duration_threshold: Some(Duration::from_secs(1)),
+            response_size_threshold: Some(1000),
+            ..Default::default()
+        };
+        assert!(config.is_enabled());
+        assert!(!config.is_slow(Duration::from_millis(999), 999));
+        assert!(config.is_slow(Duration::from_secs(1), 0));
+        assert!(config.is_slow(Duration::ZERO, 1000));
+        assert!(!SlowQueryConfig::default().is_enabled());
+    }
+
+    #[test]
+    fn truncate_long_params() {
+        assert_eq!(truncate_params("[1]"), "[1]");
+        let params = format!("[\"{}\"]", "é".repeat(MAX_PARAMS_LEN));
+        let truncated = truncate_params(&params);
+        assert!(truncated.len() <= MAX_PARAMS_LEN + 3);
+        assert!(truncated.ends_with("..."));
+    }
+
+    #[test]
+    fn caller_from_peer_or_trusted_proxy() {
+        let caller = |peer: Option<IpAddr>, headers: &HeaderMap, trusted: &[IpAddr]| {
+            RpcCaller::resolve(peer, headers, trusted).map(|caller| caller.0.to_string())
+        };
+        let peer: IpAddr = "10.0.0.9".parse().unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert("x-real-ip", "10.0.0.2".parse().unwrap());
+        assert_eq!(caller(Some(peer), &headers, &[]).as_deref(), Some("10.0.0.9"));
+        assert_eq!(caller(None, &headers, &[]).as_deref(), Some("10.0.0.2"));
+
+        let trusted = [peer, "10.0.0.8".parse().unwrap()];
+        assert_eq!(caller(Some(peer), &headers, &trusted).as_deref(), Some("10.0.0.2"));
+
+        headers.insert("x-forwarded-for", "1.1.1.1, 10.0.0.1, 10.0.0.8".parse().unwrap());
+        assert_eq!(caller(Some(peer), &headers, &trusted).as_deref(), Some("10.0.0.1"));
+        assert_eq!(caller(None, &headers, &trusted).as_deref(), Some("10.0.0.1"));
+
+        headers.insert("x-forwarded-for", "10.0.0.8".parse().unwrap());
+        headers.remove("x-real-ip");
+        assert_eq!(caller(Some(peer), &headers, &trusted).as_deref(), Some("10.0.0.9"));
+        assert_eq!(caller(None, &headers, &trusted), None);
+    }
+}
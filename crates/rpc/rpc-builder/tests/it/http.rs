@@ -299,6 +299,7 @@ where
         Some(block_number.into()),
         None,
         None,
+        None,
     )
     .await
     .unwrap_err();
@@ -498,6 +499,14 @@ where
         .await
         .unwrap()
         .is_none());
+    assert!(OtterscanClient::<Transaction, Header>::get_contracts_deployed(
+        client,
+        block_number,
+        block_number
+    )
+    .await
+    .unwrap()
+    .is_empty());
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -13,6 +13,7 @@ use alloy_rpc_types_eth::{
 };
 use alloy_serde::JsonStorageKey;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_hyperliquid_types::PrecompileOverrides;
 use reth_rpc_server_types::{result::internal_rpc_err, ToRpcResult};
 use tracing::trace;
 
@@ -210,14 +211,22 @@ pub trait EthApi<T: RpcObject, B: RpcObject, R: RpcObject, H: RpcObject> {
 
     /// `eth_simulateV1` executes an arbitrary number of transactions on top of the requested state.
     /// The transactions are packed into individual blocks. Overrides can be provided.
+    ///
+    /// Read precompile results can be overridden for all blocks of the simulation with the extra
+    /// third positional parameter, by precompile address and call input.
     #[method(name = "simulateV1")]
     async fn simulate_v1(
         &self,
         opts: SimulatePayload,
         block_number: Option<BlockId>,
+        precompile_overrides: Option<PrecompileOverrides>,
     ) -> RpcResult<Vec<SimulatedBlock<B>>>;
 
     /// Executes a new message call immediately without creating a transaction on the block chain.
+    ///
+    /// Read precompile results can be overridden to simulate the call against hypothetical
+    /// HyperCore state with the extra fifth positional parameter, by precompile address and call
+    /// input.
     #[method(name = "call")]
     async fn call(
         &self,
@@ -225,6 +234,7 @@ pub trait EthApi<T: RpcObject, B: RpcObject, R: RpcObject, H: RpcObject> {
         block_number: Option<BlockId>,
         state_overrides: Option<StateOverride>,
         block_overrides: Option<Box<BlockOverrides>>,
+        precompile_overrides: Option<PrecompileOverrides>,
     ) -> RpcResult<Bytes>;
 
     /// Simulate arbitrary number of transactions at an arbitrary blockchain index, with the
@@ -629,10 +639,11 @@ where
         &self,
         payload: SimulatePayload,
         block_number: Option<BlockId>,
+        precompile_overrides: Option<PrecompileOverrides>,
     ) -> RpcResult<Vec<SimulatedBlock<RpcBlock<T::NetworkTypes>>>> {
-        trace!(target: "rpc::eth", ?block_number, "Serving eth_simulateV1");
+        trace!(target: "rpc::eth", ?block_number, ?precompile_overrides, "Serving eth_simulateV1");
         let _permit = self.tracing_task_guard().clone().acquire_owned().await;
-        Ok(EthCall::simulate_v1(self, payload, block_number).await?)
+        Ok(EthCall::simulate_v1(self, payload, block_number, precompile_overrides).await?)
     }
 
     /// Handler for: `eth_call`
@@ -642,13 +653,15 @@ where
         block_number: Option<BlockId>,
         state_overrides: Option<StateOverride>,
         block_overrides: Option<Box<BlockOverrides>>,
+        precompile_overrides: Option<PrecompileOverrides>,
     ) -> RpcResult<Bytes> {
-        trace!(target: "rpc::eth", ?request, ?block_number, ?state_overrides, ?block_overrides, "Serving eth_call");
+        trace!(target: "rpc::eth", ?request, ?block_number, ?state_overrides, ?block_overrides, ?precompile_overrides, "Serving eth_call");
         Ok(EthCall::call(
             self,
             request,
             block_number,
             EvmOverrides::new(state_overrides, block_overrides),
+            precompile_overrides,
         )
         .await?)
     }
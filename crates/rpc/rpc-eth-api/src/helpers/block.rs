@@ -13,7 +13,7 @@ use futures::Future;
 use reth_node_api::BlockBody;
 use reth_primitives::{RecoveredBlock, SealedBlock};
 use reth_provider::{
-    BlockIdReader, BlockReader, BlockReaderIdExt, ProviderHeader, ProviderReceipt,
+    BlockIdReader, BlockReader, BlockReaderIdExt, ProviderBlock, ProviderHeader, ProviderReceipt,
 };
 use reth_rpc_types_compat::block::from_block;
 use revm_primitives::U256;
@@ -108,6 +108,23 @@ pub trait EthBlocks: LoadBlock {
         &self,
         block_id: BlockId,
     ) -> impl Future<Output = BlockReceiptsResult<Self::NetworkTypes, Self::Error>> + Send
+    where
+        Self: LoadReceipt,
+    {
+        async move {
+            let Some((block, receipts)) = self.load_block_and_receipts(block_id).await? else {
+                return Ok(None)
+            };
+            self.build_block_receipts(&block, &receipts).map(Some)
+        }
+    }
+
+    /// Converts the receipts of all transactions of the given block into rpc receipts.
+    fn build_block_receipts(
+        &self,
+        block: &SealedBlock<ProviderBlock<Self::Provider>>,
+        receipts: &[ProviderReceipt<Self::Provider>],
+    ) -> Result<Vec<RpcReceipt<Self::NetworkTypes>>, Self::Error>
     where
         Self: LoadReceipt;
 
@@ -2,6 +2,7 @@
 //! are executed on the `tokio` runtime.
 
 use futures::Future;
+use reth_db_api::DatabaseReadStats;
 use reth_rpc_eth_types::EthApiError;
 use reth_tasks::{
     pool::{BlockingTaskGuard, BlockingTaskPool},
@@ -52,8 +53,9 @@ pub trait SpawnBlocking: EthApiTypes + Clone + Send + Sync + 'static {
     {
         let (tx, rx) = oneshot::channel();
         let this = self.clone();
+        let db_reads = DatabaseReadStats::current();
         self.io_task_spawner().spawn_blocking(Box::pin(async move {
-            let res = async move { f(this) }.await;
+            let res = DatabaseReadStats::scope(db_reads, || f(this));
             let _ = tx.send(res);
         }));
 
@@ -71,7 +73,9 @@ pub trait SpawnBlocking: EthApiTypes + Clone + Send + Sync + 'static {
         R: Send + 'static,
     {
         let this = self.clone();
-        let fut = self.tracing_task_pool().spawn(move || f(this));
+        let db_reads = DatabaseReadStats::current();
+        let fut =
+            self.tracing_task_pool().spawn(move || DatabaseReadStats::scope(db_reads, || f(this)));
         async move { fut.await.map_err(|_| EthApiError::InternalBlockingTaskError)? }
     }
 }
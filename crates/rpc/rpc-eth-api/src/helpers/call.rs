@@ -21,6 +21,7 @@ use reth_evm::{
     ConfigureEvm, ConfigureEvmEnv, Evm, EvmEnv, HaltReasonFor, InspectorFor, SpecFor,
     TransactionEnv,
 };
+use reth_hyperliquid_types::PrecompileOverrides;
 use reth_node_api::BlockBody;
 use reth_primitives::Recovered;
 use reth_primitives_traits::SignedTransaction;
@@ -62,12 +63,15 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
     /// `eth_simulateV1` executes an arbitrary number of transactions on top of the requested state.
     /// The transactions are packed into individual blocks. Overrides can be provided.
     ///
+    /// The given read precompile results override the recorded ones for all simulated blocks.
+    ///
     /// See also: <https://github.com/ethereum/go-ethereum/pull/27720>
     #[allow(clippy::type_complexity)]
     fn simulate_v1(
         &self,
         payload: SimulatePayload,
         block: Option<BlockId>,
+        precompile_overrides: Option<PrecompileOverrides>,
     ) -> impl Future<Output = SimulatedBlocksResult<Self::NetworkTypes, Self::Error>> + Send {
         async move {
             if payload.block_state_calls.len() > self.max_simulate_blocks() as usize {
@@ -100,6 +104,14 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
             // Always disable EIP-3607
             evm_env.cfg_env.disable_eip3607 = true;
 
+            let evm_config = match precompile_overrides {
+                Some(overrides) => self
+                    .evm_config()
+                    .with_precompile_overrides(overrides)
+                    .map_err(|err| EthApiError::InvalidParams(err.to_string()))?,
+                None => self.evm_config().clone(),
+            };
+
             let this = self.clone();
             self.spawn_with_state_at_block(block, move |state| {
                 let mut db = CacheDB::new(StateProviderDatabase::new(state));
@@ -188,7 +200,8 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
 
                         let (res, (_, tx_env)) = {
                             if trace_transfers {
-                                this.transact_with_inspector(
+                                this.transact_with_evm_config_and_inspector(
+                                    &evm_config,
                                     &mut db,
                                     evm_env.clone(),
                                     tx_env,
@@ -198,7 +211,12 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
                                         .with_logs(true),
                                 )?
                             } else {
-                                this.transact(&mut db, evm_env.clone(), tx_env.clone())?
+                                this.transact_with_evm_config(
+                                    &evm_config,
+                                    &mut db,
+                                    evm_env.clone(),
+                                    tx_env.clone(),
+                                )?
                             }
                         };
 
@@ -244,15 +262,31 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
     }
 
     /// Executes the call request (`eth_call`) and returns the output
+    ///
+    /// The given read precompile results override the recorded ones for this call only.
     fn call(
         &self,
         request: TransactionRequest,
         block_number: Option<BlockId>,
         overrides: EvmOverrides,
+        precompile_overrides: Option<PrecompileOverrides>,
     ) -> impl Future<Output = Result<Bytes, Self::Error>> + Send {
         async move {
-            let (res, _env) =
-                self.transact_call_at(request, block_number.unwrap_or_default(), overrides).await?;
+            let at = block_number.unwrap_or_default();
+            let (res, _env) = match precompile_overrides {
+                Some(precompile_overrides) => {
+                    let evm_config = self
+                        .evm_config()
+                        .with_precompile_overrides(precompile_overrides)
+                        .map_err(|err| EthApiError::InvalidParams(err.to_string()))?;
+                    let this = self.clone();
+                    self.spawn_with_call_at(request, at, overrides, move |db, evm_env, tx_env| {
+                        this.transact_with_evm_config(&evm_config, db, evm_env, tx_env)
+                    })
+                    .await?
+                }
+                None => self.transact_call_at(request, at, overrides).await?,
+            };
 
             ensure_success(res.result)
         }
@@ -503,7 +537,29 @@ pub trait Call:
     where
         DB: Database<Error = ProviderError>,
     {
-        let mut evm = self.evm_config().evm_with_env(db, evm_env.clone());
+        self.transact_with_evm_config(self.evm_config(), db, evm_env, tx_env)
+    }
+
+    /// Executes the `TxEnv` against the given [Database] with the given EVM configuration, e.g.
+    /// one with precompile overrides, without committing state changes.
+    #[expect(clippy::type_complexity)]
+    fn transact_with_evm_config<DB>(
+        &self,
+        evm_config: &Self::Evm,
+        db: DB,
+        evm_env: EvmEnv<<Self::Evm as ConfigureEvmEnv>::Spec>,
+        tx_env: <Self::Evm as ConfigureEvmEnv>::TxEnv,
+    ) -> Result<
+        (
+            ResultAndState<HaltReasonFor<Self::Evm>>,
+            (EvmEnv<<Self::Evm as ConfigureEvmEnv>::Spec>, <Self::Evm as ConfigureEvmEnv>::TxEnv),
+        ),
+        Self::Error,
+    >
+    where
+        DB: Database<Error = ProviderError>,
+    {
+        let mut evm = evm_config.evm_with_env(db, evm_env.clone());
         let res = evm.transact(tx_env.clone()).map_err(Self::Error::from_evm_err)?;
 
         Ok((res, (evm_env, tx_env)))
@@ -529,7 +585,37 @@ pub trait Call:
         DB: Database<Error = ProviderError>,
         I: InspectorFor<DB, Self::Evm>,
     {
-        let mut evm = self.evm_config().evm_with_env_and_inspector(db, evm_env.clone(), inspector);
+        self.transact_with_evm_config_and_inspector(
+            self.evm_config(),
+            db,
+            evm_env,
+            tx_env,
+            inspector,
+        )
+    }
+
+    /// Executes the [`EvmEnv`] against the given [Database] with the given EVM configuration
+    /// without committing state changes.
+    #[expect(clippy::type_complexity)]
+    fn transact_with_evm_config_and_inspector<DB, I>(
+        &self,
+        evm_config: &Self::Evm,
+        db: DB,
+        evm_env: EvmEnv<<Self::Evm as ConfigureEvmEnv>::Spec>,
+        tx_env: <Self::Evm as ConfigureEvmEnv>::TxEnv,
+        inspector: I,
+    ) -> Result<
+        (
+            ResultAndState<HaltReasonFor<Self::Evm>>,
+            (EvmEnv<<Self::Evm as ConfigureEvmEnv>::Spec>, <Self::Evm as ConfigureEvmEnv>::TxEnv),
+        ),
+        Self::Error,
+    >
+    where
+        DB: Database<Error = ProviderError>,
+        I: InspectorFor<DB, Self::Evm>,
+    {
+        let mut evm = evm_config.evm_with_env_and_inspector(db, evm_env.clone(), inspector);
         let res = evm.transact(tx_env.clone()).map_err(Self::Error::from_evm_err)?;
 
         Ok((res, (evm_env, tx_env)))
@@ -11,8 +11,8 @@ use reth_chainspec::EthChainSpec;
 use reth_primitives_traits::BlockBody;
 use reth_provider::{BlockIdReader, ChainSpecProvider, HeaderProvider};
 use reth_rpc_eth_types::{
-    fee_history::calculate_reward_percentiles_for_block, EthApiError, FeeHistoryCache,
-    FeeHistoryEntry, GasPriceOracle, RpcInvalidTransactionError,
+    fee_history::{calculate_reward_percentiles_for_block, user_transactions_gas_used},
+    EthApiError, FeeHistoryCache, FeeHistoryEntry, GasPriceOracle, RpcInvalidTransactionError,
 };
 use tracing::debug;
 
@@ -163,8 +163,18 @@ pub trait EthFees: LoadFee {
 
 
                 for header in &headers {
+                    // System transactions are excluded from the gas usage, so the receipts are
+                    // needed either way
+                    let (block, receipts) = self.cache()
+                        .get_block_and_receipts(header.hash())
+                        .await
+                        .map_err(Self::Error::from_eth_err)?
+                        .ok_or(EthApiError::InvalidBlockRange)?;
+                    let user_gas_used =
+                        user_transactions_gas_used(block.body().transactions(), &receipts);
+
                     base_fee_per_gas.push(header.base_fee_per_gas().unwrap_or_default() as u128);
-                    gas_used_ratio.push(header.gas_used() as f64 / header.gas_limit() as f64);
+                    gas_used_ratio.push(user_gas_used as f64 / header.gas_limit() as f64);
 
                     let blob_params = self.provider()
                         .chain_spec()
@@ -179,15 +189,10 @@ pub trait EthFees: LoadFee {
 
                     // Percentiles were specified, so we need to collect reward percentile ino
                     if let Some(percentiles) = &reward_percentiles {
-                        let (block, receipts) = self.cache()
-                            .get_block_and_receipts(header.hash())
-                            .await
-                            .map_err(Self::Error::from_eth_err)?
-                            .ok_or(EthApiError::InvalidBlockRange)?;
                         rewards.push(
                             calculate_reward_percentiles_for_block(
                                 percentiles,
-                                header.gas_used(),
+                                user_gas_used,
                                 header.base_fee_per_gas().unwrap_or_default(),
                                 block.body().transactions(),
                                 &receipts,
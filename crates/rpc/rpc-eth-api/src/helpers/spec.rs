@@ -74,10 +74,17 @@ pub trait EthApiSpec:
                 .map(|(name, checkpoint)| Stage { name, block: checkpoint.block_number })
                 .collect();
 
+            // the highest block reported by the sync source, e.g. the tip of the block archive
+            let highest_block = self
+                .network()
+                .highest_block()
+                .map(U256::from)
+                .map_or(current_block, |highest| highest.max(current_block));
+
             SyncStatus::Info(Box::new(SyncInfo {
                 starting_block: self.starting_block(),
                 current_block,
-                highest_block: current_block,
+                highest_block,
                 warp_chunks_amount: None,
                 warp_chunks_processed: None,
                 stages: Some(stages),
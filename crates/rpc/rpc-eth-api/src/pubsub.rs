@@ -1,13 +1,18 @@
 //! `eth_` RPC API for pubsub subscription.
 
 use alloy_json_rpc::RpcObject;
-use alloy_rpc_types_eth::pubsub::{Params, SubscriptionKind};
 use jsonrpsee::proc_macros::rpc;
+use reth_rpc_eth_types::EthSubscriptionKind;
 
 /// Ethereum pub-sub rpc interface.
 #[rpc(server, namespace = "eth")]
 pub trait EthPubSubApi<T: RpcObject> {
     /// Create an ethereum subscription for the given params
+    ///
+    /// The params depend on the subscription kind: a log filter or a boolean for the standard
+    /// subscriptions, see [`Params`](alloy_rpc_types_eth::pubsub::Params), and
+    /// [`BlocksWithReceiptsParams`](reth_rpc_eth_types::BlocksWithReceiptsParams) for
+    /// [`EthSubscriptionKind::NewBlocksWithReceipts`].
     #[subscription(
         name = "subscribe" => "subscription",
         unsubscribe = "unsubscribe",
@@ -15,7 +20,7 @@ pub trait EthPubSubApi<T: RpcObject> {
     )]
     async fn subscribe(
         &self,
-        kind: SubscriptionKind,
-        params: Option<Params>,
+        kind: EthSubscriptionKind,
+        params: Option<serde_json::Value>,
     ) -> jsonrpsee::core::SubscriptionResult;
 }
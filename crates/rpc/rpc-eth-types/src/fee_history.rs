@@ -18,7 +18,9 @@ use metrics::atomics::AtomicU64;
 use reth_chain_state::CanonStateNotification;
 use reth_chainspec::{ChainSpecProvider, EthChainSpec};
 use reth_primitives::{NodePrimitives, SealedBlock};
-use reth_primitives_traits::{Block, BlockBody};
+use reth_primitives_traits::{
+    transaction::signed::is_impersonated_tx, Block, BlockBody, SignedTransaction,
+};
 use reth_rpc_server_types::constants::gas_oracle::MAX_HEADER_HISTORY;
 use reth_storage_api::BlockReaderIdExt;
 use serde::{Deserialize, Serialize};
@@ -88,9 +90,12 @@ impl FeeHistoryCache {
                 block,
                 chain_spec.blob_params_at_timestamp(block.header().timestamp()),
             );
+            let user_gas_used = user_transactions_gas_used(block.body().transactions(), &receipts);
+            fee_history_entry.gas_used_ratio =
+                user_gas_used as f64 / fee_history_entry.gas_limit as f64;
             fee_history_entry.rewards = calculate_reward_percentiles_for_block(
                 &percentiles,
-                fee_history_entry.gas_used,
+                user_gas_used,
                 fee_history_entry.base_fee_per_gas,
                 block.body().transactions(),
                 &receipts,
@@ -268,23 +273,17 @@ pub async fn fee_history_cache_new_blocks_task<St, Provider, N>(
     }
 }
 
-/// Calculates reward percentiles for transactions in a block header.
-/// Given a list of percentiles and a sealed block header, this function computes
-/// the corresponding rewards for the transactions at each percentile.
-///
-/// The results are returned as a vector of U256 values.
-pub fn calculate_reward_percentiles_for_block<T, R>(
-    percentiles: &[f64],
-    gas_used: u64,
-    base_fee_per_gas: u64,
-    transactions: &[T],
-    receipts: &[R],
-) -> Result<Vec<u128>, EthApiError>
+/// Returns the user transactions of a block, i.e. all but the Hyperliquid system transactions,
+/// along with the gas they used.
+fn user_transactions_with_gas_used<'a, T, R>(
+    transactions: &'a [T],
+    receipts: &'a [R],
+) -> impl Iterator<Item = (&'a T, u64)> + 'a
 where
-    T: Transaction,
+    T: SignedTransaction,
     R: TxReceipt,
 {
-    let mut transactions = transactions
+    transactions
         .iter()
         .zip(receipts)
         .scan(0, |previous_gas, (tx, receipt)| {
@@ -297,10 +296,44 @@ where
             let gas_used = receipt.cumulative_gas_used() - *previous_gas;
             *previous_gas = receipt.cumulative_gas_used();
 
-            Some(TxGasAndReward {
-                gas_used,
-                reward: tx.effective_tip_per_gas(base_fee_per_gas).unwrap_or_default(),
-            })
+            Some((tx, gas_used))
+        })
+        .filter(|(tx, _)| is_impersonated_tx(tx.signature(), tx.gas_price()).is_none())
+}
+
+/// Returns the gas used by the user transactions of a block, excluding Hyperliquid system
+/// transactions.
+pub fn user_transactions_gas_used<T, R>(transactions: &[T], receipts: &[R]) -> u64
+where
+    T: SignedTransaction,
+    R: TxReceipt,
+{
+    user_transactions_with_gas_used(transactions, receipts).map(|(_, gas_used)| gas_used).sum()
+}
+
+/// Calculates reward percentiles for transactions in a block header.
+/// Given a list of percentiles and a sealed block header, this function computes
+/// the corresponding rewards for the transactions at each percentile.
+///
+/// Hyperliquid system transactions are ignored, so `gas_used` is expected to be the gas used by
+/// the user transactions, see [`user_transactions_gas_used`].
+///
+/// The results are returned as a vector of U256 values.
+pub fn calculate_reward_percentiles_for_block<T, R>(
+    percentiles: &[f64],
+    gas_used: u64,
+    base_fee_per_gas: u64,
+    transactions: &[T],
+    receipts: &[R],
+) -> Result<Vec<u128>, EthApiError>
+where
+    T: SignedTransaction,
+    R: TxReceipt,
+{
+    let mut transactions = user_transactions_with_gas_used(transactions, receipts)
+        .map(|(tx, gas_used)| TxGasAndReward {
+            gas_used,
+            reward: tx.effective_tip_per_gas(base_fee_per_gas).unwrap_or_default(),
         })
         .collect::<Vec<_>>();
 
@@ -338,6 +371,8 @@ pub struct FeeHistoryEntry {
     /// The base fee per gas for this block.
     pub base_fee_per_gas: u64,
     /// Gas used ratio this block.
+    ///
+    /// Only accounts for the gas used by user transactions once the rewards are calculated.
     pub gas_used_ratio: f64,
     /// The base per blob gas for EIP-4844.
     /// For pre EIP-4844 equals to zero.
@@ -420,3 +455,71 @@ impl FeeHistoryEntry {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::TxLegacy;
+    use alloy_primitives::{PrimitiveSignature as Signature, U256};
+    use reth_primitives::{Receipt, Transaction, TransactionSigned, TxType};
+
+    fn legacy_tx(gas_price: u128, signature: Signature) -> TransactionSigned {
+        TransactionSigned::new_unhashed(
+            Transaction::Legacy(TxLegacy { gas_price, gas_limit: 100_000, ..Default::default() }),
+            signature,
+        )
+    }
+
+    fn receipt(cumulative_gas_used: u64) -> Receipt {
+        Receipt { tx_type: TxType::Legacy, success: true, cumulative_gas_used, logs: vec![] }
+    }
+
+    /// A block starting with a system transaction followed by two user transactions, along with
+    /// their receipts.
+    fn block_with_system_tx() -> (Vec<TransactionSigned>, Vec<Receipt>) {
+        let user_signature = Signature::new(U256::from(2), U256::from(3), false);
+        let transactions = vec![
+            legacy_tx(0, Signature::new(U256::from(1), U256::from(1), true)),
+            legacy_tx(10, user_signature),
+            legacy_tx(3, user_signature),
+        ];
+        // system transactions don't add to the cumulative gas used
+        let receipts = vec![receipt(0), receipt(21_000), receipt(51_000)];
+        (transactions, receipts)
+    }
+
+    #[test]
+    fn user_transactions_gas_used_skips_system_transactions() {
+        let (transactions, receipts) = block_with_system_tx();
+        assert_eq!(user_transactions_gas_used(&transactions, &receipts), 51_000);
+        assert_eq!(user_transactions_gas_used(&transactions[..1], &receipts[..1]), 0);
+    }
+
+    #[test]
+    fn reward_percentiles_skip_system_transactions() {
+        let (transactions, receipts) = block_with_system_tx();
+        let gas_used = user_transactions_gas_used(&transactions, &receipts);
+
+        // the system transaction would report a zero reward for the lowest percentile
+        let rewards = calculate_reward_percentiles_for_block(
+            &[0., 50., 100.],
+            gas_used,
+            1,
+            &transactions,
+            &receipts,
+        )
+        .unwrap();
+        assert_eq!(rewards, vec![2, 2, 9]);
+
+        // blocks with system transactions only are treated as empty
+        let rewards = calculate_reward_percentiles_for_block(
+            &[0., 50., 100.],
+            0,
+            1,
+            &transactions[..1],
+            &receipts[..1],
+        )
+        .unwrap();
+        assert_eq!(rewards, vec![0, 0, 0]);
+    }
+}
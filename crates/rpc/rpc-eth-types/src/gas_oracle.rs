@@ -3,11 +3,13 @@
 
 use alloy_consensus::{constants::GWEI_TO_WEI, BlockHeader, Transaction};
 use alloy_eips::BlockNumberOrTag;
-use alloy_primitives::{B256, U256};
+use alloy_primitives::{Address, B256, U256};
 use alloy_rpc_types_eth::BlockId;
 use derive_more::{Deref, DerefMut, From, Into};
 use itertools::Itertools;
-use reth_primitives_traits::{BlockBody, SignedTransaction};
+use reth_primitives_traits::{
+    transaction::signed::is_impersonated_tx, BlockBody, SignedTransaction,
+};
 use reth_rpc_server_types::{
     constants,
     constants::gas_oracle::{
@@ -222,11 +224,39 @@ where
             return Ok(None)
         };
 
-        let base_fee_per_gas = block.base_fee_per_gas();
         let parent_hash = block.parent_hash();
+        let prices = effective_tip_values(
+            block.body().transactions_iter(),
+            block.base_fee_per_gas(),
+            block.beneficiary(),
+            self.ignore_price,
+            limit,
+        )?;
+
+        Ok(Some((parent_hash, prices)))
+    }
+}
 
-        // sort the functions by ascending effective tip first
-        let sorted_transactions = block.body().transactions_iter().sorted_by_cached_key(|tx| {
+/// Returns up to `limit` effective tips of the given transactions in ascending order.
+///
+/// Hyperliquid system transactions, transactions sent by the `beneficiary` and transactions with a
+/// tip under `ignore_price` are skipped.
+fn effective_tip_values<'a, T>(
+    transactions: impl IntoIterator<Item = &'a T>,
+    base_fee_per_gas: Option<u64>,
+    beneficiary: Address,
+    ignore_price: Option<u128>,
+    limit: usize,
+) -> EthResult<Vec<U256>>
+where
+    T: SignedTransaction + 'a,
+{
+    // sort the functions by ascending effective tip first
+    let sorted_transactions = transactions
+        .into_iter()
+        // system transactions don't pay for gas
+        .filter(|tx| is_impersonated_tx(tx.signature(), tx.gas_price()).is_none())
+        .sorted_by_cached_key(|tx| {
             if let Some(base_fee) = base_fee_per_gas {
                 (*tx).effective_tip_per_gas(base_fee)
             } else {
@@ -234,41 +264,40 @@ where
             }
         });
 
-        let mut prices = Vec::with_capacity(limit);
+    let mut prices = Vec::with_capacity(limit);
 
-        for tx in sorted_transactions {
-            let effective_tip = if let Some(base_fee) = base_fee_per_gas {
-                tx.effective_tip_per_gas(base_fee)
-            } else {
-                Some(tx.priority_fee_or_price())
-            };
-
-            // ignore transactions with a tip under the configured threshold
-            if let Some(ignore_under) = self.ignore_price {
-                if effective_tip < Some(ignore_under) {
-                    continue
-                }
-            }
+    for tx in sorted_transactions {
+        let effective_tip = if let Some(base_fee) = base_fee_per_gas {
+            tx.effective_tip_per_gas(base_fee)
+        } else {
+            Some(tx.priority_fee_or_price())
+        };
 
-            // check if the sender was the coinbase, if so, ignore
-            if let Ok(sender) = tx.recover_signer() {
-                if sender == block.beneficiary() {
-                    continue
-                }
+        // ignore transactions with a tip under the configured threshold
+        if let Some(ignore_under) = ignore_price {
+            if effective_tip < Some(ignore_under) {
+                continue
             }
+        }
 
-            // a `None` effective_gas_tip represents a transaction where the max_fee_per_gas is
-            // less than the base fee which would be invalid
-            prices.push(U256::from(effective_tip.ok_or(RpcInvalidTransactionError::FeeCapTooLow)?));
-
-            // we have enough entries
-            if prices.len() >= limit {
-                break
+        // check if the sender was the coinbase, if so, ignore
+        if let Ok(sender) = tx.recover_signer() {
+            if sender == beneficiary {
+                continue
             }
         }
 
-        Ok(Some((parent_hash, prices)))
+        // a `None` effective_gas_tip represents a transaction where the max_fee_per_gas is
+        // less than the base fee which would be invalid
+        prices.push(U256::from(effective_tip.ok_or(RpcInvalidTransactionError::FeeCapTooLow)?));
+
+        // we have enough entries
+        if prices.len() >= limit {
+            break
+        }
     }
+
+    Ok(prices)
 }
 
 /// Container type for mutable inner state of the [`GasPriceOracle`]
@@ -319,6 +348,9 @@ impl Default for GasCap {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_consensus::TxLegacy;
+    use alloy_primitives::PrimitiveSignature as Signature;
+    use reth_primitives::{Transaction, TransactionSigned};
 
     #[test]
     fn max_price_sanity() {
@@ -330,4 +362,26 @@ mod tests {
     fn ignore_price_sanity() {
         assert_eq!(DEFAULT_IGNORE_GAS_PRICE, U256::from(2u64));
     }
+
+    #[test]
+    fn effective_tip_values_skip_system_transactions() {
+        let tx = |gas_price, signature| {
+            TransactionSigned::new_unhashed(
+                Transaction::Legacy(TxLegacy { gas_price, ..Default::default() }),
+                signature,
+            )
+        };
+        let user_signature = Signature::new(U256::from(2), U256::from(3), false);
+        let transactions = [
+            tx(0, Signature::new(U256::from(1), U256::from(1), true)),
+            tx(10, user_signature),
+            tx(3, user_signature),
+        ];
+
+        // without skipping it, the system transaction would be rejected for its fee cap
+        let prices =
+            effective_tip_values(transactions.iter(), Some(1), Address::ZERO, None, SAMPLE_NUMBER)
+                .unwrap();
+        assert_eq!(prices, vec![U256::from(2), U256::from(9)]);
+    }
 }
@@ -16,9 +16,11 @@ pub mod gas_oracle;
 pub mod id_provider;
 pub mod logs_utils;
 pub mod pending_block;
+pub mod pubsub;
 pub mod receipt;
 pub mod revm_utils;
 pub mod simulate;
+pub mod trace_cache;
 pub mod transaction;
 pub mod utils;
 
@@ -37,5 +39,7 @@ pub use gas_oracle::{
 };
 pub use id_provider::EthSubscriptionIdProvider;
 pub use pending_block::{PendingBlock, PendingBlockEnv, PendingBlockEnvOrigin};
+pub use pubsub::{BlockWithReceipts, BlocksWithReceiptsParams, EthSubscriptionKind};
 pub use receipt::EthReceiptBuilder;
+pub use trace_cache::{TraceCache, TraceCacheConfig, TraceCacheKey};
 pub use transaction::TransactionSource;
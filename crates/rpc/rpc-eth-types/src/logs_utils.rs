@@ -79,12 +79,67 @@ pub fn append_matching_block_logs<P>(
     removed: bool,
     block_timestamp: u64,
 ) -> Result<(), ProviderError>
+where
+    P: BlockReader<Transaction: SignedTransaction>,
+{
+    append_block_logs_where(
+        all_logs,
+        provider_or_block,
+        block_num_hash,
+        receipts,
+        removed,
+        block_timestamp,
+        |_, log| log_matches_filter(block_num_hash, log, filter),
+    )
+}
+
+/// Appends the logs of a block's receipts at the given positions, as recorded by the log index.
+///
+/// A position is the index of a log among all logs of the block, including the logs of system
+/// transactions. The positions must be sorted in ascending order.
+pub fn append_indexed_block_logs<P>(
+    all_logs: &mut Vec<Log>,
+    provider_or_block: ProviderOrBlock<'_, P>,
+    positions: &[u64],
+    block_num_hash: BlockNumHash,
+    receipts: &[P::Receipt],
+    removed: bool,
+    block_timestamp: u64,
+) -> Result<(), ProviderError>
+where
+    P: BlockReader<Transaction: SignedTransaction>,
+{
+    append_block_logs_where(
+        all_logs,
+        provider_or_block,
+        block_num_hash,
+        receipts,
+        removed,
+        block_timestamp,
+        |position, _| positions.binary_search(&position).is_ok(),
+    )
+}
+
+/// Appends the logs of a block's receipts for which `matches` returns `true`, given the position
+/// of the log among all logs of the block and the log.
+fn append_block_logs_where<P>(
+    all_logs: &mut Vec<Log>,
+    provider_or_block: ProviderOrBlock<'_, P>,
+    block_num_hash: BlockNumHash,
+    receipts: &[P::Receipt],
+    removed: bool,
+    block_timestamp: u64,
+    mut matches: impl FnMut(u64, &alloy_primitives::Log) -> bool,
+) -> Result<(), ProviderError>
 where
     P: BlockReader<Transaction: SignedTransaction>,
 {
     // Tracks the index of a log in the entire block.
     let mut log_index: u64 = 0;
 
+    // Tracks the position of a log in the entire block, including the logs that are skipped.
+    let mut position: u64 = 0;
+
     // Lazy loaded number of the first transaction in the block.
     // This is useful for blocks with multiple matching logs because it
     // prevents re-querying the block body indices.
@@ -98,11 +153,13 @@ where
         let mut transaction_hash = None;
 
         for log in receipt.logs() {
+            let log_position = position;
+            position += 1;
             if is_in_hl_node_compliant_mode && receipt.cumulative_gas_used() == 0 {
                 continue;
             }
 
-            if log_matches_filter(block_num_hash, log, filter) {
+            if matches(log_position, log) {
                 // if this is the first match in the receipt's logs, look up the transaction hash
                 if transaction_hash.is_none() {
                     transaction_hash = match &provider_or_block {
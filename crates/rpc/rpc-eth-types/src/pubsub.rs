@@ -0,0 +1,120 @@
+//! Types of the `eth_subscribe` subscriptions.
+
+use alloy_primitives::U64;
+use alloy_rpc_types_eth::pubsub::SubscriptionKind;
+use alloy_rpc_types_trace::parity::LocalizedTransactionTrace;
+use serde::{Deserialize, Serialize};
+
+/// Subscription kind of `eth_subscribe`.
+///
+/// Extends the standard [`SubscriptionKind`]s with
+/// [`NewBlocksWithReceipts`](EthSubscriptionKind::NewBlocksWithReceipts).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EthSubscriptionKind {
+    /// New block headers, see [`SubscriptionKind::NewHeads`].
+    NewHeads,
+    /// Logs matching a filter, see [`SubscriptionKind::Logs`].
+    Logs,
+    /// New pending transactions, see [`SubscriptionKind::NewPendingTransactions`].
+    NewPendingTransactions,
+    /// Sync status changes, see [`SubscriptionKind::Syncing`].
+    Syncing,
+    /// New canonical blocks with all their transactions and receipts, and optionally their call
+    /// traces.
+    ///
+    /// Every block is sent as one [`BlockWithReceipts`] message. Blocks that are reorged out are
+    /// sent again with `removed` set, tip first, before the blocks of the new chain.
+    ///
+    /// Accepts [`BlocksWithReceiptsParams`] as params.
+    NewBlocksWithReceipts,
+}
+
+impl EthSubscriptionKind {
+    /// Returns the standard [`SubscriptionKind`], or `None` for a reth specific kind.
+    pub const fn as_standard(&self) -> Option<SubscriptionKind> {
+        match self {
+            Self::NewHeads => Some(SubscriptionKind::NewHeads),
+            Self::Logs => Some(SubscriptionKind::Logs),
+            Self::NewPendingTransactions => Some(SubscriptionKind::NewPendingTransactions),
+            Self::Syncing => Some(SubscriptionKind::Syncing),
+            Self::NewBlocksWithReceipts => None,
+        }
+    }
+}
+
+impl From<SubscriptionKind> for EthSubscriptionKind {
+    fn from(kind: SubscriptionKind) -> Self {
+        match kind {
+            SubscriptionKind::NewHeads => Self::NewHeads,
+            SubscriptionKind::Logs => Self::Logs,
+            SubscriptionKind::NewPendingTransactions => Self::NewPendingTransactions,
+            SubscriptionKind::Syncing => Self::Syncing,
+        }
+    }
+}
+
+/// Params of the [`NewBlocksWithReceipts`](EthSubscriptionKind::NewBlocksWithReceipts)
+/// subscription.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BlocksWithReceiptsParams {
+    /// Whether to include the call traces of the transactions of each block.
+    #[serde(default)]
+    pub include_traces: bool,
+    /// First block to send.
+    ///
+    /// If set, all canonical blocks starting at this block are sent before the new blocks, so
+    /// that a client can resume the subscription after reconnecting. Subscriptions can be resumed
+    /// from at most 10000 blocks behind the chain tip, or 100 blocks if traces are included.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_block: Option<U64>,
+}
+
+/// Message of the [`NewBlocksWithReceipts`](EthSubscriptionKind::NewBlocksWithReceipts)
+/// subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockWithReceipts<B, R> {
+    /// The block, with full transaction objects.
+    pub block: B,
+    /// The receipts of all transactions of the block.
+    pub receipts: Vec<R>,
+    /// The call traces of all transactions of the block, if requested.
+    ///
+    /// Not set for removed blocks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub traces: Option<Vec<LocalizedTransactionTrace>>,
+    /// Whether the block was removed from the canonical chain by a reorg.
+    pub removed: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_kind() {
+        let kind: EthSubscriptionKind = serde_json::from_str(r#""newBlocksWithReceipts""#).unwrap();
+        assert_eq!(kind, EthSubscriptionKind::NewBlocksWithReceipts);
+
+        let kind: EthSubscriptionKind =
+            serde_json::from_str(r#""newPendingTransactions""#).unwrap();
+        assert_eq!(kind, SubscriptionKind::NewPendingTransactions.into());
+    }
+
+    #[test]
+    fn deserialize_params() {
+        let params: BlocksWithReceiptsParams =
+            serde_json::from_str(r#"{"includeTraces":true,"fromBlock":"0x10"}"#).unwrap();
+        assert_eq!(
+            params,
+            BlocksWithReceiptsParams { include_traces: true, from_block: Some(U64::from(16)) }
+        );
+
+        let params: BlocksWithReceiptsParams = serde_json::from_str("{}").unwrap();
+        assert_eq!(params, BlocksWithReceiptsParams::default());
+
+        assert!(serde_json::from_str::<BlocksWithReceiptsParams>(r#"{"address":"0x"}"#).is_err());
+    }
+}
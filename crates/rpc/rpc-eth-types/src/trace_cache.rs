@@ -0,0 +1,482 @@
+//! Disk-backed cache of the results of historical trace calls.
+//!
+//! Blocks are final once they're ingested, so tracing a block with the same tracer config always
+//! returns the same result. Results are stored as JSON in `<dir>/<block_hash>/<key>.json` and the
+//! least recently used ones are evicted once the cache exceeds its maximum size. The results of
+//! unwound blocks are removed, see [`trace_cache_invalidation_task`].
+
+use alloy_eips::{BlockId, BlockNumberOrTag};
+use alloy_primitives::{hex, keccak256, B256};
+use futures::{Stream, StreamExt};
+use parking_lot::Mutex;
+use reth_chain_state::CanonStateNotification;
+use reth_errors::ProviderResult;
+use reth_metrics::{
+    metrics::{Counter, Gauge},
+    Metrics,
+};
+use reth_primitives::NodePrimitives;
+use reth_storage_api::BlockIdReader;
+use schnellru::{LruMap, Unlimited};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::{hash_map::Entry, HashMap, HashSet},
+    ffi::OsStr,
+    fs,
+    future::Future,
+    io,
+    path::PathBuf,
+    sync::Arc,
+    time::SystemTime,
+};
+use tracing::{debug, warn};
+
+/// Settings for the [`TraceCache`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceCacheConfig {
+    /// Directory the cached results are stored in.
+    pub dir: PathBuf,
+    /// Maximum total size of the cached results in bytes.
+    pub max_size: u64,
+}
+
+/// Identifies a cached result by the traced block and the call that produced it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TraceCacheKey {
+    /// Hash of the traced block.
+    block_hash: B256,
+    /// Hash of the method and the parameters of the call.
+    call: B256,
+}
+
+impl TraceCacheKey {
+    /// Creates the key of a call to `method` with `params` that traces the given block.
+    ///
+    /// The keys of JSON objects in the parameters are sorted, so the key doesn't depend on the
+    /// order of the fields in the request.
+    pub fn new(
+        block_hash: B256,
+        method: &str,
+        params: &impl Serialize,
+    ) -> Result<Self, serde_json::Error> {
+        let params = sort_keys(serde_json::to_value(params)?);
+        let call = keccak256(serde_json::to_vec(&(method, params))?);
+        Ok(Self { block_hash, call })
+    }
+
+    /// Creates the key of a call to `method` with `params` that traces the given block.
+    ///
+    /// Returns `None` if the block isn't stored, e.g. for the pending block or an unknown hash.
+    pub fn for_block<P: BlockIdReader>(
+        provider: &P,
+        block_id: BlockId,
+        method: &str,
+        params: &impl Serialize,
+    ) -> ProviderResult<Option<Self>> {
+        let block_hash = match block_id {
+            BlockId::Number(BlockNumberOrTag::Pending) => return Ok(None),
+            BlockId::Hash(hash) => provider.block_number(hash.block_hash)?.map(|_| hash.block_hash),
+            BlockId::Number(_) => provider.block_hash_for_id(block_id)?,
+        };
+        Ok(block_hash.and_then(|block_hash| Self::new(block_hash, method, params).ok()))
+    }
+
+    /// Returns the hash of the traced block.
+    pub const fn block_hash(&self) -> B256 {
+        self.block_hash
+    }
+}
+
+/// Returns the value with the keys of all objects sorted.
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries =
+                map.into_iter().map(|(key, value)| (key, sort_keys(value))).collect::<Vec<_>>();
+            entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(entries.into_iter().collect())
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
+
+/// Disk-backed cache of trace results, see the [module docs](self).
+///
+/// This type is cheap to clone.
+#[derive(Clone)]
+pub struct TraceCache {
+    inner: Arc<TraceCacheInner>,
+}
+
+impl TraceCache {
+    /// Opens the cache in the configured directory and indexes the results stored by a previous
+    /// run.
+    pub fn open(config: &TraceCacheConfig) -> io::Result<Self> {
+        fs::create_dir_all(&config.dir)?;
+
+        let mut stored = Vec::new();
+        for block_dir in fs::read_dir(&config.dir)? {
+            let block_dir = block_dir?;
+            let Some(block_hash) = parse_hash(&block_dir.file_name()) else { continue };
+            for file in fs::read_dir(block_dir.path())? {
+                let path = file?.path();
+                if path.extension() != Some(OsStr::new("json")) {
+                    // leftover of an interrupted write
+                    fs::remove_file(&path)?;
+                    continue
+                }
+                let Some(call) = path.file_stem().and_then(parse_hash) else { continue };
+                let metadata = fs::metadata(&path)?;
+                let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                stored.push((modified, TraceCacheKey { block_hash, call }, metadata.len()));
+            }
+        }
+
+        // the most recently written results are the last to be evicted
+        stored.sort_unstable_by_key(|(modified, _, _)| *modified);
+        let mut index = TraceCacheIndex::default();
+        for (_, key, size) in stored {
+            index.insert(key, size);
+        }
+
+        let cache = Self {
+            inner: Arc::new(TraceCacheInner {
+                dir: config.dir.clone(),
+                max_size: config.max_size,
+                index: Mutex::new(index),
+                metrics: TraceCacheMetrics::default(),
+            }),
+        };
+        cache.evict();
+        Ok(cache)
+    }
+
+    /// Returns the cached result for the key, if any.
+    pub async fn get<T>(&self, key: TraceCacheKey) -> Option<T>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        if self.inner.index.lock().entries.get(&key).is_none() {
+            self.inner.metrics.misses_total.increment(1);
+            return None
+        }
+
+        let path = self.entry_path(key);
+        let value = tokio::task::spawn_blocking(move || {
+            let data = fs::read(path)?;
+            Ok::<T, io::Error>(serde_json::from_slice(&data)?)
+        })
+        .await
+        .unwrap_or_else(|err| Err(io::Error::other(err)));
+
+        match value {
+            Ok(value) => {
+                self.inner.metrics.hits_total.increment(1);
+                Some(value)
+            }
+            Err(err) => {
+                debug!(target: "rpc::trace_cache", ?key, %err, "Failed to read cached trace");
+                self.inner.metrics.misses_total.increment(1);
+                self.remove(key);
+                None
+            }
+        }
+    }
+
+    /// Caches the result for the key.
+    ///
+    /// The result is written on a blocking task, so it's not returned by [`Self::get`] right away.
+    pub fn insert<T: Serialize>(&self, key: TraceCacheKey, value: &T) {
+        let data = match serde_json::to_vec(value) {
+            Ok(data) => data,
+            Err(err) => {
+                warn!(target: "rpc::trace_cache", ?key, %err, "Failed to serialize trace");
+                return
+            }
+        };
+        if data.len() as u64 > self.inner.max_size {
+            return
+        }
+
+        let this = self.clone();
+        tokio::task::spawn_blocking(move || {
+            if let Err(err) = this.write(key, &data) {
+                warn!(target: "rpc::trace_cache", ?key, %err, "Failed to cache trace");
+            }
+        });
+    }
+
+    /// Returns the cached result for the key, or awaits `f` and caches its result if it succeeds.
+    pub async fn get_or_insert_with<T, E, F>(&self, key: TraceCacheKey, f: F) -> Result<T, E>
+    where
+        T: Serialize + DeserializeOwned + Send + 'static,
+        F: Future<Output = Result<T, E>>,
+    {
+        if let Some(value) = self.get(key).await {
+            return Ok(value)
+        }
+        let value = f.await?;
+        self.insert(key, &value);
+        Ok(value)
+    }
+
+    /// Removes the cached results of the given blocks.
+    pub fn invalidate(&self, block_hashes: impl IntoIterator<Item = B256>) {
+        for block_hash in block_hashes {
+            let removed = {
+                let mut index = self.inner.index.lock();
+                let removed = index.remove_block(block_hash);
+                self.inner.metrics.record_size(&index);
+                removed
+            };
+            if removed {
+                debug!(target: "rpc::trace_cache", %block_hash, "Removing traces of unwound block");
+                self.inner.metrics.invalidated_blocks_total.increment(1);
+                if let Err(err) = fs::remove_dir_all(self.block_dir(block_hash)) {
+                    warn!(target: "rpc::trace_cache", %block_hash, %err, "Failed to remove traces");
+                }
+            }
+        }
+    }
+
+    /// Writes the result to disk and evicts results until the cache is within its maximum size.
+    fn write(&self, key: TraceCacheKey, data: &[u8]) -> io::Result<()> {
+        let path = self.entry_path(key);
+        fs::create_dir_all(self.block_dir(key.block_hash))?;
+        // write to a temporary file first, so interrupted writes don't leave partial results
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, data)?;
+        fs::rename(&tmp_path, &path)?;
+
+        {
+            let mut index = self.inner.index.lock();
+            index.insert(key, data.len() as u64);
+            self.inner.metrics.record_size(&index);
+        }
+        self.inner.metrics.inserts_total.increment(1);
+
+        self.evict();
+        Ok(())
+    }
+
+    /// Removes a single result.
+    fn remove(&self, key: TraceCacheKey) {
+        {
+            let mut index = self.inner.index.lock();
+            index.remove(&key);
+            self.inner.metrics.record_size(&index);
+        }
+        self.remove_file(key);
+    }
+
+    /// Removes the least recently used results until the cache is within its maximum size.
+    fn evict(&self) {
+        loop {
+            let key = {
+                let mut index = self.inner.index.lock();
+                let key = (index.size > self.inner.max_size).then(|| index.pop_oldest()).flatten();
+                self.inner.metrics.record_size(&index);
+                key
+            };
+            let Some(key) = key else { break };
+            self.inner.metrics.evictions_total.increment(1);
+            self.remove_file(key);
+        }
+    }
+
+    /// Removes the file of the result, and the directory of its block if it's empty.
+    fn remove_file(&self, key: TraceCacheKey) {
+        let _ = fs::remove_file(self.entry_path(key));
+        // fails if there are other results of the block
+        let _ = fs::remove_dir(self.block_dir(key.block_hash));
+    }
+
+    /// Returns the directory the results of the block are stored in.
+    fn block_dir(&self, block_hash: B256) -> PathBuf {
+        self.inner.dir.join(hex::encode(block_hash))
+    }
+
+    /// Returns the path of the file the result is stored in.
+    fn entry_path(&self, key: TraceCacheKey) -> PathBuf {
+        self.block_dir(key.block_hash).join(format!("{}.json", hex::encode(key.call)))
+    }
+}
+
+impl std::fmt::Debug for TraceCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TraceCache")
+            .field("dir", &self.inner.dir)
+            .field("max_size", &self.inner.max_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Parses a hex encoded hash from a file name.
+fn parse_hash(name: &OsStr) -> Option<B256> {
+    name.to_str()?.parse().ok()
+}
+
+struct TraceCacheInner {
+    /// Directory the results are stored in.
+    dir: PathBuf,
+    /// Maximum total size of the results in bytes.
+    max_size: u64,
+    /// Index of the stored results.
+    index: Mutex<TraceCacheIndex>,
+    /// Cache metrics.
+    metrics: TraceCacheMetrics,
+}
+
+/// In-memory index of the stored results.
+struct TraceCacheIndex {
+    /// Size of each result, least recently used first.
+    entries: LruMap<TraceCacheKey, u64, Unlimited>,
+    /// Calls with a stored result, by block.
+    blocks: HashMap<B256, HashSet<B256>>,
+    /// Total size of the results in bytes.
+    size: u64,
+}
+
+impl Default for TraceCacheIndex {
+    fn default() -> Self {
+        Self { entries: LruMap::new(Unlimited), blocks: HashMap::default(), size: 0 }
+    }
+}
+
+impl TraceCacheIndex {
+    /// Adds the result, replacing a previous result for the same key.
+    fn insert(&mut self, key: TraceCacheKey, size: u64) {
+        self.remove(&key);
+        self.entries.insert(key, size);
+        self.blocks.entry(key.block_hash).or_default().insert(key.call);
+        self.size += size;
+    }
+
+    /// Removes the result.
+    fn remove(&mut self, key: &TraceCacheKey) {
+        if let Some(size) = self.entries.remove(key) {
+            self.forget(key, size);
+        }
+    }
+
+    /// Removes the least recently used result.
+    fn pop_oldest(&mut self) -> Option<TraceCacheKey> {
+        let (key, size) = self.entries.pop_oldest()?;
+        self.forget(&key, size);
+        Some(key)
+    }
+
+    /// Removes all results of the block, returns false if there were none.
+    fn remove_block(&mut self, block_hash: B256) -> bool {
+        let Some(calls) = self.blocks.remove(&block_hash) else { return false };
+        for call in calls {
+            if let Some(size) = self.entries.remove(&TraceCacheKey { block_hash, call }) {
+                self.size -= size;
+            }
+        }
+        true
+    }
+
+    /// Updates the total size and the calls of the block after a result was removed.
+    fn forget(&mut self, key: &TraceCacheKey, size: u64) {
+        self.size -= size;
+        if let Entry::Occupied(mut calls) = self.blocks.entry(key.block_hash) {
+            calls.get_mut().remove(&key.call);
+            if calls.get().is_empty() {
+                calls.remove();
+            }
+        }
+    }
+}
+
+/// Removes the cached results of the blocks that are unwound.
+pub async fn trace_cache_invalidation_task<St, N: NodePrimitives>(cache: TraceCache, mut events: St)
+where
+    St: Stream<Item = CanonStateNotification<N>> + Unpin + 'static,
+{
+    while let Some(event) = events.next().await {
+        if let Some(reverted) = event.reverted() {
+            cache.invalidate(reverted.blocks_iter().map(|block| block.hash()));
+        }
+    }
+}
+
+/// Metrics of the [`TraceCache`].
+#[derive(Metrics)]
+#[metrics(scope = "rpc.trace_cache")]
+struct TraceCacheMetrics {
+    /// The number of cache hits.
+    hits_total: Counter,
+    /// The number of cache misses.
+    misses_total: Counter,
+    /// The number of results written to the cache.
+    inserts_total: Counter,
+    /// The number of results evicted because the cache exceeded its maximum size.
+    evictions_total: Counter,
+    /// The number of unwound blocks whose results were removed.
+    invalidated_blocks_total: Counter,
+    /// The number of cached results.
+    cached_count: Gauge,
+    /// The total size of the cached results in bytes.
+    size_bytes: Gauge,
+}
+
+impl TraceCacheMetrics {
+    fn record_size(&self, index: &TraceCacheIndex) {
+        self.cached_count.set(index.entries.len() as f64);
+        self.size_bytes.set(index.size as f64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn key(block: u8, call: u8) -> TraceCacheKey {
+        TraceCacheKey { block_hash: B256::with_last_byte(block), call: B256::with_last_byte(call) }
+    }
+
+    #[test]
+    fn key_ignores_field_order() {
+        let a = json!({ "tracer": "callTracer", "tracerConfig": { "a": 1, "b": 2 } });
+        let b = json!({ "tracerConfig": { "b": 2, "a": 1 }, "tracer": "callTracer" });
+        let block_hash = B256::with_last_byte(1);
+        assert_eq!(
+            TraceCacheKey::new(block_hash, "debug_traceBlock", &a).unwrap(),
+            TraceCacheKey::new(block_hash, "debug_traceBlock", &b).unwrap()
+        );
+        assert_ne!(
+            TraceCacheKey::new(block_hash, "debug_traceBlock", &a).unwrap(),
+            TraceCacheKey::new(block_hash, "trace_block", &a).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn evicts_and_invalidates() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = TraceCacheConfig { dir: dir.path().to_path_buf(), max_size: 25 };
+        let cache = TraceCache::open(&config).unwrap();
+
+        cache.write(key(1, 1), b"\"0123456789\"").unwrap();
+        cache.write(key(1, 2), b"\"0123456\"").unwrap();
+        assert_eq!(cache.get::<String>(key(1, 1)).await.as_deref(), Some("0123456789"));
+
+        // evicts the least recently used result
+        cache.write(key(2, 1), b"\"0123\"").unwrap();
+        assert_eq!(cache.get::<String>(key(1, 2)).await, None);
+        assert_eq!(cache.get::<String>(key(1, 1)).await.as_deref(), Some("0123456789"));
+
+        // results are indexed again after a restart
+        drop(cache);
+        let cache = TraceCache::open(&config).unwrap();
+        assert_eq!(cache.get::<String>(key(2, 1)).await.as_deref(), Some("0123"));
+
+        cache.invalidate([B256::with_last_byte(1)]);
+        assert_eq!(cache.get::<String>(key(1, 1)).await, None);
+        assert!(!cache.block_dir(B256::with_last_byte(1)).exists());
+        assert_eq!(cache.get::<String>(key(2, 1)).await.as_deref(), Some("0123"));
+    }
+}
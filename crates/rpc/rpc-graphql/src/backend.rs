@@ -0,0 +1,547 @@
+//! Data access of the GraphQL resolvers.
+//!
+//! The resolvers of the schema are not generic over the node, they read all data through the
+//! object safe [`GraphQlBackend`] which is implemented for the `eth_` namespace handlers by
+//! [`EthGraphQlBackend`].
+
+use alloy_consensus::{BlockHeader, ReceiptWithBloom, Transaction, TxReceipt};
+use alloy_eips::{eip2718::Encodable2718, eip2930::AccessList, BlockId, Typed2718};
+use alloy_primitives::{Address, Bloom, Bytes, PrimitiveSignature as Signature, B256, B64, U256};
+use alloy_rpc_types_eth::{state::EvmOverrides, Filter, Log, SyncStatus, TransactionRequest};
+use async_graphql::{Error, Result};
+use async_trait::async_trait;
+use jsonrpsee_types::ErrorObject;
+use reth_primitives_traits::{
+    transaction::signed::is_impersonated_tx, Block, BlockBody, RecoveredBlock, SignedTransaction,
+};
+use reth_rpc_eth_api::{
+    helpers::{
+        Call, EthApiSpec, EthCall, EthFees, EthState, EthTransactions, FullEthApi, LoadBlock,
+    },
+    EthFilterApiServer, RpcNodeCore, RpcNodeCoreExt, RpcTransaction,
+};
+use reth_rpc_eth_types::TransactionSource;
+use reth_storage_api::BlockNumReader;
+use std::{fmt, sync::Arc};
+
+/// A canonical block with the transactions visible to the GraphQL clients.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockData {
+    /// Hash of the block.
+    pub hash: B256,
+    /// Number of the block.
+    pub number: u64,
+    /// Hash of the parent block.
+    pub parent_hash: B256,
+    /// Proof-of-work nonce of the block.
+    pub nonce: Option<B64>,
+    /// Hash of the ommers of the block.
+    pub ommers_hash: B256,
+    /// Root of the transactions trie.
+    pub transactions_root: B256,
+    /// Root of the state trie.
+    pub state_root: B256,
+    /// Root of the receipts trie.
+    pub receipts_root: B256,
+    /// Root of the withdrawals trie, if any.
+    pub withdrawals_root: Option<B256>,
+    /// Recipient of the block rewards.
+    pub beneficiary: Address,
+    /// Extra data of the block.
+    pub extra_data: Bytes,
+    /// Gas limit of the block.
+    pub gas_limit: u64,
+    /// Gas used by all transactions of the block.
+    pub gas_used: u64,
+    /// Base fee of the block, if any.
+    pub base_fee_per_gas: Option<u64>,
+    /// Timestamp of the block.
+    pub timestamp: u64,
+    /// Bloom filter of the logs of the block.
+    pub logs_bloom: Bloom,
+    /// Mix hash of the block.
+    pub mix_hash: Option<B256>,
+    /// Difficulty of the block.
+    pub difficulty: U256,
+    /// RLP encoded header.
+    pub raw_header: Bytes,
+    /// RLP encoded block.
+    pub raw: Bytes,
+    /// The visible transactions of the block.
+    pub transactions: Vec<TransactionData>,
+}
+
+impl BlockData {
+    /// Converts a recovered block.
+    ///
+    /// System transactions are left out in `HL_NODE_COMPLIANT` mode, exactly like in the blocks
+    /// returned by the `eth_` namespace, and the remaining transactions are re-indexed.
+    pub fn from_block<B: Block>(block: &RecoveredBlock<B>) -> Self {
+        let header = block.header();
+        let location = |index, receipt_index| TransactionLocation {
+            block_hash: block.hash(),
+            block_number: header.number(),
+            base_fee_per_gas: header.base_fee_per_gas(),
+            index,
+            receipt_index,
+        };
+        let senders = block.senders();
+        let transactions = visible_transactions(block.body().transactions())
+            .enumerate()
+            .map(|(index, (receipt_index, tx))| {
+                let location = location(index as u64, receipt_index);
+                TransactionData::new(tx, senders[receipt_index], Some(location))
+            })
+            .collect();
+
+        Self {
+            hash: block.hash(),
+            number: header.number(),
+            parent_hash: header.parent_hash(),
+            nonce: header.nonce(),
+            ommers_hash: header.ommers_hash(),
+            transactions_root: header.transactions_root(),
+            state_root: header.state_root(),
+            receipts_root: header.receipts_root(),
+            withdrawals_root: header.withdrawals_root(),
+            beneficiary: header.beneficiary(),
+            extra_data: header.extra_data().clone(),
+            gas_limit: header.gas_limit(),
+            gas_used: header.gas_used(),
+            base_fee_per_gas: header.base_fee_per_gas(),
+            timestamp: header.timestamp(),
+            logs_bloom: header.logs_bloom(),
+            mix_hash: header.mix_hash(),
+            difficulty: header.difficulty(),
+            raw_header: alloy_rlp::encode(header).into(),
+            raw: alloy_rlp::encode(block.sealed_block()).into(),
+            transactions,
+        }
+    }
+}
+
+/// A transaction visible to the GraphQL clients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionData {
+    /// Hash of the transaction.
+    pub hash: B256,
+    /// Nonce of the sender.
+    pub nonce: u64,
+    /// Sender of the transaction.
+    pub from: Address,
+    /// Recipient of the transaction, `None` for contract creations.
+    pub to: Option<Address>,
+    /// Transferred value.
+    pub value: U256,
+    /// Gas limit of the transaction.
+    pub gas: u64,
+    /// Gas price of legacy and EIP-2930 transactions.
+    pub gas_price: Option<u128>,
+    /// Max fee per gas, equal to the gas price of legacy transactions.
+    pub max_fee_per_gas: u128,
+    /// Max priority fee per gas of dynamic fee transactions.
+    pub max_priority_fee_per_gas: Option<u128>,
+    /// Input data of the transaction.
+    pub input: Bytes,
+    /// Chain id of the transaction, if any.
+    pub chain_id: Option<u64>,
+    /// EIP-2718 type of the transaction.
+    pub tx_type: u8,
+    /// Access list of the transaction, if any.
+    pub access_list: Option<AccessList>,
+    /// Signature of the transaction.
+    pub signature: Signature,
+    /// EIP-2718 encoded transaction.
+    pub raw: Bytes,
+    /// Location of the transaction, `None` if it is pending.
+    pub location: Option<TransactionLocation>,
+}
+
+impl TransactionData {
+    /// Converts a signed transaction.
+    pub fn new<T: SignedTransaction>(
+        tx: &T,
+        from: Address,
+        location: Option<TransactionLocation>,
+    ) -> Self {
+        Self {
+            hash: *tx.tx_hash(),
+            nonce: tx.nonce(),
+            from,
+            to: tx.to(),
+            value: tx.value(),
+            gas: tx.gas_limit(),
+            gas_price: tx.gas_price(),
+            max_fee_per_gas: tx.max_fee_per_gas(),
+            max_priority_fee_per_gas: tx.max_priority_fee_per_gas(),
+            input: tx.input().clone(),
+            chain_id: tx.chain_id(),
+            tx_type: tx.ty(),
+            access_list: tx.access_list().cloned(),
+            signature: *tx.signature(),
+            raw: tx.encoded_2718().into(),
+            location,
+        }
+    }
+
+    /// Returns the gas price paid per unit of gas in the given block.
+    pub fn effective_gas_price(&self, base_fee_per_gas: Option<u64>) -> u128 {
+        match (self.max_priority_fee_per_gas, base_fee_per_gas) {
+            (Some(priority_fee), Some(base_fee)) => {
+                self.max_fee_per_gas.min(priority_fee.saturating_add(base_fee as u128))
+            }
+            _ => self.max_fee_per_gas,
+        }
+    }
+}
+
+/// Position of a transaction in a canonical block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionLocation {
+    /// Hash of the block.
+    pub block_hash: B256,
+    /// Number of the block.
+    pub block_number: u64,
+    /// Base fee of the block, if any.
+    pub base_fee_per_gas: Option<u64>,
+    /// Index among the visible transactions of the block.
+    pub index: u64,
+    /// Index among all transactions of the block.
+    pub receipt_index: usize,
+}
+
+/// The receipt of a visible transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiptData {
+    /// Whether the transaction succeeded.
+    pub status: bool,
+    /// Gas used by the block up to and including the transaction.
+    pub cumulative_gas_used: u64,
+    /// Gas used by the transaction.
+    pub gas_used: u64,
+    /// Logs emitted by the transaction.
+    pub logs: Vec<LogData>,
+    /// EIP-2718 encoded receipt.
+    pub raw: Bytes,
+}
+
+/// A log emitted by a visible transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogData {
+    /// Index of the log among the visible logs of the block.
+    pub index: u64,
+    /// Address of the contract that emitted the log.
+    pub address: Address,
+    /// Topics of the log.
+    pub topics: Vec<B256>,
+    /// Data of the log.
+    pub data: Bytes,
+    /// Hash of the transaction that emitted the log.
+    pub transaction_hash: B256,
+    /// Number of the block of the log.
+    pub block_number: u64,
+}
+
+impl From<Log> for LogData {
+    fn from(log: Log) -> Self {
+        Self {
+            index: log.log_index.unwrap_or_default(),
+            address: log.address(),
+            topics: log.topics().to_vec(),
+            data: log.data().data.clone(),
+            transaction_hash: log.transaction_hash.unwrap_or_default(),
+            block_number: log.block_number.unwrap_or_default(),
+        }
+    }
+}
+
+/// Outcome of a call executed against the state of a block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallOutcome {
+    /// Return data or revert reason of the call.
+    pub data: Bytes,
+    /// Gas used by the call.
+    pub gas_used: u64,
+    /// Whether the call succeeded.
+    pub status: bool,
+}
+
+/// Progress of the sync, if the node is syncing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncStateData {
+    /// Block the sync started at.
+    pub starting_block: u64,
+    /// Block the sync is currently at.
+    pub current_block: u64,
+    /// Highest block known to the node.
+    pub highest_block: u64,
+}
+
+/// Data source of the GraphQL resolvers.
+#[async_trait]
+pub trait GraphQlBackend: Send + Sync + 'static {
+    /// Returns the number of the latest block.
+    async fn latest_block_number(&self) -> Result<u64>;
+
+    /// Returns the block with the given id.
+    async fn block(&self, id: BlockId) -> Result<Option<Arc<BlockData>>>;
+
+    /// Returns the receipts of the visible transactions of the block with the given hash.
+    async fn receipts(&self, block_hash: B256) -> Result<Arc<Vec<ReceiptData>>>;
+
+    /// Returns the visible transaction with the given hash, mined or pending.
+    async fn transaction(&self, hash: B256) -> Result<Option<TransactionData>>;
+
+    /// Returns the logs matching the filter.
+    async fn logs(&self, filter: Filter) -> Result<Vec<LogData>>;
+
+    /// Returns the balance of the account at the given block.
+    async fn balance(&self, address: Address, at: BlockId) -> Result<U256>;
+
+    /// Returns the nonce of the account at the given block.
+    async fn transaction_count(&self, address: Address, at: BlockId) -> Result<u64>;
+
+    /// Returns the code of the account at the given block.
+    async fn code(&self, address: Address, at: BlockId) -> Result<Bytes>;
+
+    /// Returns the value of a storage slot of the account at the given block.
+    async fn storage(&self, address: Address, slot: B256, at: BlockId) -> Result<B256>;
+
+    /// Executes a call against the state of the given block.
+    async fn call(&self, request: TransactionRequest, at: BlockId) -> Result<CallOutcome>;
+
+    /// Estimates the gas a transaction needs on top of the given block.
+    async fn estimate_gas(&self, request: TransactionRequest, at: BlockId) -> Result<u64>;
+
+    /// Returns the suggested gas price.
+    async fn gas_price(&self) -> Result<U256>;
+
+    /// Returns the suggested priority fee.
+    async fn max_priority_fee_per_gas(&self) -> Result<U256>;
+
+    /// Returns the chain id.
+    fn chain_id(&self) -> u64;
+
+    /// Returns the sync progress, `None` if the node is not syncing.
+    fn sync_state(&self) -> Result<Option<SyncStateData>>;
+
+    /// Submits a raw transaction and returns its hash.
+    async fn send_raw_transaction(&self, tx: Bytes) -> Result<B256>;
+}
+
+/// Submits the raw transactions sent through GraphQL somewhere else than the transaction pool of
+/// the node, e.g. to an upstream RPC.
+#[async_trait]
+pub trait RawTransactionForwarder: Send + Sync + 'static {
+    /// Forwards the raw transaction and returns its hash.
+    async fn forward_raw_transaction(&self, tx: Bytes) -> Result<B256, ErrorObject<'static>>;
+}
+
+/// [`GraphQlBackend`] over the `eth_` namespace handlers.
+pub struct EthGraphQlBackend<Eth, Filter> {
+    eth_api: Eth,
+    filter: Filter,
+    tx_forwarder: Option<Arc<dyn RawTransactionForwarder>>,
+}
+
+impl<Eth, Filter> EthGraphQlBackend<Eth, Filter> {
+    /// Creates a new backend from the `eth_` API and the `eth_getLogs` handler.
+    pub const fn new(eth_api: Eth, filter: Filter) -> Self {
+        Self { eth_api, filter, tx_forwarder: None }
+    }
+
+    /// Forwards the raw transactions with the given forwarder instead of submitting them to the
+    /// transaction pool.
+    pub fn with_tx_forwarder(mut self, tx_forwarder: Arc<dyn RawTransactionForwarder>) -> Self {
+        self.tx_forwarder = Some(tx_forwarder);
+        self
+    }
+}
+
+impl<Eth, Filter> fmt::Debug for EthGraphQlBackend<Eth, Filter> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EthGraphQlBackend").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl<Eth, F> GraphQlBackend for EthGraphQlBackend<Eth, F>
+where
+    Eth: FullEthApi + Clone + 'static,
+    F: EthFilterApiServer<RpcTransaction<Eth::NetworkTypes>> + 'static,
+{
+    async fn latest_block_number(&self) -> Result<u64> {
+        self.eth_api.provider().best_block_number().map_err(internal_error)
+    }
+
+    async fn block(&self, id: BlockId) -> Result<Option<Arc<BlockData>>> {
+        let block = LoadBlock::block_with_senders(&self.eth_api, id).await.map_err(rpc_error)?;
+        Ok(block.map(|block| Arc::new(BlockData::from_block(&block))))
+    }
+
+    async fn receipts(&self, block_hash: B256) -> Result<Arc<Vec<ReceiptData>>> {
+        let Some((block, receipts)) = self
+            .eth_api
+            .cache()
+            .get_block_and_receipts(block_hash)
+            .await
+            .map_err(internal_error)?
+        else {
+            return Err(Error::new(format!("block {block_hash} not found")))
+        };
+
+        let mut log_index = 0;
+        let receipts = visible_transactions(block.body().transactions())
+            .map(|(receipt_index, tx)| {
+                let receipt = &receipts[receipt_index];
+                let cumulative_gas_used = receipt.cumulative_gas_used();
+                let previous_gas_used = receipt_index
+                    .checked_sub(1)
+                    .map(|index| receipts[index].cumulative_gas_used())
+                    .unwrap_or_default();
+                let logs = receipt
+                    .logs()
+                    .iter()
+                    .map(|log| {
+                        let log = LogData {
+                            index: log_index,
+                            address: log.address,
+                            topics: log.topics().to_vec(),
+                            data: log.data.data.clone(),
+                            transaction_hash: *tx.tx_hash(),
+                            block_number: block.header().number(),
+                        };
+                        log_index += 1;
+                        log
+                    })
+                    .collect();
+                ReceiptData {
+                    status: receipt.status(),
+                    cumulative_gas_used,
+                    gas_used: cumulative_gas_used.saturating_sub(previous_gas_used),
+                    logs,
+                    raw: ReceiptWithBloom::from(receipt.clone()).encoded_2718().into(),
+                }
+            })
+            .collect();
+
+        Ok(Arc::new(receipts))
+    }
+
+    async fn transaction(&self, hash: B256) -> Result<Option<TransactionData>> {
+        match EthTransactions::transaction_by_hash(&self.eth_api, hash).await.map_err(rpc_error)? {
+            Some(TransactionSource::Pool(tx)) => {
+                let (tx, from) = tx.into_parts();
+                Ok(Some(TransactionData::new(&tx, from, None)))
+            }
+            Some(TransactionSource::Block { block_hash, .. }) => {
+                // the transaction is looked up in the converted block so that hidden system
+                // transactions are not returned and the index matches the block
+                Ok(self.block(block_hash.into()).await?.and_then(|block| {
+                    block.transactions.iter().find(|tx| tx.hash == hash).cloned()
+                }))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn logs(&self, filter: Filter) -> Result<Vec<LogData>> {
+        let logs = self.filter.logs(filter).await.map_err(rpc_error)?;
+        Ok(logs.into_iter().map(Into::into).collect())
+    }
+
+    async fn balance(&self, address: Address, at: BlockId) -> Result<U256> {
+        EthState::balance(&self.eth_api, address, Some(at)).await.map_err(rpc_error)
+    }
+
+    async fn transaction_count(&self, address: Address, at: BlockId) -> Result<u64> {
+        let count = EthState::transaction_count(&self.eth_api, address, Some(at))
+            .await
+            .map_err(rpc_error)?;
+        Ok(count.saturating_to())
+    }
+
+    async fn code(&self, address: Address, at: BlockId) -> Result<Bytes> {
+        EthState::get_code(&self.eth_api, address, Some(at)).await.map_err(rpc_error)
+    }
+
+    async fn storage(&self, address: Address, slot: B256, at: BlockId) -> Result<B256> {
+        EthState::storage_at(&self.eth_api, address, slot.into(), Some(at)).await.map_err(rpc_error)
+    }
+
+    async fn call(&self, request: TransactionRequest, at: BlockId) -> Result<CallOutcome> {
+        let (res, _) = Call::transact_call_at(&self.eth_api, request, at, EvmOverrides::default())
+            .await
+            .map_err(rpc_error)?;
+        Ok(CallOutcome {
+            data: res.result.output().cloned().unwrap_or_default(),
+            gas_used: res.result.gas_used(),
+            status: res.result.is_success(),
+        })
+    }
+
+    async fn estimate_gas(&self, request: TransactionRequest, at: BlockId) -> Result<u64> {
+        let gas =
+            EthCall::estimate_gas_at(&self.eth_api, request, at, None).await.map_err(rpc_error)?;
+        Ok(gas.saturating_to())
+    }
+
+    async fn gas_price(&self) -> Result<U256> {
+        EthFees::gas_price(&self.eth_api).await.map_err(rpc_error)
+    }
+
+    async fn max_priority_fee_per_gas(&self) -> Result<U256> {
+        EthFees::suggested_priority_fee(&self.eth_api).await.map_err(rpc_error)
+    }
+
+    fn chain_id(&self) -> u64 {
+        EthApiSpec::chain_id(&self.eth_api).to()
+    }
+
+    fn sync_state(&self) -> Result<Option<SyncStateData>> {
+        match self.eth_api.sync_status().map_err(internal_error)? {
+            SyncStatus::Info(info) => Ok(Some(SyncStateData {
+                starting_block: info.starting_block.saturating_to(),
+                current_block: info.current_block.saturating_to(),
+                highest_block: info.highest_block.saturating_to(),
+            })),
+            SyncStatus::None => Ok(None),
+        }
+    }
+
+    async fn send_raw_transaction(&self, tx: Bytes) -> Result<B256> {
+        match &self.tx_forwarder {
+            Some(forwarder) => forwarder.forward_raw_transaction(tx).await.map_err(rpc_error),
+            None => {
+                EthTransactions::send_raw_transaction(&self.eth_api, tx).await.map_err(rpc_error)
+            }
+        }
+    }
+}
+
+/// Returns the transactions that are visible to the clients, with their index in the block.
+///
+/// System transactions are hidden in `HL_NODE_COMPLIANT` mode.
+fn visible_transactions<T: SignedTransaction>(
+    transactions: &[T],
+) -> impl Iterator<Item = (usize, &T)> {
+    let is_in_hl_node_compliant_mode = is_in_hl_node_compliant_mode();
+    transactions.iter().enumerate().filter(move |(_, tx)| {
+        !(is_in_hl_node_compliant_mode &&
+            is_impersonated_tx(tx.signature(), tx.gas_price()).is_some())
+    })
+}
+
+fn is_in_hl_node_compliant_mode() -> bool {
+    std::env::var("HL_NODE_COMPLIANT").is_ok()
+}
+
+/// Converts an error of the `eth_` namespace into a GraphQL error.
+fn rpc_error(err: impl Into<ErrorObject<'static>>) -> Error {
+    Error::new(err.into().message())
+}
+
+/// Converts an internal error into a GraphQL error.
+fn internal_error(err: impl fmt::Display) -> Error {
+    Error::new(err.to_string())
+}
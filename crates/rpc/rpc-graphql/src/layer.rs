@@ -0,0 +1,189 @@
+//! HTTP middleware serving the GraphQL endpoint next to the JSON-RPC server.
+
+use crate::GraphQlSchema;
+use async_graphql::{
+    http::{parse_query_string, GraphiQLSource},
+    BatchRequest,
+};
+use futures::future::BoxFuture;
+use http::{header, HeaderValue, Method, StatusCode, Uri};
+use http_body_util::{BodyExt, LengthLimitError, Limited};
+use jsonrpsee::{
+    server::{HttpBody, HttpRequest, HttpResponse},
+    RpcModule,
+};
+use jsonrpsee_types::ErrorObjectOwned;
+use serde_json::Value;
+use std::{
+    fmt,
+    task::{Context, Poll},
+};
+use tower::{Layer, Service};
+
+/// Path of the GraphQL endpoint on the http server.
+pub const GRAPHQL_PATH: &str = "/graphql";
+
+/// Path of the GraphiQL user interface on the http server.
+pub const GRAPHIQL_PATH: &str = "/graphql/ui";
+
+/// Name of the JSON-RPC method that executes GraphQL requests, see [`graphql_module`].
+pub const GRAPHQL_METHOD: &str = "graphql_query";
+
+/// Maximum size of a GraphQL request body: 5MB
+const MAX_REQUEST_BODY_SIZE: usize = 5 * 1024 * 1024;
+
+/// Returns the [`RpcModule`] with the [`GRAPHQL_METHOD`], which executes a GraphQL request
+/// against the schema.
+pub fn graphql_module(schema: GraphQlSchema) -> RpcModule<()> {
+    let mut module = RpcModule::new(());
+    module
+        .register_async_method(GRAPHQL_METHOD, move |params, _, _| {
+            let schema = schema.clone();
+            async move {
+                let request = params.one::<BatchRequest>()?;
+                Ok::<_, ErrorObjectOwned>(schema.execute_batch(request).await)
+            }
+        })
+        .expect("module is empty");
+    module
+}
+
+/// Layer that serves GraphQL requests sent to [`GRAPHQL_PATH`] and passes all other requests on
+/// to the JSON-RPC server.
+///
+/// GraphQL requests are passed on as calls of the [`GRAPHQL_METHOD`], so that they go through the
+/// same RPC middleware as all other calls, e.g. API key quotas, metrics and the slow query log.
+/// The module returned by [`graphql_module`] has to be served by the JSON-RPC server.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GraphQlLayer;
+
+impl GraphQlLayer {
+    /// Creates a new layer.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for GraphQlLayer {
+    type Service = GraphQlService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GraphQlService { inner }
+    }
+}
+
+/// The [`Service`] created by the [`GraphQlLayer`].
+#[derive(Debug, Clone)]
+pub struct GraphQlService<S> {
+    inner: S,
+}
+
+impl<S> Service<HttpRequest> for GraphQlService<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse> + Clone + Send + 'static,
+    S::Error: Send,
+    S::Future: Send + 'static,
+{
+    type Response = HttpResponse;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: HttpRequest) -> Self::Future {
+        match req.uri().path() {
+            GRAPHQL_PATH => {
+                // The service that was polled ready has to handle the request
+                let clone = self.inner.clone();
+                let inner = std::mem::replace(&mut self.inner, clone);
+                Box::pin(execute(inner, req))
+            }
+            GRAPHIQL_PATH if req.method() == Method::GET => {
+                // Relative to the user interface, so that API keys passed in the path are kept
+                let html = GraphiQLSource::build().endpoint("../graphql").finish();
+                Box::pin(futures::future::ready(Ok(response(StatusCode::OK, "text/html", html))))
+            }
+            _ => Box::pin(self.inner.call(req)),
+        }
+    }
+}
+
+/// Executes a GraphQL request, passed as query string of a `GET` request or as JSON body of a
+/// `POST` request, as a call of the [`GRAPHQL_METHOD`].
+async fn execute<S>(mut inner: S, req: HttpRequest) -> Result<HttpResponse, S::Error>
+where
+    S: Service<HttpRequest, Response = HttpResponse>,
+{
+    let (mut parts, body) = req.into_parts();
+    let request = match parts.method {
+        Method::GET => parse_query_string(parts.uri.query().unwrap_or_default())
+            .map_err(|err| err.to_string())
+            .and_then(|request| serde_json::to_value(request).map_err(|err| err.to_string())),
+        Method::POST => match Limited::new(body, MAX_REQUEST_BODY_SIZE).collect().await {
+            Ok(body) => serde_json::from_slice(&body.to_bytes()).map_err(|err| err.to_string()),
+            Err(err) if err.is::<LengthLimitError>() => {
+                return Ok(error_response(StatusCode::PAYLOAD_TOO_LARGE, err))
+            }
+            Err(err) => Err(err.to_string()),
+        },
+        _ => {
+            return Ok(error_response(
+                StatusCode::METHOD_NOT_ALLOWED,
+                "only GET and POST are supported",
+            ))
+        }
+    };
+    let request = match request {
+        Ok(request) => request,
+        Err(err) => return Ok(error_response(StatusCode::BAD_REQUEST, err)),
+    };
+
+    // Keep the headers and extensions, e.g. the authenticated API key, of the original request
+    let call = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 0,
+        "method": GRAPHQL_METHOD,
+        "params": [request],
+    });
+    parts.method = Method::POST;
+    parts.uri = Uri::from_static("/");
+    parts.headers.remove(header::CONTENT_LENGTH);
+    parts.headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    let res = inner.call(HttpRequest::from_parts(parts, HttpBody::new(call.to_string()))).await?;
+    if res.status() != StatusCode::OK {
+        return Ok(res)
+    }
+
+    let body = match res.into_body().collect().await {
+        Ok(body) => body.to_bytes(),
+        Err(err) => return Ok(error_response(StatusCode::INTERNAL_SERVER_ERROR, err)),
+    };
+    let mut output = match serde_json::from_slice::<Value>(&body) {
+        Ok(output) => output,
+        Err(err) => return Ok(error_response(StatusCode::INTERNAL_SERVER_ERROR, err)),
+    };
+    Ok(match (output.get_mut("result").map(Value::take), output.get("error")) {
+        (Some(result), _) => response(StatusCode::OK, "application/json", result.to_string()),
+        (None, Some(error)) => {
+            let message = error.get("message").and_then(Value::as_str).unwrap_or_default();
+            error_response(StatusCode::OK, message)
+        }
+        (None, None) => error_response(StatusCode::INTERNAL_SERVER_ERROR, "invalid response"),
+    })
+}
+
+/// Returns a GraphQL error response with the given status.
+fn error_response(status: StatusCode, message: impl fmt::Display) -> HttpResponse {
+    let body = serde_json::json!({ "errors": [{ "message": message.to_string() }] });
+    response(status, "application/json", body.to_string())
+}
+
+fn response(status: StatusCode, content_type: &'static str, body: String) -> HttpResponse {
+    http::Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, content_type)
+        .body(HttpBody::new(body))
+        .expect("valid response")
+}
@@ -0,0 +1,50 @@
+//! [EIP-1767](https://eips.ethereum.org/EIPS/eip-1767) GraphQL server.
+//!
+//! The GraphQL endpoint is served by the http RPC server under [`GRAPHQL_PATH`], see
+//! [`GraphQlLayer`]. Requests are executed as calls of the [`GRAPHQL_METHOD`] and all data is read
+//! through the `eth_` namespace handlers, so the GraphQL endpoint goes through the same RPC
+//! middleware, respects the same limits and hides the same system transactions as the JSON-RPC
+//! API.
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
+    html_favicon_url = "https://avatars0.githubusercontent.com/u/97369466?s=256",
+    issue_tracker_base_url = "https://github.com/paradigmxyz/reth/issues/"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+use reth_rpc_server_types::constants::{DEFAULT_GRAPHQL_MAX_COMPLEXITY, DEFAULT_GRAPHQL_MAX_DEPTH};
+use serde::{Deserialize, Serialize};
+
+pub mod backend;
+mod layer;
+pub mod scalars;
+pub mod schema;
+
+pub use backend::{EthGraphQlBackend, GraphQlBackend, RawTransactionForwarder};
+pub use layer::{
+    graphql_module, GraphQlLayer, GraphQlService, GRAPHIQL_PATH, GRAPHQL_METHOD, GRAPHQL_PATH,
+};
+pub use schema::{build_schema, GraphQlSchema, Mutation, Query, MAX_BLOCK_RANGE};
+
+/// Limits of the queries accepted by the GraphQL server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphQlConfig {
+    /// Maximum complexity of a query.
+    ///
+    /// Every requested field counts as one, the fields requested for each block of a `blocks`
+    /// query are counted once per block.
+    pub max_complexity: usize,
+    /// Maximum depth of a query.
+    pub max_depth: usize,
+}
+
+impl Default for GraphQlConfig {
+    fn default() -> Self {
+        Self {
+            max_complexity: DEFAULT_GRAPHQL_MAX_COMPLEXITY,
+            max_depth: DEFAULT_GRAPHQL_MAX_DEPTH,
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Scalar types of the EIP-1767 schema.
+
+use alloy_primitives::U256;
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// 32 byte binary string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bytes32(pub alloy_primitives::B256);
+
+/// A 32 byte binary string, represented as 0x-prefixed hexadecimal.
+#[Scalar(name = "Bytes32")]
+impl ScalarType for Bytes32 {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => s.parse().map(Self).map_err(InputValueError::custom),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.0.to_string())
+    }
+}
+
+/// 20 byte Ethereum address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Address(pub alloy_primitives::Address);
+
+/// A 20 byte Ethereum address, represented as 0x-prefixed hexadecimal.
+#[Scalar(name = "Address")]
+impl ScalarType for Address {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => s.parse().map(Self).map_err(InputValueError::custom),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.0.to_string())
+    }
+}
+
+/// Arbitrary length binary string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bytes(pub alloy_primitives::Bytes);
+
+/// An arbitrary length binary string, represented as 0x-prefixed hexadecimal.
+/// An empty byte string is represented as '0x'. Byte strings must have an even number of
+/// hexadecimal nybbles.
+#[Scalar(name = "Bytes")]
+impl ScalarType for Bytes {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => s.parse().map(Self).map_err(InputValueError::custom),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.0.to_string())
+    }
+}
+
+/// Arbitrary length integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BigInt(pub U256);
+
+/// A large integer. Input is accepted as either a JSON number or as a string.
+/// Strings may be either decimal or 0x-prefixed hexadecimal. Output values are all
+/// 0x-prefixed hexadecimal.
+#[Scalar(name = "BigInt")]
+impl ScalarType for BigInt {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => s.parse().map(Self).map_err(InputValueError::custom),
+            Value::Number(n) => n
+                .as_u64()
+                .map(|n| Self(U256::from(n)))
+                .ok_or_else(|| InputValueError::custom("expected an unsigned integer")),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(format!("{:#x}", self.0))
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(value: u128) -> Self {
+        Self(U256::from(value))
+    }
+}
+
+/// 64 bit unsigned integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Long(pub u64);
+
+/// A 64 bit unsigned integer. Input is accepted as either a JSON number or as a string.
+/// Strings may be either decimal or 0x-prefixed hexadecimal. Output values are all 0x-prefixed
+/// hexadecimal.
+#[Scalar(name = "Long")]
+impl ScalarType for Long {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => {
+                let parsed = match s.strip_prefix("0x") {
+                    Some(hex) => u64::from_str_radix(hex, 16),
+                    None => s.parse(),
+                };
+                parsed.map(Self).map_err(InputValueError::custom)
+            }
+            Value::Number(n) => n
+                .as_u64()
+                .map(Self)
+                .ok_or_else(|| InputValueError::custom("expected an unsigned 64 bit integer")),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(format!("{:#x}", self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_long() {
+        assert_eq!(Long::parse(Value::from(10)).unwrap(), Long(10));
+        assert_eq!(Long::parse(Value::from("10")).unwrap(), Long(10));
+        assert_eq!(Long::parse(Value::from("0x10")).unwrap(), Long(16));
+        assert!(Long::parse(Value::from("0xzz")).is_err());
+        assert!(Long::parse(Value::from(-1)).is_err());
+        assert_eq!(Long(16).to_value(), Value::from("0x10"));
+    }
+
+    #[test]
+    fn parse_big_int() {
+        assert_eq!(BigInt::parse(Value::from(10)).unwrap(), BigInt(U256::from(10)));
+        assert_eq!(BigInt::parse(Value::from("0x10")).unwrap(), BigInt(U256::from(16)));
+        assert_eq!(BigInt(U256::ZERO).to_value(), Value::from("0x0"));
+    }
+}
@@ -0,0 +1,1057 @@
+//! Object types and resolvers of the EIP-1767 schema.
+//!
+//! See also <https://eips.ethereum.org/EIPS/eip-1767>
+
+use crate::{
+    backend::{BlockData, CallOutcome, GraphQlBackend, LogData, ReceiptData, TransactionData},
+    scalars::{Address, BigInt, Bytes, Bytes32, Long},
+    GraphQlConfig,
+};
+use alloy_eips::BlockId;
+use alloy_primitives::{TxKind, B256};
+use alloy_rpc_types_eth::{Filter, TransactionInput, TransactionRequest};
+use async_graphql::{
+    Context, EmptySubscription, Error, InputObject, Object, Result, Schema, SimpleObject,
+};
+use std::sync::Arc;
+
+/// Maximum number of blocks returned by the `blocks` query.
+///
+/// Ranges without an upper bound count as this many blocks towards the complexity of a query.
+pub const MAX_BLOCK_RANGE: u64 = 1_000;
+
+/// The EIP-1767 schema.
+pub type GraphQlSchema = Schema<Query, Mutation, EmptySubscription>;
+
+/// Builds the schema over the given backend, enforcing the limits of the config.
+pub fn build_schema(backend: Arc<dyn GraphQlBackend>, config: &GraphQlConfig) -> GraphQlSchema {
+    Schema::build(Query, Mutation, EmptySubscription)
+        .data(backend)
+        .limit_complexity(config.max_complexity)
+        .limit_depth(config.max_depth)
+        .finish()
+}
+
+fn backend<'a>(ctx: &Context<'a>) -> &'a Arc<dyn GraphQlBackend> {
+    ctx.data_unchecked::<Arc<dyn GraphQlBackend>>()
+}
+
+/// Complexity of a `blocks` query.
+fn block_range_complexity(from: Option<Long>, to: Option<Long>, child_complexity: usize) -> usize {
+    let blocks = match (from, to) {
+        (Some(from), Some(to)) => to.0.saturating_sub(from.0).saturating_add(1),
+        _ => MAX_BLOCK_RANGE,
+    };
+    usize::try_from(blocks.min(MAX_BLOCK_RANGE))
+        .unwrap_or(usize::MAX)
+        .saturating_mul(child_complexity)
+}
+
+/// The root query type.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Query;
+
+#[Object]
+impl Query {
+    /// Fetches an Ethereum block by number or by hash. If neither is supplied, the most
+    /// recent known block is returned.
+    async fn block(
+        &self,
+        ctx: &Context<'_>,
+        number: Option<Long>,
+        hash: Option<Bytes32>,
+    ) -> Result<Option<Block>> {
+        let id = match (number, hash) {
+            (Some(_), Some(_)) => return Err("only one of number or hash must be specified".into()),
+            (Some(number), None) => BlockId::number(number.0),
+            (None, Some(hash)) => BlockId::hash(hash.0),
+            (None, None) => BlockId::latest(),
+        };
+        Ok(backend(ctx).block(id).await?.map(Block))
+    }
+
+    /// Returns all the blocks between two numbers, inclusive. If to is not supplied, it
+    /// defaults to the most recent known block.
+    #[graphql(complexity = "block_range_complexity(from, to, child_complexity)")]
+    async fn blocks(
+        &self,
+        ctx: &Context<'_>,
+        from: Option<Long>,
+        to: Option<Long>,
+    ) -> Result<Vec<Block>> {
+        let backend = backend(ctx);
+        let Some(from) = from else { return Err("from block must be specified".into()) };
+        let latest = backend.latest_block_number().await?;
+        let to = to.map_or(latest, |to| to.0.min(latest));
+        if from.0 > to {
+            return Ok(Vec::new())
+        }
+        if to - from.0 >= MAX_BLOCK_RANGE {
+            return Err(format!("block range exceeds the limit of {MAX_BLOCK_RANGE} blocks").into())
+        }
+
+        let blocks =
+            futures::future::try_join_all((from.0..=to).map(|n| backend.block(n.into()))).await?;
+        Ok(blocks.into_iter().flatten().map(Block).collect())
+    }
+
+    /// Returns a transaction specified by its hash.
+    async fn transaction(&self, ctx: &Context<'_>, hash: Bytes32) -> Result<Option<Transaction>> {
+        Ok(backend(ctx).transaction(hash.0).await?.map(Transaction))
+    }
+
+    /// Returns log entries matching the provided filter.
+    async fn logs(&self, ctx: &Context<'_>, filter: FilterCriteria) -> Result<Vec<Log>> {
+        let logs = backend(ctx).logs(filter.try_into()?).await?;
+        Ok(logs.into_iter().map(Log).collect())
+    }
+
+    /// Returns the node's estimate of a gas price sufficient to ensure a transaction is
+    /// mined in a timely fashion.
+    async fn gas_price(&self, ctx: &Context<'_>) -> Result<BigInt> {
+        Ok(BigInt(backend(ctx).gas_price().await?))
+    }
+
+    /// Returns the node's estimate of a gas tip sufficient to ensure a
+    /// transaction is mined in a timely fashion.
+    async fn max_priority_fee_per_gas(&self, ctx: &Context<'_>) -> Result<BigInt> {
+        Ok(BigInt(backend(ctx).max_priority_fee_per_gas().await?))
+    }
+
+    /// Returns information on the current synchronisation state.
+    async fn syncing(&self, ctx: &Context<'_>) -> Result<Option<SyncState>> {
+        Ok(backend(ctx).sync_state()?.map(|state| SyncState {
+            starting_block: Long(state.starting_block),
+            current_block: Long(state.current_block),
+            highest_block: Long(state.highest_block),
+        }))
+    }
+
+    /// Returns the current chain ID for transaction replay protection.
+    #[graphql(name = "chainID")]
+    async fn chain_id(&self, ctx: &Context<'_>) -> BigInt {
+        BigInt::from(backend(ctx).chain_id() as u128)
+    }
+}
+
+/// The root mutation type.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Mutation;
+
+#[Object]
+impl Mutation {
+    /// Sends an RLP-encoded transaction to the network.
+    async fn send_raw_transaction(&self, ctx: &Context<'_>, data: Bytes) -> Result<Bytes32> {
+        Ok(Bytes32(backend(ctx).send_raw_transaction(data.0).await?))
+    }
+}
+
+/// An Ethereum block.
+#[derive(Debug, Clone)]
+pub struct Block(Arc<BlockData>);
+
+#[Object]
+impl Block {
+    /// The number of this block, starting at 0 for the genesis block.
+    async fn number(&self) -> Long {
+        Long(self.0.number)
+    }
+
+    /// The block hash of this block.
+    async fn hash(&self) -> Bytes32 {
+        Bytes32(self.0.hash)
+    }
+
+    /// The parent block of this block.
+    async fn parent(&self, ctx: &Context<'_>) -> Result<Option<Self>> {
+        if self.0.number == 0 {
+            return Ok(None)
+        }
+        Ok(backend(ctx).block(self.0.parent_hash.into()).await?.map(Block))
+    }
+
+    /// The block nonce, an 8 byte sequence determined by the miner.
+    async fn nonce(&self) -> Bytes {
+        Bytes(self.0.nonce.unwrap_or_default().into())
+    }
+
+    /// The keccak256 hash of the root of the trie of transactions in this
+    /// block.
+    async fn transactions_root(&self) -> Bytes32 {
+        Bytes32(self.0.transactions_root)
+    }
+
+    /// The number of transactions in this block.
+    async fn transaction_count(&self) -> Option<Long> {
+        Some(Long(self.0.transactions.len() as u64))
+    }
+
+    /// The keccak256 hash of the state trie after this block was processed.
+    async fn state_root(&self) -> Bytes32 {
+        Bytes32(self.0.state_root)
+    }
+
+    /// The keccak256 hash of the trie of transaction receipts in this block.
+    async fn receipts_root(&self) -> Bytes32 {
+        Bytes32(self.0.receipts_root)
+    }
+
+    /// The account that mined this block. If block is not specified, the state at this
+    /// block is used.
+    async fn miner(&self, block: Option<Long>) -> Account {
+        Account::new(self.0.beneficiary, block.map_or(self.0.hash.into(), |n| n.0.into()))
+    }
+
+    /// An arbitrary data field supplied by the miner.
+    async fn extra_data(&self) -> Bytes {
+        Bytes(self.0.extra_data.clone())
+    }
+
+    /// The maximum amount of gas that was available to transactions in this block.
+    async fn gas_limit(&self) -> Long {
+        Long(self.0.gas_limit)
+    }
+
+    /// The amount of gas that was used executing transactions in this block.
+    async fn gas_used(&self) -> Long {
+        Long(self.0.gas_used)
+    }
+
+    /// The fee per unit of gas burned by the protocol in this block.
+    async fn base_fee_per_gas(&self) -> Option<BigInt> {
+        self.0.base_fee_per_gas.map(|fee| BigInt::from(fee as u128))
+    }
+
+    /// The unix timestamp at which this block was mined.
+    async fn timestamp(&self) -> Long {
+        Long(self.0.timestamp)
+    }
+
+    /// A bloom filter that can be used to check if a block may contain log entries
+    /// matching a filter.
+    async fn logs_bloom(&self) -> Bytes {
+        Bytes(self.0.logs_bloom.0.to_vec().into())
+    }
+
+    /// The hash that was used as an input to the proof-of-work process.
+    async fn mix_hash(&self) -> Bytes32 {
+        Bytes32(self.0.mix_hash.unwrap_or_default())
+    }
+
+    /// A measure of the difficulty of mining this block.
+    async fn difficulty(&self) -> BigInt {
+        BigInt(self.0.difficulty)
+    }
+
+    /// The keccak256 hash of all the ommers (aka uncles) referenced by this block.
+    async fn ommer_hash(&self) -> Bytes32 {
+        Bytes32(self.0.ommers_hash)
+    }
+
+    /// The withdrawals trie root in this block. If withdrawals are
+    /// unavailable for this block, this field will be null.
+    async fn withdrawals_root(&self) -> Option<Bytes32> {
+        self.0.withdrawals_root.map(Bytes32)
+    }
+
+    /// A list of transactions associated with this block.
+    async fn transactions(&self) -> Option<Vec<Transaction>> {
+        Some(self.0.transactions.iter().cloned().map(Transaction).collect())
+    }
+
+    /// Returns the transaction at the specified index.
+    async fn transaction_at(&self, index: Long) -> Option<Transaction> {
+        let index = usize::try_from(index.0).ok()?;
+        self.0.transactions.get(index).cloned().map(Transaction)
+    }
+
+    /// Returns a filtered set of logs from this block.
+    async fn logs(&self, ctx: &Context<'_>, filter: BlockFilterCriteria) -> Result<Vec<Log>> {
+        let receipts = backend(ctx).receipts(self.0.hash).await?;
+        let addresses: Vec<_> =
+            filter.addresses.unwrap_or_default().into_iter().map(|a| a.0).collect();
+        let topics = topic_sets(filter.topics.unwrap_or_default())?;
+        Ok(receipts
+            .iter()
+            .flat_map(|receipt| &receipt.logs)
+            .filter(|log| {
+                (addresses.is_empty() || addresses.contains(&log.address)) &&
+                    topics.iter().enumerate().all(|(i, set)| {
+                        set.is_empty() || log.topics.get(i).is_some_and(|t| set.contains(t))
+                    })
+            })
+            .cloned()
+            .map(Log)
+            .collect())
+    }
+
+    /// Fetches an Ethereum account at the current block's state.
+    async fn account(&self, address: Address) -> Account {
+        Account::new(address.0, self.0.hash.into())
+    }
+
+    /// Executes a local call operation at the current block's state.
+    async fn call(&self, ctx: &Context<'_>, data: CallData) -> Result<Option<CallResult>> {
+        let CallOutcome { data, gas_used, status } =
+            backend(ctx).call(data.into(), self.0.hash.into()).await?;
+        Ok(Some(CallResult {
+            data: Bytes(data),
+            gas_used: Long(gas_used),
+            status: Long(status as u64),
+        }))
+    }
+
+    /// Estimates the amount of gas that will be required for successful execution
+    /// of a transaction at the current block's state.
+    async fn estimate_gas(&self, ctx: &Context<'_>, data: CallData) -> Result<Long> {
+        Ok(Long(backend(ctx).estimate_gas(data.into(), self.0.hash.into()).await?))
+    }
+
+    /// The RLP encoding of the block's header.
+    async fn raw_header(&self) -> Bytes {
+        Bytes(self.0.raw_header.clone())
+    }
+
+    /// The RLP encoding of the block.
+    async fn raw(&self) -> Bytes {
+        Bytes(self.0.raw.clone())
+    }
+}
+
+/// An Ethereum transaction.
+#[derive(Debug, Clone)]
+pub struct Transaction(TransactionData);
+
+impl Transaction {
+    /// Returns the receipt of the transaction, `None` if it is pending.
+    async fn receipt(&self, ctx: &Context<'_>) -> Result<Option<ReceiptData>> {
+        let Some(location) = self.0.location else { return Ok(None) };
+        let receipts = backend(ctx).receipts(location.block_hash).await?;
+        Ok(receipts.get(location.index as usize).cloned())
+    }
+}
+
+#[Object]
+impl Transaction {
+    /// The hash of this transaction.
+    async fn hash(&self) -> Bytes32 {
+        Bytes32(self.0.hash)
+    }
+
+    /// The nonce of the account this transaction was generated with.
+    async fn nonce(&self) -> Long {
+        Long(self.0.nonce)
+    }
+
+    /// The index of this transaction in the parent block. This will be null if the
+    /// transaction has not yet been mined.
+    async fn index(&self) -> Option<Long> {
+        self.0.location.map(|location| Long(location.index))
+    }
+
+    /// The account that sent this transaction - this will always be an externally owned
+    /// account.
+    async fn from(&self, block: Option<Long>) -> Account {
+        Account::new(self.0.from, block_or_latest(block))
+    }
+
+    /// The account the transaction was sent to. This is null for contract-creating
+    /// transactions.
+    async fn to(&self, block: Option<Long>) -> Option<Account> {
+        self.0.to.map(|to| Account::new(to, block_or_latest(block)))
+    }
+
+    /// The value, in wei, sent along with this transaction.
+    async fn value(&self) -> BigInt {
+        BigInt(self.0.value)
+    }
+
+    /// The price offered to miners for gas, in wei per unit.
+    async fn gas_price(&self) -> BigInt {
+        match self.0.location {
+            Some(location) => self.0.effective_gas_price(location.base_fee_per_gas),
+            None => self.0.gas_price.unwrap_or(self.0.max_fee_per_gas),
+        }
+        .into()
+    }
+
+    /// The maximum fee per gas offered to include a transaction, in wei.
+    async fn max_fee_per_gas(&self) -> Option<BigInt> {
+        self.0.max_priority_fee_per_gas.map(|_| self.0.max_fee_per_gas.into())
+    }
+
+    /// The maximum miner tip per gas offered to include a transaction,
+    /// in wei.
+    async fn max_priority_fee_per_gas(&self) -> Option<BigInt> {
+        self.0.max_priority_fee_per_gas.map(Into::into)
+    }
+
+    /// The actual amount of reward going to miner after considering the max fee
+    /// cap.
+    async fn effective_tip(&self) -> Option<BigInt> {
+        let location = self.0.location?;
+        let base_fee = location.base_fee_per_gas.unwrap_or_default() as u128;
+        Some(self.0.effective_gas_price(location.base_fee_per_gas).saturating_sub(base_fee).into())
+    }
+
+    /// The maximum amount of gas this transaction can consume.
+    async fn gas(&self) -> Long {
+        Long(self.0.gas)
+    }
+
+    /// The data supplied to the target of the transaction.
+    async fn input_data(&self) -> Bytes {
+        Bytes(self.0.input.clone())
+    }
+
+    /// The block this transaction was mined in. This will be null if the transaction
+    /// has not yet been mined.
+    async fn block(&self, ctx: &Context<'_>) -> Result<Option<Block>> {
+        let Some(location) = self.0.location else { return Ok(None) };
+        Ok(backend(ctx).block(location.block_hash.into()).await?.map(Block))
+    }
+
+    /// The return status of the transaction. This will be 1 if the transaction
+    /// succeeded, or 0 if it failed (due to a revert, or due to running out of gas). If the
+    /// transaction has not yet been mined, this field will be null.
+    async fn status(&self, ctx: &Context<'_>) -> Result<Option<Long>> {
+        Ok(self.receipt(ctx).await?.map(|receipt| Long(receipt.status as u64)))
+    }
+
+    /// The amount of gas that was used processing this transaction. If the
+    /// transaction has not yet been mined, this field will be null.
+    async fn gas_used(&self, ctx: &Context<'_>) -> Result<Option<Long>> {
+        Ok(self.receipt(ctx).await?.map(|receipt| Long(receipt.gas_used)))
+    }
+
+    /// The total gas used in the block up to and including this
+    /// transaction. If the transaction has not yet been mined, this field will be null.
+    async fn cumulative_gas_used(&self, ctx: &Context<'_>) -> Result<Option<Long>> {
+        Ok(self.receipt(ctx).await?.map(|receipt| Long(receipt.cumulative_gas_used)))
+    }
+
+    /// Actual value per gas deducted from the sender's account. If the
+    /// transaction has not yet been mined, this field will be null.
+    async fn effective_gas_price(&self) -> Option<BigInt> {
+        let location = self.0.location?;
+        Some(self.0.effective_gas_price(location.base_fee_per_gas).into())
+    }
+
+    /// The account that was created by a contract creation transaction. If
+    /// the transaction was not a contract creation transaction, or it has not yet been mined,
+    /// this field will be null.
+    async fn created_contract(&self, block: Option<Long>) -> Option<Account> {
+        if self.0.to.is_some() || self.0.location.is_none() {
+            return None
+        }
+        Some(Account::new(self.0.from.create(self.0.nonce), block_or_latest(block)))
+    }
+
+    /// A list of log entries emitted by this transaction. If the transaction has not
+    /// yet been mined, this field will be null.
+    async fn logs(&self, ctx: &Context<'_>) -> Result<Option<Vec<Log>>> {
+        Ok(self.receipt(ctx).await?.map(|receipt| receipt.logs.into_iter().map(Log).collect()))
+    }
+
+    /// The first part of the signature.
+    async fn r(&self) -> BigInt {
+        BigInt(self.0.signature.r())
+    }
+
+    /// The second part of the signature.
+    async fn s(&self) -> BigInt {
+        BigInt(self.0.signature.s())
+    }
+
+    /// The recovery id of the signature, including the EIP-155 chain id of legacy
+    /// transactions.
+    async fn v(&self) -> BigInt {
+        let parity = self.0.signature.v() as u128;
+        match (self.0.tx_type, self.0.chain_id) {
+            (0, Some(chain_id)) => BigInt::from(35 + 2 * chain_id as u128 + parity),
+            (0, None) => BigInt::from(27 + parity),
+            _ => BigInt::from(parity),
+        }
+    }
+
+    /// The parity of the signature of typed transactions.
+    async fn y_parity(&self) -> Option<BigInt> {
+        (self.0.tx_type != 0).then(|| BigInt::from(self.0.signature.v() as u128))
+    }
+
+    /// The EIP-2718 type of the transaction.
+    #[graphql(name = "type")]
+    async fn tx_type(&self) -> Option<Long> {
+        Some(Long(self.0.tx_type as u64))
+    }
+
+    /// The list of addresses and storage keys the transaction plans to access.
+    async fn access_list(&self) -> Option<Vec<AccessTuple>> {
+        self.0.access_list.as_ref().map(|access_list| {
+            access_list
+                .iter()
+                .map(|item| AccessTuple {
+                    address: Address(item.address),
+                    storage_keys: item.storage_keys.iter().copied().map(Bytes32).collect(),
+                })
+                .collect()
+        })
+    }
+
+    /// The canonical encoding of the transaction.
+    async fn raw(&self) -> Bytes {
+        Bytes(self.0.raw.clone())
+    }
+
+    /// The canonical encoding of the receipt. For post EIP-2718 typed
+    /// transactions this is the transaction type followed by the receipt encoding.
+    async fn raw_receipt(&self, ctx: &Context<'_>) -> Result<Bytes> {
+        Ok(Bytes(self.receipt(ctx).await?.map(|receipt| receipt.raw).unwrap_or_default()))
+    }
+}
+
+/// An Ethereum log entry.
+#[derive(Debug, Clone)]
+pub struct Log(LogData);
+
+#[Object]
+impl Log {
+    /// The index of this log in the block.
+    async fn index(&self) -> Long {
+        Long(self.0.index)
+    }
+
+    /// The account which generated this log - this will always be a contract
+    /// account.
+    async fn account(&self, block: Option<Long>) -> Account {
+        Account::new(self.0.address, block_or_latest(block))
+    }
+
+    /// A list of 0-4 indexed topics for the log.
+    async fn topics(&self) -> Vec<Bytes32> {
+        self.0.topics.iter().copied().map(Bytes32).collect()
+    }
+
+    /// Unindexed data for this log.
+    async fn data(&self) -> Bytes {
+        Bytes(self.0.data.clone())
+    }
+
+    /// The transaction that generated this log entry.
+    async fn transaction(&self, ctx: &Context<'_>) -> Result<Transaction> {
+        backend(ctx)
+            .transaction(self.0.transaction_hash)
+            .await?
+            .map(Transaction)
+            .ok_or_else(|| Error::new(format!("transaction {} not found", self.0.transaction_hash)))
+    }
+}
+
+/// An Ethereum account at a particular block.
+#[derive(Debug, Clone, Copy)]
+pub struct Account {
+    address: alloy_primitives::Address,
+    block: BlockId,
+}
+
+impl Account {
+    const fn new(address: alloy_primitives::Address, block: BlockId) -> Self {
+        Self { address, block }
+    }
+}
+
+#[Object]
+impl Account {
+    /// The address owning the account.
+    async fn address(&self) -> Address {
+        Address(self.address)
+    }
+
+    /// The balance of the account, in wei.
+    async fn balance(&self, ctx: &Context<'_>) -> Result<BigInt> {
+        Ok(BigInt(backend(ctx).balance(self.address, self.block).await?))
+    }
+
+    /// The number of transactions sent from this account, or in the case
+    /// of a contract, the number of contracts created. Otherwise known as the nonce.
+    async fn transaction_count(&self, ctx: &Context<'_>) -> Result<Long> {
+        Ok(Long(backend(ctx).transaction_count(self.address, self.block).await?))
+    }
+
+    /// Contains the smart contract code for this account, if the account is a (non
+    /// self-destructed) contract.
+    async fn code(&self, ctx: &Context<'_>) -> Result<Bytes> {
+        Ok(Bytes(backend(ctx).code(self.address, self.block).await?))
+    }
+
+    /// Provides access to the storage of a contract account, indexed by its 32 byte
+    /// slot identifier.
+    async fn storage(&self, ctx: &Context<'_>, slot: Bytes32) -> Result<Bytes32> {
+        Ok(Bytes32(backend(ctx).storage(self.address, slot.0, self.block).await?))
+    }
+}
+
+/// An EIP-2930 access list entry.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct AccessTuple {
+    /// The address the transaction plans to access.
+    address: Address,
+    /// The storage slots of the address the transaction plans to access.
+    storage_keys: Vec<Bytes32>,
+}
+
+/// The result of a local call operation.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct CallResult {
+    /// The return data of the called contract.
+    data: Bytes,
+    /// The amount of gas used by the call, after any refunds.
+    gas_used: Long,
+    /// The result of the call - 1 for success or 0 for failure.
+    status: Long,
+}
+
+/// Contains the current synchronisation state of the client.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct SyncState {
+    /// The block number at which synchronisation started.
+    starting_block: Long,
+    /// The point at which synchronisation has presently reached.
+    current_block: Long,
+    /// The latest known block number.
+    highest_block: Long,
+}
+
+/// Encapsulates log filter criteria for a filter applied to a single block.
+#[derive(Debug, Clone, InputObject)]
+pub struct BlockFilterCriteria {
+    /// The list of addresses that are of interest. If this list is empty, results
+    /// will not be filtered by address.
+    addresses: Option<Vec<Address>>,
+    /// Restricts matches to particular event topics. Each event has a list of
+    /// topics. Topics matches a prefix of that list. An empty element array matches any topic.
+    /// Non-empty elements represent an alternative that matches any of the contained topics.
+    topics: Option<Vec<Vec<Bytes32>>>,
+}
+
+/// Encapsulates log filter criteria for searching log entries.
+#[derive(Debug, Clone, InputObject)]
+pub struct FilterCriteria {
+    /// The block at which to start searching, inclusive. Defaults to the latest
+    /// block if not supplied.
+    from_block: Option<Long>,
+    /// The block at which to stop searching, inclusive. Defaults to the latest
+    /// block if not supplied.
+    to_block: Option<Long>,
+    /// A list of addresses that are of interest. If this list is empty, results
+    /// will not be filtered by address.
+    addresses: Option<Vec<Address>>,
+    /// Restricts matches to particular event topics. Each event has a list of
+    /// topics. Topics matches a prefix of that list. An empty element array matches any topic.
+    /// Non-empty elements represent an alternative that matches any of the contained topics.
+    topics: Option<Vec<Vec<Bytes32>>>,
+}
+
+impl TryFrom<FilterCriteria> for Filter {
+    type Error = Error;
+
+    fn try_from(criteria: FilterCriteria) -> Result<Self> {
+        let mut filter = Self::new().address(
+            criteria.addresses.unwrap_or_default().into_iter().map(|a| a.0).collect::<Vec<_>>(),
+        );
+        if let Some(from) = criteria.from_block {
+            filter = filter.from_block(from.0);
+        }
+        if let Some(to) = criteria.to_block {
+            filter = filter.to_block(to.0);
+        }
+        for (i, topics) in topic_sets(criteria.topics.unwrap_or_default())?.into_iter().enumerate()
+        {
+            filter.topics[i] = topics.into();
+        }
+        Ok(filter)
+    }
+}
+
+/// Converts the topics of a filter, which can not have more than four positions.
+fn topic_sets(topics: Vec<Vec<Bytes32>>) -> Result<Vec<Vec<B256>>> {
+    if topics.len() > 4 {
+        return Err("filter can not have more than 4 topics".into())
+    }
+    Ok(topics.into_iter().map(|set| set.into_iter().map(|t| t.0).collect()).collect())
+}
+
+/// Represents the data associated with a local contract call. All fields are optional.
+#[derive(Debug, Clone, InputObject)]
+pub struct CallData {
+    /// The address making the call.
+    from: Option<Address>,
+    /// The address the call is sent to.
+    to: Option<Address>,
+    /// The amount of gas sent with the call.
+    gas: Option<Long>,
+    /// The price, in wei, offered for each unit of gas.
+    gas_price: Option<BigInt>,
+    /// The maximum fee per gas offered, in wei.
+    max_fee_per_gas: Option<BigInt>,
+    /// The maximum miner tip per gas offered, in wei.
+    max_priority_fee_per_gas: Option<BigInt>,
+    /// The value, in wei, sent along with the call.
+    value: Option<BigInt>,
+    /// The data sent to the callee.
+    data: Option<Bytes>,
+}
+
+impl From<CallData> for TransactionRequest {
+    fn from(data: CallData) -> Self {
+        Self {
+            from: data.from.map(|from| from.0),
+            to: data.to.map(|to| TxKind::Call(to.0)),
+            gas: data.gas.map(|gas| gas.0),
+            gas_price: data.gas_price.map(|price| price.0.saturating_to()),
+            max_fee_per_gas: data.max_fee_per_gas.map(|fee| fee.0.saturating_to()),
+            max_priority_fee_per_gas: data
+                .max_priority_fee_per_gas
+                .map(|fee| fee.0.saturating_to()),
+            value: data.value.map(|value| value.0),
+            input: data.data.map(|data| TransactionInput::new(data.0)).unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Accounts without an explicit block are read at the latest block.
+fn block_or_latest(block: Option<Long>) -> BlockId {
+    block.map_or(BlockId::latest(), |n| n.0.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{SyncStateData, TransactionLocation};
+    use alloy_eips::BlockNumberOrTag;
+    use alloy_primitives::{bytes, PrimitiveSignature as Signature, U256};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    const TX_HASH: B256 = B256::with_last_byte(0xaa);
+    const CONTRACT: alloy_primitives::Address = alloy_primitives::Address::with_last_byte(2);
+    const TOPIC: B256 = B256::with_last_byte(0x11);
+
+    /// Backend serving a chain of blocks, of which only the latest one has a transaction.
+    #[derive(Debug)]
+    struct TestBackend {
+        blocks: Vec<Arc<BlockData>>,
+        receipt: ReceiptData,
+        sent: Mutex<Vec<alloy_primitives::Bytes>>,
+    }
+
+    impl TestBackend {
+        fn new(len: u64) -> Self {
+            let mut blocks: Vec<_> = (0..len)
+                .map(|number| BlockData {
+                    number,
+                    hash: B256::with_last_byte(number as u8 + 1),
+                    parent_hash: B256::with_last_byte(number as u8),
+                    base_fee_per_gas: Some(7),
+                    ..Default::default()
+                })
+                .collect();
+
+            let latest = blocks.last_mut().unwrap();
+            latest.transactions.push(TransactionData {
+                hash: TX_HASH,
+                nonce: 3,
+                from: alloy_primitives::Address::with_last_byte(1),
+                to: Some(CONTRACT),
+                value: U256::from(5),
+                gas: 50_000,
+                gas_price: Some(10),
+                max_fee_per_gas: 10,
+                max_priority_fee_per_gas: None,
+                input: bytes!("abcd"),
+                chain_id: Some(999),
+                tx_type: 0,
+                access_list: None,
+                signature: Signature::new(U256::from(1), U256::from(2), true),
+                raw: Default::default(),
+                location: Some(TransactionLocation {
+                    block_hash: latest.hash,
+                    block_number: latest.number,
+                    base_fee_per_gas: latest.base_fee_per_gas,
+                    index: 0,
+                    receipt_index: 1,
+                }),
+            });
+            let receipt = ReceiptData {
+                status: true,
+                cumulative_gas_used: 30_000,
+                gas_used: 21_000,
+                logs: vec![LogData {
+                    index: 0,
+                    address: CONTRACT,
+                    topics: vec![TOPIC],
+                    data: bytes!("01"),
+                    transaction_hash: TX_HASH,
+                    block_number: latest.number,
+                }],
+                raw: Default::default(),
+            };
+
+            Self {
+                blocks: blocks.into_iter().map(Arc::new).collect(),
+                receipt,
+                sent: Mutex::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl GraphQlBackend for TestBackend {
+        async fn latest_block_number(&self) -> Result<u64> {
+            Ok(self.blocks.len() as u64 - 1)
+        }
+
+        async fn block(&self, id: BlockId) -> Result<Option<Arc<BlockData>>> {
+            Ok(match id {
+                BlockId::Hash(hash) => self.blocks.iter().find(|b| b.hash == hash.block_hash),
+                BlockId::Number(BlockNumberOrTag::Number(n)) => self.blocks.get(n as usize),
+                BlockId::Number(_) => self.blocks.last(),
+            }
+            .cloned())
+        }
+
+        async fn receipts(&self, block_hash: B256) -> Result<Arc<Vec<ReceiptData>>> {
+            let block = self.blocks.iter().find(|b| b.hash == block_hash).ok_or("not found")?;
+            Ok(Arc::new(block.transactions.iter().map(|_| self.receipt.clone()).collect()))
+        }
+
+        async fn transaction(&self, hash: B256) -> Result<Option<TransactionData>> {
+            Ok(self.blocks.iter().flat_map(|b| &b.transactions).find(|tx| tx.hash == hash).cloned())
+        }
+
+        async fn logs(&self, filter: Filter) -> Result<Vec<LogData>> {
+            Ok(self
+                .receipt
+                .logs
+                .iter()
+                .filter(|log| filter.address.matches(&log.address))
+                .cloned()
+                .collect())
+        }
+
+        async fn balance(&self, _: alloy_primitives::Address, _: BlockId) -> Result<U256> {
+            Ok(U256::from(1))
+        }
+
+        async fn transaction_count(&self, _: alloy_primitives::Address, _: BlockId) -> Result<u64> {
+            Ok(0)
+        }
+
+        async fn code(
+            &self,
+            _: alloy_primitives::Address,
+            _: BlockId,
+        ) -> Result<alloy_primitives::Bytes> {
+            Ok(Default::default())
+        }
+
+        async fn storage(&self, _: alloy_primitives::Address, _: B256, _: BlockId) -> Result<B256> {
+            Ok(B256::ZERO)
+        }
+
+        async fn call(&self, _: TransactionRequest, _: BlockId) -> Result<CallOutcome> {
+            Err("unsupported".into())
+        }
+
+        async fn estimate_gas(&self, _: TransactionRequest, _: BlockId) -> Result<u64> {
+            Err("unsupported".into())
+        }
+
+        async fn gas_price(&self) -> Result<U256> {
+            Ok(U256::from(7))
+        }
+
+        async fn max_priority_fee_per_gas(&self) -> Result<U256> {
+            Ok(U256::from(1))
+        }
+
+        fn chain_id(&self) -> u64 {
+            999
+        }
+
+        fn sync_state(&self) -> Result<Option<SyncStateData>> {
+            Ok(None)
+        }
+
+        async fn send_raw_transaction(&self, tx: alloy_primitives::Bytes) -> Result<B256> {
+            self.sent.lock().unwrap().push(tx);
+            Ok(TX_HASH)
+        }
+    }
+
+    fn schema(config: &GraphQlConfig) -> GraphQlSchema {
+        build_schema(Arc::new(TestBackend::new(10)), config)
+    }
+
+    #[tokio::test]
+    async fn query_blocks() {
+        let schema = schema(&GraphQlConfig::default());
+
+        let response = schema.execute("{ block { number parent { number } } chainID }").await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({
+                "block": { "number": "0x9", "parent": { "number": "0x8" } },
+                "chainID": "0x3e7",
+            })
+        );
+
+        let response = schema.execute("{ blocks(from: 2, to: \"0x3\") { number } }").await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({ "blocks": [{ "number": "0x2" }, { "number": "0x3" }] })
+        );
+    }
+
+    #[tokio::test]
+    async fn enforces_limits() {
+        let schema = schema(&GraphQlConfig { max_complexity: 20, max_depth: 3 });
+
+        // ten blocks with a single field each are within the limit
+        let response = schema.execute("{ blocks(from: 0, to: 9) { number } }").await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+        // ten blocks with three fields each are not
+        let response = schema.execute("{ blocks(from: 0, to: 9) { number hash gasUsed } }").await;
+        assert_eq!(response.errors.len(), 1);
+
+        // open ranges count as the maximum range
+        let response = schema.execute("{ blocks(from: 0) { number } }").await;
+        assert_eq!(response.errors.len(), 1);
+
+        let response =
+            schema.execute("{ block { parent { parent { parent { number } } } } }").await;
+        assert_eq!(response.errors.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn query_transaction() {
+        let schema = schema(&GraphQlConfig::default());
+
+        let response = schema
+            .execute(format!(
+                "{{ transaction(hash: \"{TX_HASH}\") {{ nonce index from {{ address }} \
+                 to {{ address }} gasPrice effectiveGasPrice status gasUsed cumulativeGasUsed \
+                 block {{ number }} logs {{ index topics }} }} }}"
+            ))
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({
+                "transaction": {
+                    "nonce": "0x3",
+                    "index": "0x0",
+                    "from": { "address": "0x0000000000000000000000000000000000000001" },
+                    "to": { "address": "0x0000000000000000000000000000000000000002" },
+                    "gasPrice": "0xa",
+                    "effectiveGasPrice": "0xa",
+                    "status": "0x1",
+                    "gasUsed": "0x5208",
+                    "cumulativeGasUsed": "0x7530",
+                    "block": { "number": "0x9" },
+                    "logs": [{ "index": "0x0", "topics": [TOPIC.to_string()] }],
+                }
+            })
+        );
+
+        let response = schema
+            .execute(format!("{{ transaction(hash: \"{}\") {{ nonce }} }}", B256::ZERO))
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(response.data.into_json().unwrap(), serde_json::json!({ "transaction": null }));
+    }
+
+    #[tokio::test]
+    async fn query_block_transactions_and_logs() {
+        let schema = schema(&GraphQlConfig::default());
+
+        let response = schema
+            .execute(format!(
+                "{{ block(number: 9) {{ transactionCount transactionAt(index: 0) {{ nonce }} \
+                 matching: logs(filter: {{ topics: [[\"{TOPIC}\"]] }}) {{ data \
+                 transaction {{ nonce }} }} \
+                 other: logs(filter: {{ topics: [[], [\"{TOPIC}\"]] }}) {{ data }} }} }}"
+            ))
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({
+                "block": {
+                    "transactionCount": "0x1",
+                    "transactionAt": { "nonce": "0x3" },
+                    "matching": [{ "data": "0x01", "transaction": { "nonce": "0x3" } }],
+                    "other": [],
+                }
+            })
+        );
+
+        let response = schema
+            .execute("{ block(number: 8) { transactionCount logs(filter: {}) { data } } }")
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({ "block": { "transactionCount": "0x0", "logs": [] } })
+        );
+    }
+
+    #[tokio::test]
+    async fn query_logs() {
+        let schema = schema(&GraphQlConfig::default());
+
+        let response = schema
+            .execute(format!(
+                "{{ matching: logs(filter: {{ addresses: [\"{CONTRACT}\"] }}) {{ index data \
+                 account {{ address }} transaction {{ hash }} }} \
+                 other: logs(filter: {{ addresses: [\"{}\"] }}) {{ index }} }}",
+                alloy_primitives::Address::ZERO
+            ))
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({
+                "matching": [{
+                    "index": "0x0",
+                    "data": "0x01",
+                    "account": { "address": CONTRACT.to_string() },
+                    "transaction": { "hash": TX_HASH.to_string() },
+                }],
+                "other": [],
+            })
+        );
+
+        let response =
+            schema.execute("{ logs(filter: { topics: [[], [], [], [], []] }) { index } }").await;
+        assert_eq!(response.errors.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn send_raw_transaction() {
+        let backend = Arc::new(TestBackend::new(1));
+        let schema = build_schema(backend.clone(), &GraphQlConfig::default());
+
+        let response = schema.execute("mutation { sendRawTransaction(data: \"0x02f8\") }").await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({ "sendRawTransaction": TX_HASH.to_string() })
+        );
+        assert_eq!(*backend.sent.lock().unwrap(), vec![bytes!("02f8")]);
+    }
+}
@@ -29,6 +29,18 @@ pub fn default_max_tracing_requests() -> usize {
 /// The default number of getproof calls we are allowing to run concurrently.
 pub const DEFAULT_PROOF_PERMITS: usize = 25;
 
+/// The default number of slow queries kept for `admin_slowQueries`.
+pub const DEFAULT_SLOW_QUERY_LOG_SIZE: usize = 100;
+
+/// The default maximum size of the trace cache: 10GB
+pub const DEFAULT_TRACE_CACHE_MAX_SIZE_MB: u64 = 10 * 1024;
+
+/// The default maximum complexity of a GraphQL query.
+pub const DEFAULT_GRAPHQL_MAX_COMPLEXITY: usize = 10_000;
+
+/// The default maximum depth of a GraphQL query.
+pub const DEFAULT_GRAPHQL_MAX_DEPTH: usize = 16;
+
 /// The default IPC endpoint
 #[cfg(windows)]
 pub const DEFAULT_IPC_ENDPOINT: &str = r"\\.\pipe\reth.ipc";
@@ -0,0 +1,91 @@
+//! Types for the Hyperliquid `hl` namespace of the RPC server.
+
+use alloy_primitives::{Address, BlockNumber, TxHash, U256};
+use serde::{Deserialize, Serialize};
+
+/// Position of a transfer in the index, used to resume paginated queries.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferCursor {
+    /// Number of the block containing the transfer.
+    pub block_number: BlockNumber,
+    /// Index of the transfer among the indexed transfers of the block.
+    pub index: u32,
+}
+
+/// An ERC20 token transfer, as returned by `hl_getTokenTransfers`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenTransfer {
+    /// The token contract.
+    pub token: Address,
+    /// The sender of the tokens.
+    pub from: Address,
+    /// The recipient of the tokens.
+    pub to: Address,
+    /// The amount of tokens transferred.
+    pub value: U256,
+    /// Number of the block containing the transfer.
+    pub block_number: BlockNumber,
+    /// Hash of the transaction that transferred the tokens.
+    pub transaction_hash: TxHash,
+    /// Index of the HyperCore spot token, if the tokens were bridged from or to HyperCore.
+    pub spot_token: Option<u64>,
+}
+
+/// A page of token transfers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenTransfersPage {
+    /// The transfers, in the order they happened.
+    pub transfers: Vec<TokenTransfer>,
+    /// Cursor of the next page, `None` if this is the last page of the block range.
+    pub next_cursor: Option<TransferCursor>,
+}
+
+/// Tokens received and sent by a holder in a block, as returned by `hl_getTokenBalanceDeltas`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenBalanceDelta {
+    /// Number of the block the balance changed in.
+    pub block_number: BlockNumber,
+    /// Total amount of tokens received in the block.
+    pub received: U256,
+    /// Total amount of tokens sent in the block.
+    pub sent: U256,
+}
+
+/// Direction of a [`NativeTransfer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NativeTransferKind {
+    /// Tokens deposited from HyperCore to the user.
+    Deposit,
+    /// Tokens withdrawn by the user to HyperCore.
+    Withdrawal,
+}
+
+/// A native token transfer between HyperCore and HyperEVM, as returned by
+/// `hl_getNativeTransfers`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeTransfer {
+    /// The direction of the transfer.
+    pub kind: NativeTransferKind,
+    /// The amount of tokens transferred.
+    pub value: U256,
+    /// Number of the block containing the transfer.
+    pub block_number: BlockNumber,
+    /// Hash of the transaction that transferred the tokens.
+    pub transaction_hash: TxHash,
+}
+
+/// A page of native token transfers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeTransfersPage {
+    /// The transfers, in the order they happened.
+    pub transfers: Vec<NativeTransfer>,
+    /// Cursor of the next page, `None` if this is the last page of the block range.
+    pub next_cursor: Option<TransferCursor>,
+}
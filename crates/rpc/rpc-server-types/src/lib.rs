@@ -10,9 +10,18 @@
 
 /// Common RPC constants.
 pub mod constants;
+pub mod hl;
+pub mod otterscan;
 pub mod result;
+pub mod slow_query;
 
 mod module;
 pub use module::{RethRpcModule, RpcModuleSelection};
 
+pub use hl::{
+    NativeTransfer, NativeTransferKind, NativeTransfersPage, TokenBalanceDelta, TokenTransfer,
+    TokenTransfersPage, TransferCursor,
+};
+pub use otterscan::{ContractDeployment, TransactionsWithReceipts};
 pub use result::ToRpcResult;
+pub use slow_query::{SlowQuery, SlowQueryLog};
@@ -0,0 +1,38 @@
+//! Types for the Otterscan namespace extensions of the RPC server.
+
+use alloy_primitives::{Address, BlockNumber, TxHash};
+use serde::{Deserialize, Serialize};
+
+/// A contract deployed in a transaction, as returned by `ots_getContractsDeployed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractDeployment {
+    /// Address of the deployed contract.
+    pub address: Address,
+    /// Number of the block the contract was deployed in.
+    pub block_number: BlockNumber,
+    /// Hash of the transaction that deployed the contract.
+    pub hash: TxHash,
+    /// Sender of the transaction that deployed the contract.
+    pub creator: Address,
+    /// Contract that deployed the contract, if it wasn't deployed by the transaction itself.
+    pub factory: Option<Address>,
+}
+
+/// A page of transactions touching an address, as returned by `ots_searchTransactionsBefore` and
+/// `ots_searchTransactionsAfter`.
+///
+/// Unlike the Otterscan type of `alloy-rpc-types-trace`, the transactions are the RPC
+/// transactions of the network.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionsWithReceipts<T, R> {
+    /// Transactions of the page, from the most recent one.
+    pub txs: Vec<T>,
+    /// Receipts of the transactions.
+    pub receipts: Vec<R>,
+    /// Whether there are no more recent transactions.
+    pub first_page: bool,
+    /// Whether there are no older transactions.
+    pub last_page: bool,
+}
@@ -0,0 +1,97 @@
+//! Types for the slow query log of the RPC server.
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{collections::VecDeque, sync::Arc};
+
+/// A call that exceeded the slow query thresholds of the RPC server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlowQuery {
+    /// Unix timestamp in milliseconds at which the call started.
+    pub started_at: u64,
+    /// The called method.
+    pub method: String,
+    /// The JSON encoded parameters, truncated.
+    pub params: String,
+    /// Address of the caller.
+    pub caller: Option<String>,
+    /// How long the call took in milliseconds.
+    pub duration_ms: u64,
+    /// Size of the JSON encoded response.
+    pub response_bytes: usize,
+    /// Whether the call succeeded.
+    pub success: bool,
+    /// Read-only database transactions opened by the call.
+    pub db_read_transactions: u64,
+    /// Database cursors created in read-only transactions by the call.
+    pub db_cursors: u64,
+    /// Database point lookups in read-only transactions by the call.
+    pub db_gets: u64,
+}
+
+/// A shared, bounded log of the most recent slow queries.
+#[derive(Debug, Clone, Default)]
+pub struct SlowQueryLog {
+    /// The logged queries, oldest first.
+    queries: Arc<Mutex<VecDeque<SlowQuery>>>,
+    /// Maximum number of queries to keep.
+    capacity: usize,
+}
+
+impl SlowQueryLog {
+    /// Creates a new log that keeps the `capacity` most recent queries.
+    pub fn new(capacity: usize) -> Self {
+        Self { queries: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))), capacity }
+    }
+
+    /// Adds a query, evicting the oldest one if the log is full.
+    pub fn push(&self, query: SlowQuery) {
+        if self.capacity == 0 {
+            return
+        }
+        let mut queries = self.queries.lock();
+        if queries.len() == self.capacity {
+            queries.pop_front();
+        }
+        queries.push_back(query);
+    }
+
+    /// Returns the logged queries, most recent first.
+    pub fn recent(&self) -> Vec<SlowQuery> {
+        self.queries.lock().iter().rev().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(started_at: u64) -> SlowQuery {
+        SlowQuery {
+            started_at,
+            method: "eth_getLogs".to_string(),
+            params: "[]".to_string(),
+            caller: None,
+            duration_ms: 1000,
+            response_bytes: 0,
+            success: true,
+            db_read_transactions: 0,
+            db_cursors: 0,
+            db_gets: 0,
+        }
+    }
+
+    #[test]
+    fn evicts_oldest() {
+        let log = SlowQueryLog::new(2);
+        log.push(query(1));
+        log.push(query(2));
+        log.push(query(3));
+        assert_eq!(log.recent(), vec![query(3), query(2)]);
+
+        let disabled = SlowQueryLog::new(0);
+        disabled.push(query(1));
+        assert!(disabled.recent().is_empty());
+    }
+}
@@ -12,8 +12,8 @@ use reth_network_api::{NetworkInfo, Peers};
 use reth_network_peers::{id2pk, AnyNode, NodeRecord};
 use reth_network_types::PeerKind;
 use reth_primitives::EthereumHardfork;
-use reth_rpc_api::AdminApiServer;
-use reth_rpc_server_types::ToRpcResult;
+use reth_rpc_api::{AdminApiServer, SlowQueryApiServer};
+use reth_rpc_server_types::{SlowQuery, SlowQueryLog, ToRpcResult};
 
 /// `admin` API implementation.
 ///
@@ -197,3 +197,24 @@ impl<N, ChainSpec> std::fmt::Debug for AdminApi<N, ChainSpec> {
         f.debug_struct("AdminApi").finish_non_exhaustive()
     }
 }
+
+/// `admin` API implementation for the slow query log of the RPC server.
+#[derive(Debug, Clone)]
+pub struct SlowQueryApi {
+    /// The log the RPC server middleware records slow queries to.
+    log: SlowQueryLog,
+}
+
+impl SlowQueryApi {
+    /// Creates a new instance of `SlowQueryApi`.
+    pub const fn new(log: SlowQueryLog) -> Self {
+        Self { log }
+    }
+}
+
+impl SlowQueryApiServer for SlowQueryApi {
+    /// Handler for `admin_slowQueries`
+    fn slow_queries(&self) -> RpcResult<Vec<SlowQuery>> {
+        Ok(self.log.recent())
+    }
+}
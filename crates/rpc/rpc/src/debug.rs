@@ -37,7 +37,7 @@ use reth_rpc_eth_api::{
     helpers::{EthTransactions, TraceExt},
     EthApiTypes, FromEthApiError, RpcNodeCore,
 };
-use reth_rpc_eth_types::{EthApiError, StateCacheDb};
+use reth_rpc_eth_types::{EthApiError, StateCacheDb, TraceCache, TraceCacheKey};
 use reth_rpc_server_types::{result::internal_rpc_err, ToRpcResult};
 use reth_tasks::pool::BlockingTaskGuard;
 use revm::{context_interface::Transaction, state::EvmState, DatabaseCommit};
@@ -62,8 +62,14 @@ impl<Eth, BlockExecutor> DebugApi<Eth, BlockExecutor> {
         eth: Eth,
         blocking_task_guard: BlockingTaskGuard,
         block_executor: BlockExecutor,
+        trace_cache: Option<TraceCache>,
     ) -> Self {
-        let inner = Arc::new(DebugApiInner { eth_api: eth, blocking_task_guard, block_executor });
+        let inner = Arc::new(DebugApiInner {
+            eth_api: eth,
+            blocking_task_guard,
+            block_executor,
+            trace_cache,
+        });
         Self { inner }
     }
 
@@ -207,6 +213,32 @@ where
         self.trace_block(block, evm_env, opts).await
     }
 
+    /// Same as [`Self::debug_trace_block`], but returns the result from the trace cache if it's
+    /// enabled.
+    ///
+    /// Results of JS tracers aren't cached.
+    async fn debug_trace_block_cached(
+        &self,
+        block_id: BlockId,
+        opts: GethDebugTracingOptions,
+    ) -> Result<Vec<TraceResult>, Eth::Error> {
+        let key = match &self.inner.trace_cache {
+            Some(_) if !matches!(opts.tracer, Some(GethDebugTracerType::JsTracer(_))) => {
+                TraceCacheKey::for_block(self.provider(), block_id, "debug_traceBlock", &opts)
+                    .map_err(Eth::Error::from_eth_err)?
+            }
+            _ => None,
+        };
+        let trace = async {
+            let _permit = self.acquire_trace_permit().await;
+            self.debug_trace_block(block_id, opts).await
+        };
+        match (&self.inner.trace_cache, key) {
+            (Some(cache), Some(key)) => cache.get_or_insert_with(key, trace).await,
+            _ => trace.await,
+        }
+    }
+
     /// Trace the transaction according to the provided options.
     ///
     /// Ref: <https://geth.ethereum.org/docs/developers/evm-tracing/built-in-tracers>
@@ -947,8 +979,7 @@ where
         block: B256,
         opts: Option<GethDebugTracingOptions>,
     ) -> RpcResult<Vec<TraceResult>> {
-        let _permit = self.acquire_trace_permit().await;
-        Self::debug_trace_block(self, block.into(), opts.unwrap_or_default())
+        Self::debug_trace_block_cached(self, block.into(), opts.unwrap_or_default())
             .await
             .map_err(Into::into)
     }
@@ -959,8 +990,7 @@ where
         block: BlockNumberOrTag,
         opts: Option<GethDebugTracingOptions>,
     ) -> RpcResult<Vec<TraceResult>> {
-        let _permit = self.acquire_trace_permit().await;
-        Self::debug_trace_block(self, block.into(), opts.unwrap_or_default())
+        Self::debug_trace_block_cached(self, block.into(), opts.unwrap_or_default())
             .await
             .map_err(Into::into)
     }
@@ -1261,4 +1291,6 @@ struct DebugApiInner<Eth, BlockExecutor> {
     blocking_task_guard: BlockingTaskGuard,
     /// block executor for debug & trace apis
     block_executor: BlockExecutor,
+    /// Cache of block traces, if enabled
+    trace_cache: Option<TraceCache>,
 }
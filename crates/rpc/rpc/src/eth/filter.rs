@@ -9,24 +9,25 @@ use alloy_rpc_types_eth::{
 use async_trait::async_trait;
 use jsonrpsee::{core::RpcResult, server::IdProvider};
 use reth_chainspec::ChainInfo;
+use reth_db_api::models::{LogPosition, LogTopicKey};
 use reth_primitives::RecoveredBlock;
 use reth_provider::{
-    BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, HeaderProvider, ProviderBlock,
-    ProviderError, ProviderReceipt,
+    BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, HeaderProvider, LogIndexReader,
+    ProviderBlock, ProviderError, ProviderReceipt,
 };
 use reth_rpc_eth_api::{
     EthApiTypes, EthFilterApiServer, FullEthApiTypes, RpcNodeCoreExt, RpcTransaction,
     TransactionCompat,
 };
 use reth_rpc_eth_types::{
-    logs_utils::{self, append_matching_block_logs, ProviderOrBlock},
+    logs_utils::{self, append_indexed_block_logs, append_matching_block_logs, ProviderOrBlock},
     EthApiError, EthFilterConfig, EthStateCache, EthSubscriptionIdProvider,
 };
 use reth_rpc_server_types::{result::rpc_error_with_code, ToRpcResult};
 use reth_tasks::TaskSpawner;
 use reth_transaction_pool::{NewSubpoolTransactionStream, PoolTransaction, TransactionPool};
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, BTreeSet, HashMap},
     fmt,
     iter::StepBy,
     ops::RangeInclusive,
@@ -155,7 +156,7 @@ where
 
 impl<Eth> EthFilter<Eth>
 where
-    Eth: FullEthApiTypes<Provider: BlockReader + BlockIdReader> + RpcNodeCoreExt,
+    Eth: FullEthApiTypes<Provider: BlockReader + BlockIdReader + LogIndexReader> + RpcNodeCoreExt,
 {
     /// Access the underlying provider.
     fn provider(&self) -> &Eth::Provider {
@@ -262,7 +263,7 @@ where
 #[async_trait]
 impl<Eth> EthFilterApiServer<RpcTransaction<Eth::NetworkTypes>> for EthFilter<Eth>
 where
-    Eth: FullEthApiTypes + RpcNodeCoreExt<Provider: BlockIdReader> + 'static,
+    Eth: FullEthApiTypes + RpcNodeCoreExt<Provider: BlockIdReader + LogIndexReader> + 'static,
 {
     /// Handler for `eth_newFilter`
     async fn new_filter(&self, filter: Filter) -> RpcResult<FilterId> {
@@ -381,7 +382,8 @@ struct EthFilterInner<Eth: EthApiTypes> {
 
 impl<Eth> EthFilterInner<Eth>
 where
-    Eth: RpcNodeCoreExt<Provider: BlockIdReader, Pool: TransactionPool> + EthApiTypes,
+    Eth: RpcNodeCoreExt<Provider: BlockIdReader + LogIndexReader, Pool: TransactionPool>
+        + EthApiTypes,
 {
     /// Access the underlying provider.
     fn provider(&self) -> &Eth::Provider {
@@ -492,21 +494,43 @@ where
             return Err(EthFilterError::InvalidBlockRangeParams)
         }
 
-        if to_block - from_block > self.max_blocks_per_filter {
+        // Blocks covered by the log index are looked up in the index instead of scanning their
+        // header blooms, so only the remainder of the range counts towards the block limit.
+        let indexed_range = self.indexed_block_range(filter, from_block, to_block)?;
+        let scan_from = indexed_range.as_ref().map_or(from_block, |range| range.end() + 1);
+        if scan_from <= to_block && to_block - scan_from > self.max_blocks_per_filter {
             return Err(EthFilterError::QueryExceedsMaxBlocks(self.max_blocks_per_filter))
         }
 
         let mut all_logs = Vec::new();
         let filter_params = FilteredParams::new(Some(filter.clone()));
 
+        if let Some(indexed_range) = indexed_range {
+            for (block_number, positions) in self.indexed_logs(filter, indexed_range)? {
+                let header = self
+                    .provider()
+                    .sealed_header(block_number)?
+                    .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
+                let num_hash = BlockNumHash::new(block_number, header.hash());
+                self.append_block_logs(
+                    &mut all_logs,
+                    BlockLogs::Indexed(&positions),
+                    num_hash,
+                    header.header().timestamp(),
+                    from_block..=to_block,
+                    chain_info.best_number,
+                )
+                .await?;
+            }
+        }
+
         // derive bloom filters from filter input, so we can check headers for matching logs
         let address_filter = FilteredParams::address_filter(&filter.address);
         let topics_filter = FilteredParams::topics_filter(&filter.topics);
 
         // loop over the range of new blocks and check logs if the filter matches the log's bloom
         // filter
-        for (from, to) in
-            BlockRangeInclusiveIter::new(from_block..=to_block, self.max_headers_range)
+        for (from, to) in BlockRangeInclusiveIter::new(scan_from..=to_block, self.max_headers_range)
         {
             let headers = self.provider().headers_range(from..=to)?;
 
@@ -526,32 +550,15 @@ where
                     };
 
                     let num_hash = BlockNumHash::new(header.number(), block_hash);
-                    if let Some((receipts, maybe_block)) =
-                        self.receipts_and_maybe_block(&num_hash, chain_info.best_number).await?
-                    {
-                        append_matching_block_logs(
-                            &mut all_logs,
-                            maybe_block
-                                .map(ProviderOrBlock::Block)
-                                .unwrap_or_else(|| ProviderOrBlock::Provider(self.provider())),
-                            &filter_params,
-                            num_hash,
-                            &receipts,
-                            false,
-                            header.timestamp(),
-                        )?;
-
-                        // size check but only if range is multiple blocks, so we always return all
-                        // logs of a single block
-                        let is_multi_block_range = from_block != to_block;
-                        if is_multi_block_range && all_logs.len() > self.max_logs_per_response {
-                            return Err(EthFilterError::QueryExceedsMaxResults {
-                                max_logs: self.max_logs_per_response,
-                                from_block,
-                                to_block: num_hash.number.saturating_sub(1),
-                            });
-                        }
-                    }
+                    self.append_block_logs(
+                        &mut all_logs,
+                        BlockLogs::Matching(&filter_params),
+                        num_hash,
+                        header.timestamp(),
+                        from_block..=to_block,
+                        chain_info.best_number,
+                    )
+                    .await?;
                 }
             }
         }
@@ -559,6 +566,128 @@ where
         Ok(all_logs)
     }
 
+    /// Returns the part of the given _inclusive_ range, starting at `from_block`, that can be
+    /// served from the log index.
+    ///
+    /// Returns `None` if the filter has neither addresses nor topics, or if `from_block` is not
+    /// covered by the index.
+    fn indexed_block_range(
+        &self,
+        filter: &Filter,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Option<RangeInclusive<u64>>, EthFilterError> {
+        if filter.address.is_empty() && filter.topics.iter().all(|topic| topic.is_empty()) {
+            return Ok(None)
+        }
+
+        let Some(index_range) = self.provider().log_index_range()? else { return Ok(None) };
+        if !index_range.contains(&from_block) {
+            return Ok(None)
+        }
+
+        Ok(Some(from_block..=to_block.min(*index_range.end())))
+    }
+
+    /// Returns the positions of the logs in the given range that match the filter, by block in
+    /// ascending order.
+    ///
+    /// The log index records the position of each topic in the log, so the logs at the returned
+    /// positions don't need to be matched against the filter again.
+    fn indexed_logs(
+        &self,
+        filter: &Filter,
+        range: RangeInclusive<u64>,
+    ) -> Result<BTreeMap<u64, Vec<u64>>, EthFilterError> {
+        let mut candidates: Option<BTreeSet<LogPosition>> = None;
+
+        if !filter.address.is_empty() {
+            let mut positions = BTreeSet::new();
+            for address in filter.address.iter() {
+                positions.extend(self.provider().log_address_positions(*address, range.clone())?);
+            }
+            candidates = Some(positions);
+        }
+
+        for (topic_position, topic) in
+            filter.topics.iter().enumerate().filter(|(_, topic)| !topic.is_empty())
+        {
+            let mut positions = BTreeSet::new();
+            for value in topic.iter() {
+                let key = LogTopicKey::new(topic_position as u8, *value);
+                positions.extend(self.provider().log_topic_positions(key, range.clone())?);
+            }
+            candidates = Some(match candidates {
+                Some(candidates) => candidates.intersection(&positions).copied().collect(),
+                None => positions,
+            });
+        }
+
+        let mut logs = BTreeMap::<u64, Vec<u64>>::new();
+        for position in candidates.unwrap_or_default() {
+            logs.entry(position.block_number).or_default().push(position.index);
+        }
+        Ok(logs)
+    }
+
+    /// Appends the logs of the given block that are selected by `logs`.
+    ///
+    /// Returns an error if the amount of matches of a multi block `query_range` exceeds the
+    /// configured limit.
+    async fn append_block_logs(
+        &self,
+        all_logs: &mut Vec<Log>,
+        logs: BlockLogs<'_>,
+        num_hash: BlockNumHash,
+        timestamp: u64,
+        query_range: RangeInclusive<u64>,
+        best_number: u64,
+    ) -> Result<(), EthFilterError> {
+        let Some((receipts, maybe_block)) =
+            self.receipts_and_maybe_block(&num_hash, best_number).await?
+        else {
+            return Ok(())
+        };
+
+        let provider_or_block = maybe_block
+            .map(ProviderOrBlock::Block)
+            .unwrap_or_else(|| ProviderOrBlock::Provider(self.provider()));
+        match logs {
+            BlockLogs::Matching(filter_params) => append_matching_block_logs(
+                all_logs,
+                provider_or_block,
+                filter_params,
+                num_hash,
+                &receipts,
+                false,
+                timestamp,
+            )?,
+            BlockLogs::Indexed(positions) => append_indexed_block_logs(
+                all_logs,
+                provider_or_block,
+                positions,
+                num_hash,
+                &receipts,
+                false,
+                timestamp,
+            )?,
+        }
+
+        // size check but only if range is multiple blocks, so we always return all
+        // logs of a single block
+        let (from_block, to_block) = query_range.into_inner();
+        let is_multi_block_range = from_block != to_block;
+        if is_multi_block_range && all_logs.len() > self.max_logs_per_response {
+            return Err(EthFilterError::QueryExceedsMaxResults {
+                max_logs: self.max_logs_per_response,
+                from_block,
+                to_block: num_hash.number.saturating_sub(1),
+            });
+        }
+
+        Ok(())
+    }
+
     /// Retrieves receipts and block from cache if near the tip (4 blocks), otherwise only receipts.
     async fn receipts_and_maybe_block(
         &self,
@@ -585,6 +714,15 @@ where
     }
 }
 
+/// Logs of a block to append to the response of a filter.
+#[derive(Debug, Clone, Copy)]
+enum BlockLogs<'a> {
+    /// The logs that match the filter.
+    Matching(&'a FilteredParams),
+    /// The logs at the given positions in the block, as found in the log index.
+    Indexed(&'a [u64]),
+}
+
 /// All active filters
 #[derive(Debug, Clone, Default)]
 pub struct ActiveFilters<T> {
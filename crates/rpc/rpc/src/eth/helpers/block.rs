@@ -1,10 +1,11 @@
 //! Contains RPC handler implementations specific to blocks.
 
 use alloy_consensus::{transaction::TransactionMeta, BlockHeader};
-use alloy_rpc_types_eth::{BlockId, TransactionReceipt};
+use alloy_rpc_types_eth::TransactionReceipt;
 use reth_chainspec::EthChainSpec;
+use reth_primitives::SealedBlock;
 use reth_primitives_traits::{BlockBody, SignedTransaction};
-use reth_provider::{BlockReader, ChainSpecProvider};
+use reth_provider::{BlockReader, ChainSpecProvider, ProviderBlock, ProviderReceipt};
 use reth_rpc_eth_api::{
     helpers::{EthBlocks, LoadBlock, LoadPendingBlock, LoadReceipt, SpawnBlocking},
     types::RpcTypes,
@@ -26,45 +27,41 @@ where
     >,
     Provider: BlockReader + ChainSpecProvider,
 {
-    async fn block_receipts(
+    fn build_block_receipts(
         &self,
-        block_id: BlockId,
-    ) -> Result<Option<Vec<RpcReceipt<Self::NetworkTypes>>>, Self::Error>
+        block: &SealedBlock<ProviderBlock<Self::Provider>>,
+        receipts: &[ProviderReceipt<Self::Provider>],
+    ) -> Result<Vec<RpcReceipt<Self::NetworkTypes>>, Self::Error>
     where
         Self: LoadReceipt,
     {
-        if let Some((block, receipts)) = self.load_block_and_receipts(block_id).await? {
-            let block_number = block.number();
-            let base_fee = block.base_fee_per_gas();
-            let block_hash = block.hash();
-            let excess_blob_gas = block.excess_blob_gas();
-            let timestamp = block.timestamp();
-            let blob_params = self.provider().chain_spec().blob_params_at_timestamp(timestamp);
+        let block_number = block.number();
+        let base_fee = block.base_fee_per_gas();
+        let block_hash = block.hash();
+        let excess_blob_gas = block.excess_blob_gas();
+        let timestamp = block.timestamp();
+        let blob_params = self.provider().chain_spec().blob_params_at_timestamp(timestamp);
 
-            return block
-                .body()
-                .transactions()
-                .iter()
-                .zip(receipts.iter())
-                .enumerate()
-                .map(|(idx, (tx, receipt))| {
-                    let meta = TransactionMeta {
-                        tx_hash: *tx.tx_hash(),
-                        index: idx as u64,
-                        block_hash,
-                        block_number,
-                        base_fee,
-                        excess_blob_gas,
-                        timestamp,
-                    };
-                    EthReceiptBuilder::new(tx, meta, receipt, &receipts, blob_params)
-                        .map(|builder| builder.build())
-                })
-                .collect::<Result<Vec<_>, Self::Error>>()
-                .map(Some)
-        }
-
-        Ok(None)
+        block
+            .body()
+            .transactions()
+            .iter()
+            .zip(receipts.iter())
+            .enumerate()
+            .map(|(idx, (tx, receipt))| {
+                let meta = TransactionMeta {
+                    tx_hash: *tx.tx_hash(),
+                    index: idx as u64,
+                    block_hash,
+                    block_number,
+                    base_fee,
+                    excess_blob_gas,
+                    timestamp,
+                };
+                EthReceiptBuilder::new(tx, meta, receipt, receipts, blob_params)
+                    .map(|builder| builder.build())
+            })
+            .collect()
     }
 }
 
@@ -1,33 +1,52 @@
 //! `eth_` `PubSub` RPC handler implementation
 
-use std::sync::Arc;
+use std::{ops::RangeInclusive, sync::Arc};
 
-use alloy_primitives::TxHash;
+use alloy_consensus::BlockHeader;
+use alloy_primitives::{BlockNumber, TxHash, U64};
 use alloy_rpc_types_eth::{
     pubsub::{Params, PubSubSyncStatus, SubscriptionKind, SyncStatusMetadata},
-    FilteredParams, Header, Log,
+    BlockTransactionsKind, FilteredParams, Header, Log,
 };
 use futures::StreamExt;
 use jsonrpsee::{
     server::SubscriptionMessage, types::ErrorObject, PendingSubscriptionSink, SubscriptionSink,
 };
 use reth_network_api::NetworkInfo;
-use reth_primitives::NodePrimitives;
-use reth_provider::{BlockNumReader, CanonStateSubscriptions};
+use reth_primitives::{NodePrimitives, RecoveredBlock};
+use reth_provider::{
+    BlockHashReader, BlockNumReader, BlockReader, CanonStateNotification,
+    CanonStateNotificationStream, CanonStateSubscriptions, ProviderBlock, ProviderReceipt,
+};
 use reth_rpc_eth_api::{
-    pubsub::EthPubSubApiServer, EthApiTypes, RpcNodeCore, RpcTransaction, TransactionCompat,
+    helpers::{EthBlocks, FullEthApi, Trace},
+    pubsub::EthPubSubApiServer,
+    EthApiTypes, RpcBlock, RpcNodeCore, RpcNodeCoreExt, RpcReceipt, RpcTransaction,
+    TransactionCompat,
+};
+use reth_rpc_eth_types::{
+    logs_utils, BlockWithReceipts, BlocksWithReceiptsParams, EthSubscriptionKind,
 };
-use reth_rpc_eth_types::logs_utils;
 use reth_rpc_server_types::result::{internal_rpc_err, invalid_params_rpc_err};
-use reth_tasks::{TaskSpawner, TokioTaskExecutor};
+use reth_rpc_types_compat::block::from_block;
+use reth_tasks::{pool::BlockingTaskGuard, TaskSpawner, TokioTaskExecutor};
 use reth_transaction_pool::{NewTransactionEvent, PoolConsensusTx, TransactionPool};
-use serde::Serialize;
+use revm_inspectors::tracing::TracingInspectorConfig;
+use serde::{de::DeserializeOwned, Serialize};
 use tokio_stream::{
     wrappers::{BroadcastStream, ReceiverStream},
     Stream,
 };
 use tracing::error;
 
+/// Maximum number of blocks a `newBlocksWithReceipts` subscription can be resumed from behind the
+/// chain tip.
+const MAX_RESUME_BLOCKS: u64 = 10_000;
+
+/// Maximum number of blocks a `newBlocksWithReceipts` subscription with traces can be resumed from
+/// behind the chain tip, since every resumed block is traced.
+const MAX_TRACED_RESUME_BLOCKS: u64 = 100;
+
 /// `Eth` pubsub RPC implementation.
 ///
 /// This handles `eth_subscribe` RPC calls.
@@ -45,36 +64,84 @@ impl<Eth> EthPubSub<Eth> {
     /// Creates a new, shareable instance.
     ///
     /// Subscription tasks are spawned via [`tokio::task::spawn`]
-    pub fn new(eth_api: Eth) -> Self {
-        Self::with_spawner(eth_api, Box::<TokioTaskExecutor>::default())
+    pub fn new(eth_api: Eth, blocking_task_guard: BlockingTaskGuard) -> Self {
+        Self::with_spawner(eth_api, Box::<TokioTaskExecutor>::default(), blocking_task_guard)
     }
 
     /// Creates a new, shareable instance.
-    pub fn with_spawner(eth_api: Eth, subscription_task_spawner: Box<dyn TaskSpawner>) -> Self {
-        let inner = EthPubSubInner { eth_api };
+    ///
+    /// Blocks of subscriptions with traces are only traced while holding a permit of the
+    /// `blocking_task_guard`.
+    pub fn with_spawner(
+        eth_api: Eth,
+        subscription_task_spawner: Box<dyn TaskSpawner>,
+        blocking_task_guard: BlockingTaskGuard,
+    ) -> Self {
+        let inner = EthPubSubInner { eth_api, blocking_task_guard };
         Self { inner: Arc::new(inner), subscription_task_spawner }
     }
 }
 
 #[async_trait::async_trait]
-impl<Eth> EthPubSubApiServer<RpcTransaction<Eth::NetworkTypes>> for EthPubSub<Eth>
+impl<N, Eth> EthPubSubApiServer<RpcTransaction<Eth::NetworkTypes>> for EthPubSub<Eth>
 where
+    N: NodePrimitives,
     Eth: RpcNodeCore<
-            Provider: BlockNumReader + CanonStateSubscriptions,
+            Provider: BlockNumReader
+                          + CanonStateSubscriptions<Primitives = N>
+                          + BlockReader<Block = N::Block, Receipt = N::Receipt>,
             Pool: TransactionPool,
             Network: NetworkInfo,
         > + EthApiTypes<TransactionCompat: TransactionCompat<PoolConsensusTx<Eth::Pool>>>
+        + FullEthApi
         + 'static,
 {
     /// Handler for `eth_subscribe`
     async fn subscribe(
         &self,
         pending: PendingSubscriptionSink,
-        kind: SubscriptionKind,
-        params: Option<Params>,
+        kind: EthSubscriptionKind,
+        params: Option<serde_json::Value>,
     ) -> jsonrpsee::core::SubscriptionResult {
-        let sink = pending.accept().await?;
         let pubsub = self.inner.clone();
+
+        let Some(kind) = kind.as_standard() else {
+            let params = match parse_params::<BlocksWithReceiptsParams>(params) {
+                Ok(params) => params.unwrap_or_default(),
+                Err(err) => {
+                    pending.reject(err).await;
+                    return Ok(())
+                }
+            };
+            // subscribe before reading the chain tip, so that no block is missed
+            let new_blocks = pubsub.eth_api.provider().canonical_state_stream();
+            let resumed = match pubsub.resumed_blocks(params.from_block, params.include_traces) {
+                Ok(resumed) => resumed,
+                Err(err) => {
+                    pending.reject(err).await;
+                    return Ok(())
+                }
+            };
+
+            let sink = pending.accept().await?;
+            self.subscription_task_spawner.spawn(Box::pin(async move {
+                let _ = pubsub
+                    .pipe_blocks_with_receipts(sink, new_blocks, resumed, params.include_traces)
+                    .await;
+            }));
+
+            return Ok(())
+        };
+
+        let params = match parse_params::<Params>(params) {
+            Ok(params) => params,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(())
+            }
+        };
+
+        let sink = pending.accept().await?;
         self.subscription_task_spawner.spawn(Box::pin(async move {
             let _ = handle_accepted(pubsub, sink, kind, params).await;
         }));
@@ -83,6 +150,16 @@ where
     }
 }
 
+/// Deserializes the params of an [`EthPubSub::subscribe`] call.
+fn parse_params<T: DeserializeOwned>(
+    params: Option<serde_json::Value>,
+) -> Result<Option<T>, ErrorObject<'static>> {
+    params
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|err| invalid_params_rpc_err(format!("Invalid subscription params: {err}")))
+}
+
 /// The actual handler for an accepted [`EthPubSub::subscribe`] call.
 async fn handle_accepted<Eth>(
     pubsub: Arc<EthPubSubInner<Eth>>,
@@ -246,6 +323,8 @@ impl<Eth> std::fmt::Debug for EthPubSub<Eth> {
 struct EthPubSubInner<EthApi> {
     /// The `eth` API.
     eth_api: EthApi,
+    /// Guard for the blocking tasks tracing blocks.
+    blocking_task_guard: BlockingTaskGuard,
 }
 
 // == impl EthPubSubInner ===
@@ -324,3 +403,324 @@ where
             })
     }
 }
+
+impl<N: NodePrimitives, Eth> EthPubSubInner<Eth>
+where
+    Eth: RpcNodeCore<
+            Provider: BlockNumReader
+                          + CanonStateSubscriptions<Primitives = N>
+                          + BlockReader<Block = N::Block, Receipt = N::Receipt>,
+        > + FullEthApi,
+{
+    /// Returns the range of canonical blocks to send before the new blocks of a
+    /// `newBlocksWithReceipts` subscription that is resumed from the given block.
+    ///
+    /// Subscriptions with traces can be resumed from fewer blocks, see
+    /// [`MAX_TRACED_RESUME_BLOCKS`].
+    fn resumed_blocks(
+        &self,
+        from_block: Option<U64>,
+        include_traces: bool,
+    ) -> Result<RangeInclusive<BlockNumber>, ErrorObject<'static>> {
+        let best_block = self
+            .eth_api
+            .provider()
+            .best_block_number()
+            .map_err(|err| internal_rpc_err(err.to_string()))?;
+        resume_range(best_block, from_block.map(|block| block.to()), include_traces)
+    }
+
+    /// Sends the resumed blocks, and then all blocks of the new canonical chains to the
+    /// subscription sink.
+    ///
+    /// Blocks of a reverted chain are sent again as removed if they were sent before.
+    async fn pipe_blocks_with_receipts(
+        &self,
+        sink: SubscriptionSink,
+        mut new_blocks: CanonStateNotificationStream<N>,
+        resumed: RangeInclusive<BlockNumber>,
+        include_traces: bool,
+    ) -> Result<(), ErrorObject<'static>> {
+        let mut sent = SentBlocks::new(*resumed.start());
+
+        for number in resumed {
+            let block_and_receipts = match self.eth_api.provider().block_hash(number) {
+                Ok(Some(hash)) => self.eth_api.cache().get_block_and_receipts(hash).await,
+                Ok(None) => Ok(None),
+                Err(err) => Err(err),
+            }
+            .map_err(|err| internal_rpc_err(err.to_string()))?;
+            // the block was reverted in the meantime, the new chain is sent below
+            let Some((block, receipts)) = block_and_receipts else { break };
+
+            if !self
+                .send_block_with_receipts(&sink, &block, &receipts, false, include_traces)
+                .await?
+            {
+                return Ok(())
+            }
+            sent.next = number + 1;
+        }
+
+        loop {
+            let notification = tokio::select! {
+                _ = sink.closed() => {
+                    // connection dropped
+                    return Ok(())
+                },
+                maybe_notification = new_blocks.next() => match maybe_notification {
+                    Some(notification) => notification,
+                    None => return Ok(()),
+                },
+            };
+
+            for (block, receipts, removed) in sent.blocks_to_send(&notification) {
+                if !self
+                    .send_block_with_receipts(&sink, block, receipts, removed, include_traces)
+                    .await?
+                {
+                    return Ok(())
+                }
+            }
+        }
+    }
+
+    /// Sends a block with its receipts to the subscription sink.
+    ///
+    /// Returns `false` if the subscription was closed.
+    async fn send_block_with_receipts(
+        &self,
+        sink: &SubscriptionSink,
+        block: &RecoveredBlock<N::Block>,
+        receipts: &[N::Receipt],
+        removed: bool,
+        include_traces: bool,
+    ) -> Result<bool, ErrorObject<'static>> {
+        let item = self
+            .block_with_receipts(block, receipts, removed, include_traces)
+            .await
+            .map_err(Into::into)?;
+        let msg = SubscriptionMessage::from_json(&item).map_err(SubscriptionSerializeError::new)?;
+        Ok(sink.send(msg).await.is_ok())
+    }
+}
+
+/// Returns the range of canonical blocks to send before the new blocks of a
+/// `newBlocksWithReceipts` subscription that is resumed from `from_block`, given the chain tip.
+fn resume_range(
+    best_block: BlockNumber,
+    from_block: Option<BlockNumber>,
+    include_traces: bool,
+) -> Result<RangeInclusive<BlockNumber>, ErrorObject<'static>> {
+    let Some(from_block) = from_block else { return Ok(best_block + 1..=best_block) };
+
+    if from_block > best_block + 1 {
+        return Err(invalid_params_rpc_err(format!(
+            "fromBlock {from_block} is ahead of the chain tip {best_block}"
+        )))
+    }
+    let max_blocks = if include_traces { MAX_TRACED_RESUME_BLOCKS } else { MAX_RESUME_BLOCKS };
+    if best_block + 1 - from_block > max_blocks {
+        return Err(invalid_params_rpc_err(format!(
+            "fromBlock {from_block} is more than {max_blocks} blocks behind the chain tip \
+             {best_block}"
+        )))
+    }
+
+    Ok(from_block..=best_block)
+}
+
+/// Blocks sent by a `newBlocksWithReceipts` subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SentBlocks {
+    /// First block sent by the subscription.
+    first: BlockNumber,
+    /// Blocks in `first..next` have been sent.
+    next: BlockNumber,
+}
+
+impl SentBlocks {
+    const fn new(first: BlockNumber) -> Self {
+        Self { first, next: first }
+    }
+
+    /// Returns the blocks of the notification to send, along with whether they were removed, and
+    /// records them as sent.
+    ///
+    /// Reverted blocks are returned tip first, and only if they were sent before. Committed blocks
+    /// are returned unless they were sent before.
+    fn blocks_to_send<'a, N: NodePrimitives>(
+        &mut self,
+        notification: &'a CanonStateNotification<N>,
+    ) -> Vec<(&'a RecoveredBlock<N::Block>, &'a [N::Receipt], bool)> {
+        let (reverted, committed) = match notification {
+            CanonStateNotification::Commit { new } => (None, new),
+            CanonStateNotification::Reorg { old, new } => (Some(old), new),
+        };
+
+        let mut blocks = Vec::new();
+        if let Some(reverted) = reverted {
+            let mut removed = reverted.blocks_and_receipts().collect::<Vec<_>>();
+            removed.retain(|(block, _)| (self.first..self.next).contains(&block.number()));
+            blocks.extend(
+                removed.into_iter().rev().map(|(block, receipts)| (block, &receipts[..], true)),
+            );
+            self.next = self.next.min(reverted.first().number());
+        }
+
+        for (block, receipts) in committed.blocks_and_receipts() {
+            if block.number() < self.next {
+                // already sent
+                continue
+            }
+            blocks.push((block, &receipts[..], false));
+            self.next = block.number() + 1;
+        }
+
+        blocks
+    }
+}
+
+impl<Eth> EthPubSubInner<Eth>
+where
+    Eth: FullEthApi,
+{
+    /// Converts a block and its receipts into a `newBlocksWithReceipts` message.
+    ///
+    /// Call traces are only included for blocks that are not removed, since those are traced on
+    /// top of the canonical chain.
+    async fn block_with_receipts(
+        &self,
+        block: &RecoveredBlock<ProviderBlock<Eth::Provider>>,
+        receipts: &[ProviderReceipt<Eth::Provider>],
+        removed: bool,
+        include_traces: bool,
+    ) -> Result<
+        BlockWithReceipts<RpcBlock<Eth::NetworkTypes>, RpcReceipt<Eth::NetworkTypes>>,
+        Eth::Error,
+    > {
+        let traces = if include_traces && !removed {
+            // tracing is limited like the `trace_` and `debug_` handlers
+            let _permit = self.blocking_task_guard.clone().acquire_owned().await;
+            self.eth_api
+                .trace_block_with(
+                    block.hash().into(),
+                    Some(Arc::new(block.clone())),
+                    TracingInspectorConfig::default_parity(),
+                    |tx_info, inspector, _, _, _| {
+                        Ok(inspector
+                            .into_parity_builder()
+                            .into_localized_transaction_traces(tx_info))
+                    },
+                )
+                .await?
+                .map(|traces| traces.into_iter().flatten().collect())
+        } else {
+            None
+        };
+
+        let receipts = self.eth_api.build_block_receipts(block.sealed_block(), receipts)?;
+        let block =
+            from_block(block.clone(), BlockTransactionsKind::Full, self.eth_api.tx_resp_builder())?;
+
+        Ok(BlockWithReceipts { block, receipts, traces, removed })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::Header;
+    use alloy_primitives::Bytes;
+    use reth_primitives::{Block, SealedBlock};
+    use reth_provider::{Chain, ExecutionOutcome};
+
+    /// Returns an empty block of the given fork of the chain.
+    fn block(number: BlockNumber, fork: u8) -> RecoveredBlock<Block> {
+        let header = Header { number, extra_data: Bytes::from(vec![fork]), ..Default::default() };
+        let block = Block { header, body: Default::default() };
+        RecoveredBlock::new_sealed(SealedBlock::seal_slow(block), Vec::new())
+    }
+
+    /// Returns the blocks of the given fork of the chain as a chain segment.
+    fn chain(numbers: RangeInclusive<BlockNumber>, fork: u8) -> Arc<Chain> {
+        let blocks = numbers.clone().map(|number| block(number, fork)).collect::<Vec<_>>();
+        let execution_outcome = ExecutionOutcome {
+            receipts: vec![Vec::new(); blocks.len()],
+            first_block: *numbers.start(),
+            ..Default::default()
+        };
+        Arc::new(Chain::new(blocks, execution_outcome, None))
+    }
+
+    fn commit(numbers: RangeInclusive<BlockNumber>, fork: u8) -> CanonStateNotification {
+        CanonStateNotification::Commit { new: chain(numbers, fork) }
+    }
+
+    /// Returns the number, fork and `removed` flag of the blocks sent for the notification.
+    fn send(sent: &mut SentBlocks, notification: CanonStateNotification) -> Vec<(u64, u8, bool)> {
+        sent.blocks_to_send(&notification)
+            .into_iter()
+            .map(|(block, _, removed)| (block.number(), block.header().extra_data[0], removed))
+            .collect()
+    }
+
+    #[test]
+    fn sends_new_blocks_once() {
+        // subscribed at the tip 10
+        let mut sent = SentBlocks::new(11);
+
+        assert_eq!(send(&mut sent, commit(11..=12, 0)), vec![(11, 0, false), (12, 0, false)]);
+        assert_eq!(send(&mut sent, commit(12..=13, 0)), vec![(13, 0, false)]);
+        assert_eq!(sent, SentBlocks { first: 11, next: 14 });
+    }
+
+    #[test]
+    fn sends_reorged_blocks_as_removed() {
+        let mut sent = SentBlocks::new(1);
+        send(&mut sent, commit(1..=4, 0));
+
+        let reorg = CanonStateNotification::Reorg { old: chain(3..=4, 0), new: chain(3..=5, 1) };
+        assert_eq!(
+            send(&mut sent, reorg),
+            vec![(4, 0, true), (3, 0, true), (3, 1, false), (4, 1, false), (5, 1, false)]
+        );
+        assert_eq!(sent.next, 6);
+
+        // reverted blocks that were not sent yet are not sent as removed
+        let mut sent = SentBlocks::new(4);
+        send(&mut sent, commit(4..=4, 0));
+        let reorg = CanonStateNotification::Reorg { old: chain(4..=6, 0), new: chain(4..=5, 1) };
+        assert_eq!(send(&mut sent, reorg), vec![(4, 0, true), (4, 1, false), (5, 1, false)]);
+    }
+
+    #[test]
+    fn resumes_from_block() {
+        assert_eq!(resume_range(100, None, false).unwrap(), 101..=100);
+        assert_eq!(resume_range(100, Some(90), false).unwrap(), 90..=100);
+        assert_eq!(resume_range(100, Some(101), false).unwrap(), 101..=100);
+        assert!(resume_range(100, Some(102), false).is_err());
+
+        // blocks committed while the resumed blocks were sent are not sent again
+        let resumed = resume_range(100, Some(90), false).unwrap();
+        let mut sent = SentBlocks::new(*resumed.start());
+        sent.next = resumed.end() + 1;
+        assert_eq!(send(&mut sent, commit(99..=101, 0)), vec![(101, 0, false)]);
+    }
+
+    #[test]
+    fn limits_resumed_blocks() {
+        let best_block = 20_000;
+        let oldest_traced = best_block + 1 - MAX_TRACED_RESUME_BLOCKS;
+        assert_eq!(
+            resume_range(best_block, Some(oldest_traced), true).unwrap(),
+            oldest_traced..=best_block
+        );
+        assert!(resume_range(best_block, Some(oldest_traced - 1), true).is_err());
+        assert!(resume_range(best_block, Some(oldest_traced - 1), false).is_ok());
+
+        let oldest = best_block + 1 - MAX_RESUME_BLOCKS;
+        assert!(resume_range(best_block, Some(oldest), false).is_ok());
+        assert!(resume_range(best_block, Some(oldest - 1), false).is_err());
+    }
+}
@@ -0,0 +1,277 @@
+use alloy_primitives::{Address, BlockNumber};
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use reth_db_api::models::{self, TokenTransferKey};
+use reth_primitives_traits::SignedTransaction;
+use reth_provider::{TokenTransfersReader, TransactionsProvider};
+use reth_rpc_api::HlApiServer;
+use reth_rpc_eth_api::{helpers::EthTransactions, FromEthApiError, RpcNodeCore};
+use reth_rpc_eth_types::EthApiError;
+use reth_rpc_server_types::{
+    NativeTransfer, NativeTransferKind, NativeTransfersPage, TokenBalanceDelta, TokenTransfer,
+    TokenTransfersPage, TransferCursor,
+};
+
+/// Maximum number of transfers returned in a page of `hl_getTokenTransfers`,
+/// `hl_getTokenTransfersByToken` and `hl_getNativeTransfers`.
+const TOKEN_TRANSFERS_PAGE_SIZE: usize = 1000;
+
+/// Maximum number of blocks that can be queried with `hl_getTokenBalanceDeltas`.
+const MAX_BALANCE_DELTAS_BLOCK_RANGE: u64 = 100_000;
+
+/// `hl` API implementation.
+///
+/// This type serves the Hyperliquid specific indices of the node.
+#[derive(Debug, Clone)]
+pub struct HlApi<Eth> {
+    eth: Eth,
+}
+
+impl<Eth> HlApi<Eth> {
+    /// Creates a new instance of `HlApi`.
+    pub const fn new(eth: Eth) -> Self {
+        Self { eth }
+    }
+}
+
+/// Token transfers index a page is read from.
+#[derive(Debug, Clone, Copy)]
+enum TransfersBy {
+    /// Transfers sent or received by a holder, optionally only of a token.
+    Holder(Address, Option<Address>),
+    /// Transfers of a token.
+    Token(Address),
+}
+
+impl<Eth> HlApi<Eth>
+where
+    Eth: EthTransactions<Provider: TokenTransfersReader> + 'static,
+{
+    /// Returns the page of transfers in the block range, starting at the cursor.
+    async fn token_transfers_page(
+        &self,
+        by: TransfersBy,
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+        cursor: Option<TransferCursor>,
+    ) -> RpcResult<TokenTransfersPage> {
+        let start = page_start(from_block, to_block, cursor)?;
+
+        self.eth
+            .spawn_blocking_io(move |this| {
+                token_transfers_page(this.provider(), by, from_block, to_block, start)
+                    .map_err(Eth::Error::from_eth_err)
+            })
+            .await
+            .map_err(Into::into)
+    }
+}
+
+#[async_trait]
+impl<Eth> HlApiServer for HlApi<Eth>
+where
+    Eth: EthTransactions<Provider: TokenTransfersReader> + 'static,
+{
+    /// Handler for `hl_getTokenTransfers`
+    async fn get_token_transfers(
+        &self,
+        address: Address,
+        token: Option<Address>,
+        from_block: u64,
+        to_block: u64,
+        cursor: Option<TransferCursor>,
+    ) -> RpcResult<TokenTransfersPage> {
+        self.token_transfers_page(TransfersBy::Holder(address, token), from_block, to_block, cursor)
+            .await
+    }
+
+    /// Handler for `hl_getTokenTransfersByToken`
+    async fn get_token_transfers_by_token(
+        &self,
+        token: Address,
+        from_block: u64,
+        to_block: u64,
+        cursor: Option<TransferCursor>,
+    ) -> RpcResult<TokenTransfersPage> {
+        self.token_transfers_page(TransfersBy::Token(token), from_block, to_block, cursor).await
+    }
+
+    /// Handler for `hl_getTokenBalanceDeltas`
+    async fn get_token_balance_deltas(
+        &self,
+        address: Address,
+        token: Address,
+        from_block: u64,
+        to_block: u64,
+    ) -> RpcResult<Vec<TokenBalanceDelta>> {
+        if to_block < from_block {
+            return Err(EthApiError::InvalidBlockRange.into())
+        }
+        if to_block - from_block >= MAX_BALANCE_DELTAS_BLOCK_RANGE {
+            return Err(EthApiError::InvalidParams(format!(
+                "block range exceeds the maximum of {MAX_BALANCE_DELTAS_BLOCK_RANGE} blocks"
+            ))
+            .into())
+        }
+
+        self.eth
+            .spawn_blocking_io(move |this| {
+                let provider = this.provider();
+                ensure_indexed(provider, from_block, to_block).map_err(Eth::Error::from_eth_err)?;
+                let deltas = provider
+                    .token_balance_deltas(address, token, from_block..=to_block)
+                    .map_err(Eth::Error::from_eth_err)?;
+                Ok(deltas
+                    .into_iter()
+                    .map(|(block_number, delta)| TokenBalanceDelta {
+                        block_number,
+                        received: delta.received,
+                        sent: delta.sent,
+                    })
+                    .collect())
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Handler for `hl_getNativeTransfers`
+    async fn get_native_transfers(
+        &self,
+        address: Address,
+        from_block: u64,
+        to_block: u64,
+        cursor: Option<TransferCursor>,
+    ) -> RpcResult<NativeTransfersPage> {
+        let start = page_start(from_block, to_block, cursor)?;
+
+        self.eth
+            .spawn_blocking_io(move |this| {
+                native_transfers_page(this.provider(), address, from_block, to_block, start)
+                    .map_err(Eth::Error::from_eth_err)
+            })
+            .await
+            .map_err(Into::into)
+    }
+}
+
+/// Returns the `(block, index)` position a page of the inclusive block range starts at.
+fn page_start(
+    from_block: BlockNumber,
+    to_block: BlockNumber,
+    cursor: Option<TransferCursor>,
+) -> Result<(BlockNumber, u32), EthApiError> {
+    if to_block < from_block {
+        return Err(EthApiError::InvalidBlockRange)
+    }
+    match cursor {
+        Some(cursor) if !(from_block..=to_block).contains(&cursor.block_number) => {
+            Err(EthApiError::InvalidParams("cursor is outside of the block range".to_string()))
+        }
+        Some(cursor) => Ok((cursor.block_number, cursor.index)),
+        None => Ok((from_block, 0)),
+    }
+}
+
+/// Removes the entry read past the page size, returning the cursor of the next page it starts.
+fn next_cursor<V>(entries: &mut Vec<(TokenTransferKey, V)>) -> Option<TransferCursor> {
+    (entries.len() > TOKEN_TRANSFERS_PAGE_SIZE)
+        .then(|| entries.pop())
+        .flatten()
+        .map(|(key, _)| TransferCursor { block_number: key.block_number, index: key.index })
+}
+
+/// Returns an error if the token transfers of the inclusive block range are not indexed.
+fn ensure_indexed<P: TokenTransfersReader>(
+    provider: &P,
+    from_block: BlockNumber,
+    to_block: BlockNumber,
+) -> Result<(), EthApiError> {
+    match provider.token_transfers_range()? {
+        Some(range) if range.contains(&from_block) && range.contains(&to_block) => Ok(()),
+        Some(range) => Err(EthApiError::InvalidParams(format!(
+            "token transfers are only indexed from block {} to {}",
+            range.start(),
+            range.end()
+        ))),
+        None => Err(EthApiError::InvalidParams("token transfers are not indexed".to_string())),
+    }
+}
+
+/// Reads a page of transfers starting at the `(block, index)` position, and resolves their
+/// transaction hashes.
+fn token_transfers_page<P: TokenTransfersReader + TransactionsProvider>(
+    provider: &P,
+    by: TransfersBy,
+    from_block: BlockNumber,
+    to_block: BlockNumber,
+    start: (BlockNumber, u32),
+) -> Result<TokenTransfersPage, EthApiError> {
+    ensure_indexed(provider, from_block, to_block)?;
+
+    // One more transfer than the page size is read to find the start of the next page.
+    let limit = TOKEN_TRANSFERS_PAGE_SIZE + 1;
+    let mut entries = match by {
+        TransfersBy::Holder(holder, token) => {
+            provider.holder_token_transfers(holder, token, start, to_block, limit)?
+        }
+        TransfersBy::Token(token) => provider.token_transfers(token, start, to_block, limit)?,
+    };
+    let next_cursor = next_cursor(&mut entries);
+
+    let transfers = entries
+        .into_iter()
+        .map(|(key, transfer)| {
+            let tx = provider
+                .transaction_by_id(transfer.tx_number)?
+                .ok_or(EthApiError::TransactionNotFound)?;
+            Ok(TokenTransfer {
+                token: transfer.token,
+                from: transfer.from,
+                to: transfer.to,
+                value: transfer.value,
+                block_number: key.block_number,
+                transaction_hash: *tx.tx_hash(),
+                spot_token: transfer.spot_token,
+            })
+        })
+        .collect::<Result<_, EthApiError>>()?;
+
+    Ok(TokenTransfersPage { transfers, next_cursor })
+}
+
+/// Reads a page of the native transfers of the user starting at the `(block, index)` position,
+/// and resolves their transaction hashes.
+fn native_transfers_page<P: TokenTransfersReader + TransactionsProvider>(
+    provider: &P,
+    user: Address,
+    from_block: BlockNumber,
+    to_block: BlockNumber,
+    start: (BlockNumber, u32),
+) -> Result<NativeTransfersPage, EthApiError> {
+    ensure_indexed(provider, from_block, to_block)?;
+
+    let mut entries =
+        provider.native_transfers(user, start, to_block, TOKEN_TRANSFERS_PAGE_SIZE + 1)?;
+    let next_cursor = next_cursor(&mut entries);
+
+    let transfers = entries
+        .into_iter()
+        .map(|(key, transfer)| {
+            let tx = provider
+                .transaction_by_id(transfer.tx_number)?
+                .ok_or(EthApiError::TransactionNotFound)?;
+            let kind = match transfer.kind {
+                models::NativeTransferKind::Deposit => NativeTransferKind::Deposit,
+                models::NativeTransferKind::Withdrawal => NativeTransferKind::Withdrawal,
+            };
+            Ok(NativeTransfer {
+                kind,
+                value: transfer.value,
+                block_number: key.block_number,
+                transaction_hash: *tx.tx_hash(),
+            })
+        })
+        .collect::<Result<_, EthApiError>>()?;
+
+    Ok(NativeTransfersPage { transfers, next_cursor })
+}
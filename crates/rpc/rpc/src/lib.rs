@@ -36,6 +36,7 @@ mod admin;
 mod debug;
 mod engine;
 pub mod eth;
+mod hl;
 mod miner;
 mod net;
 mod otterscan;
@@ -46,10 +47,11 @@ mod txpool;
 mod validation;
 mod web3;
 
-pub use admin::AdminApi;
+pub use admin::{AdminApi, SlowQueryApi};
 pub use debug::DebugApi;
 pub use engine::{EngineApi, EngineEthApi};
 pub use eth::{EthApi, EthApiBuilder, EthBundle, EthFilter, EthPubSub};
+pub use hl::HlApi;
 pub use miner::MinerApi;
 pub use net::NetApi;
 pub use otterscan::OtterscanApi;
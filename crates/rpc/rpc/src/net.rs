@@ -1,6 +1,6 @@
 use alloy_primitives::U64;
 use jsonrpsee::core::RpcResult as Result;
-use reth_network_api::PeersInfo;
+use reth_network_api::{NetworkInfo, PeersInfo};
 use reth_rpc_api::NetApiServer;
 use reth_rpc_eth_api::helpers::EthApiSpec;
 
@@ -26,7 +26,7 @@ impl<Net, Eth> NetApi<Net, Eth> {
 /// Net rpc implementation
 impl<Net, Eth> NetApiServer for NetApi<Net, Eth>
 where
-    Net: PeersInfo + 'static,
+    Net: NetworkInfo + PeersInfo + 'static,
     Eth: EthApiSpec + 'static,
 {
     /// Handler for `net_version`
@@ -36,13 +36,20 @@ where
     }
 
     /// Handler for `net_peerCount`
+    ///
+    /// Nodes that sync blocks from other sources than devp2p peers report the number of active
+    /// sources instead.
     fn peer_count(&self) -> Result<U64> {
-        Ok(U64::from(self.network.num_connected_peers()))
+        let sources = self.network.sync_sources();
+        if sources.is_empty() {
+            return Ok(U64::from(self.network.num_connected_peers()))
+        }
+        Ok(U64::from(sources.iter().filter(|source| source.is_active()).count()))
     }
 
     /// Handler for `net_listening`
     fn is_listening(&self) -> Result<bool> {
-        Ok(true)
+        Ok(self.network.is_listening())
     }
 }
 
@@ -1,24 +1,33 @@
-use alloy_consensus::{BlockHeader, Transaction, Typed2718};
+use alloy_consensus::{transaction::TransactionMeta, BlockHeader, Transaction, Typed2718};
 use alloy_eips::{BlockId, BlockNumberOrTag};
 use alloy_network::{ReceiptResponse, TransactionResponse};
-use alloy_primitives::{Address, Bytes, TxHash, B256, U256};
-use alloy_rpc_types_eth::{BlockTransactions, TransactionReceipt};
+use alloy_primitives::{Address, BlockNumber, Bytes, TxHash, TxNumber, B256, U256};
+use alloy_rpc_types_eth::{BlockTransactions, TransactionInfo, TransactionReceipt};
 use alloy_rpc_types_trace::{
     otterscan::{
         BlockDetails, ContractCreator, InternalOperation, OperationType, OtsBlockTransactions,
-        OtsReceipt, OtsTransactionReceipt, TraceEntry, TransactionsWithReceipts,
+        OtsReceipt, OtsTransactionReceipt, TraceEntry,
     },
     parity::{Action, CreateAction, CreateOutput, TraceOutput},
 };
 use async_trait::async_trait;
 use jsonrpsee::{core::RpcResult, types::ErrorObjectOwned};
+use reth_db_api::models::StoredBlockBodyIndices;
+use reth_primitives_traits::SignedTransaction;
+use reth_provider::{
+    AddressTransactionsReader, BlockNumReader, BlockReader, ContractCreatorsReader,
+    ProviderReceipt, ProviderTx, TransactionsProvider,
+};
 use reth_rpc_api::{EthApiServer, OtterscanServer};
 use reth_rpc_eth_api::{
-    helpers::{EthTransactions, TraceExt},
-    FullEthApiTypes, RpcBlock, RpcHeader, RpcReceipt, RpcTransaction, TransactionCompat,
+    helpers::{EthTransactions, LoadReceipt, TraceExt},
+    FromEthApiError, FullEthApiTypes, RpcBlock, RpcHeader, RpcNodeCore, RpcReceipt, RpcTransaction,
+    TransactionCompat,
+};
+use reth_rpc_eth_types::{utils::binary_search, EthApiError, TraceCache, TraceCacheKey};
+use reth_rpc_server_types::{
+    result::internal_rpc_err, ContractDeployment, TransactionsWithReceipts,
 };
-use reth_rpc_eth_types::{utils::binary_search, EthApiError};
-use reth_rpc_server_types::result::internal_rpc_err;
 use revm::context_interface::result::ExecutionResult;
 use revm_inspectors::{
     tracing::{types::CallTraceNode, TracingInspectorConfig},
@@ -27,16 +36,26 @@ use revm_inspectors::{
 
 const API_LEVEL: u64 = 8;
 
+/// Maximum number of blocks that can be queried with `ots_getContractsDeployed`.
+const MAX_CONTRACTS_DEPLOYED_BLOCK_RANGE: u64 = 100_000;
+
+/// Page of transactions returned by `ots_searchTransactionsBefore` and
+/// `ots_searchTransactionsAfter`.
+type OtsTransactionsWithReceipts<N> =
+    TransactionsWithReceipts<RpcTransaction<N>, OtsTransactionReceipt>;
+
 /// Otterscan API.
 #[derive(Debug)]
 pub struct OtterscanApi<Eth> {
     eth: Eth,
+    /// Cache of transaction traces, if enabled
+    trace_cache: Option<TraceCache>,
 }
 
 impl<Eth> OtterscanApi<Eth> {
     /// Creates a new instance of `Otterscan`.
-    pub const fn new(eth: Eth) -> Self {
-        Self { eth }
+    pub const fn new(eth: Eth, trace_cache: Option<TraceCache>) -> Self {
+        Self { eth, trace_cache }
     }
 }
 
@@ -62,6 +81,94 @@ where
     }
 }
 
+impl<Eth> OtterscanApi<Eth>
+where
+    Eth: EthApiServer<
+            RpcTransaction<Eth::NetworkTypes>,
+            RpcBlock<Eth::NetworkTypes>,
+            RpcReceipt<Eth::NetworkTypes>,
+            RpcHeader<Eth::NetworkTypes>,
+        > + EthTransactions<Provider: AddressTransactionsReader + ContractCreatorsReader>
+        + LoadReceipt
+        + 'static,
+{
+    /// Resolves the given transaction numbers into a page of `TransactionsWithReceipts`.
+    async fn transactions_with_receipts(
+        &self,
+        tx_numbers: Vec<TxNumber>,
+        first_page: bool,
+        last_page: bool,
+    ) -> RpcResult<OtsTransactionsWithReceipts<Eth::NetworkTypes>> {
+        let loaded = self
+            .eth
+            .spawn_blocking_io(move |this| {
+                load_transactions(this.provider(), tx_numbers).map_err(Eth::Error::from_eth_err)
+            })
+            .await
+            .map_err(Into::into)?;
+
+        let mut txs = Vec::with_capacity(loaded.len());
+        let mut receipts = Vec::with_capacity(loaded.len());
+        for (tx, sender, meta, receipt) in loaded {
+            let tx_info = TransactionInfo {
+                hash: Some(meta.tx_hash),
+                index: Some(meta.index),
+                block_hash: Some(meta.block_hash),
+                block_number: Some(meta.block_number),
+                base_fee: meta.base_fee,
+            };
+            let rpc_tx = self
+                .eth
+                .tx_resp_builder()
+                .fill(tx.clone().with_signer(sender), tx_info)
+                .map_err(Into::into)?;
+            let receipt =
+                self.eth.build_transaction_receipt(tx, meta, receipt).await.map_err(Into::into)?;
+            receipts.push(ots_receipt(receipt, rpc_tx.ty(), Some(meta.timestamp)));
+            txs.push(rpc_tx);
+        }
+
+        Ok(TransactionsWithReceipts { txs, receipts, first_page, last_page })
+    }
+}
+
+impl<Eth> OtterscanApi<Eth>
+where
+    Eth: TraceExt + 'static,
+{
+    /// Replays the transaction and returns its call trace.
+    async fn trace_entries(&self, tx_hash: TxHash) -> RpcResult<Option<Vec<TraceEntry>>> {
+        let traces = self
+            .eth
+            .spawn_trace_transaction_in_block(
+                tx_hash,
+                TracingInspectorConfig::default_parity(),
+                move |_tx_info, inspector, _, _| Ok(inspector.into_traces().into_nodes()),
+            )
+            .await
+            .map_err(Into::into)?
+            .map(|traces| {
+                traces
+                    .into_iter()
+                    .map(|CallTraceNode { trace, .. }| TraceEntry {
+                        r#type: if trace.is_selfdestruct() {
+                            "SELFDESTRUCT".to_string()
+                        } else {
+                            trace.kind.to_string()
+                        },
+                        depth: trace.depth as u32,
+                        from: trace.caller,
+                        to: trace.address,
+                        value: trace.value,
+                        input: trace.data,
+                        output: trace.output,
+                    })
+                    .collect::<Vec<_>>()
+            });
+        Ok(traces)
+    }
+}
+
 #[async_trait]
 impl<Eth> OtterscanServer<RpcTransaction<Eth::NetworkTypes>, RpcHeader<Eth::NetworkTypes>>
     for OtterscanApi<Eth>
@@ -71,7 +178,8 @@ where
             RpcBlock<Eth::NetworkTypes>,
             RpcReceipt<Eth::NetworkTypes>,
             RpcHeader<Eth::NetworkTypes>,
-        > + EthTransactions
+        > + EthTransactions<Provider: AddressTransactionsReader + ContractCreatorsReader>
+        + LoadReceipt
         + TraceExt
         + 'static,
 {
@@ -141,34 +249,22 @@ where
 
     /// Handler for `ots_traceTransaction`
     async fn trace_transaction(&self, tx_hash: TxHash) -> RpcResult<Option<Vec<TraceEntry>>> {
-        let traces = self
-            .eth
-            .spawn_trace_transaction_in_block(
-                tx_hash,
-                TracingInspectorConfig::default_parity(),
-                move |_tx_info, inspector, _, _| Ok(inspector.into_traces().into_nodes()),
-            )
-            .await
-            .map_err(Into::into)?
-            .map(|traces| {
-                traces
-                    .into_iter()
-                    .map(|CallTraceNode { trace, .. }| TraceEntry {
-                        r#type: if trace.is_selfdestruct() {
-                            "SELFDESTRUCT".to_string()
-                        } else {
-                            trace.kind.to_string()
-                        },
-                        depth: trace.depth as u32,
-                        from: trace.caller,
-                        to: trace.address,
-                        value: trace.value,
-                        input: trace.data,
-                        output: trace.output,
-                    })
-                    .collect::<Vec<_>>()
-            });
-        Ok(traces)
+        let key = match &self.trace_cache {
+            Some(_) => self
+                .eth
+                .provider()
+                .transaction_by_hash_with_meta(tx_hash)
+                .map_err(EthApiError::from)?
+                .and_then(|(_, meta)| {
+                    TraceCacheKey::new(meta.block_hash, "ots_traceTransaction", &tx_hash).ok()
+                }),
+            None => None,
+        };
+        let traces = self.trace_entries(tx_hash);
+        match (&self.trace_cache, key) {
+            (Some(cache), Some(key)) => cache.get_or_insert_with(key, traces).await,
+            _ => traces.await,
+        }
     }
 
     /// Handler for `ots_getBlockDetails`
@@ -255,32 +351,7 @@ where
         let receipts = receipts
             .drain(page_start..page_end)
             .zip(transactions.iter().map(Typed2718::ty))
-            .map(|(receipt, tx_ty)| {
-                let inner = OtsReceipt {
-                    status: receipt.status(),
-                    cumulative_gas_used: receipt.cumulative_gas_used(),
-                    logs: None,
-                    logs_bloom: None,
-                    r#type: tx_ty,
-                };
-
-                let receipt = TransactionReceipt {
-                    inner,
-                    transaction_hash: receipt.transaction_hash(),
-                    transaction_index: receipt.transaction_index(),
-                    block_hash: receipt.block_hash(),
-                    block_number: receipt.block_number(),
-                    gas_used: receipt.gas_used(),
-                    effective_gas_price: receipt.effective_gas_price(),
-                    blob_gas_used: receipt.blob_gas_used(),
-                    blob_gas_price: receipt.blob_gas_price(),
-                    from: receipt.from(),
-                    to: receipt.to(),
-                    contract_address: receipt.contract_address(),
-                };
-
-                OtsTransactionReceipt { receipt, timestamp }
-            })
+            .map(|(receipt, tx_ty)| ots_receipt(receipt, tx_ty, timestamp))
             .collect();
 
         // use `transaction_count` to indicate the paginate information
@@ -292,21 +363,39 @@ where
     /// Handler for `ots_searchTransactionsBefore`
     async fn search_transactions_before(
         &self,
-        _address: Address,
-        _block_number: u64,
-        _page_size: usize,
-    ) -> RpcResult<TransactionsWithReceipts> {
-        Err(internal_rpc_err("unimplemented"))
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> RpcResult<OtsTransactionsWithReceipts<Eth::NetworkTypes>> {
+        let (tx_numbers, first_page, last_page) = self
+            .eth
+            .spawn_blocking_io(move |this| {
+                search_before(this.provider(), address, block_number, page_size)
+                    .map_err(Eth::Error::from_eth_err)
+            })
+            .await
+            .map_err(Into::into)?;
+
+        self.transactions_with_receipts(tx_numbers, first_page, last_page).await
     }
 
     /// Handler for `ots_searchTransactionsAfter`
     async fn search_transactions_after(
         &self,
-        _address: Address,
-        _block_number: u64,
-        _page_size: usize,
-    ) -> RpcResult<TransactionsWithReceipts> {
-        Err(internal_rpc_err("unimplemented"))
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> RpcResult<OtsTransactionsWithReceipts<Eth::NetworkTypes>> {
+        let (tx_numbers, first_page, last_page) = self
+            .eth
+            .spawn_blocking_io(move |this| {
+                search_after(this.provider(), address, block_number, page_size)
+                    .map_err(Eth::Error::from_eth_err)
+            })
+            .await
+            .map_err(Into::into)?;
+
+        self.transactions_with_receipts(tx_numbers, first_page, last_page).await
     }
 
     /// Handler for `ots_getTransactionBySenderAndNonce`
@@ -329,18 +418,33 @@ where
             return Ok(None);
         }
 
-        let num = binary_search::<_, _, ErrorObjectOwned>(
-            1,
-            self.eth.block_number()?.saturating_to(),
-            |mid| {
-                Box::pin(async move {
-                    Ok(!EthApiServer::get_code(&self.eth, address, Some(mid.into()))
-                        .await?
-                        .is_empty())
-                })
-            },
-        )
-        .await?;
+        let indexed = self
+            .eth
+            .spawn_blocking_io(move |this| {
+                indexed_contract_creator(this.provider(), address).map_err(Eth::Error::from_eth_err)
+            })
+            .await
+            .map_err(Into::into)?;
+
+        let num = match indexed {
+            IndexedContractCreator::Found(creator) => return Ok(Some(creator)),
+            IndexedContractCreator::NotCreated => return Ok(None),
+            IndexedContractCreator::InBlock(num) => num,
+            IndexedContractCreator::NotIndexed => {
+                binary_search::<_, _, ErrorObjectOwned>(
+                    1,
+                    self.eth.block_number()?.saturating_to(),
+                    |mid| {
+                        Box::pin(async move {
+                            Ok(!EthApiServer::get_code(&self.eth, address, Some(mid.into()))
+                                .await?
+                                .is_empty())
+                        })
+                    },
+                )
+                .await?
+            }
+        };
 
         let traces = self
             .eth
@@ -386,4 +490,276 @@ where
         let found = traces.and_then(|traces| traces.first().copied());
         Ok(found)
     }
+
+    /// Handler for `ots_getContractsDeployed`
+    async fn get_contracts_deployed(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> RpcResult<Vec<ContractDeployment>> {
+        if to_block < from_block {
+            return Err(EthApiError::InvalidBlockRange.into())
+        }
+        if to_block - from_block >= MAX_CONTRACTS_DEPLOYED_BLOCK_RANGE {
+            return Err(EthApiError::InvalidParams(format!(
+                "block range exceeds the maximum of {MAX_CONTRACTS_DEPLOYED_BLOCK_RANGE} blocks"
+            ))
+            .into())
+        }
+
+        self.eth
+            .spawn_blocking_io(move |this| {
+                contracts_deployed(this.provider(), from_block, to_block)
+                    .map_err(Eth::Error::from_eth_err)
+            })
+            .await
+            .map_err(Into::into)
+    }
+}
+
+/// Converts a transaction receipt into the trimmed down receipt returned by Otterscan.
+fn ots_receipt<R: ReceiptResponse>(
+    receipt: R,
+    tx_ty: u8,
+    timestamp: Option<u64>,
+) -> OtsTransactionReceipt {
+    let inner = OtsReceipt {
+        status: receipt.status(),
+        cumulative_gas_used: receipt.cumulative_gas_used(),
+        logs: None,
+        logs_bloom: None,
+        r#type: tx_ty,
+    };
+
+    let receipt = TransactionReceipt {
+        inner,
+        transaction_hash: receipt.transaction_hash(),
+        transaction_index: receipt.transaction_index(),
+        block_hash: receipt.block_hash(),
+        block_number: receipt.block_number(),
+        gas_used: receipt.gas_used(),
+        effective_gas_price: receipt.effective_gas_price(),
+        blob_gas_used: receipt.blob_gas_used(),
+        blob_gas_price: receipt.blob_gas_price(),
+        from: receipt.from(),
+        to: receipt.to(),
+        contract_address: receipt.contract_address(),
+    };
+
+    OtsTransactionReceipt { receipt, timestamp }
+}
+
+/// Returns a page of transactions touching the address before the given block, in descending
+/// order, alongside the first and last page flags.
+///
+/// Like Erigon, the page ends on a block boundary: once `page_size` transactions are found, the
+/// remaining transactions of the last block are included as well. Block `0` starts the search from
+/// the most recent transaction.
+fn search_before<P: AddressTransactionsReader + BlockReader>(
+    provider: &P,
+    address: Address,
+    block_number: u64,
+    page_size: usize,
+) -> Result<(Vec<TxNumber>, bool, bool), EthApiError> {
+    let before = match block_number {
+        0 => TxNumber::MAX,
+        block_number => provider
+            .block_body_indices(block_number)?
+            .map_or(TxNumber::MAX, |body| body.first_tx_num()),
+    };
+
+    let mut tx_numbers = provider.address_transactions_before(address, before, page_size)?;
+    let mut oldest = before;
+    if let Some(&last) = tx_numbers.last() {
+        let block_start = transaction_block_body(provider, last)?.first_tx_num();
+        tx_numbers.extend(
+            provider
+                .address_transactions_before(address, last, (last - block_start) as usize)?
+                .into_iter()
+                .filter(|tx_number| *tx_number >= block_start),
+        );
+        oldest = block_start;
+    }
+
+    let first_page = before == TxNumber::MAX ||
+        provider.address_transactions_after(address, before, 1)?.is_empty();
+    let last_page = provider.address_transactions_before(address, oldest, 1)?.is_empty();
+
+    Ok((tx_numbers, first_page, last_page))
+}
+
+/// Returns a page of transactions touching the address after the given block, in descending order,
+/// alongside the first and last page flags.
+///
+/// Like [`search_before`], the page ends on a block boundary.
+fn search_after<P: AddressTransactionsReader + BlockReader>(
+    provider: &P,
+    address: Address,
+    block_number: u64,
+    page_size: usize,
+) -> Result<(Vec<TxNumber>, bool, bool), EthApiError> {
+    let from = provider
+        .block_body_indices(block_number)?
+        .ok_or(EthApiError::HeaderNotFound(block_number.into()))?
+        .next_tx_num();
+
+    let mut tx_numbers = provider.address_transactions_after(address, from, page_size)?;
+    let mut newest = from;
+    if let Some(&last) = tx_numbers.last() {
+        let block_end = transaction_block_body(provider, last)?.last_tx_num();
+        tx_numbers.extend(
+            provider
+                .address_transactions_after(address, last + 1, (block_end - last) as usize)?
+                .into_iter()
+                .filter(|tx_number| *tx_number <= block_end),
+        );
+        newest = block_end + 1;
+    }
+
+    let first_page = provider.address_transactions_after(address, newest, 1)?.is_empty();
+    let last_page = provider.address_transactions_before(address, from, 1)?.is_empty();
+
+    // Pages are always ordered from the most recent transaction
+    tx_numbers.reverse();
+
+    Ok((tx_numbers, first_page, last_page))
+}
+
+/// Returns the body indices of the block containing the transaction.
+fn transaction_block_body<P: BlockReader>(
+    provider: &P,
+    tx_number: TxNumber,
+) -> Result<StoredBlockBodyIndices, EthApiError> {
+    let block_number =
+        provider.transaction_block(tx_number)?.ok_or(EthApiError::TransactionNotFound)?;
+    provider
+        .block_body_indices(block_number)?
+        .ok_or(EthApiError::HeaderNotFound(block_number.into()))
+}
+
+/// A transaction loaded by its number, with its sender, metadata and receipt.
+type LoadedTransaction<P> = (ProviderTx<P>, Address, TransactionMeta, ProviderReceipt<P>);
+
+/// Loads the transactions with the given numbers, their senders, metadata and receipts.
+fn load_transactions<P: BlockReader>(
+    provider: &P,
+    tx_numbers: Vec<TxNumber>,
+) -> Result<Vec<LoadedTransaction<P>>, EthApiError> {
+    // Pages end on block boundaries, so the transactions of a block are next to each other
+    let mut block = None;
+    let mut loaded = Vec::with_capacity(tx_numbers.len());
+    for tx_number in tx_numbers {
+        let tx = provider.transaction_by_id(tx_number)?.ok_or(EthApiError::TransactionNotFound)?;
+        let sender = match provider.transaction_sender(tx_number)? {
+            Some(sender) => sender,
+            None => tx.recover_signer().map_err(|_| EthApiError::InvalidTransactionSignature)?,
+        };
+        let receipt = provider.receipt(tx_number)?.ok_or(EthApiError::TransactionNotFound)?;
+
+        let block_number =
+            provider.transaction_block(tx_number)?.ok_or(EthApiError::TransactionNotFound)?;
+        let (header, body) = match block.take() {
+            Some((header, body)) if header.number() == block_number => (header, body),
+            _ => (
+                provider
+                    .sealed_header(block_number)?
+                    .ok_or(EthApiError::HeaderNotFound(block_number.into()))?,
+                provider
+                    .block_body_indices(block_number)?
+                    .ok_or(EthApiError::HeaderNotFound(block_number.into()))?,
+            ),
+        };
+        let meta = TransactionMeta {
+            tx_hash: *tx.tx_hash(),
+            index: tx_number - body.first_tx_num(),
+            block_hash: header.hash(),
+            block_number,
+            base_fee: header.base_fee_per_gas(),
+            excess_blob_gas: header.excess_blob_gas(),
+            timestamp: header.timestamp(),
+        };
+        block = Some((header, body));
+
+        loaded.push((tx, sender, meta, receipt));
+    }
+    Ok(loaded)
+}
+
+/// Result of looking up a contract in the contract creators index.
+enum IndexedContractCreator {
+    /// The contract and its creator were found in the index.
+    Found(ContractCreator),
+    /// The contract was created in the given block, by a contract that has to be found by tracing
+    /// the block.
+    InBlock(BlockNumber),
+    /// The contract wasn't created in any block, e.g. it was allocated at genesis.
+    NotCreated,
+    /// The index doesn't cover the latest block yet.
+    NotIndexed,
+}
+
+/// Looks up the creator of a contract in the contract creators index.
+fn indexed_contract_creator<P: ContractCreatorsReader + BlockReader + BlockNumReader>(
+    provider: &P,
+    address: Address,
+) -> Result<IndexedContractCreator, EthApiError> {
+    let Some((block_number, creation)) = provider.contract_creation(address)? else {
+        let indexed = match provider.contract_creations_range()? {
+            Some(range) => *range.end() >= provider.best_block_number()?,
+            None => false,
+        };
+        return Ok(if indexed {
+            IndexedContractCreator::NotCreated
+        } else {
+            IndexedContractCreator::NotIndexed
+        })
+    };
+
+    let tx =
+        provider.transaction_by_id(creation.tx_number)?.ok_or(EthApiError::TransactionNotFound)?;
+    let creator = match creation.factory {
+        Some(factory) => factory,
+        // Contracts deployed by the transaction itself have no factory, but so do contracts
+        // deployed with `CREATE2` by one of several deployers in the same transaction.
+        None if tx.is_create() && creation.creator.create(tx.nonce()) == address => {
+            creation.creator
+        }
+        None => return Ok(IndexedContractCreator::InBlock(block_number)),
+    };
+
+    Ok(IndexedContractCreator::Found(ContractCreator { hash: *tx.tx_hash(), creator }))
+}
+
+/// Returns the contracts deployed in the given inclusive block range, which must be indexed.
+fn contracts_deployed<P: ContractCreatorsReader + BlockReader>(
+    provider: &P,
+    from_block: BlockNumber,
+    to_block: BlockNumber,
+) -> Result<Vec<ContractDeployment>, EthApiError> {
+    let indexed = provider.contract_creations_range()?;
+    if indexed.is_none_or(|range| *range.end() < to_block) {
+        return Err(EthApiError::InvalidParams(format!(
+            "contract creations are not indexed up to block {to_block} yet"
+        )))
+    }
+
+    let mut creations = provider.contract_creations_by_block_range(from_block..=to_block)?;
+    // Creations are stored by contract address within a block
+    creations.sort_by_key(|(_, creation)| creation.tx_number);
+
+    creations
+        .into_iter()
+        .map(|(key, creation)| {
+            let tx = provider
+                .transaction_by_id(creation.tx_number)?
+                .ok_or(EthApiError::TransactionNotFound)?;
+            Ok(ContractDeployment {
+                address: key.address(),
+                block_number: key.block_number(),
+                hash: *tx.tx_hash(),
+                creator: creation.creator,
+                factory: creation.factory,
+            })
+        })
+        .collect()
 }
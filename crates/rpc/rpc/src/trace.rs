@@ -22,7 +22,9 @@ use reth_provider::{BlockNumReader, BlockReader, ChainSpecProvider};
 use reth_revm::{database::StateProviderDatabase, db::CacheDB};
 use reth_rpc_api::TraceApiServer;
 use reth_rpc_eth_api::{helpers::TraceExt, FromEthApiError, RpcNodeCore};
-use reth_rpc_eth_types::{error::EthApiError, utils::recover_raw_transaction};
+use reth_rpc_eth_types::{
+    error::EthApiError, utils::recover_raw_transaction, TraceCache, TraceCacheKey,
+};
 use reth_tasks::pool::BlockingTaskGuard;
 use reth_transaction_pool::{PoolPooledTx, PoolTransaction, TransactionPool};
 use revm::DatabaseCommit;
@@ -30,7 +32,8 @@ use revm_inspectors::{
     opcode::OpcodeGasInspector,
     tracing::{parity::populate_state_diff, TracingInspector, TracingInspectorConfig},
 };
-use std::sync::Arc;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{collections::BTreeSet, future::Future, sync::Arc};
 use tokio::sync::{AcquireError, OwnedSemaphorePermit};
 
 /// `trace` API implementation.
@@ -44,8 +47,12 @@ pub struct TraceApi<Eth> {
 
 impl<Eth> TraceApi<Eth> {
     /// Create a new instance of the [`TraceApi`]
-    pub fn new(eth_api: Eth, blocking_task_guard: BlockingTaskGuard) -> Self {
-        let inner = Arc::new(TraceApiInner { eth_api, blocking_task_guard });
+    pub fn new(
+        eth_api: Eth,
+        blocking_task_guard: BlockingTaskGuard,
+        trace_cache: Option<TraceCache>,
+    ) -> Self {
+        let inner = Arc::new(TraceApiInner { eth_api, blocking_task_guard, trace_cache });
         Self { inner }
     }
 
@@ -69,6 +76,37 @@ impl<Eth: RpcNodeCore> TraceApi<Eth> {
     }
 }
 
+impl<Eth> TraceApi<Eth>
+where
+    Eth: TraceExt + 'static,
+{
+    /// Returns the result of a call tracing the given block from the trace cache if it's enabled,
+    /// or acquires a permit and runs the call.
+    async fn trace_block_cached<T, F>(
+        &self,
+        block_id: BlockId,
+        method: &str,
+        params: &impl Serialize,
+        f: F,
+    ) -> Result<T, Eth::Error>
+    where
+        T: Serialize + DeserializeOwned + Send + 'static,
+        F: Future<Output = Result<T, Eth::Error>>,
+    {
+        let trace = async {
+            let _permit = self.acquire_trace_permit().await;
+            f.await
+        };
+        let Some(cache) = &self.inner.trace_cache else { return trace.await };
+        match TraceCacheKey::for_block(self.provider(), block_id, method, params)
+            .map_err(Eth::Error::from_eth_err)?
+        {
+            Some(key) => cache.get_or_insert_with(key, trace).await,
+            None => trace.await,
+        }
+    }
+}
+
 // === impl TraceApi ===
 
 impl<Eth> TraceApi<Eth>
@@ -598,8 +636,15 @@ where
         block_id: BlockId,
         trace_types: HashSet<TraceType>,
     ) -> RpcResult<Option<Vec<TraceResultsWithTransactionHash>>> {
-        let _permit = self.acquire_trace_permit().await;
-        Ok(Self::replay_block_transactions(self, block_id, trace_types)
+        // sorted, so the cache key doesn't depend on the order of the requested types
+        let params = trace_types.iter().map(|ty| format!("{ty:?}")).collect::<BTreeSet<_>>();
+        Ok(self
+            .trace_block_cached(
+                block_id,
+                "trace_replayBlockTransactions",
+                &params,
+                Self::replay_block_transactions(self, block_id, trace_types),
+            )
             .await
             .map_err(Into::into)?)
     }
@@ -619,8 +664,10 @@ where
         &self,
         block_id: BlockId,
     ) -> RpcResult<Option<Vec<LocalizedTransactionTrace>>> {
-        let _permit = self.acquire_trace_permit().await;
-        Ok(Self::trace_block(self, block_id).await.map_err(Into::into)?)
+        Ok(self
+            .trace_block_cached(block_id, "trace_block", &(), Self::trace_block(self, block_id))
+            .await
+            .map_err(Into::into)?)
     }
 
     /// Handler for `trace_filter`
@@ -687,6 +734,8 @@ struct TraceApiInner<Eth> {
     eth_api: Eth,
     // restrict the number of concurrent calls to `trace_*`
     blocking_task_guard: BlockingTaskGuard,
+    /// Cache of block traces, if enabled
+    trace_cache: Option<TraceCache>,
 }
 
 /// Helper to construct a [`LocalizedTransactionTrace`] that describes a reward to the block
@@ -248,13 +248,18 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
     ///   [`StageId::Execution`]
     /// - [`StaticFileSegment::Transactions`](reth_static_file_types::StaticFileSegment::Transactions)
     ///   -> [`StageId::Bodies`]
+    /// - Account and storage changesets -> the lowest checkpoint of the stages reading them, minus
+    ///   the latest blocks that may still be reorged
     ///
     /// CAUTION: This method locks the static file producer Mutex, hence can block the thread if the
     /// lock is occupied.
     pub fn move_to_static_files(&self) -> RethResult<()> {
         // Copies data from database to static files
-        let lowest_static_file_height =
-            self.static_file_producer.lock().copy_to_static_files()?.min_block_num();
+        let lowest_static_file_height = self
+            .static_file_producer
+            .lock()
+            .copy_to_static_files()?
+            .min_block_num_without_changesets();
 
         // Deletes data which has been copied to static files.
         if let Some(prune_tip) = lowest_static_file_height {
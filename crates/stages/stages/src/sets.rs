@@ -39,8 +39,10 @@
 use crate::{
     stages::{
         AccountHashingStage, BodyStage, ExecutionStage, FinishStage, HeaderStage,
-        IndexAccountHistoryStage, IndexStorageHistoryStage, MerkleStage, PruneSenderRecoveryStage,
-        PruneStage, SenderRecoveryStage, StorageHashingStage, TransactionLookupStage,
+        IndexAccountHistoryStage, IndexAddressTransactionsStage, IndexContractCreatorsStage,
+        IndexLogsStage, IndexStorageHistoryStage, IndexTokenTransfersStage, MerkleStage,
+        PruneSenderRecoveryStage, PruneStage, SenderRecoveryStage, StorageHashingStage,
+        TransactionLookupStage,
     },
     StageSet, StageSetBuilder,
 };
@@ -78,6 +80,10 @@ use tokio::sync::watch;
 /// - [`TransactionLookupStage`]
 /// - [`IndexStorageHistoryStage`]
 /// - [`IndexAccountHistoryStage`]
+/// - [`IndexAddressTransactionsStage`], if enabled
+/// - [`IndexLogsStage`], if enabled
+/// - [`IndexTokenTransfersStage`], if enabled
+/// - [`IndexContractCreatorsStage`], if enabled
 /// - [`PruneStage`] (execute)
 /// - [`FinishStage`]
 #[derive(Debug)]
@@ -292,6 +298,7 @@ where
 /// - [`PruneSenderRecoveryStage`]
 /// - [`HashingStages`]
 /// - [`HistoryIndexingStages`]
+/// - [`IndexContractCreatorsStage`], if enabled
 /// - [`PruneStage`]
 #[derive(Debug)]
 #[non_exhaustive]
@@ -325,9 +332,17 @@ where
     PruneSenderRecoveryStage: Stage<Provider>,
     HashingStages: StageSet<Provider>,
     HistoryIndexingStages: StageSet<Provider>,
+    IndexContractCreatorsStage<E>: Stage<Provider>,
     PruneStage: Stage<Provider>,
 {
     fn builder(self) -> StageSetBuilder<Provider> {
+        let index_contract_creators =
+            self.stages_config.index_contract_creators.enabled.then(|| {
+                IndexContractCreatorsStage::new(
+                    self.executor_provider.clone(),
+                    self.stages_config.index_contract_creators,
+                )
+            });
         ExecutionStages::new(self.executor_provider, self.consensus, self.stages_config.clone())
             .builder()
             // If sender recovery prune mode is set, add the prune sender recovery stage.
@@ -339,6 +354,9 @@ where
                 stages_config: self.stages_config.clone(),
                 prune_modes: self.prune_modes.clone(),
             })
+            // If enabled, contract creations are indexed by re-executing the blocks, which reads
+            // the history indices.
+            .add_stage_opt(index_contract_creators)
             // If any prune modes are set, add the prune stage.
             .add_stage_opt(self.prune_modes.is_empty().not().then(|| {
                 // Prune stage should be added after all hashing stages, because otherwise it will
@@ -433,6 +451,9 @@ where
     TransactionLookupStage: Stage<Provider>,
     IndexStorageHistoryStage: Stage<Provider>,
     IndexAccountHistoryStage: Stage<Provider>,
+    IndexAddressTransactionsStage: Stage<Provider>,
+    IndexLogsStage: Stage<Provider>,
+    IndexTokenTransfersStage: Stage<Provider>,
 {
     fn builder(self) -> StageSetBuilder<Provider> {
         StageSetBuilder::default()
@@ -451,5 +472,25 @@ where
                 self.stages_config.etl.clone(),
                 self.prune_modes.storage_history,
             ))
+            .add_stage_opt(self.stages_config.index_address_transactions.enabled.then(|| {
+                IndexAddressTransactionsStage::new(
+                    self.stages_config.index_address_transactions,
+                    self.stages_config.etl.clone(),
+                    self.prune_modes.address_transactions,
+                )
+            }))
+            .add_stage_opt(self.stages_config.index_logs.enabled.then(|| {
+                IndexLogsStage::new(
+                    self.stages_config.index_logs,
+                    self.stages_config.etl.clone(),
+                    self.prune_modes.log_index,
+                )
+            }))
+            .add_stage_opt(self.stages_config.index_token_transfers.enabled.then(|| {
+                IndexTokenTransfersStage::new(
+                    self.stages_config.index_token_transfers,
+                    self.prune_modes.token_transfers,
+                )
+            }))
     }
 }
@@ -9,6 +9,8 @@ use reth_db::{static_file::HeaderMask, tables};
 use reth_evm::{
     execute::{BlockExecutorProvider, Executor},
     metrics::ExecutorMetrics,
+    system_calls::NoopHook,
+    touched_accounts::TouchedAccountsCollector,
 };
 use reth_execution_types::Chain;
 use reth_exex::{ExExManagerHandle, ExExNotification, ExExNotificationSource};
@@ -321,6 +323,11 @@ where
         let mut cumulative_gas = 0;
         let batch_start = Instant::now();
 
+        // Accounts changed by internal calls are only needed by the address transactions index.
+        let collect_internal_accounts =
+            provider.prune_modes_ref().address_transactions.is_none_or(|mode| !mode.is_full());
+        let mut internal_accounts = Vec::new();
+
         let mut blocks = Vec::new();
         let mut results = Vec::new();
         for block_number in start_block..=max_block {
@@ -342,8 +349,10 @@ where
             // Execute the block
             let execute_start = Instant::now();
 
+            let (touched_accounts, state_hook) =
+                TouchedAccountsCollector::new(Box::new(NoopHook::default()));
             let result = self.metrics.metered_one(&block, |input| {
-                executor.execute_one(input).map_err(|error| {
+                executor.execute_one_with_state_hook(input, state_hook).map_err(|error| {
                     let header = block.header();
                     StageError::Block {
                         block: Box::new(BlockWithParent::new(
@@ -366,6 +375,19 @@ where
             }
             results.push(result);
 
+            if collect_internal_accounts {
+                let body = provider
+                    .block_body_indices(block_number)?
+                    .ok_or(ProviderError::BlockBodyIndicesNotFound(block_number))?;
+                internal_accounts.extend(body.tx_num_range().zip(
+                    touched_accounts.finish().internal_accounts(
+                        block.body().transactions(),
+                        block.senders(),
+                        block.header().beneficiary(),
+                    ),
+                ));
+            }
+
             execution_duration += execute_start.elapsed();
 
             // Log execution throughput
@@ -467,6 +489,7 @@ where
 
         // write output
         provider.write_state(&state, OriginalValuesKnown::Yes, StorageLocation::StaticFiles)?;
+        provider.write_internal_accounts(internal_accounts)?;
 
         let db_write_duration = time.elapsed();
         debug!(
@@ -0,0 +1,272 @@
+use super::load_history_indices;
+use alloy_primitives::Address;
+use reth_config::config::{EtlConfig, OptionalIndexConfig};
+use reth_db_api::{
+    models::ShardedKey, table::Decode, tables, transaction::DbTxMut, BlockNumberList,
+};
+use reth_etl::Collector;
+use reth_provider::{
+    AddressTransactionsReader, BlockReader, DBProvider, HistoryWriter, PruneCheckpointReader,
+    PruneCheckpointWriter,
+};
+use reth_prune_types::{PruneCheckpoint, PruneMode, PrunePurpose, PruneSegment};
+use reth_stages_api::{
+    ExecInput, ExecOutput, Stage, StageCheckpoint, StageError, StageId, UnwindInput, UnwindOutput,
+};
+use reth_storage_errors::provider::ProviderError;
+use std::fmt::Debug;
+use tracing::info;
+
+/// Number of blocks whose transactions are read before their indices are pushed to the
+/// [`Collector`].
+const DEFAULT_CACHE_THRESHOLD: u64 = 10_000;
+
+/// Stage is indexing the transactions that touched each address, so they can be searched by
+/// address. For more information on index sharding take a look at
+/// [`tables::AddressTransactions`].
+#[derive(Debug)]
+pub struct IndexAddressTransactionsStage {
+    /// Number of blocks after which the control
+    /// flow will be returned to the pipeline for commit.
+    pub commit_threshold: u64,
+    /// Pruning configuration.
+    pub prune_mode: Option<PruneMode>,
+    /// ETL configuration
+    pub etl_config: EtlConfig,
+}
+
+impl IndexAddressTransactionsStage {
+    /// Create new instance of [`IndexAddressTransactionsStage`].
+    pub const fn new(
+        config: OptionalIndexConfig,
+        etl_config: EtlConfig,
+        prune_mode: Option<PruneMode>,
+    ) -> Self {
+        Self { commit_threshold: config.commit_threshold, etl_config, prune_mode }
+    }
+}
+
+impl Default for IndexAddressTransactionsStage {
+    fn default() -> Self {
+        Self { commit_threshold: 100_000, prune_mode: None, etl_config: EtlConfig::default() }
+    }
+}
+
+impl<Provider> Stage<Provider> for IndexAddressTransactionsStage
+where
+    Provider: DBProvider<Tx: DbTxMut>
+        + HistoryWriter
+        + AddressTransactionsReader
+        + BlockReader
+        + PruneCheckpointReader
+        + PruneCheckpointWriter,
+{
+    /// Return the id of the stage
+    fn id(&self) -> StageId {
+        StageId::IndexAddressTransactions
+    }
+
+    /// Execute the stage.
+    fn execute(
+        &mut self,
+        provider: &Provider,
+        mut input: ExecInput,
+    ) -> Result<ExecOutput, StageError> {
+        if let Some((target_prunable_block, prune_mode)) = self
+            .prune_mode
+            .map(|mode| {
+                mode.prune_target_block(
+                    input.target(),
+                    PruneSegment::AddressTransactions,
+                    PrunePurpose::User,
+                )
+            })
+            .transpose()?
+            .flatten()
+        {
+            if target_prunable_block > input.checkpoint().block_number {
+                input.checkpoint = Some(StageCheckpoint::new(target_prunable_block));
+
+                // Save prune checkpoint only if we don't have one already.
+                // Otherwise, pruner may skip the unpruned range of blocks.
+                if provider.get_prune_checkpoint(PruneSegment::AddressTransactions)?.is_none() {
+                    let target_prunable_tx_number = provider
+                        .block_body_indices(target_prunable_block)?
+                        .ok_or(ProviderError::BlockBodyIndicesNotFound(target_prunable_block))?
+                        .last_tx_num();
+
+                    provider.save_prune_checkpoint(
+                        PruneSegment::AddressTransactions,
+                        PruneCheckpoint {
+                            block_number: Some(target_prunable_block),
+                            tx_number: Some(target_prunable_tx_number),
+                            prune_mode,
+                        },
+                    )?;
+                }
+            }
+        }
+
+        if input.target_reached() {
+            return Ok(ExecOutput::done(input.checkpoint()))
+        }
+
+        let (mut range, is_final_range) =
+            input.next_block_range_with_threshold(self.commit_threshold);
+        let first_sync = input.checkpoint().block_number == 0;
+
+        // On first sync we might have transactions coming from genesis. We clear the table since
+        // it's faster to rebuild from scratch.
+        if first_sync {
+            provider.tx_ref().clear::<tables::AddressTransactions>()?;
+            range = 0..=*range.end();
+        }
+
+        info!(target: "sync::stages::index_address_transactions::exec", ?first_sync, ?range, "Collecting indices");
+        let mut collector = Collector::new(self.etl_config.file_size, self.etl_config.dir.clone());
+        for chunk_start in range.clone().step_by(DEFAULT_CACHE_THRESHOLD as usize) {
+            let chunk_end = (chunk_start + DEFAULT_CACHE_THRESHOLD - 1).min(*range.end());
+            for (address, tx_numbers) in
+                provider.touched_addresses_with_range(chunk_start..=chunk_end)?
+            {
+                let last = *tx_numbers.last().expect("touched addresses have transactions");
+                collector.insert(
+                    ShardedKey::new(address, last),
+                    BlockNumberList::new_pre_sorted(tx_numbers),
+                )?;
+            }
+        }
+
+        info!(target: "sync::stages::index_address_transactions::exec", "Loading indices into database");
+        load_history_indices::<_, tables::AddressTransactions, _>(
+            provider,
+            collector,
+            first_sync,
+            ShardedKey::new,
+            ShardedKey::<Address>::decode_owned,
+            |key| key.key,
+        )?;
+
+        Ok(ExecOutput { checkpoint: StageCheckpoint::new(*range.end()), done: is_final_range })
+    }
+
+    /// Unwind the stage.
+    fn unwind(
+        &mut self,
+        provider: &Provider,
+        input: UnwindInput,
+    ) -> Result<UnwindOutput, StageError> {
+        let (range, unwind_progress, _) =
+            input.unwind_block_range_with_threshold(self.commit_threshold);
+
+        provider.unwind_address_transactions_indices_range(range)?;
+
+        Ok(UnwindOutput { checkpoint: StageCheckpoint::new(unwind_progress) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{StorageKind, TestStageDB};
+    use alloy_consensus::Transaction;
+    use alloy_primitives::{BlockNumber, TxNumber};
+    use reth_primitives_traits::SignedTransaction;
+    use reth_provider::DatabaseProviderFactory;
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+    use std::collections::BTreeMap;
+
+    /// Inserts blocks `0..=tip` and returns the expected senders and recipients of each
+    /// transaction number.
+    fn setup(db: &TestStageDB, tip: BlockNumber) -> BTreeMap<TxNumber, Vec<Address>> {
+        let mut rng = generators::rng();
+        let blocks = random_block_range(
+            &mut rng,
+            0..=tip,
+            BlockRangeParams { parent: None, tx_count: 1..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Static).unwrap();
+
+        blocks
+            .iter()
+            .flat_map(|block| block.body().transactions.iter())
+            .enumerate()
+            .map(|(tx_number, tx)| {
+                let sender = tx.recover_signer().unwrap();
+                (tx_number as TxNumber, [Some(sender), tx.to()].into_iter().flatten().collect())
+            })
+            .collect()
+    }
+
+    fn run(db: &TestStageDB, run_to: u64, input_checkpoint: Option<BlockNumber>) {
+        let input = ExecInput {
+            target: Some(run_to),
+            checkpoint: input_checkpoint.map(StageCheckpoint::new),
+        };
+        let mut stage = IndexAddressTransactionsStage::default();
+        let provider = db.factory.database_provider_rw().unwrap();
+        let out = stage.execute(&provider, input).unwrap();
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(run_to), done: true });
+        provider.commit().unwrap();
+    }
+
+    fn unwind(db: &TestStageDB, unwind_from: u64, unwind_to: u64) {
+        let input = UnwindInput {
+            checkpoint: StageCheckpoint::new(unwind_from),
+            unwind_to,
+            ..Default::default()
+        };
+        let mut stage = IndexAddressTransactionsStage::default();
+        let provider = db.factory.database_provider_rw().unwrap();
+        let out = stage.unwind(&provider, input).unwrap();
+        assert_eq!(out, UnwindOutput { checkpoint: StageCheckpoint::new(unwind_to) });
+        provider.commit().unwrap();
+    }
+
+    fn assert_indexed(db: &TestStageDB, expected: &BTreeMap<TxNumber, Vec<Address>>) {
+        let provider = db.factory.database_provider_ro().unwrap();
+        for (&tx_number, addresses) in expected {
+            for &address in addresses {
+                let found =
+                    provider.address_transactions_before(address, tx_number + 1, 1).unwrap();
+                assert_eq!(found, vec![tx_number], "tx {tx_number} not indexed for {address}");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_and_unwind() {
+        let db = TestStageDB::default();
+        let expected = setup(&db, 20);
+
+        run(&db, 10, None);
+        run(&db, 20, Some(10));
+        assert_indexed(&db, &expected);
+
+        let last_tx_at_10 = db
+            .factory
+            .database_provider_ro()
+            .unwrap()
+            .block_body_indices(10)
+            .unwrap()
+            .unwrap()
+            .last_tx_num();
+
+        unwind(&db, 20, 10);
+
+        // Only the transactions up to the unwind target are left.
+        let (kept, removed): (BTreeMap<_, _>, BTreeMap<_, _>) =
+            expected.into_iter().partition(|(tx_number, _)| *tx_number <= last_tx_at_10);
+        assert_indexed(&db, &kept);
+
+        let provider = db.factory.database_provider_ro().unwrap();
+        for addresses in removed.values() {
+            for &address in addresses {
+                let found = provider
+                    .address_transactions_after(address, last_tx_at_10 + 1, usize::MAX)
+                    .unwrap();
+                assert!(found.is_empty(), "unwound transactions still indexed for {address}");
+            }
+        }
+    }
+}
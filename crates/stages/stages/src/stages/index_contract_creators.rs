@@ -0,0 +1,216 @@
+use alloy_primitives::{map::AddressMap, BlockNumber};
+use reth_config::config::IndexContractCreatorsConfig;
+use reth_db_api::{
+    models::{BlockNumberAddress, ContractCreation},
+    tables,
+    transaction::DbTxMut,
+};
+use reth_evm::{
+    execute::{BlockExecutorProvider, Executor},
+    system_calls::NoopHook,
+    touched_accounts::TouchedAccountsCollector,
+};
+use reth_primitives_traits::{BlockBody, NodePrimitives};
+use reth_provider::{
+    AccountReader, BlockNumReader, BlockReader, DBProvider, HistoricalStateProviderRef,
+    HistoryWriter, ProviderError, ProviderResult, PruneCheckpointReader, StateCommitmentProvider,
+    StaticFileProviderFactory, TransactionVariant,
+};
+use reth_prune_types::PruneSegment;
+use reth_revm::database::StateProviderDatabase;
+use reth_stages_api::{
+    ExecInput, ExecOutput, Stage, StageCheckpoint, StageError, StageId, UnwindInput, UnwindOutput,
+};
+use tracing::{error, info};
+
+/// The contract creators index stage re-executes blocks to index the contracts they create, with
+/// the transaction and the contract that created them. For more information take a look at
+/// [`tables::ContractCreations`] and [`tables::ContractCreators`], and at
+/// [`reth_evm::touched_accounts`] for how the deploying contracts are found.
+///
+/// Blocks persisted by the engine are indexed when they are written, with the contracts found
+/// while the engine executed them.
+#[derive(Debug)]
+pub struct IndexContractCreatorsStage<E> {
+    /// Executor factory used to re-execute the blocks.
+    executor_provider: E,
+    /// Number of blocks after which the control
+    /// flow will be returned to the pipeline for commit.
+    pub commit_threshold: u64,
+}
+
+impl<E> IndexContractCreatorsStage<E> {
+    /// Create new instance of [`IndexContractCreatorsStage`].
+    pub const fn new(executor_provider: E, config: IndexContractCreatorsConfig) -> Self {
+        Self { executor_provider, commit_threshold: config.commit_threshold }
+    }
+}
+
+impl<E, Provider> Stage<Provider> for IndexContractCreatorsStage<E>
+where
+    E: BlockExecutorProvider,
+    Provider: DBProvider<Tx: DbTxMut>
+        + BlockReader<Block = <E::Primitives as NodePrimitives>::Block>
+        + StaticFileProviderFactory
+        + StateCommitmentProvider
+        + PruneCheckpointReader
+        + HistoryWriter,
+{
+    /// Return the id of the stage
+    fn id(&self) -> StageId {
+        StageId::IndexContractCreators
+    }
+
+    /// Execute the stage.
+    fn execute(&mut self, provider: &Provider, input: ExecInput) -> Result<ExecOutput, StageError> {
+        if input.target_reached() {
+            return Ok(ExecOutput::done(input.checkpoint()))
+        }
+
+        let (range, is_final_range) = input.next_block_range_with_threshold(self.commit_threshold);
+
+        // On first sync we might have contract creations coming from a previous run. We clear the
+        // tables since the genesis block creates no contracts.
+        if input.checkpoint().block_number == 0 {
+            provider.tx_ref().clear::<tables::ContractCreations>()?;
+            provider.tx_ref().clear::<tables::ContractCreators>()?;
+        }
+
+        info!(target: "sync::stages::index_contract_creators::exec", ?range, "Re-executing blocks");
+
+        // The executor caches the state touched by the range, so both the executor and the nonce
+        // lookups only need to read the state at the start of the range.
+        let state = historical_state(provider, *range.start())?;
+        let mut executor = self
+            .executor_provider
+            .executor(StateProviderDatabase::new(historical_state(provider, *range.start())?));
+        let mut nonces = AddressMap::<u64>::default();
+
+        let mut creations = Vec::new();
+        for block_number in range.clone() {
+            let block = provider
+                .block_with_senders(block_number.into(), TransactionVariant::NoHash)?
+                .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
+            let body = provider
+                .block_body_indices(block_number)?
+                .ok_or(ProviderError::BlockBodyIndicesNotFound(block_number))?;
+
+            let (collector, state_hook) =
+                TouchedAccountsCollector::new(Box::new(NoopHook::default()));
+            // The execution stage already executed the block, so failing to re-execute it, e.g.
+            // because its read precompile calls are missing, must not unwind it.
+            executor.execute_one_with_state_hook(&block, state_hook).map_err(|error| {
+                error!(
+                    target: "sync::stages::index_contract_creators::exec",
+                    block_number,
+                    %error,
+                    "Failed to re-execute block"
+                );
+                StageError::Fatal(Box::new(error))
+            })?;
+
+            let block_creations = collector.finish().contract_creations(
+                block.body().transactions(),
+                block.senders(),
+                &mut nonces,
+                |address| Ok(state.basic_account(&address)?.map_or(0, |acc| acc.nonce)),
+            )?;
+            creations.extend(block_creations.into_iter().map(|creation| {
+                (
+                    BlockNumberAddress((block_number, creation.address)),
+                    ContractCreation {
+                        tx_number: body.first_tx_num() + creation.transaction_index as u64,
+                        creator: creation.creator,
+                        factory: creation.factory,
+                    },
+                )
+            }));
+        }
+
+        info!(
+            target: "sync::stages::index_contract_creators::exec",
+            count = creations.len(),
+            "Inserting contract creations"
+        );
+        provider.insert_contract_creations(creations)?;
+
+        Ok(ExecOutput { checkpoint: StageCheckpoint::new(*range.end()), done: is_final_range })
+    }
+
+    /// Unwind the stage.
+    fn unwind(
+        &mut self,
+        provider: &Provider,
+        input: UnwindInput,
+    ) -> Result<UnwindOutput, StageError> {
+        let (range, unwind_progress, _) =
+            input.unwind_block_range_with_threshold(self.commit_threshold);
+
+        provider.unwind_contract_creations_range(range)?;
+
+        Ok(UnwindOutput { checkpoint: StageCheckpoint::new(unwind_progress) })
+    }
+}
+
+/// Returns the state at the start of the given block, failing if the account or storage history
+/// needed for it was pruned.
+fn historical_state<Provider>(
+    provider: &Provider,
+    block_number: BlockNumber,
+) -> ProviderResult<HistoricalStateProviderRef<'_, Provider>>
+where
+    Provider: DBProvider
+        + BlockNumReader
+        + StateCommitmentProvider
+        + StaticFileProviderFactory
+        + PruneCheckpointReader,
+{
+    let mut state = HistoricalStateProviderRef::new(provider, block_number);
+    if let Some(block_number) = provider
+        .get_prune_checkpoint(PruneSegment::AccountHistory)?
+        .and_then(|checkpoint| checkpoint.block_number)
+    {
+        state = state.with_lowest_available_account_history_block_number(block_number + 1);
+    }
+    if let Some(block_number) = provider
+        .get_prune_checkpoint(PruneSegment::StorageHistory)?
+        .and_then(|checkpoint| checkpoint.block_number)
+    {
+        state = state.with_lowest_available_storage_history_block_number(block_number + 1);
+    }
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestStageDB;
+    use alloy_primitives::{address, Address};
+    use reth_provider::{ContractCreatorsReader, DatabaseProviderFactory};
+
+    const SENDER: Address = address!("0x00000000000000000000000000000000000000aa");
+
+    #[test]
+    fn insert_and_unwind() {
+        let db = TestStageDB::default();
+        let creation = |tx_number| ContractCreation { tx_number, creator: SENDER, factory: None };
+        let first = SENDER.create(0);
+        let second = SENDER.create(1);
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        provider
+            .insert_contract_creations([
+                (BlockNumberAddress((1, first)), creation(0)),
+                (BlockNumberAddress((2, second)), creation(1)),
+            ])
+            .unwrap();
+        assert_eq!(provider.contract_creation(second).unwrap(), Some((2, creation(1))));
+        assert_eq!(provider.contract_creations_by_block_range(2..=2).unwrap().len(), 1);
+
+        assert_eq!(provider.unwind_contract_creations_range(2..=3).unwrap(), 1);
+        assert_eq!(provider.contract_creation(first).unwrap(), Some((1, creation(0))));
+        assert_eq!(provider.contract_creation(second).unwrap(), None);
+        assert_eq!(provider.contract_creations_by_block_range(0..=3).unwrap().len(), 1);
+        provider.commit().unwrap();
+    }
+}
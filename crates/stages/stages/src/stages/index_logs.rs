@@ -0,0 +1,295 @@
+use super::load_history_indices;
+use alloy_primitives::Address;
+use reth_config::config::{EtlConfig, OptionalIndexConfig};
+use reth_db_api::{
+    models::{LogPosition, LogTopicKey, ShardedKey},
+    table::Decode,
+    tables,
+    transaction::DbTxMut,
+    BlockNumberList,
+};
+use reth_etl::Collector;
+use reth_provider::{
+    DBProvider, HistoryWriter, LogIndexReader, PruneCheckpointReader, PruneCheckpointWriter,
+};
+use reth_prune_types::{PruneCheckpoint, PruneMode, PrunePurpose, PruneSegment};
+use reth_stages_api::{
+    ExecInput, ExecOutput, Stage, StageCheckpoint, StageError, StageId, UnwindInput, UnwindOutput,
+};
+use std::fmt::Debug;
+use tracing::info;
+
+/// Number of blocks whose receipts are read before their indices are pushed to the
+/// [`Collector`]s.
+const DEFAULT_CACHE_THRESHOLD: u64 = 10_000;
+
+/// Stage is indexing the positions of the logs of each address and topic, so `eth_getLogs` can
+/// find matching logs without scanning the header blooms and the receipts. For more information on
+/// index sharding take a look at [`tables::LogAddressIndex`] and [`tables::LogTopicIndex`].
+#[derive(Debug)]
+pub struct IndexLogsStage {
+    /// Number of blocks after which the control
+    /// flow will be returned to the pipeline for commit.
+    pub commit_threshold: u64,
+    /// Pruning configuration.
+    pub prune_mode: Option<PruneMode>,
+    /// ETL configuration
+    pub etl_config: EtlConfig,
+}
+
+impl IndexLogsStage {
+    /// Create new instance of [`IndexLogsStage`].
+    pub const fn new(
+        config: OptionalIndexConfig,
+        etl_config: EtlConfig,
+        prune_mode: Option<PruneMode>,
+    ) -> Self {
+        Self { commit_threshold: config.commit_threshold, etl_config, prune_mode }
+    }
+}
+
+impl Default for IndexLogsStage {
+    fn default() -> Self {
+        Self { commit_threshold: 100_000, prune_mode: None, etl_config: EtlConfig::default() }
+    }
+}
+
+impl<Provider> Stage<Provider> for IndexLogsStage
+where
+    Provider: DBProvider<Tx: DbTxMut>
+        + HistoryWriter
+        + LogIndexReader
+        + PruneCheckpointReader
+        + PruneCheckpointWriter,
+{
+    /// Return the id of the stage
+    fn id(&self) -> StageId {
+        StageId::IndexLogs
+    }
+
+    /// Execute the stage.
+    fn execute(
+        &mut self,
+        provider: &Provider,
+        mut input: ExecInput,
+    ) -> Result<ExecOutput, StageError> {
+        if let Some((target_prunable_block, prune_mode)) = self
+            .prune_mode
+            .map(|mode| {
+                mode.prune_target_block(input.target(), PruneSegment::LogIndex, PrunePurpose::User)
+            })
+            .transpose()?
+            .flatten()
+        {
+            if target_prunable_block > input.checkpoint().block_number {
+                input.checkpoint = Some(StageCheckpoint::new(target_prunable_block));
+
+                // Save prune checkpoint only if we don't have one already.
+                // Otherwise, pruner may skip the unpruned range of blocks.
+                if provider.get_prune_checkpoint(PruneSegment::LogIndex)?.is_none() {
+                    provider.save_prune_checkpoint(
+                        PruneSegment::LogIndex,
+                        PruneCheckpoint {
+                            block_number: Some(target_prunable_block),
+                            tx_number: None,
+                            prune_mode,
+                        },
+                    )?;
+                }
+            }
+        }
+
+        if input.target_reached() {
+            return Ok(ExecOutput::done(input.checkpoint()))
+        }
+
+        let (mut range, is_final_range) =
+            input.next_block_range_with_threshold(self.commit_threshold);
+        let first_sync = input.checkpoint().block_number == 0;
+
+        // On first sync we might have logs coming from genesis. We clear the tables since it's
+        // faster to rebuild from scratch.
+        if first_sync {
+            provider.tx_ref().clear::<tables::LogAddressIndex>()?;
+            provider.tx_ref().clear::<tables::LogTopicIndex>()?;
+            range = 0..=*range.end();
+        }
+
+        info!(target: "sync::stages::index_logs::exec", ?first_sync, ?range, "Collecting indices");
+        let mut address_collector =
+            Collector::new(self.etl_config.file_size, self.etl_config.dir.clone());
+        let mut topic_collector =
+            Collector::new(self.etl_config.file_size, self.etl_config.dir.clone());
+        for chunk_start in range.clone().step_by(DEFAULT_CACHE_THRESHOLD as usize) {
+            let chunk_end = (chunk_start + DEFAULT_CACHE_THRESHOLD - 1).min(*range.end());
+            let entries = provider.log_index_entries_with_range(chunk_start..=chunk_end)?;
+            for (address, positions) in entries.addresses {
+                let (last, list) = stored_positions(positions);
+                address_collector.insert(ShardedKey::new(address, last), list)?;
+            }
+            for (key, positions) in entries.topics {
+                let (last, list) = stored_positions(positions);
+                topic_collector.insert(ShardedKey::new(key, last), list)?;
+            }
+        }
+
+        info!(target: "sync::stages::index_logs::exec", "Loading indices into database");
+        load_history_indices::<_, tables::LogAddressIndex, _>(
+            provider,
+            address_collector,
+            first_sync,
+            ShardedKey::new,
+            ShardedKey::<Address>::decode_owned,
+            |key| key.key,
+        )?;
+        load_history_indices::<_, tables::LogTopicIndex, _>(
+            provider,
+            topic_collector,
+            first_sync,
+            ShardedKey::new,
+            ShardedKey::<LogTopicKey>::decode_owned,
+            |key| key.key,
+        )?;
+
+        Ok(ExecOutput { checkpoint: StageCheckpoint::new(*range.end()), done: is_final_range })
+    }
+
+    /// Unwind the stage.
+    fn unwind(
+        &mut self,
+        provider: &Provider,
+        input: UnwindInput,
+    ) -> Result<UnwindOutput, StageError> {
+        let (range, unwind_progress, _) =
+            input.unwind_block_range_with_threshold(self.commit_threshold);
+
+        provider.unwind_log_indices_range(range)?;
+
+        Ok(UnwindOutput { checkpoint: StageCheckpoint::new(unwind_progress) })
+    }
+}
+
+/// Returns the last stored position of the log index entries of a key along with the list of the
+/// stored positions.
+fn stored_positions(positions: Vec<LogPosition>) -> (u64, BlockNumberList) {
+    let last = *positions.last().expect("index entries have positions");
+    (last.into(), BlockNumberList::new_pre_sorted(positions.into_iter().map(u64::from)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{StorageKind, TestStageDB};
+    use alloy_primitives::BlockNumber;
+    use reth_provider::DatabaseProviderFactory;
+    use reth_testing_utils::generators::{
+        self, random_block_range, random_log, random_receipt, BlockRangeParams,
+    };
+    use std::collections::BTreeMap;
+
+    /// Inserts blocks `0..=tip` with their receipts and returns the expected log positions of each
+    /// log address and topic.
+    fn setup(
+        db: &TestStageDB,
+        tip: BlockNumber,
+    ) -> (BTreeMap<Address, Vec<LogPosition>>, BTreeMap<LogTopicKey, Vec<LogPosition>>) {
+        let mut rng = generators::rng();
+        let blocks = random_block_range(
+            &mut rng,
+            0..=tip,
+            BlockRangeParams { parent: None, tx_count: 1..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Static).unwrap();
+
+        let mut addresses = BTreeMap::<_, Vec<_>>::new();
+        let mut topics = BTreeMap::<_, Vec<_>>::new();
+        let mut receipts = Vec::new();
+        let mut tx_num = 0u64;
+        for block in &blocks {
+            let mut block_receipts = Vec::new();
+            let mut log_index = 0;
+            for transaction in &block.body().transactions {
+                let mut receipt = random_receipt(&mut rng, transaction, Some(0));
+                receipt.logs =
+                    (0..2).map(|_| random_log(&mut rng, None, Some(2))).collect::<Vec<_>>();
+                for log in &receipt.logs {
+                    let position = LogPosition::new(block.number, log_index);
+                    addresses.entry(log.address).or_default().push(position);
+                    for (topic_position, topic) in log.topics().iter().enumerate() {
+                        let key = LogTopicKey::new(topic_position as u8, *topic);
+                        topics.entry(key).or_default().push(position);
+                    }
+                    log_index += 1;
+                }
+                block_receipts.push((tx_num, receipt));
+                tx_num += 1;
+            }
+            receipts.push((block.number, block_receipts));
+        }
+        db.insert_receipts_by_block(receipts, StorageKind::Static).unwrap();
+
+        (addresses, topics)
+    }
+
+    fn run(db: &TestStageDB, run_to: u64, input_checkpoint: Option<BlockNumber>) {
+        let input = ExecInput {
+            target: Some(run_to),
+            checkpoint: input_checkpoint.map(StageCheckpoint::new),
+        };
+        let mut stage = IndexLogsStage::default();
+        let provider = db.factory.database_provider_rw().unwrap();
+        let out = stage.execute(&provider, input).unwrap();
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(run_to), done: true });
+        provider.commit().unwrap();
+    }
+
+    fn unwind(db: &TestStageDB, unwind_from: u64, unwind_to: u64) {
+        let input = UnwindInput {
+            checkpoint: StageCheckpoint::new(unwind_from),
+            unwind_to,
+            ..Default::default()
+        };
+        let mut stage = IndexLogsStage::default();
+        let provider = db.factory.database_provider_rw().unwrap();
+        let out = stage.unwind(&provider, input).unwrap();
+        assert_eq!(out, UnwindOutput { checkpoint: StageCheckpoint::new(unwind_to) });
+        provider.commit().unwrap();
+    }
+
+    fn assert_indexed(
+        db: &TestStageDB,
+        tip: BlockNumber,
+        addresses: &BTreeMap<Address, Vec<LogPosition>>,
+        topics: &BTreeMap<LogTopicKey, Vec<LogPosition>>,
+    ) {
+        let provider = db.factory.database_provider_ro().unwrap();
+        let below_tip = |positions: &Vec<LogPosition>| {
+            positions.iter().copied().filter(|p| p.block_number <= tip).collect::<Vec<_>>()
+        };
+        for (&address, positions) in addresses {
+            assert_eq!(
+                provider.log_address_positions(address, 0..=BlockNumber::MAX).unwrap(),
+                below_tip(positions)
+            );
+        }
+        for (&key, positions) in topics {
+            assert_eq!(
+                provider.log_topic_positions(key, 0..=BlockNumber::MAX).unwrap(),
+                below_tip(positions)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_and_unwind() {
+        let db = TestStageDB::default();
+        let (addresses, topics) = setup(&db, 20);
+
+        run(&db, 10, None);
+        run(&db, 20, Some(10));
+        assert_indexed(&db, 20, &addresses, &topics);
+
+        unwind(&db, 20, 10);
+        assert_indexed(&db, 10, &addresses, &topics);
+    }
+}
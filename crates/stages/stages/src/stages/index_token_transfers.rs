@@ -0,0 +1,247 @@
+use reth_config::config::OptionalIndexConfig;
+use reth_db_api::{tables, transaction::DbTxMut};
+use reth_provider::{
+    DBProvider, HistoryWriter, PruneCheckpointReader, PruneCheckpointWriter, TokenTransfersReader,
+};
+use reth_prune_types::{PruneCheckpoint, PruneMode, PrunePurpose, PruneSegment};
+use reth_stages_api::{
+    ExecInput, ExecOutput, Stage, StageCheckpoint, StageError, StageId, UnwindInput, UnwindOutput,
+};
+use std::fmt::Debug;
+use tracing::info;
+
+/// Number of blocks whose transactions and receipts are read before their transfers are written
+/// to the database.
+const DEFAULT_CACHE_THRESHOLD: u64 = 10_000;
+
+/// Stage is indexing the ERC20 token transfers by token and by holder, along with the balance
+/// deltas of the holders in each block, see [`tables::TokenTransfers`],
+/// [`tables::HolderTokenTransfers`] and [`tables::TokenBalanceDeltas`].
+///
+/// The native token transfers between HyperCore and HyperEVM are indexed by user in
+/// [`tables::NativeTransfers`].
+#[derive(Debug)]
+pub struct IndexTokenTransfersStage {
+    /// Number of blocks after which the control
+    /// flow will be returned to the pipeline for commit.
+    pub commit_threshold: u64,
+    /// Pruning configuration.
+    pub prune_mode: Option<PruneMode>,
+}
+
+impl IndexTokenTransfersStage {
+    /// Create new instance of [`IndexTokenTransfersStage`].
+    pub const fn new(config: OptionalIndexConfig, prune_mode: Option<PruneMode>) -> Self {
+        Self { commit_threshold: config.commit_threshold, prune_mode }
+    }
+}
+
+impl Default for IndexTokenTransfersStage {
+    fn default() -> Self {
+        Self { commit_threshold: 100_000, prune_mode: None }
+    }
+}
+
+impl<Provider> Stage<Provider> for IndexTokenTransfersStage
+where
+    Provider: DBProvider<Tx: DbTxMut>
+        + HistoryWriter
+        + TokenTransfersReader
+        + PruneCheckpointReader
+        + PruneCheckpointWriter,
+{
+    /// Return the id of the stage
+    fn id(&self) -> StageId {
+        StageId::IndexTokenTransfers
+    }
+
+    /// Execute the stage.
+    fn execute(
+        &mut self,
+        provider: &Provider,
+        mut input: ExecInput,
+    ) -> Result<ExecOutput, StageError> {
+        if let Some((target_prunable_block, prune_mode)) = self
+            .prune_mode
+            .map(|mode| {
+                mode.prune_target_block(
+                    input.target(),
+                    PruneSegment::TokenTransfers,
+                    PrunePurpose::User,
+                )
+            })
+            .transpose()?
+            .flatten()
+        {
+            if target_prunable_block > input.checkpoint().block_number {
+                input.checkpoint = Some(StageCheckpoint::new(target_prunable_block));
+
+                // Save prune checkpoint only if we don't have one already.
+                // Otherwise, pruner may skip the unpruned range of blocks.
+                if provider.get_prune_checkpoint(PruneSegment::TokenTransfers)?.is_none() {
+                    provider.save_prune_checkpoint(
+                        PruneSegment::TokenTransfers,
+                        PruneCheckpoint {
+                            block_number: Some(target_prunable_block),
+                            tx_number: None,
+                            prune_mode,
+                        },
+                    )?;
+                }
+            }
+        }
+
+        if input.target_reached() {
+            return Ok(ExecOutput::done(input.checkpoint()))
+        }
+
+        let (mut range, is_final_range) =
+            input.next_block_range_with_threshold(self.commit_threshold);
+        let first_sync = input.checkpoint().block_number == 0;
+
+        // On first sync we might have transfers coming from genesis. We clear the tables since
+        // it's faster to rebuild from scratch.
+        if first_sync {
+            provider.tx_ref().clear::<tables::TokenTransfers>()?;
+            provider.tx_ref().clear::<tables::HolderTokenTransfers>()?;
+            provider.tx_ref().clear::<tables::TokenBalanceDeltas>()?;
+            provider.tx_ref().clear::<tables::NativeTransfers>()?;
+            range = 0..=*range.end();
+        }
+
+        info!(target: "sync::stages::index_token_transfers::exec", ?first_sync, ?range, "Indexing token transfers");
+        for chunk_start in range.clone().step_by(DEFAULT_CACHE_THRESHOLD as usize) {
+            let chunk_end = (chunk_start + DEFAULT_CACHE_THRESHOLD - 1).min(*range.end());
+            let entries = provider.token_transfer_entries_with_range(chunk_start..=chunk_end)?;
+            provider.insert_token_transfers(entries)?;
+        }
+
+        Ok(ExecOutput { checkpoint: StageCheckpoint::new(*range.end()), done: is_final_range })
+    }
+
+    /// Unwind the stage.
+    fn unwind(
+        &mut self,
+        provider: &Provider,
+        input: UnwindInput,
+    ) -> Result<UnwindOutput, StageError> {
+        let (range, unwind_progress, _) =
+            input.unwind_block_range_with_threshold(self.commit_threshold);
+
+        provider.unwind_token_transfers_range(range)?;
+
+        Ok(UnwindOutput { checkpoint: StageCheckpoint::new(unwind_progress) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{StorageKind, TestStageDB};
+    use alloy_primitives::{Address, BlockNumber, Log, LogData, U256};
+    use reth_db_api::models::TokenBalanceDelta;
+    use reth_provider::{DatabaseProviderFactory, TRANSFER_EVENT_TOPIC};
+    use reth_testing_utils::generators::{
+        self, random_block_range, random_receipt, BlockRangeParams,
+    };
+
+    const TOKEN: Address = Address::repeat_byte(0xaa);
+    const HOLDER: Address = Address::repeat_byte(0xbb);
+
+    /// Inserts blocks `0..=tip` whose transactions each transfer one token to [`HOLDER`] from an
+    /// address derived from the block number.
+    fn setup(db: &TestStageDB, tip: BlockNumber) {
+        let mut rng = generators::rng();
+        let blocks = random_block_range(
+            &mut rng,
+            0..=tip,
+            BlockRangeParams { parent: None, tx_count: 1..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Static).unwrap();
+
+        let mut receipts = Vec::new();
+        let mut tx_num = 0u64;
+        for block in &blocks {
+            let mut block_receipts = Vec::new();
+            for transaction in &block.body().transactions {
+                let mut receipt = random_receipt(&mut rng, transaction, Some(0));
+                let from = Address::with_last_byte(block.number as u8 + 1);
+                receipt.logs = vec![Log {
+                    address: TOKEN,
+                    data: LogData::new_unchecked(
+                        vec![TRANSFER_EVENT_TOPIC, from.into_word(), HOLDER.into_word()],
+                        U256::from(1).to_be_bytes_vec().into(),
+                    ),
+                }];
+                block_receipts.push((tx_num, receipt));
+                tx_num += 1;
+            }
+            receipts.push((block.number, block_receipts));
+        }
+        db.insert_receipts_by_block(receipts, StorageKind::Static).unwrap();
+    }
+
+    fn run(db: &TestStageDB, run_to: u64, input_checkpoint: Option<BlockNumber>) {
+        let input = ExecInput {
+            target: Some(run_to),
+            checkpoint: input_checkpoint.map(StageCheckpoint::new),
+        };
+        let mut stage = IndexTokenTransfersStage::default();
+        let provider = db.factory.database_provider_rw().unwrap();
+        let out = stage.execute(&provider, input).unwrap();
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(run_to), done: true });
+        provider.commit().unwrap();
+    }
+
+    fn unwind(db: &TestStageDB, unwind_from: u64, unwind_to: u64) {
+        let input = UnwindInput {
+            checkpoint: StageCheckpoint::new(unwind_from),
+            unwind_to,
+            ..Default::default()
+        };
+        let mut stage = IndexTokenTransfersStage::default();
+        let provider = db.factory.database_provider_rw().unwrap();
+        let out = stage.unwind(&provider, input).unwrap();
+        assert_eq!(out, UnwindOutput { checkpoint: StageCheckpoint::new(unwind_to) });
+        provider.commit().unwrap();
+    }
+
+    fn assert_indexed(db: &TestStageDB, tip: BlockNumber) {
+        let provider = db.factory.database_provider_ro().unwrap();
+        let expected = provider.token_transfer_entries_with_range(0..=tip).unwrap();
+
+        let transfers = provider.token_transfers(TOKEN, (0, 0), BlockNumber::MAX, usize::MAX);
+        assert_eq!(transfers.unwrap(), expected.transfers);
+
+        let holder_transfers =
+            provider.holder_token_transfers(HOLDER, Some(TOKEN), (0, 0), BlockNumber::MAX, 5);
+        assert_eq!(holder_transfers.unwrap().len(), expected.transfers.len().min(5));
+
+        let deltas = provider.token_balance_deltas(HOLDER, TOKEN, 0..=BlockNumber::MAX).unwrap();
+        assert_eq!(deltas.len() as u64, tip + 1);
+        for (block_number, delta) in deltas {
+            let received = expected
+                .transfers
+                .iter()
+                .filter(|(key, _)| key.block_number == block_number)
+                .count();
+            assert_eq!(
+                delta,
+                TokenBalanceDelta { received: U256::from(received), sent: U256::ZERO }
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_and_unwind() {
+        let db = TestStageDB::default();
+        setup(&db, 20);
+
+        run(&db, 10, None);
+        run(&db, 20, Some(10));
+        assert_indexed(&db, 20);
+
+        unwind(&db, 20, 10);
+        assert_indexed(&db, 10);
+    }
+}
@@ -12,8 +12,16 @@ mod hashing_storage;
 mod headers;
 /// Index history of account changes
 mod index_account_history;
+/// Index of the transactions touching each address
+mod index_address_transactions;
+/// Index of the contracts created by each transaction
+mod index_contract_creators;
+/// Index of the positions of the logs of each address and topic
+mod index_logs;
 /// Index history of storage changes
 mod index_storage_history;
+/// Index of the ERC20 token transfers of each token and holder
+mod index_token_transfers;
 /// Stage for computing state root.
 mod merkle;
 mod prune;
@@ -31,7 +39,11 @@ pub use hashing_account::*;
 pub use hashing_storage::*;
 pub use headers::*;
 pub use index_account_history::*;
+pub use index_address_transactions::*;
+pub use index_contract_creators::*;
+pub use index_logs::*;
 pub use index_storage_history::*;
+pub use index_token_transfers::*;
 pub use merkle::*;
 pub use prune::*;
 pub use s3::*;
@@ -1,8 +1,8 @@
 use reth_db_api::{table::Value, transaction::DbTxMut};
 use reth_primitives::NodePrimitives;
 use reth_provider::{
-    BlockReader, DBProvider, PruneCheckpointReader, PruneCheckpointWriter,
-    StaticFileProviderFactory,
+    AddressTransactionsReader, BlockReader, DBProvider, LogIndexReader, PruneCheckpointReader,
+    PruneCheckpointWriter, StaticFileProviderFactory, TokenTransfersReader,
 };
 use reth_prune::{
     PruneMode, PruneModes, PruneSegment, PrunerBuilder, SegmentOutput, SegmentOutputCheckpoint,
@@ -42,6 +42,9 @@ where
         + PruneCheckpointReader
         + PruneCheckpointWriter
         + BlockReader
+        + AddressTransactionsReader
+        + LogIndexReader
+        + TokenTransfersReader
         + StaticFileProviderFactory<Primitives: NodePrimitives<SignedTx: Value, Receipt: Value>>,
 {
     fn id(&self) -> StageId {
@@ -131,6 +134,9 @@ where
         + PruneCheckpointReader
         + PruneCheckpointWriter
         + BlockReader
+        + AddressTransactionsReader
+        + LogIndexReader
+        + TokenTransfersReader
         + StaticFileProviderFactory<Primitives: NodePrimitives<SignedTx: Value, Receipt: Value>>,
 {
     fn id(&self) -> StageId {
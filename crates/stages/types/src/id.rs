@@ -20,6 +20,10 @@ pub enum StageId {
     TransactionLookup,
     IndexStorageHistory,
     IndexAccountHistory,
+    IndexAddressTransactions,
+    IndexLogs,
+    IndexTokenTransfers,
+    IndexContractCreators,
     Prune,
     Finish,
     /// Other custom stage with a provided string identifier.
@@ -58,6 +62,17 @@ impl StageId {
         Self::Prune,
     ];
 
+    /// Stages that only run when enabled in the config.
+    ///
+    /// They are not part of [`StageId::ALL`], so that existing datadirs don't have to backfill
+    /// them. A disabled stage has no checkpoint.
+    pub const OPTIONAL: [Self; 4] = [
+        Self::IndexAddressTransactions,
+        Self::IndexLogs,
+        Self::IndexTokenTransfers,
+        Self::IndexContractCreators,
+    ];
+
     /// Return stage id formatted as string.
     pub const fn as_str(&self) -> &str {
         match self {
@@ -75,6 +90,10 @@ impl StageId {
             Self::TransactionLookup => "TransactionLookup",
             Self::IndexAccountHistory => "IndexAccountHistory",
             Self::IndexStorageHistory => "IndexStorageHistory",
+            Self::IndexAddressTransactions => "IndexAddressTransactions",
+            Self::IndexLogs => "IndexLogs",
+            Self::IndexTokenTransfers => "IndexTokenTransfers",
+            Self::IndexContractCreators => "IndexContractCreators",
             Self::Prune => "Prune",
             Self::Finish => "Finish",
             Self::Other(s) => s,
@@ -119,6 +138,10 @@ mod tests {
         assert_eq!(StageId::MerkleExecute.to_string(), "MerkleExecute");
         assert_eq!(StageId::IndexAccountHistory.to_string(), "IndexAccountHistory");
         assert_eq!(StageId::IndexStorageHistory.to_string(), "IndexStorageHistory");
+        assert_eq!(StageId::IndexAddressTransactions.to_string(), "IndexAddressTransactions");
+        assert_eq!(StageId::IndexLogs.to_string(), "IndexLogs");
+        assert_eq!(StageId::IndexTokenTransfers.to_string(), "IndexTokenTransfers");
+        assert_eq!(StageId::IndexContractCreators.to_string(), "IndexContractCreators");
         assert_eq!(StageId::TransactionLookup.to_string(), "TransactionLookup");
         assert_eq!(StageId::Finish.to_string(), "Finish");
 
@@ -12,7 +12,7 @@ mod static_file_producer;
 
 pub use static_file_producer::{
     StaticFileProducer, StaticFileProducerInner, StaticFileProducerResult,
-    StaticFileProducerWithResult,
+    StaticFileProducerWithResult, CHANGESETS_DISTANCE, CHANGESET_STAGES,
 };
 
 // Re-export for convenience.
@@ -0,0 +1,57 @@
+use crate::segments::Segment;
+use alloy_primitives::BlockNumber;
+use reth_db_api::{cursor::DbCursorRO, tables, transaction::DbTx};
+use reth_provider::{providers::StaticFileWriter, DBProvider, StaticFileProviderFactory};
+use reth_static_file_types::StaticFileSegment;
+use reth_storage_errors::provider::ProviderResult;
+use std::ops::RangeInclusive;
+
+/// Number of sidecars the zstd dictionary of a static file is trained on.
+const DICTIONARY_SAMPLES: usize = 1000;
+
+/// Static File segment responsible for [`StaticFileSegment::HlSidecars`] part of data.
+#[derive(Debug, Default)]
+pub struct HlSidecars;
+
+impl<Provider: StaticFileProviderFactory + DBProvider> Segment<Provider> for HlSidecars {
+    fn segment(&self) -> StaticFileSegment {
+        StaticFileSegment::HlSidecars
+    }
+
+    fn copy_to_static_files(
+        &self,
+        provider: Provider,
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<()> {
+        let static_file_provider = provider.static_file_provider();
+        let mut static_file_writer =
+            static_file_provider.get_writer(*block_range.start(), StaticFileSegment::HlSidecars)?;
+
+        let mut sidecars_cursor = provider.tx_ref().cursor_read::<tables::HlSidecars>()?;
+        let mut sidecars_walker = sidecars_cursor.walk_range(block_range.clone())?.peekable();
+
+        // Every block gets a row, with an empty sidecar if none was recorded for it.
+        for block in block_range {
+            let sidecar = sidecars_walker
+                .next_if(|entry| entry.as_ref().map_or(true, |(b, _)| *b == block))
+                .transpose()?
+                .map(|(_, sidecar)| sidecar)
+                .unwrap_or_default();
+
+            // The dictionary of a new static file is trained on its first recorded sidecars
+            static_file_writer.append_hl_sidecar(&sidecar, block, |file_range| {
+                provider
+                    .tx_ref()
+                    .cursor_read::<tables::HlSidecars>()?
+                    .walk_range(file_range)?
+                    .filter(|entry| entry.as_ref().map_or(true, |(_, sidecar)| !sidecar.is_empty()))
+                    .take(DICTIONARY_SAMPLES)
+                    .map(|entry| entry.map(|(_, sidecar)| sidecar))
+                    .collect::<Result<_, _>>()
+                    .map_err(Into::into)
+            })?;
+        }
+
+        Ok(())
+    }
+}
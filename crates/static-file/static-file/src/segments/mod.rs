@@ -9,6 +9,12 @@ pub use headers::Headers;
 mod receipts;
 pub use receipts::Receipts;
 
+mod changesets;
+pub use changesets::{AccountChangeSets, StorageChangeSets};
+
+mod hl_sidecars;
+pub use hl_sidecars::HlSidecars;
+
 use alloy_primitives::BlockNumber;
 use reth_provider::StaticFileProviderFactory;
 use reth_static_file_types::StaticFileSegment;
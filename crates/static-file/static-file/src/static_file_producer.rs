@@ -11,7 +11,7 @@ use reth_provider::{
     providers::StaticFileWriter, BlockReader, ChainStateBlockReader, DBProvider,
     DatabaseProviderFactory, StageCheckpointReader, StaticFileProviderFactory,
 };
-use reth_prune_types::PruneModes;
+use reth_prune_types::{PruneModes, MINIMUM_PRUNING_DISTANCE};
 use reth_stages_types::StageId;
 use reth_static_file_types::{HighestStaticFiles, StaticFileTargets};
 use reth_storage_errors::provider::ProviderResult;
@@ -23,6 +23,21 @@ use std::{
 };
 use tracing::{debug, trace};
 
+/// Stages reading the changesets from the database. Only the changesets of blocks that all of them
+/// processed are moved to static files.
+pub const CHANGESET_STAGES: [StageId; 6] = [
+    StageId::Execution,
+    StageId::AccountHashing,
+    StageId::StorageHashing,
+    StageId::MerkleExecute,
+    StageId::IndexAccountHistory,
+    StageId::IndexStorageHistory,
+];
+
+/// Number of the latest blocks whose changesets are kept in the database, as they may still be
+/// reorged.
+pub const CHANGESETS_DISTANCE: u64 = MINIMUM_PRUNING_DISTANCE;
+
 /// Result of [`StaticFileProducerInner::run`] execution.
 pub type StaticFileProducerResult = ProviderResult<StaticFileTargets>;
 
@@ -140,6 +155,15 @@ where
         if let Some(block_range) = targets.receipts.clone() {
             segments.push((Box::new(segments::Receipts), block_range));
         }
+        if let Some(block_range) = targets.account_changesets.clone() {
+            segments.push((Box::new(segments::AccountChangeSets), block_range));
+        }
+        if let Some(block_range) = targets.storage_changesets.clone() {
+            segments.push((Box::new(segments::StorageChangeSets), block_range));
+        }
+        if let Some(block_range) = targets.hl_sidecars.clone() {
+            segments.push((Box::new(segments::HlSidecars), block_range));
+        }
 
         segments.par_iter().try_for_each(|(segment, block_range)| -> ProviderResult<()> {
             debug!(target: "static_file", segment = %segment.segment(), ?block_range, "StaticFileProducer segment");
@@ -183,11 +207,16 @@ where
             .map(|stage| provider.get_stage_checkpoint(stage).map(|c| c.map(|c| c.block_number)))
             .collect::<Result<Vec<_>, _>>()?;
 
+        let changesets = self.changesets_target()?;
+
         let highest_static_files = HighestStaticFiles {
             headers: stages_checkpoints[0],
             receipts: stages_checkpoints[1],
             transactions: stages_checkpoints[2],
             block_meta: stages_checkpoints[2],
+            account_changesets: changesets,
+            storage_changesets: changesets,
+            hl_sidecars: stages_checkpoints[1],
         };
         let targets = self.get_static_file_targets(highest_static_files)?;
         self.run(targets)?;
@@ -195,6 +224,40 @@ where
         Ok(highest_static_files)
     }
 
+    /// Copies the changesets from database to static files up to
+    /// [`Self::changesets_target`].
+    ///
+    /// Returns the highest block of the changesets in static files.
+    pub fn copy_changesets_to_static_files(&self) -> ProviderResult<Option<BlockNumber>> {
+        let changesets = self.changesets_target()?;
+        let targets = self.get_static_file_targets(HighestStaticFiles {
+            account_changesets: changesets,
+            storage_changesets: changesets,
+            ..Default::default()
+        })?;
+        self.run(targets)?;
+
+        Ok(changesets)
+    }
+
+    /// Returns the highest block whose changesets can be moved to static files.
+    ///
+    /// That's the lowest checkpoint of the [`CHANGESET_STAGES`], minus [`CHANGESETS_DISTANCE`]
+    /// blocks that may still be reorged.
+    pub fn changesets_target(&self) -> ProviderResult<Option<BlockNumber>> {
+        let provider = self.provider.database_provider_ro()?;
+        let mut lowest = None::<BlockNumber>;
+        for stage in CHANGESET_STAGES {
+            let Some(checkpoint) = provider.get_stage_checkpoint(stage)? else { return Ok(None) };
+            lowest = Some(
+                lowest
+                    .map_or(checkpoint.block_number, |lowest| lowest.min(checkpoint.block_number)),
+            );
+        }
+
+        Ok(lowest.and_then(|lowest| lowest.checked_sub(CHANGESETS_DISTANCE)))
+    }
+
     /// Returns a static file targets at the provided finalized block numbers per segment.
     /// The target is determined by the check against highest `static_files` using
     /// [`reth_provider::providers::StaticFileProvider::get_highest_static_files`].
@@ -230,6 +293,33 @@ where
             block_meta: finalized_block_numbers.block_meta.and_then(|finalized_block_number| {
                 self.get_static_file_target(highest_static_files.block_meta, finalized_block_number)
             }),
+            // StaticFile changesets only if they're not pruned according to the user configuration
+            account_changesets: if self.prune_modes.account_history.is_none() {
+                finalized_block_numbers.account_changesets.and_then(|finalized_block_number| {
+                    self.get_static_file_target(
+                        highest_static_files.account_changesets,
+                        finalized_block_number,
+                    )
+                })
+            } else {
+                None
+            },
+            storage_changesets: if self.prune_modes.storage_history.is_none() {
+                finalized_block_numbers.storage_changesets.and_then(|finalized_block_number| {
+                    self.get_static_file_target(
+                        highest_static_files.storage_changesets,
+                        finalized_block_number,
+                    )
+                })
+            } else {
+                None
+            },
+            hl_sidecars: finalized_block_numbers.hl_sidecars.and_then(|finalized_block_number| {
+                self.get_static_file_target(
+                    highest_static_files.hl_sidecars,
+                    finalized_block_number,
+                )
+            }),
         };
 
         trace!(
@@ -257,17 +347,24 @@ where
 #[cfg(test)]
 mod tests {
     use crate::static_file_producer::{
-        StaticFileProducer, StaticFileProducerInner, StaticFileTargets,
+        StaticFileProducer, StaticFileProducerInner, StaticFileTargets, CHANGESETS_DISTANCE,
+        CHANGESET_STAGES,
     };
-    use alloy_primitives::{B256, U256};
+    use alloy_primitives::{Address, B256, U256};
     use assert_matches::assert_matches;
-    use reth_db_api::{database::Database, transaction::DbTx};
+    use reth_db_api::{
+        database::Database,
+        models::{AccountBeforeTx, StorageBeforeTx},
+        transaction::DbTx,
+    };
+    use reth_primitives_traits::{Account, StorageEntry};
     use reth_provider::{
-        providers::StaticFileWriter, test_utils::MockNodeTypesWithDB, ProviderError,
-        ProviderFactory, StaticFileProviderFactory,
+        providers::StaticFileWriter, test_utils::MockNodeTypesWithDB, DatabaseProviderFactory,
+        ProviderError, ProviderFactory, StageCheckpointWriter, StaticFileProviderFactory,
     };
     use reth_prune_types::PruneModes;
     use reth_stages::test_utils::{StorageKind, TestStageDB};
+    use reth_stages_types::StageCheckpoint;
     use reth_static_file_types::{HighestStaticFiles, StaticFileSegment};
     use reth_testing_utils::generators::{
         self, random_block_range, random_receipt, BlockRangeParams,
@@ -327,6 +424,9 @@ mod tests {
                 receipts: Some(1),
                 transactions: Some(1),
                 block_meta: None,
+                account_changesets: None,
+                storage_changesets: None,
+                hl_sidecars: None,
             })
             .expect("get static file targets");
         assert_eq!(
@@ -335,7 +435,10 @@ mod tests {
                 headers: Some(0..=1),
                 receipts: Some(0..=1),
                 transactions: Some(0..=1),
-                block_meta: None
+                block_meta: None,
+                account_changesets: None,
+                storage_changesets: None,
+                hl_sidecars: None
             }
         );
         assert_matches!(static_file_producer.run(targets), Ok(_));
@@ -345,7 +448,10 @@ mod tests {
                 headers: Some(1),
                 receipts: Some(1),
                 transactions: Some(1),
-                block_meta: None
+                block_meta: None,
+                account_changesets: None,
+                storage_changesets: None,
+                hl_sidecars: None
             }
         );
 
@@ -355,6 +461,9 @@ mod tests {
                 receipts: Some(3),
                 transactions: Some(3),
                 block_meta: None,
+                account_changesets: None,
+                storage_changesets: None,
+                hl_sidecars: None,
             })
             .expect("get static file targets");
         assert_eq!(
@@ -363,7 +472,10 @@ mod tests {
                 headers: Some(2..=3),
                 receipts: Some(2..=3),
                 transactions: Some(2..=3),
-                block_meta: None
+                block_meta: None,
+                account_changesets: None,
+                storage_changesets: None,
+                hl_sidecars: None
             }
         );
         assert_matches!(static_file_producer.run(targets), Ok(_));
@@ -373,7 +485,10 @@ mod tests {
                 headers: Some(3),
                 receipts: Some(3),
                 transactions: Some(3),
-                block_meta: None
+                block_meta: None,
+                account_changesets: None,
+                storage_changesets: None,
+                hl_sidecars: None
             }
         );
 
@@ -383,6 +498,9 @@ mod tests {
                 receipts: Some(4),
                 transactions: Some(4),
                 block_meta: None,
+                account_changesets: None,
+                storage_changesets: None,
+                hl_sidecars: None,
             })
             .expect("get static file targets");
         assert_eq!(
@@ -391,7 +509,10 @@ mod tests {
                 headers: Some(4..=4),
                 receipts: Some(4..=4),
                 transactions: Some(4..=4),
-                block_meta: None
+                block_meta: None,
+                account_changesets: None,
+                storage_changesets: None,
+                hl_sidecars: None
             }
         );
         assert_matches!(
@@ -404,7 +525,10 @@ mod tests {
                 headers: Some(3),
                 receipts: Some(3),
                 transactions: Some(3),
-                block_meta: None
+                block_meta: None,
+                account_changesets: None,
+                storage_changesets: None,
+                hl_sidecars: None
             }
         );
     }
@@ -434,6 +558,9 @@ mod tests {
                         receipts: Some(1),
                         transactions: Some(1),
                         block_meta: None,
+                        account_changesets: None,
+                        storage_changesets: None,
+                        hl_sidecars: None,
                     })
                     .expect("get static file targets");
                 assert_matches!(locked_producer.run(targets.clone()), Ok(_));
@@ -449,4 +576,64 @@ mod tests {
             assert!(only_one.take().is_some_and(|_| target.any()) || !target.any())
         }
     }
+
+    #[test]
+    fn copy_changesets() {
+        let db = TestStageDB::default();
+        let account = Account { nonce: 1, ..Default::default() };
+        let entry = StorageEntry { key: B256::with_last_byte(1), value: U256::from(1) };
+        let changeset = |block| vec![(Address::with_last_byte(block), account, vec![entry])];
+        // Block 1 doesn't change any account
+        db.insert_changesets([changeset(0), Vec::new(), changeset(2), changeset(3)], None)
+            .expect("insert changesets");
+
+        let static_file_producer =
+            StaticFileProducerInner::new(db.factory.clone(), PruneModes::default());
+        let save_checkpoints = |block_number| {
+            let provider = db.factory.database_provider_rw().unwrap();
+            for stage in CHANGESET_STAGES {
+                provider.save_stage_checkpoint(stage, StageCheckpoint::new(block_number)).unwrap();
+            }
+            provider
+                .save_stage_checkpoint(CHANGESET_STAGES[0], StageCheckpoint::new(block_number + 1))
+                .unwrap();
+            provider.commit().unwrap();
+        };
+
+        // Changesets that may still be reorged stay in the database
+        save_checkpoints(CHANGESETS_DISTANCE - 1);
+        assert_eq!(static_file_producer.changesets_target().unwrap(), None);
+        assert_eq!(static_file_producer.copy_changesets_to_static_files().unwrap(), None);
+
+        save_checkpoints(CHANGESETS_DISTANCE + 2);
+        assert_eq!(static_file_producer.changesets_target().unwrap(), Some(2));
+        assert_eq!(static_file_producer.copy_changesets_to_static_files().unwrap(), Some(2));
+
+        let static_file_provider = db.factory.static_file_provider();
+        let highest_static_files = static_file_provider.get_highest_static_files();
+        assert_eq!(highest_static_files.account_changesets, Some(2));
+        assert_eq!(highest_static_files.storage_changesets, Some(2));
+
+        for (block, address) in [(0, Some(0)), (1, None), (2, Some(2))] {
+            let accounts = static_file_provider.account_changeset(block).unwrap().unwrap();
+            let storage = static_file_provider.storage_changeset(block).unwrap().unwrap();
+            let address = address.map(Address::with_last_byte);
+            assert_eq!(
+                accounts.changes,
+                address
+                    .map(|address| AccountBeforeTx { address, info: Some(account) })
+                    .into_iter()
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                storage.changes,
+                address
+                    .map(|address| StorageBeforeTx { address, entry })
+                    .into_iter()
+                    .collect::<Vec<_>>()
+            );
+        }
+        assert_eq!(static_file_provider.account_changeset(3).unwrap(), None);
+        assert_eq!(static_file_provider.storage_changeset(3).unwrap(), None);
+    }
 }
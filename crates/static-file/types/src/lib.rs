@@ -39,6 +39,15 @@ pub struct HighestStaticFiles {
     /// Highest static file block of transactions, inclusive.
     /// If [`None`], no static file is available.
     pub block_meta: Option<BlockNumber>,
+    /// Highest static file block of account changesets, inclusive.
+    /// If [`None`], no static file is available.
+    pub account_changesets: Option<BlockNumber>,
+    /// Highest static file block of storage changesets, inclusive.
+    /// If [`None`], no static file is available.
+    pub storage_changesets: Option<BlockNumber>,
+    /// Highest static file block of Hyperliquid sidecars, inclusive.
+    /// If [`None`], no static file is available.
+    pub hl_sidecars: Option<BlockNumber>,
 }
 
 impl HighestStaticFiles {
@@ -49,6 +58,9 @@ impl HighestStaticFiles {
             StaticFileSegment::Transactions => self.transactions,
             StaticFileSegment::Receipts => self.receipts,
             StaticFileSegment::BlockMeta => self.block_meta,
+            StaticFileSegment::AccountChangeSets => self.account_changesets,
+            StaticFileSegment::StorageChangeSets => self.storage_changesets,
+            StaticFileSegment::HlSidecars => self.hl_sidecars,
         }
     }
 
@@ -59,12 +71,24 @@ impl HighestStaticFiles {
             StaticFileSegment::Transactions => &mut self.transactions,
             StaticFileSegment::Receipts => &mut self.receipts,
             StaticFileSegment::BlockMeta => &mut self.block_meta,
+            StaticFileSegment::AccountChangeSets => &mut self.account_changesets,
+            StaticFileSegment::StorageChangeSets => &mut self.storage_changesets,
+            StaticFileSegment::HlSidecars => &mut self.hl_sidecars,
         }
     }
 
     /// Returns an iterator over all static file segments
     fn iter(&self) -> impl Iterator<Item = Option<BlockNumber>> {
-        [self.headers, self.transactions, self.receipts, self.block_meta].into_iter()
+        [
+            self.headers,
+            self.transactions,
+            self.receipts,
+            self.block_meta,
+            self.account_changesets,
+            self.storage_changesets,
+            self.hl_sidecars,
+        ]
+        .into_iter()
     }
 
     /// Returns the minimum block of all segments.
@@ -72,6 +96,14 @@ impl HighestStaticFiles {
         self.iter().flatten().min()
     }
 
+    /// Returns the minimum block of all segments, except the changesets.
+    ///
+    /// The changesets are kept in the database for the latest blocks, so they would hold back the
+    /// pruning of the other segments.
+    pub fn min_block_num_without_changesets(&self) -> Option<u64> {
+        Self { account_changesets: None, storage_changesets: None, ..*self }.min_block_num()
+    }
+
     /// Returns the maximum block of all segments.
     pub fn max_block_num(&self) -> Option<u64> {
         self.iter().flatten().max()
@@ -89,6 +121,12 @@ pub struct StaticFileTargets {
     pub transactions: Option<RangeInclusive<BlockNumber>>,
     /// Targeted range of block meta.
     pub block_meta: Option<RangeInclusive<BlockNumber>>,
+    /// Targeted range of account changesets.
+    pub account_changesets: Option<RangeInclusive<BlockNumber>>,
+    /// Targeted range of storage changesets.
+    pub storage_changesets: Option<RangeInclusive<BlockNumber>>,
+    /// Targeted range of Hyperliquid sidecars.
+    pub hl_sidecars: Option<RangeInclusive<BlockNumber>>,
 }
 
 impl StaticFileTargets {
@@ -97,7 +135,10 @@ impl StaticFileTargets {
         self.headers.is_some() ||
             self.receipts.is_some() ||
             self.transactions.is_some() ||
-            self.block_meta.is_some()
+            self.block_meta.is_some() ||
+            self.account_changesets.is_some() ||
+            self.storage_changesets.is_some() ||
+            self.hl_sidecars.is_some()
     }
 
     /// Returns `true` if all targets are either [`None`] or has beginning of the range equal to the
@@ -108,6 +149,9 @@ impl StaticFileTargets {
             (self.receipts.as_ref(), static_files.receipts),
             (self.transactions.as_ref(), static_files.transactions),
             (self.block_meta.as_ref(), static_files.block_meta),
+            (self.account_changesets.as_ref(), static_files.account_changesets),
+            (self.storage_changesets.as_ref(), static_files.storage_changesets),
+            (self.hl_sidecars.as_ref(), static_files.hl_sidecars),
         ]
         .iter()
         .all(|(target_block_range, highest_static_fileted_block)| {
@@ -142,6 +186,9 @@ mod tests {
             receipts: Some(200),
             transactions: None,
             block_meta: None,
+            account_changesets: Some(200),
+            storage_changesets: None,
+            hl_sidecars: None,
         };
 
         // Test for headers segment
@@ -152,6 +199,10 @@ mod tests {
 
         // Test for transactions segment
         assert_eq!(files.highest(StaticFileSegment::Transactions), None);
+
+        // Test for changeset segments
+        assert_eq!(files.highest(StaticFileSegment::AccountChangeSets), Some(200));
+        assert_eq!(files.highest(StaticFileSegment::StorageChangeSets), None);
     }
 
     #[test]
@@ -182,11 +233,19 @@ mod tests {
             receipts: Some(100),
             transactions: None,
             block_meta: None,
+            account_changesets: None,
+            storage_changesets: None,
+            hl_sidecars: None,
         };
 
         // Minimum value among the available segments
         assert_eq!(files.min_block_num(), Some(100));
 
+        // The lagging changesets are ignored
+        let files = HighestStaticFiles { account_changesets: Some(50), ..files };
+        assert_eq!(files.min_block_num(), Some(50));
+        assert_eq!(files.min_block_num_without_changesets(), Some(100));
+
         let empty_files = HighestStaticFiles::default();
         // No values, should return None
         assert_eq!(empty_files.min_block_num(), None);
@@ -199,6 +258,9 @@ mod tests {
             receipts: Some(100),
             transactions: Some(500),
             block_meta: Some(500),
+            account_changesets: None,
+            storage_changesets: None,
+            hl_sidecars: None,
         };
 
         // Maximum value among the available segments
@@ -41,6 +41,15 @@ pub enum StaticFileSegment {
     /// Static File segment responsible for the `BlockBodyIndices`, `BlockOmmers`,
     /// `BlockWithdrawals` tables.
     BlockMeta,
+    #[strum(serialize = "accountchangesets")]
+    /// Static File segment responsible for the `AccountChangeSets` table, one row per block.
+    AccountChangeSets,
+    #[strum(serialize = "storagechangesets")]
+    /// Static File segment responsible for the `StorageChangeSets` table, one row per block.
+    StorageChangeSets,
+    #[strum(serialize = "hlsidecars")]
+    /// Static File segment responsible for the `HlSidecars` table, one row per block.
+    HlSidecars,
 }
 
 impl StaticFileSegment {
@@ -51,6 +60,9 @@ impl StaticFileSegment {
             Self::Transactions => "transactions",
             Self::Receipts => "receipts",
             Self::BlockMeta => "blockmeta",
+            Self::AccountChangeSets => "accountchangesets",
+            Self::StorageChangeSets => "storagechangesets",
+            Self::HlSidecars => "hlsidecars",
         }
     }
 
@@ -58,19 +70,35 @@ impl StaticFileSegment {
     pub fn iter() -> impl Iterator<Item = Self> {
         // The order of segments is significant and must be maintained to ensure correctness. For
         // example, Transactions require BlockBodyIndices from Blockmeta to be sound.
-        [Self::Headers, Self::BlockMeta, Self::Transactions, Self::Receipts].into_iter()
+        [
+            Self::Headers,
+            Self::BlockMeta,
+            Self::Transactions,
+            Self::Receipts,
+            Self::AccountChangeSets,
+            Self::StorageChangeSets,
+            Self::HlSidecars,
+        ]
+        .into_iter()
     }
 
     /// Returns the default configuration of the segment.
     pub const fn config(&self) -> SegmentConfig {
-        SegmentConfig { compression: Compression::Lz4 }
+        match self {
+            Self::HlSidecars => SegmentConfig { compression: Compression::ZstdWithDictionary },
+            _ => SegmentConfig { compression: Compression::Lz4 },
+        }
     }
 
     /// Returns the number of columns for the segment
     pub const fn columns(&self) -> usize {
         match self {
             Self::Headers | Self::BlockMeta => 3,
-            Self::Transactions | Self::Receipts => 1,
+            Self::Transactions |
+            Self::Receipts |
+            Self::AccountChangeSets |
+            Self::StorageChangeSets |
+            Self::HlSidecars => 1,
         }
     }
 
@@ -143,6 +171,17 @@ impl StaticFileSegment {
         matches!(self, Self::Receipts)
     }
 
+    /// Returns `true` if the segment is `StaticFileSegment::AccountChangeSets` or
+    /// `StaticFileSegment::StorageChangeSets`.
+    pub const fn is_changesets(&self) -> bool {
+        matches!(self, Self::AccountChangeSets | Self::StorageChangeSets)
+    }
+
+    /// Returns `true` if the segment is `StaticFileSegment::HlSidecars`.
+    pub const fn is_hl_sidecars(&self) -> bool {
+        matches!(self, Self::HlSidecars)
+    }
+
     /// Returns `true` if a segment row is linked to a transaction.
     pub const fn is_tx_based(&self) -> bool {
         matches!(self, Self::Receipts | Self::Transactions)
@@ -150,7 +189,14 @@ impl StaticFileSegment {
 
     /// Returns `true` if a segment row is linked to a block.
     pub const fn is_block_based(&self) -> bool {
-        matches!(self, Self::Headers | Self::BlockMeta)
+        matches!(
+            self,
+            Self::Headers |
+                Self::BlockMeta |
+                Self::AccountChangeSets |
+                Self::StorageChangeSets |
+                Self::HlSidecars
+        )
     }
 }
 
@@ -377,6 +423,12 @@ mod tests {
         let test_vectors = [
             (StaticFileSegment::Headers, 2..=30, "static_file_headers_2_30", None),
             (StaticFileSegment::Receipts, 30..=300, "static_file_receipts_30_300", None),
+            (
+                StaticFileSegment::AccountChangeSets,
+                0..=499_999,
+                "static_file_accountchangesets_0_499999",
+                None,
+            ),
             (
                 StaticFileSegment::Transactions,
                 1_123_233..=11_223_233,
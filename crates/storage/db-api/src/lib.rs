@@ -73,6 +73,10 @@ pub mod database_metrics;
 
 pub mod mock;
 
+/// Per unit of work read-only database activity.
+mod read_stats;
+pub use read_stats::DatabaseReadStats;
+
 /// Table traits
 pub mod table;
 
@@ -0,0 +1,128 @@
+//! Changeset related models and types.
+
+use crate::models::AccountBeforeTx;
+use alloy_primitives::Address;
+use reth_codecs::Compact;
+use reth_primitives::StorageEntry;
+use serde::Serialize;
+
+/// Storage slot value before a block, as it is saved in the storage changeset static files.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize)]
+pub struct StorageBeforeTx {
+    /// Address of the account the storage slot belongs to.
+    pub address: Address,
+    /// Storage slot and its value before the block.
+    pub entry: StorageEntry,
+}
+
+impl Compact for StorageBeforeTx {
+    fn to_compact<B>(&self, buf: &mut B) -> usize
+    where
+        B: bytes::BufMut + AsMut<[u8]>,
+    {
+        buf.put_slice(self.address.as_slice());
+        self.entry.to_compact(buf) + 20
+    }
+
+    fn from_compact(buf: &[u8], len: usize) -> (Self, &[u8]) {
+        let address = Address::from_slice(&buf[..20]);
+        let (entry, buf) = StorageEntry::from_compact(&buf[20..], len - 20);
+        (Self { address, entry }, buf)
+    }
+}
+
+/// The account changeset of a block, as it is saved in static files.
+///
+/// The changes are sorted by address, like in the `AccountChangeSets` table.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize)]
+pub struct StaticFileAccountChangeSet {
+    /// Accounts changed in the block, with their state before it.
+    pub changes: Vec<AccountBeforeTx>,
+}
+
+impl Compact for StaticFileAccountChangeSet {
+    fn to_compact<B>(&self, buf: &mut B) -> usize
+    where
+        B: bytes::BufMut + AsMut<[u8]>,
+    {
+        let mut buffer = bytes::BytesMut::new();
+        self.changes.to_compact(&mut buffer);
+        let total_length = buffer.len();
+        buf.put(buffer);
+        total_length
+    }
+
+    fn from_compact(buf: &[u8], _len: usize) -> (Self, &[u8]) {
+        let (changes, new_buf) = Vec::from_compact(buf, buf.len());
+        (Self { changes }, new_buf)
+    }
+}
+
+/// The storage changeset of a block, as it is saved in static files.
+///
+/// The changes are sorted by address and storage key, like in the `StorageChangeSets` table.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize)]
+pub struct StaticFileStorageChangeSet {
+    /// Storage slots changed in the block, with their value before it.
+    pub changes: Vec<StorageBeforeTx>,
+}
+
+impl Compact for StaticFileStorageChangeSet {
+    fn to_compact<B>(&self, buf: &mut B) -> usize
+    where
+        B: bytes::BufMut + AsMut<[u8]>,
+    {
+        let mut buffer = bytes::BytesMut::new();
+        self.changes.to_compact(&mut buffer);
+        let total_length = buffer.len();
+        buf.put(buffer);
+        total_length
+    }
+
+    fn from_compact(buf: &[u8], _len: usize) -> (Self, &[u8]) {
+        let (changes, new_buf) = Vec::from_compact(buf, buf.len());
+        (Self { changes }, new_buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::{Compress, Decompress};
+    use alloy_primitives::{B256, U256};
+    use reth_primitives_traits::Account;
+
+    #[test]
+    fn changesets_roundtrip() {
+        let accounts = StaticFileAccountChangeSet {
+            changes: vec![
+                AccountBeforeTx { address: Address::with_last_byte(1), info: None },
+                AccountBeforeTx {
+                    address: Address::with_last_byte(2),
+                    info: Some(Account { nonce: 1, ..Default::default() }),
+                },
+            ],
+        };
+        assert_eq!(
+            accounts.clone(),
+            StaticFileAccountChangeSet::decompress(&accounts.compress()).unwrap()
+        );
+
+        let storages = StaticFileStorageChangeSet {
+            changes: vec![
+                StorageBeforeTx {
+                    address: Address::with_last_byte(1),
+                    entry: StorageEntry::new(B256::with_last_byte(1), U256::ZERO),
+                },
+                StorageBeforeTx {
+                    address: Address::with_last_byte(1),
+                    entry: StorageEntry::new(B256::with_last_byte(2), U256::from(7)),
+                },
+            ],
+        };
+        assert_eq!(
+            storages.clone(),
+            StaticFileStorageChangeSet::decompress(&storages.compress()).unwrap()
+        );
+    }
+}
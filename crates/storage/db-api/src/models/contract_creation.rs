@@ -0,0 +1,22 @@
+//! Contract creation related models and types.
+
+use alloy_primitives::{Address, TxNumber};
+use reth_codecs::{add_arbitrary_tests, Compact};
+use serde::{Deserialize, Serialize};
+
+/// Creation of a contract, as stored in [`ContractCreations`](crate::tables::ContractCreations).
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Compact)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(compact)]
+pub struct ContractCreation {
+    /// Number of the transaction that created the contract.
+    pub tx_number: TxNumber,
+    /// Sender of the transaction that created the contract.
+    pub creator: Address,
+    /// Contract that deployed the contract with `CREATE` or `CREATE2` during the transaction.
+    ///
+    /// `None` if the contract was deployed by the transaction itself, or if the deploying
+    /// contract couldn't be told apart from other contracts deploying with `CREATE2` in the same
+    /// transaction.
+    pub factory: Option<Address>,
+}
@@ -0,0 +1,105 @@
+//! Hyperliquid sidecar related models and types.
+
+use crate::{
+    table::{Compress, Decompress},
+    DatabaseError,
+};
+use alloy_primitives::Bytes;
+use reth_codecs::Compact;
+use serde::Serialize;
+
+/// Data recorded by hl-node for a block that is not part of the block itself, but is needed to
+/// re-execute it.
+///
+/// Both fields are kept msgpack encoded, as they are found in the hl-node archive. An empty
+/// sidecar stands for a block whose data wasn't recorded.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize)]
+pub struct HlSidecar {
+    /// The original system transactions of the block, with their receipts.
+    pub system_txs: Bytes,
+    /// The read precompile calls of the block, with their results.
+    pub read_precompile_calls: Bytes,
+}
+
+impl HlSidecar {
+    /// Returns `true` if no data was recorded for the block.
+    pub fn is_empty(&self) -> bool {
+        self.system_txs.is_empty() && self.read_precompile_calls.is_empty()
+    }
+
+    /// Decodes the sidecar from the first `len` bytes of `buf`, returning `None` if they are not a
+    /// valid encoding.
+    fn decode(buf: &[u8], len: usize) -> Option<Self> {
+        let buf = buf.get(..len)?;
+        let (system_txs_len, rest) = buf.split_first_chunk::<4>()?;
+        let system_txs_len = u32::from_be_bytes(*system_txs_len) as usize;
+        if rest.len() < system_txs_len {
+            return None
+        }
+        let (system_txs, read_precompile_calls) = rest.split_at(system_txs_len);
+        Some(Self {
+            system_txs: Bytes::copy_from_slice(system_txs),
+            read_precompile_calls: Bytes::copy_from_slice(read_precompile_calls),
+        })
+    }
+}
+
+impl Compact for HlSidecar {
+    fn to_compact<B>(&self, buf: &mut B) -> usize
+    where
+        B: bytes::BufMut + AsMut<[u8]>,
+    {
+        buf.put_u32(self.system_txs.len() as u32);
+        buf.put_slice(&self.system_txs);
+        buf.put_slice(&self.read_precompile_calls);
+        4 + self.system_txs.len() + self.read_precompile_calls.len()
+    }
+
+    fn from_compact(buf: &[u8], len: usize) -> (Self, &[u8]) {
+        let sidecar = Self::decode(buf, len).expect("invalid sidecar encoding");
+        (sidecar, &buf[len..])
+    }
+}
+
+impl Compress for HlSidecar {
+    type Compressed = Vec<u8>;
+
+    fn compress_to_buf<B: bytes::BufMut + AsMut<[u8]>>(&self, buf: &mut B) {
+        let _ = Compact::to_compact(self, buf);
+    }
+}
+
+impl Decompress for HlSidecar {
+    fn decompress(value: &[u8]) -> Result<Self, DatabaseError> {
+        Self::decode(value, value.len()).ok_or(DatabaseError::Decode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hl_sidecar_roundtrip() {
+        let sidecar = HlSidecar {
+            system_txs: Bytes::from_static(&[0x91, 0x01]),
+            read_precompile_calls: Bytes::from_static(&[0x92, 0x02, 0x03]),
+        };
+        assert_eq!(sidecar.clone(), HlSidecar::decompress(&sidecar.compress()).unwrap());
+
+        let empty = HlSidecar::default();
+        assert!(empty.is_empty());
+        assert_eq!(empty.clone(), HlSidecar::decompress(&empty.compress()).unwrap());
+    }
+
+    #[test]
+    fn hl_sidecar_truncated() {
+        assert!(HlSidecar::decompress(&[]).is_err());
+        assert!(HlSidecar::decompress(&[0, 0, 0]).is_err());
+        assert!(HlSidecar::decompress(&[0, 0, 0, 2, 0x91]).is_err());
+        assert_eq!(
+            HlSidecar::decompress(&[0, 0, 0, 1, 0x91]).unwrap(),
+            HlSidecar { system_txs: Bytes::from_static(&[0x91]), ..Default::default() }
+        );
+    }
+}
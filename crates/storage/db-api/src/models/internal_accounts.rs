@@ -0,0 +1,16 @@
+//! Internal call related models and types.
+
+use alloy_primitives::Address;
+use reth_codecs::{add_arbitrary_tests, Compact};
+use serde::{Deserialize, Serialize};
+
+/// Accounts changed by the internal calls of a transaction, as stored in
+/// [`TransactionInternalAccounts`](crate::tables::TransactionInternalAccounts).
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize, Compact)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(compact)]
+pub struct InternalAccounts {
+    /// Accounts other than the sender and the recipient of the transaction, and the beneficiary
+    /// of its block.
+    pub addresses: Vec<Address>,
+}
@@ -0,0 +1,139 @@
+//! Log index related models and types.
+
+use crate::{
+    impl_fixed_arbitrary,
+    table::{Decode, Encode},
+    DatabaseError,
+};
+use alloy_primitives::{BlockNumber, B256};
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+
+/// Position of a log in the chain, as stored in the lists of
+/// [`LogAddressIndex`](crate::tables::LogAddressIndex) and
+/// [`LogTopicIndex`](crate::tables::LogTopicIndex).
+///
+/// Stored as a single `u64`, with the block number in the upper 40 bits and the index of the log
+/// among all logs of the block in the lower 24 bits, so that the stored positions are ordered the
+/// same way as the logs.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Ord, PartialOrd, Hash,
+)]
+pub struct LogPosition {
+    /// Number of the block containing the log.
+    pub block_number: BlockNumber,
+    /// Index of the log among all logs of the block, including the logs of system transactions.
+    pub index: u64,
+}
+
+impl LogPosition {
+    /// Number of the lower bits of a stored position holding the index of the log in its block.
+    const INDEX_BITS: u32 = 24;
+
+    /// Highest index of a log in its block that can be stored.
+    pub const MAX_INDEX: u64 = (1 << Self::INDEX_BITS) - 1;
+
+    /// Highest number of a block whose logs can be stored.
+    pub const MAX_BLOCK_NUMBER: BlockNumber = u64::MAX >> Self::INDEX_BITS;
+
+    /// Creates a new `LogPosition`.
+    pub const fn new(block_number: BlockNumber, index: u64) -> Self {
+        Self { block_number, index }
+    }
+
+    /// Returns the range of the stored positions of all logs in the given block range.
+    ///
+    /// Note: End is inclusive
+    pub fn range(blocks: RangeInclusive<BlockNumber>) -> RangeInclusive<u64> {
+        let start = (*blocks.start()).min(Self::MAX_BLOCK_NUMBER);
+        let end = (*blocks.end()).min(Self::MAX_BLOCK_NUMBER);
+        Self::new(start, 0).into()..=Self::new(end, Self::MAX_INDEX).into()
+    }
+}
+
+impl From<LogPosition> for u64 {
+    fn from(position: LogPosition) -> Self {
+        debug_assert!(position.block_number <= LogPosition::MAX_BLOCK_NUMBER);
+        debug_assert!(position.index <= LogPosition::MAX_INDEX);
+        (position.block_number << LogPosition::INDEX_BITS) | position.index
+    }
+}
+
+impl From<u64> for LogPosition {
+    fn from(value: u64) -> Self {
+        Self::new(value >> Self::INDEX_BITS, value & Self::MAX_INDEX)
+    }
+}
+
+/// Position of a topic in a log concatenated with the topic.
+///
+/// Since it's used as a key, it isn't compressed when encoding it.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Ord, PartialOrd, Hash,
+)]
+pub struct LogTopicKey {
+    /// Position of the topic in the topics of the log, from 0 to 3.
+    pub position: u8,
+    /// The topic.
+    pub topic: B256,
+}
+
+impl LogTopicKey {
+    /// Creates a new `LogTopicKey`.
+    pub const fn new(position: u8, topic: B256) -> Self {
+        Self { position, topic }
+    }
+}
+
+impl Encode for LogTopicKey {
+    type Encoded = [u8; 33];
+
+    fn encode(self) -> Self::Encoded {
+        let mut buf = [0u8; 33];
+        buf[0] = self.position;
+        buf[1..].copy_from_slice(self.topic.as_slice());
+        buf
+    }
+}
+
+impl Decode for LogTopicKey {
+    fn decode(value: &[u8]) -> Result<Self, DatabaseError> {
+        let [position, topic @ ..] = value else { return Err(DatabaseError::Decode) };
+        if topic.len() != 32 {
+            return Err(DatabaseError::Decode)
+        }
+        Ok(Self { position: *position, topic: B256::from_slice(topic) })
+    }
+}
+
+impl_fixed_arbitrary!((LogTopicKey, 33));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_position_roundtrip() {
+        let position = LogPosition::new(0x12_3456_789a, 0xbc_def0);
+        let stored = u64::from(position);
+        assert_eq!(stored, 0x1234_5678_9abc_def0);
+        assert_eq!(LogPosition::from(stored), position);
+
+        assert!(stored < u64::from(LogPosition::new(0x12_3456_789a, 0xbc_def1)));
+        let last = LogPosition::new(1, LogPosition::MAX_INDEX);
+        assert!(u64::from(last) < u64::from(LogPosition::new(2, 0)));
+        assert_eq!(
+            LogPosition::range(1..=BlockNumber::MAX),
+            u64::from(LogPosition::new(1, 0))..=u64::MAX
+        );
+    }
+
+    #[test]
+    fn log_topic_key_roundtrip() {
+        let key = LogTopicKey::new(2, B256::repeat_byte(0xab));
+        let encoded = key.encode();
+        assert_eq!(encoded[0], 2);
+        assert_eq!(LogTopicKey::decode(&encoded).unwrap(), key);
+        assert!(LogTopicKey::decode(&encoded[..32]).is_err());
+    }
+}
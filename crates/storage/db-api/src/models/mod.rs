@@ -17,18 +17,30 @@ use serde::{Deserialize, Serialize};
 
 pub mod accounts;
 pub mod blocks;
+pub mod changesets;
+pub mod contract_creation;
+pub mod hl_sidecar;
 pub mod integer_list;
+pub mod internal_accounts;
+pub mod log_index;
 pub mod sharded_key;
 pub mod storage_sharded_key;
+pub mod token_transfer;
 
 pub use accounts::*;
 pub use blocks::*;
+pub use changesets::*;
+pub use contract_creation::*;
+pub use hl_sidecar::*;
 pub use integer_list::IntegerList;
+pub use internal_accounts::*;
+pub use log_index::*;
 pub use reth_db_models::{
     AccountBeforeTx, ClientVersion, StaticFileBlockWithdrawals, StoredBlockBodyIndices,
     StoredBlockWithdrawals,
 };
 pub use sharded_key::ShardedKey;
+pub use token_transfer::*;
 
 /// Macro that implements [`Encode`] and [`Decode`] for uint types.
 macro_rules! impl_uints {
@@ -226,6 +238,13 @@ impl_compression_for_compact!(
     StoredBlockOmmers<H>,
     StoredBlockWithdrawals,
     StaticFileBlockWithdrawals,
+    StaticFileAccountChangeSet,
+    StaticFileStorageChangeSet,
+    ContractCreation,
+    InternalAccounts,
+    TokenTransfer,
+    TokenBalanceDelta,
+    NativeTransfer,
     Bytecode,
     AccountBeforeTx,
     TransactionSigned,
@@ -336,10 +355,13 @@ mod tests {
         assert_eq!(CompactClientVersion::bitflag_encoded_bytes(), 0);
         assert_eq!(CompactU256::bitflag_encoded_bytes(), 1);
         assert_eq!(CompactU64::bitflag_encoded_bytes(), 1);
+        assert_eq!(ContractCreation::bitflag_encoded_bytes(), 1);
         assert_eq!(EntitiesCheckpoint::bitflag_encoded_bytes(), 1);
         assert_eq!(ExecutionCheckpoint::bitflag_encoded_bytes(), 0);
         assert_eq!(HeadersCheckpoint::bitflag_encoded_bytes(), 0);
         assert_eq!(IndexHistoryCheckpoint::bitflag_encoded_bytes(), 0);
+        assert_eq!(NativeTransfer::bitflag_encoded_bytes(), 2);
+        assert_eq!(NativeTransferKind::bitflag_encoded_bytes(), 1);
         assert_eq!(PruneCheckpoint::bitflag_encoded_bytes(), 1);
         assert_eq!(PruneMode::bitflag_encoded_bytes(), 1);
         assert_eq!(PruneSegment::bitflag_encoded_bytes(), 1);
@@ -349,6 +371,8 @@ mod tests {
         assert_eq!(StoredBlockBodyIndices::bitflag_encoded_bytes(), 1);
         assert_eq!(StoredBlockWithdrawals::bitflag_encoded_bytes(), 0);
         assert_eq!(StorageHashingCheckpoint::bitflag_encoded_bytes(), 1);
+        assert_eq!(TokenBalanceDelta::bitflag_encoded_bytes(), 2);
+        assert_eq!(TokenTransfer::bitflag_encoded_bytes(), 2);
 
         validate_bitflag_backwards_compat!(Account, UnusedBits::NotZero);
         validate_bitflag_backwards_compat!(AccountHashingCheckpoint, UnusedBits::NotZero);
@@ -356,10 +380,13 @@ mod tests {
         validate_bitflag_backwards_compat!(CompactClientVersion, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(CompactU256, UnusedBits::NotZero);
         validate_bitflag_backwards_compat!(CompactU64, UnusedBits::NotZero);
+        validate_bitflag_backwards_compat!(ContractCreation, UnusedBits::NotZero);
         validate_bitflag_backwards_compat!(EntitiesCheckpoint, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(ExecutionCheckpoint, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(HeadersCheckpoint, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(IndexHistoryCheckpoint, UnusedBits::Zero);
+        validate_bitflag_backwards_compat!(NativeTransfer, UnusedBits::NotZero);
+        validate_bitflag_backwards_compat!(NativeTransferKind, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(PruneCheckpoint, UnusedBits::NotZero);
         validate_bitflag_backwards_compat!(PruneMode, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(PruneSegment, UnusedBits::Zero);
@@ -369,5 +396,7 @@ mod tests {
         validate_bitflag_backwards_compat!(StoredBlockBodyIndices, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(StoredBlockWithdrawals, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(StorageHashingCheckpoint, UnusedBits::NotZero);
+        validate_bitflag_backwards_compat!(TokenBalanceDelta, UnusedBits::NotZero);
+        validate_bitflag_backwards_compat!(TokenTransfer, UnusedBits::NotZero);
     }
 }
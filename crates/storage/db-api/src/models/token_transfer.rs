@@ -0,0 +1,212 @@
+//! Token transfer related models and types.
+
+use crate::{
+    impl_fixed_arbitrary,
+    table::{Decode, Encode},
+    DatabaseError,
+};
+use alloy_primitives::{Address, BlockNumber, TxNumber, U256};
+use reth_codecs::{add_arbitrary_tests, Compact};
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+
+/// [`Address`] concatenated with the [`BlockNumber`] and the index of a token transfer within the
+/// block.
+///
+/// Since it's used as a key, it isn't compressed when encoding it.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Ord, PartialOrd, Hash,
+)]
+pub struct TokenTransferKey {
+    /// The token, the holder or the native transfer user the transfer is indexed by.
+    pub address: Address,
+    /// Number of the block containing the transfer.
+    pub block_number: BlockNumber,
+    /// Index of the transfer among the token transfers of the block.
+    pub index: u32,
+}
+
+impl TokenTransferKey {
+    /// Creates a new `TokenTransferKey`.
+    pub const fn new(address: Address, block_number: BlockNumber, index: u32) -> Self {
+        Self { address, block_number, index }
+    }
+
+    /// Returns the range of keys of the address from the given transfer position to the end of
+    /// `end_block`.
+    ///
+    /// Note: End is inclusive
+    pub fn range(
+        address: Address,
+        (block_number, index): (BlockNumber, u32),
+        end_block: BlockNumber,
+    ) -> RangeInclusive<Self> {
+        Self::new(address, block_number, index)..=Self::new(address, end_block, u32::MAX)
+    }
+}
+
+impl Encode for TokenTransferKey {
+    type Encoded = [u8; 32];
+
+    fn encode(self) -> Self::Encoded {
+        let mut buf = [0u8; 32];
+        buf[..20].copy_from_slice(self.address.as_slice());
+        buf[20..28].copy_from_slice(&self.block_number.to_be_bytes());
+        buf[28..].copy_from_slice(&self.index.to_be_bytes());
+        buf
+    }
+}
+
+impl Decode for TokenTransferKey {
+    fn decode(value: &[u8]) -> Result<Self, DatabaseError> {
+        if value.len() != 32 {
+            return Err(DatabaseError::Decode)
+        }
+        let address = Address::from_slice(&value[..20]);
+        let block_number =
+            u64::from_be_bytes(value[20..28].try_into().map_err(|_| DatabaseError::Decode)?);
+        let index = u32::from_be_bytes(value[28..].try_into().map_err(|_| DatabaseError::Decode)?);
+        Ok(Self { address, block_number, index })
+    }
+}
+
+/// Holder [`Address`] concatenated with the token [`Address`] and a [`BlockNumber`].
+///
+/// Since it's used as a key, it isn't compressed when encoding it.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Ord, PartialOrd, Hash,
+)]
+pub struct TokenBalanceKey {
+    /// The holder of the tokens.
+    pub holder: Address,
+    /// The token contract.
+    pub token: Address,
+    /// Number of the block the balance changed in.
+    pub block_number: BlockNumber,
+}
+
+impl TokenBalanceKey {
+    /// Creates a new `TokenBalanceKey`.
+    pub const fn new(holder: Address, token: Address, block_number: BlockNumber) -> Self {
+        Self { holder, token, block_number }
+    }
+
+    /// Returns the range of keys of the holder and token in the given block range.
+    pub fn range(
+        holder: Address,
+        token: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> RangeInclusive<Self> {
+        Self::new(holder, token, *range.start())..=Self::new(holder, token, *range.end())
+    }
+}
+
+impl Encode for TokenBalanceKey {
+    type Encoded = [u8; 48];
+
+    fn encode(self) -> Self::Encoded {
+        let mut buf = [0u8; 48];
+        buf[..20].copy_from_slice(self.holder.as_slice());
+        buf[20..40].copy_from_slice(self.token.as_slice());
+        buf[40..].copy_from_slice(&self.block_number.to_be_bytes());
+        buf
+    }
+}
+
+impl Decode for TokenBalanceKey {
+    fn decode(value: &[u8]) -> Result<Self, DatabaseError> {
+        if value.len() != 48 {
+            return Err(DatabaseError::Decode)
+        }
+        let holder = Address::from_slice(&value[..20]);
+        let token = Address::from_slice(&value[20..40]);
+        let block_number =
+            u64::from_be_bytes(value[40..].try_into().map_err(|_| DatabaseError::Decode)?);
+        Ok(Self { holder, token, block_number })
+    }
+}
+
+impl_fixed_arbitrary!((TokenTransferKey, 32), (TokenBalanceKey, 48));
+
+/// A token transfer, as stored in [`TokenTransfers`](crate::tables::TokenTransfers) and
+/// [`HolderTokenTransfers`](crate::tables::HolderTokenTransfers).
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Compact)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(compact)]
+pub struct TokenTransfer {
+    /// Number of the transaction that transferred the tokens.
+    pub tx_number: TxNumber,
+    /// The token contract.
+    pub token: Address,
+    /// The sender of the tokens.
+    pub from: Address,
+    /// The recipient of the tokens.
+    pub to: Address,
+    /// The amount of tokens transferred.
+    pub value: U256,
+    /// Index of the HyperCore spot token, if the transfer bridged the tokens from or to
+    /// HyperCore.
+    pub spot_token: Option<u64>,
+}
+
+/// Tokens received and sent by a holder in a block, as stored in
+/// [`TokenBalanceDeltas`](crate::tables::TokenBalanceDeltas).
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Compact)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(compact)]
+pub struct TokenBalanceDelta {
+    /// Total amount of tokens received.
+    pub received: U256,
+    /// Total amount of tokens sent.
+    pub sent: U256,
+}
+
+/// A transfer of the native token between HyperCore and HyperEVM, as stored in
+/// [`NativeTransfers`](crate::tables::NativeTransfers).
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Compact)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(compact)]
+pub struct NativeTransfer {
+    /// Number of the transaction that transferred the tokens.
+    pub tx_number: TxNumber,
+    /// The amount of tokens transferred.
+    pub value: U256,
+    /// The direction of the transfer.
+    pub kind: NativeTransferKind,
+}
+
+/// Direction of a [`NativeTransfer`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Compact)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(compact)]
+#[serde(rename_all = "camelCase")]
+pub enum NativeTransferKind {
+    /// Tokens sent from HyperCore to the user by a system transaction of the native token system
+    /// address.
+    #[default]
+    Deposit,
+    /// Tokens sent by the user to the native token system address, and credited on HyperCore.
+    Withdrawal,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_token_transfer_key_rand() {
+        let mut bytes = [0u8; 32];
+        thread_rng().fill(bytes.as_mut_slice());
+        let key = TokenTransferKey::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(bytes, Encode::encode(key));
+    }
+
+    #[test]
+    fn test_token_balance_key_rand() {
+        let mut bytes = [0u8; 48];
+        thread_rng().fill(bytes.as_mut_slice());
+        let key = TokenBalanceKey::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(bytes, Encode::encode(key));
+    }
+}
@@ -0,0 +1,118 @@
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+thread_local! {
+    /// The stats that the reads of the current thread are recorded to.
+    static CURRENT: RefCell<Option<Arc<DatabaseReadStats>>> = const { RefCell::new(None) };
+}
+
+/// Read-only database activity of a unit of work, e.g. an RPC call.
+///
+/// The reads are recorded to the stats that are current on the reading thread, see
+/// [`DatabaseReadStats::scope`]. Work that is moved to other threads has to carry the stats along,
+/// by capturing [`DatabaseReadStats::current`] and entering the scope again on the other thread.
+#[derive(Debug, Default)]
+pub struct DatabaseReadStats {
+    transactions: AtomicU64,
+    cursors: AtomicU64,
+    gets: AtomicU64,
+}
+
+impl DatabaseReadStats {
+    /// Returns the stats the reads of the current thread are recorded to, if any.
+    pub fn current() -> Option<Arc<Self>> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Runs `f` with `stats` as the current stats of the thread, restoring the previous ones
+    /// afterwards.
+    pub fn scope<R>(stats: Option<Arc<Self>>, f: impl FnOnce() -> R) -> R {
+        /// Restores the previous stats, also if `f` panics.
+        struct Restore(Option<Arc<DatabaseReadStats>>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                CURRENT.with(|current| *current.borrow_mut() = previous);
+            }
+        }
+
+        let _restore = Restore(CURRENT.with(|current| current.replace(stats)));
+        f()
+    }
+
+    /// Number of read-only transactions opened.
+    pub fn transactions(&self) -> u64 {
+        self.transactions.load(Ordering::Relaxed)
+    }
+
+    /// Number of cursors created in read-only transactions.
+    pub fn cursors(&self) -> u64 {
+        self.cursors.load(Ordering::Relaxed)
+    }
+
+    /// Number of point lookups performed in read-only transactions.
+    pub fn gets(&self) -> u64 {
+        self.gets.load(Ordering::Relaxed)
+    }
+
+    /// Records an opened read-only transaction to the current stats.
+    pub fn record_transaction() {
+        Self::record(|stats| &stats.transactions)
+    }
+
+    /// Records a cursor created in a read-only transaction to the current stats.
+    pub fn record_cursor() {
+        Self::record(|stats| &stats.cursors)
+    }
+
+    /// Records a point lookup in a read-only transaction to the current stats.
+    pub fn record_get() {
+        Self::record(|stats| &stats.gets)
+    }
+
+    fn record(counter: impl FnOnce(&Self) -> &AtomicU64) {
+        CURRENT.with(|current| {
+            if let Some(stats) = current.borrow().as_deref() {
+                counter(stats).fetch_add(1, Ordering::Relaxed);
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_to_current_scope() {
+        DatabaseReadStats::record_get();
+
+        let outer = Arc::new(DatabaseReadStats::default());
+        let inner = Arc::new(DatabaseReadStats::default());
+        DatabaseReadStats::scope(Some(outer.clone()), || {
+            DatabaseReadStats::record_transaction();
+            DatabaseReadStats::scope(Some(inner.clone()), || {
+                DatabaseReadStats::record_cursor();
+                DatabaseReadStats::record_get();
+            });
+            DatabaseReadStats::record_get();
+
+            let stats = DatabaseReadStats::current();
+            std::thread::spawn(move || {
+                DatabaseReadStats::scope(stats, DatabaseReadStats::record_get);
+            })
+            .join()
+            .unwrap();
+        });
+        assert!(DatabaseReadStats::current().is_none());
+
+        assert_eq!((outer.transactions(), outer.cursors(), outer.gets()), (1, 0, 2));
+        assert_eq!((inner.transactions(), inner.cursors(), inner.gets()), (0, 1, 1));
+    }
+}
@@ -21,8 +21,10 @@ use crate::{
         accounts::BlockNumberAddress,
         blocks::{HeaderHash, StoredBlockOmmers},
         storage_sharded_key::StorageShardedKey,
-        AccountBeforeTx, ClientVersion, CompactU256, IntegerList, ShardedKey,
-        StoredBlockBodyIndices, StoredBlockWithdrawals,
+        AccountBeforeTx, ClientVersion, CompactU256, ContractCreation, HlSidecar, IntegerList,
+        InternalAccounts, LogTopicKey, NativeTransfer, ShardedKey, StoredBlockBodyIndices,
+        StoredBlockWithdrawals, TokenBalanceDelta, TokenBalanceKey, TokenTransfer,
+        TokenTransferKey,
     },
     table::{Decode, DupSort, Encode, Table, TableInfo},
 };
@@ -344,6 +346,13 @@ tables! {
         type Value = StoredBlockWithdrawals;
     }
 
+    /// Stores the Hyperliquid sidecar of each ingested block: its original system transactions
+    /// and read precompile calls, as recorded by hl-node.
+    table HlSidecars {
+        type Key = BlockNumber;
+        type Value = HlSidecar;
+    }
+
     /// Canonical only Stores the transaction body for canonical transactions.
     table Transactions<T = TransactionSigned> {
         type Key = TxNumber;
@@ -438,6 +447,101 @@ tables! {
         type Value = BlockNumberList;
     }
 
+    /// Stores pointers to the transactions that touched each address, either as the sender, the
+    /// recipient, the created contract, a log emitter or an account changed by an internal call.
+    ///
+    /// Shards are keyed by the highest [`TxNumber`] they contain, and the last shard of an address
+    /// uses `u64::MAX`, the same way as [`AccountsHistory`] does for block numbers:
+    /// * `Address | 100`
+    /// * `Address | u64::MAX`
+    ///
+    /// Used by `ots_searchTransactionsBefore` and `ots_searchTransactionsAfter`.
+    table AddressTransactions {
+        type Key = ShardedKey<Address>;
+        type Value = BlockNumberList;
+    }
+
+    /// Stores the accounts changed by the internal calls of each transaction, written when the
+    /// transaction is executed.
+    ///
+    /// Only transactions changing accounts other than their sender, their recipient and the block
+    /// beneficiary have an entry. Read to index and unwind [`AddressTransactions`].
+    table TransactionInternalAccounts {
+        type Key = TxNumber;
+        type Value = InternalAccounts;
+    }
+
+    /// Stores the [`LogPosition`](crate::models::LogPosition)s of the logs emitted by each address.
+    ///
+    /// Sharded the same way as [`AccountsHistory`], by the highest stored position of the shard.
+    /// Used by `eth_getLogs` instead of scanning header blooms when the requested range is covered
+    /// by the index.
+    table LogAddressIndex {
+        type Key = ShardedKey<Address>;
+        type Value = BlockNumberList;
+    }
+
+    /// Stores the [`LogPosition`](crate::models::LogPosition)s of the logs with each topic, by the
+    /// position of the topic in the log.
+    ///
+    /// Sharded the same way as [`LogAddressIndex`]. Used by `eth_getLogs` instead of scanning
+    /// header blooms when the requested range is covered by the index.
+    table LogTopicIndex {
+        type Key = ShardedKey<LogTopicKey>;
+        type Value = BlockNumberList;
+    }
+
+    /// Stores the contracts created in each block, including the ones deployed with `CREATE` or
+    /// `CREATE2` by other contracts.
+    ///
+    /// Used by `ots_getContractsDeployed` to list the contracts deployed in a block range.
+    table ContractCreations {
+        type Key = BlockNumberAddress;
+        type Value = ContractCreation;
+    }
+
+    /// Stores the block in which each contract was created, pointing to its entry in
+    /// [`ContractCreations`].
+    ///
+    /// Used by `ots_getContractCreator`.
+    table ContractCreators {
+        type Key = Address;
+        type Value = BlockNumber;
+    }
+
+    /// Stores the ERC20 transfers of each token contract, in the order they happened.
+    ///
+    /// Includes the transfers of HyperCore spot tokens bridged by system transactions.
+    table TokenTransfers {
+        type Key = TokenTransferKey;
+        type Value = TokenTransfer;
+    }
+
+    /// Stores the ERC20 transfers sent or received by each holder, in the order they happened.
+    ///
+    /// Mints and burns are not indexed for the zero address.
+    table HolderTokenTransfers {
+        type Key = TokenTransferKey;
+        type Value = TokenTransfer;
+    }
+
+    /// Stores the ERC20 tokens received and sent by each holder in the blocks its balance of a
+    /// token was changed by a transfer.
+    table TokenBalanceDeltas {
+        type Key = TokenBalanceKey;
+        type Value = TokenBalanceDelta;
+    }
+
+    /// Stores the native token transfers between HyperCore and HyperEVM of each user, in the
+    /// order they happened.
+    ///
+    /// Deposits are system transactions of the native token system address, withdrawals are
+    /// transactions sending value to it.
+    table NativeTransfers {
+        type Key = TokenTransferKey;
+        type Value = NativeTransfer;
+    }
+
     /// Stores the state of an account before a certain transaction changed it.
     /// Change on state can be: account is created, selfdestructed, touched while empty
     /// or changed balance,nonce.
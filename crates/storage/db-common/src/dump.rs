@@ -0,0 +1,346 @@
+//! Reth state dump utility functions.
+//!
+//! Writes the state at a block in the format read by [`init_from_state_dump`].
+//!
+//! [`init_from_state_dump`]: crate::init::init_from_state_dump
+
+use crate::init::{GenesisAccountWithAddress, StateRoot};
+use alloy_consensus::BlockHeader;
+use alloy_genesis::GenesisAccount;
+use alloy_primitives::{Address, BlockNumber, B256};
+use reth_db_api::{
+    cursor::{DbCursorRO, DbDupCursorRO},
+    models::storage_sharded_key::StorageShardedKey,
+    table::Table,
+    tables,
+    transaction::DbTx,
+};
+use reth_primitives::{Account, StaticFileSegment};
+use reth_provider::{
+    AccountReader, BlockHashReader, BlockNumReader, DBProvider, HeaderProvider,
+    HistoricalStateProviderRef, ProviderError, ProviderResult, PruneCheckpointReader,
+    StateCommitmentProvider, StateProvider, StaticFileProviderFactory,
+};
+use reth_prune_types::PruneSegment;
+use std::{collections::BTreeMap, io::Write};
+use tracing::info;
+
+/// Number of accounts after which the dump progress is logged.
+const LOG_INTERVAL_ACCOUNTS: usize = 100_000;
+
+/// Summary of a state dump written by [`write_state_dump`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateDumpSummary {
+    /// State root of the dumped block.
+    pub state_root: B256,
+    /// Number of dumped accounts.
+    pub accounts: usize,
+    /// Number of dumped non-zero storage slots.
+    pub storage_slots: usize,
+}
+
+/// Writes the state at `block` as JSONL to `writer`, in the format read by
+/// [`init_from_state_dump`](crate::init::init_from_state_dump).
+///
+/// The first line holds the state root of the block, followed by one line per account. The
+/// accounts and storage slots are walked in the plain state tables and the history indices at the
+/// same time, and the ones changed after `block` are read through a [`HistoricalStateProviderRef`],
+/// so the history and changesets of all blocks after `block` must be available, either in the
+/// database or in static files.
+pub fn write_state_dump<Provider>(
+    provider: &Provider,
+    block: BlockNumber,
+    mut writer: impl Write,
+) -> eyre::Result<StateDumpSummary>
+where
+    Provider: DBProvider
+        + BlockNumReader
+        + BlockHashReader
+        + HeaderProvider
+        + PruneCheckpointReader
+        + StateCommitmentProvider
+        + StaticFileProviderFactory,
+{
+    let state_root = provider
+        .header_by_number(block)?
+        .ok_or_else(|| ProviderError::HeaderNotFound(block.into()))?
+        .state_root();
+
+    // The historical state provider reads the state at the start of a block
+    let mut state = HistoricalStateProviderRef::new(provider, block + 1);
+    if let Some(pruned) = pruned_block(provider, PruneSegment::AccountHistory, block)? {
+        state = state.with_lowest_available_account_history_block_number(pruned + 1);
+    }
+    if let Some(pruned) = pruned_block(provider, PruneSegment::StorageHistory, block)? {
+        state = state.with_lowest_available_storage_history_block_number(pruned + 1);
+    }
+    ensure_changesets_available(provider, block)?;
+
+    serde_json::to_writer(&mut writer, &StateRoot { root: state_root })?;
+    writeln!(writer)?;
+
+    let tx = provider.tx_ref();
+    let mut summary = StateDumpSummary { state_root, accounts: 0, storage_slots: 0 };
+    let mut plain_storage = tx.cursor_dup_read::<tables::PlainStorageState>()?;
+    let mut storage_history = tx.cursor_read::<tables::StoragesHistory>()?;
+    let mut bytecodes = tx.cursor_read::<tables::Bytecodes>()?;
+    let mut write_account = |address: Address, account: Account| -> eyre::Result<()> {
+        let mut storage = BTreeMap::new();
+        let mut plain = plain_storage.seek_exact(address)?.map(|(_, entry)| entry);
+        let mut changed = storage_history
+            .seek(StorageShardedKey::new(address, B256::ZERO, 0))?
+            .filter(|(key, _)| key.address == address)
+            .map(|(key, _)| key.sharded_key.key);
+        while let Some(key) = plain.map(|entry| entry.key).into_iter().chain(changed).min() {
+            let mut value = None;
+            if let Some(entry) = plain.filter(|entry| entry.key == key) {
+                value = Some(entry.value);
+                plain = plain_storage.next_dup_val()?;
+            }
+            if changed == Some(key) {
+                value = state.storage(address, key)?;
+                changed = next_history_key(&mut storage_history, |next| {
+                    next.address == address && next.sharded_key.key == key
+                })?
+                .filter(|next| next.address == address)
+                .map(|next| next.sharded_key.key);
+            }
+            if let Some(value) = value.filter(|value| !value.is_zero()) {
+                storage.insert(key, B256::from(value));
+            }
+        }
+
+        let code = match account.bytecode_hash {
+            Some(hash) => Some(
+                bytecodes
+                    .seek_exact(hash)?
+                    .ok_or_else(|| eyre::eyre!("missing bytecode {hash} of account {address}"))?
+                    .1
+                    .original_bytes(),
+            ),
+            None => None,
+        };
+
+        summary.accounts += 1;
+        summary.storage_slots += storage.len();
+        let account = GenesisAccountWithAddress {
+            genesis_account: GenesisAccount {
+                nonce: Some(account.nonce),
+                balance: account.balance,
+                code,
+                storage: (!storage.is_empty()).then_some(storage),
+                private_key: None,
+            },
+            address,
+        };
+        serde_json::to_writer(&mut writer, &account)?;
+        writeln!(writer)?;
+
+        if summary.accounts % LOG_INTERVAL_ACCOUNTS == 0 {
+            info!(target: "reth::cli", accounts = summary.accounts, "Dumped accounts");
+        }
+        Ok(())
+    };
+
+    // Accounts destroyed after the block are only in the history index, and accounts created
+    // after it only in the plain state, so both are walked in address order
+    let mut plain_accounts = tx.cursor_read::<tables::PlainAccountState>()?;
+    let mut account_history = tx.cursor_read::<tables::AccountsHistory>()?;
+    let mut plain = plain_accounts.first()?;
+    let mut changed = account_history.first()?.map(|(key, _)| key.key);
+    while let Some(address) = plain.map(|(address, _)| address).into_iter().chain(changed).min() {
+        let mut account = None;
+        if let Some((_, plain_account)) = plain.filter(|(plain, _)| *plain == address) {
+            account = Some(plain_account);
+            plain = plain_accounts.next()?;
+        }
+        if changed == Some(address) {
+            account = state.basic_account(&address)?;
+            changed = next_history_key(&mut account_history, |next| next.key == address)?
+                .map(|next| next.key);
+        }
+        if let Some(account) = account {
+            write_account(address, account)?;
+        }
+    }
+
+    writer.flush()?;
+    Ok(summary)
+}
+
+/// Returns the highest pruned block of the prune segment, failing if it's after `block`.
+fn pruned_block<Provider: PruneCheckpointReader>(
+    provider: &Provider,
+    segment: PruneSegment,
+    block: BlockNumber,
+) -> eyre::Result<Option<BlockNumber>> {
+    let pruned = provider.get_prune_checkpoint(segment)?.and_then(|c| c.block_number);
+    if let Some(pruned) = pruned.filter(|pruned| *pruned > block) {
+        eyre::bail!("{segment} is pruned up to block {pruned}, can't dump block {block}")
+    }
+    Ok(pruned)
+}
+
+/// Checks that the changesets of the blocks after `block` that were deleted from the database are
+/// in static files.
+fn ensure_changesets_available<Provider>(
+    provider: &Provider,
+    block: BlockNumber,
+) -> eyre::Result<()>
+where
+    Provider: PruneCheckpointReader + StaticFileProviderFactory,
+{
+    for (segment, prune_segment) in [
+        (StaticFileSegment::AccountChangeSets, PruneSegment::AccountChangeSets),
+        (StaticFileSegment::StorageChangeSets, PruneSegment::StorageChangeSets),
+    ] {
+        let Some(pruned) = provider
+            .get_prune_checkpoint(prune_segment)?
+            .and_then(|checkpoint| checkpoint.block_number)
+            .filter(|pruned| *pruned > block)
+        else {
+            continue
+        };
+        let highest = provider.static_file_provider().get_highest_static_file_block(segment);
+        if highest.is_none_or(|highest| highest < pruned) {
+            eyre::bail!(
+                "{prune_segment} is pruned up to block {pruned}, but the {segment} static files \
+                 end at block {highest:?}, can't dump block {block}"
+            )
+        }
+    }
+    Ok(())
+}
+
+/// Moves the cursor of a history index past the remaining shards of the current key, returning
+/// the key of the next entry.
+fn next_history_key<T: Table>(
+    cursor: &mut impl DbCursorRO<T>,
+    is_current: impl Fn(&T::Key) -> bool,
+) -> ProviderResult<Option<T::Key>> {
+    while let Some((key, _)) = cursor.next()? {
+        if !is_current(&key) {
+            return Ok(Some(key))
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::init::init_genesis;
+    use alloy_genesis::Genesis;
+    use alloy_primitives::U256;
+    use reth_chainspec::{Chain, ChainSpec};
+    use reth_db_api::{
+        models::{AccountBeforeTx, BlockNumberAddress, ShardedKey},
+        transaction::DbTxMut,
+        BlockNumberList,
+    };
+    use reth_primitives::StorageEntry;
+    use reth_provider::{
+        test_utils::create_test_provider_factory_with_chain_spec, DatabaseProviderFactory,
+    };
+    use std::{io::BufRead, sync::Arc};
+
+    fn parse_dump(dump: &[u8]) -> (B256, BTreeMap<Address, GenesisAccount>) {
+        let mut lines = dump.lines();
+        let root = serde_json::from_str::<StateRoot>(&lines.next().unwrap().unwrap()).unwrap();
+        let accounts = lines
+            .map(|line| {
+                let account =
+                    serde_json::from_str::<GenesisAccountWithAddress>(&line.unwrap()).unwrap();
+                (account.address, account.genesis_account)
+            })
+            .collect();
+        (root.root, accounts)
+    }
+
+    #[test]
+    fn dump_reverts_later_changes() {
+        let changed = Address::with_last_byte(1);
+        let destroyed = Address::with_last_byte(2);
+        let created = Address::with_last_byte(3);
+        let slot = B256::with_last_byte(1);
+        let alloc = BTreeMap::from([
+            (
+                changed,
+                GenesisAccount { balance: U256::from(1), nonce: Some(0), ..Default::default() },
+            ),
+            (
+                destroyed,
+                GenesisAccount {
+                    balance: U256::from(2),
+                    nonce: Some(1),
+                    storage: Some(BTreeMap::from([(slot, B256::with_last_byte(7))])),
+                    ..Default::default()
+                },
+            ),
+        ]);
+        let chain_spec = Arc::new(ChainSpec {
+            chain: Chain::from_id(1),
+            genesis: Genesis { alloc: alloc.clone(), ..Default::default() },
+            hardforks: Default::default(),
+            paris_block_and_final_difficulty: None,
+            deposit_contract: None,
+            ..Default::default()
+        });
+        let factory = create_test_provider_factory_with_chain_spec(chain_spec);
+        init_genesis(&factory).unwrap();
+
+        // Apply the changes of a block 1 to the plain state, along with its changesets and history
+        let provider_rw = factory.database_provider_rw().unwrap();
+        let tx = provider_rw.tx_ref();
+        let before = |address: Address| {
+            let account = &alloc[&address];
+            AccountBeforeTx {
+                address,
+                info: Some(Account {
+                    nonce: account.nonce.unwrap_or_default(),
+                    balance: account.balance,
+                    bytecode_hash: None,
+                }),
+            }
+        };
+        tx.put::<tables::AccountChangeSets>(1, before(changed)).unwrap();
+        tx.put::<tables::AccountChangeSets>(1, before(destroyed)).unwrap();
+        tx.put::<tables::AccountChangeSets>(1, AccountBeforeTx { address: created, info: None })
+            .unwrap();
+        tx.put::<tables::StorageChangeSets>(
+            BlockNumberAddress((1, destroyed)),
+            StorageEntry { key: slot, value: U256::from(7) },
+        )
+        .unwrap();
+        for address in [changed, destroyed, created] {
+            tx.put::<tables::AccountsHistory>(
+                ShardedKey::new(address, u64::MAX),
+                BlockNumberList::new([1]).unwrap(),
+            )
+            .unwrap();
+        }
+        tx.put::<tables::StoragesHistory>(
+            StorageShardedKey::new(destroyed, slot, u64::MAX),
+            BlockNumberList::new([1]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::PlainAccountState>(
+            changed,
+            Account { nonce: 1, balance: U256::from(5), bytecode_hash: None },
+        )
+        .unwrap();
+        tx.put::<tables::PlainAccountState>(created, Account::default()).unwrap();
+        tx.delete::<tables::PlainAccountState>(destroyed, None).unwrap();
+        tx.delete::<tables::PlainStorageState>(destroyed, None).unwrap();
+        provider_rw.commit().unwrap();
+
+        let mut dump = Vec::new();
+        let summary = write_state_dump(&factory.provider().unwrap(), 0, &mut dump).unwrap();
+        assert_eq!(summary.accounts, 2);
+        assert_eq!(summary.storage_slots, 1);
+
+        let (root, accounts) = parse_dump(&dump);
+        assert_eq!(root, summary.state_root);
+        assert_eq!(accounts, alloc);
+    }
+}
@@ -410,8 +410,9 @@ where
     // write state to db
     dump_state(collector, provider_rw, block)?;
 
-    // insert sync stages for stages that require state
-    for stage in StageId::STATE_REQUIRED {
+    // insert sync stages for stages that require state. Optional stages can't index the blocks
+    // before the dump either, so they start after it once enabled.
+    for stage in StageId::STATE_REQUIRED.into_iter().chain(StageId::OPTIONAL) {
         provider_rw.save_stage_checkpoint(stage, StageCheckpoint::new(block))?;
     }
 
@@ -523,19 +524,19 @@ where
 
 /// Type to deserialize state root from state dump file.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
-struct StateRoot {
-    root: B256,
+pub(crate) struct StateRoot {
+    pub(crate) root: B256,
 }
 
 /// An account as in the state dump file. This contains a [`GenesisAccount`] and the account's
 /// address.
 #[derive(Debug, Serialize, Deserialize)]
-struct GenesisAccountWithAddress {
+pub(crate) struct GenesisAccountWithAddress {
     /// The account's balance, nonce, code, and storage.
     #[serde(flatten)]
-    genesis_account: GenesisAccount,
+    pub(crate) genesis_account: GenesisAccount,
     /// The account's address.
-    address: Address,
+    pub(crate) address: Address,
 }
 
 #[cfg(test)]
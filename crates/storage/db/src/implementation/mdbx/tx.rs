@@ -8,6 +8,7 @@ use crate::{
 use reth_db_api::{
     table::{Compress, DupSort, Encode, Table, TableImporter},
     transaction::{DbTx, DbTxMut},
+    DatabaseReadStats,
 };
 use reth_libmdbx::{ffi::MDBX_dbi, CommitLatency, Transaction, TransactionKind, WriteFlags, RW};
 use reth_storage_errors::db::{DatabaseWriteError, DatabaseWriteOperation};
@@ -56,6 +57,9 @@ impl<K: TransactionKind> Tx<K> {
             .map(|env_metrics| {
                 let handler = MetricsHandler::<K>::new(inner.id()?, env_metrics);
                 handler.env_metrics.record_opened_transaction(handler.transaction_mode());
+                if K::IS_READ_ONLY {
+                    DatabaseReadStats::record_transaction();
+                }
                 handler.log_transaction_opened();
                 Ok(handler)
             })
@@ -88,6 +92,10 @@ impl<K: TransactionKind> Tx<K> {
             .cursor_with_dbi(self.get_dbi::<T>()?)
             .map_err(|e| DatabaseError::InitCursor(e.into()))?;
 
+        if K::IS_READ_ONLY && self.metrics_handler.is_some() {
+            DatabaseReadStats::record_cursor();
+        }
+
         Ok(Cursor::new_with_metrics(
             inner,
             self.metrics_handler.as_ref().map(|h| h.env_metrics.clone()),
@@ -289,6 +297,9 @@ impl<K: TransactionKind> DbTx for Tx<K> {
         &self,
         key: &<T::Key as Encode>::Encoded,
     ) -> Result<Option<T::Value>, DatabaseError> {
+        if K::IS_READ_ONLY && self.metrics_handler.is_some() {
+            DatabaseReadStats::record_get();
+        }
         self.execute_with_operation_metric::<T, _>(Operation::Get, None, |tx| {
             tx.get(self.get_dbi::<T>()?, key.as_ref())
                 .map_err(|e| DatabaseError::Read(e.into()))?
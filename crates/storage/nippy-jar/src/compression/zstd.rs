@@ -60,6 +60,13 @@ impl Zstd {
         self
     }
 
+    /// Stops using dictionaries, e.g. if there isn't enough data to train them.
+    pub fn disable_dictionaries(&mut self) {
+        self.use_dict = false;
+        self.dictionaries = None;
+        self.state = ZstdState::Ready;
+    }
+
     /// Creates a list of [`Decompressor`] if using dictionaries.
     pub fn decompressors(&self) -> Result<Vec<Decompressor<'_>>, NippyJarError> {
         if let Some(dictionaries) = &self.dictionaries {
@@ -71,7 +78,7 @@ impl Zstd {
     }
 
     /// If using dictionaries, creates a list of [`Compressor`].
-    pub fn compressors(&self) -> Result<Option<Vec<Compressor<'_>>>, NippyJarError> {
+    pub fn compressors(&self) -> Result<Option<Vec<Compressor<'static>>>, NippyJarError> {
         match self.state {
             ZstdState::PendingDictionary => Err(NippyJarError::CompressorNotReady),
             ZstdState::Ready => {
@@ -81,7 +88,7 @@ impl Zstd {
 
                 if let Some(dictionaries) = &self.dictionaries {
                     debug!(target: "nippy-jar", count=?dictionaries.len(), "Generating ZSTD compressor dictionaries.");
-                    return Ok(Some(dictionaries.compressors()?))
+                    return Ok(Some(dictionaries.compressors(self.level)?))
                 }
                 Ok(None)
             }
@@ -189,7 +196,6 @@ impl Compression for Zstd {
         matches!(self.state, ZstdState::Ready)
     }
 
-    #[cfg(test)]
     /// If using it with dictionaries, prepares a dictionary for each column.
     fn prepare_compression(
         &mut self,
@@ -277,7 +283,6 @@ impl std::fmt::Debug for ZstdDictionaries<'_> {
 }
 
 impl ZstdDictionaries<'_> {
-    #[cfg(test)]
     /// Creates [`ZstdDictionaries`].
     pub(crate) fn new(raw: Vec<RawDictionary>) -> Self {
         Self(raw.into_iter().map(ZstdDictionary::Raw).collect())
@@ -287,7 +292,7 @@ impl ZstdDictionaries<'_> {
     pub(crate) fn load(raw: Vec<RawDictionary>) -> Self {
         Self(
             raw.into_iter()
-                .map(|dict| ZstdDictionary::Loaded(DecoderDictionary::copy(&dict)))
+                .map(|dict| ZstdDictionary::Loaded(DecoderDictionary::copy(&dict), dict))
                 .collect(),
         )
     }
@@ -304,33 +309,33 @@ impl ZstdDictionaries<'_> {
             .collect::<Result<Vec<_>, _>>()?)
     }
 
-    /// Creates a list of compressors from a list of [`ZstdDictionary::Raw`].
-    pub(crate) fn compressors(&self) -> Result<Vec<Compressor<'_>>, NippyJarError> {
-        Ok(self
-            .iter()
-            .flat_map(|dict| {
-                dict.raw()
-                    .ok_or(NippyJarError::CompressorNotAllowed)
-                    .map(|dict| Compressor::with_dictionary(0, dict))
+    /// Creates a list of compressors from the raw dictionaries.
+    pub(crate) fn compressors(
+        &self,
+        level: i32,
+    ) -> Result<Vec<Compressor<'static>>, NippyJarError> {
+        self.iter()
+            .map(|dict| {
+                let dict = dict.raw().ok_or(NippyJarError::CompressorNotAllowed)?;
+                Ok(Compressor::with_dictionary(level, dict)?)
             })
-            .collect::<Result<Vec<_>, _>>()?)
+            .collect()
     }
 }
 
 /// A Zstd dictionary. It's created and serialized with [`ZstdDictionary::Raw`], and deserialized as
-/// [`ZstdDictionary::Loaded`].
+/// [`ZstdDictionary::Loaded`], which keeps the raw dictionary to compress the values appended to an
+/// existing jar.
 pub(crate) enum ZstdDictionary<'a> {
-    #[allow(dead_code)]
     Raw(RawDictionary),
-    Loaded(DecoderDictionary<'a>),
+    Loaded(DecoderDictionary<'a>, RawDictionary),
 }
 
 impl ZstdDictionary<'_> {
     /// Returns a reference to the expected `RawDictionary`
     pub(crate) const fn raw(&self) -> Option<&RawDictionary> {
         match self {
-            ZstdDictionary::Raw(dict) => Some(dict),
-            ZstdDictionary::Loaded(_) => None,
+            ZstdDictionary::Raw(dict) | ZstdDictionary::Loaded(_, dict) => Some(dict),
         }
     }
 
@@ -338,7 +343,7 @@ impl ZstdDictionary<'_> {
     pub(crate) const fn loaded(&self) -> Option<&DecoderDictionary<'_>> {
         match self {
             ZstdDictionary::Raw(_) => None,
-            ZstdDictionary::Loaded(dict) => Some(dict),
+            ZstdDictionary::Loaded(dict, _) => Some(dict),
         }
     }
 }
@@ -349,7 +354,7 @@ impl<'de> Deserialize<'de> for ZstdDictionary<'_> {
         D: Deserializer<'de>,
     {
         let dict = RawDictionary::deserialize(deserializer)?;
-        Ok(Self::Loaded(DecoderDictionary::copy(&dict)))
+        Ok(Self::Loaded(DecoderDictionary::copy(&dict), dict))
     }
 }
 
@@ -359,8 +364,7 @@ impl Serialize for ZstdDictionary<'_> {
         S: Serializer,
     {
         match self {
-            ZstdDictionary::Raw(r) => r.serialize(serializer),
-            ZstdDictionary::Loaded(_) => unreachable!(),
+            ZstdDictionary::Raw(r) | ZstdDictionary::Loaded(_, r) => r.serialize(serializer),
         }
     }
 }
@@ -368,9 +372,7 @@ impl Serialize for ZstdDictionary<'_> {
 #[cfg(test)]
 impl PartialEq for ZstdDictionary<'_> {
     fn eq(&self, other: &Self) -> bool {
-        if let (Self::Raw(a), Self::Raw(b)) = (self, &other) {
-            return a == b
-        }
-        unimplemented!("`DecoderDictionary` can't be compared. So comparison should be done after decompressing a value.");
+        // `DecoderDictionary` can't be compared, but the raw dictionaries it was loaded from can.
+        self.raw() == other.raw()
     }
 }
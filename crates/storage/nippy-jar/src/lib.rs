@@ -25,9 +25,7 @@ use tracing::*;
 
 /// Compression algorithms supported by `NippyJar`.
 pub mod compression;
-#[cfg(test)]
-use compression::Compression;
-use compression::Compressors;
+use compression::{Compression, Compressors};
 
 /// empty enum for backwards compatibility
 #[derive(Debug, Serialize, Deserialize)]
@@ -260,7 +258,6 @@ impl<H: NippyJarHeader> NippyJar<H> {
     }
 }
 
-#[cfg(test)]
 impl<H: NippyJarHeader> NippyJar<H> {
     /// If required, prepares any compression algorithm to an early pass of the data.
     pub fn prepare_compression(
@@ -274,7 +271,10 @@ impl<H: NippyJarHeader> NippyJar<H> {
         }
         Ok(())
     }
+}
 
+#[cfg(test)]
+impl<H: NippyJarHeader> NippyJar<H> {
     /// Writes all data and configuration to a file and the offset index to another.
     pub fn freeze(
         self,
@@ -552,6 +552,74 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_writer_with_dictionaries() {
+        let (col1, col2) = test_data(None);
+        let num_columns = 2;
+        let file_path = tempfile::NamedTempFile::new().unwrap();
+
+        // The dictionaries are trained by the writer, before the first row is appended
+        let nippy =
+            NippyJar::new_without_header(num_columns, file_path.path()).with_zstd(true, 5000);
+        let mut writer = NippyJarWriter::new(nippy).unwrap();
+        assert!(!writer.is_compression_ready());
+        assert!(matches!(
+            writer.append_column(Some(Ok(&col1[0]))),
+            Err(NippyJarError::CompressorNotReady)
+        ));
+        writer.prepare_compression(vec![col1.clone(), col2.clone()]).unwrap();
+        assert!(writer.is_compression_ready());
+        writer.append_rows(vec![clone_with_result(&col1), clone_with_result(&col2)], 50).unwrap();
+        writer.commit().unwrap();
+        assert!(matches!(
+            writer.prepare_compression(vec![col1.clone(), col2.clone()]),
+            Err(NippyJarError::CompressorNotAllowed)
+        ));
+
+        // A reopened jar keeps compressing with the dictionaries it was created with
+        let nippy = NippyJar::load_without_header(file_path.path()).unwrap();
+        let mut writer = NippyJarWriter::new(nippy).unwrap();
+        assert!(writer.is_compression_ready());
+        writer
+            .append_rows(
+                vec![
+                    col1[50..].iter().map(|v| Ok(v.clone())).collect::<Vec<_>>(),
+                    col2[50..].iter().map(|v| Ok(v.clone())).collect::<Vec<_>>(),
+                ],
+                50,
+            )
+            .unwrap();
+        writer.commit().unwrap();
+
+        let nippy = NippyJar::load_without_header(file_path.path()).unwrap();
+        assert_eq!(nippy.rows, col1.len());
+        let mut cursor = NippyJarCursor::new(&nippy).unwrap();
+        let mut row_index = 0usize;
+        while let Some(row) = cursor.next_row().unwrap() {
+            assert_eq!((row[0], row[1]), (col1[row_index].as_slice(), col2[row_index].as_slice()));
+            row_index += 1;
+        }
+        assert_eq!(row_index, col1.len());
+
+        // Without enough values to train the dictionaries, the jar is compressed without them
+        let file_path = tempfile::NamedTempFile::new().unwrap();
+        let nippy =
+            NippyJar::new_without_header(num_columns, file_path.path()).with_zstd(true, 5000);
+        let mut writer = NippyJarWriter::new(nippy).unwrap();
+        writer.prepare_compression(vec![vec![col1[0].clone()], vec![col2[0].clone()]]).unwrap();
+        assert!(writer.is_compression_ready());
+        writer.append_rows(vec![clone_with_result(&col1), clone_with_result(&col2)], 1).unwrap();
+        writer.commit().unwrap();
+
+        let nippy = NippyJar::load_without_header(file_path.path()).unwrap();
+        assert!(matches!(nippy.compressor(), Some(Compressors::Zstd(zstd)) if !zstd.use_dict));
+        let mut cursor = NippyJarCursor::new(&nippy).unwrap();
+        assert_eq!(
+            cursor.next_row().unwrap().unwrap(),
+            vec![col1[0].as_slice(), col2[0].as_slice()]
+        );
+    }
+
     #[test]
     fn test_lz4() {
         let (col1, col2) = test_data(None);
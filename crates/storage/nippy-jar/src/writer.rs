@@ -1,12 +1,14 @@
 use crate::{
-    compression::Compression, ColumnResult, NippyJar, NippyJarChecker, NippyJarError,
-    NippyJarHeader,
+    compression::{Compression, Compressors},
+    ColumnResult, NippyJar, NippyJarChecker, NippyJarError, NippyJarHeader,
 };
 use std::{
     fs::{File, OpenOptions},
     io::{BufWriter, Read, Seek, SeekFrom, Write},
     path::Path,
 };
+use tracing::warn;
+use zstd::bulk::Compressor;
 
 /// Size of one offset in bytes.
 pub(crate) const OFFSET_SIZE_BYTES: u8 = 8;
@@ -35,6 +37,8 @@ pub struct NippyJarWriter<H: NippyJarHeader = ()> {
     offsets_file: BufWriter<File>,
     /// Temporary buffer to reuse when compressing data.
     tmp_buf: Vec<u8>,
+    /// Zstd compressors of each column, if the jar is compressed with dictionaries.
+    zstd_compressors: Option<Vec<Compressor<'static>>>,
     /// Used to find the maximum uncompressed size of a row in a jar.
     uncompressed_row_size: usize,
     /// Partial offset list which hasn't been flushed to disk.
@@ -70,11 +74,13 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
             (jar, data_file.expect("qed"), offsets_file.expect("qed"))
         };
 
+        let zstd_compressors = Self::zstd_compressors(&jar)?;
         let mut writer = Self {
             jar,
             data_file,
             offsets_file,
             tmp_buf: Vec::with_capacity(1_000_000),
+            zstd_compressors,
             uncompressed_row_size: 0,
             offsets: Vec::with_capacity(1_000_000),
             column: 0,
@@ -118,6 +124,53 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
         self.jar.rows()
     }
 
+    /// Returns `true` if the compression of the jar is ready, see
+    /// [`NippyJarWriter::prepare_compression`].
+    pub fn is_compression_ready(&self) -> bool {
+        self.jar.compressor().is_none_or(|compressor| compressor.is_ready())
+    }
+
+    /// Prepares the compression of a jar without rows, e.g. trains the zstd dictionaries on the
+    /// given column values.
+    ///
+    /// If the values are not enough to train the zstd dictionaries, the jar is compressed without
+    /// them.
+    pub fn prepare_compression(
+        &mut self,
+        columns: Vec<impl IntoIterator<Item = Vec<u8>>>,
+    ) -> Result<(), NippyJarError> {
+        if self.jar.rows > 0 {
+            return Err(NippyJarError::CompressorNotAllowed)
+        }
+
+        if let Err(err) = self.jar.prepare_compression(columns) {
+            let Some(Compressors::Zstd(zstd)) = self.jar.compressor_mut() else { return Err(err) };
+            if matches!(err, NippyJarError::ColumnLenMismatch(..)) {
+                return Err(err)
+            }
+            warn!(
+                target: "nippy-jar",
+                %err,
+                "Failed to train the zstd dictionaries, compressing without them."
+            );
+            zstd.disable_dictionaries();
+        }
+
+        self.zstd_compressors = Self::zstd_compressors(&self.jar)?;
+        self.dirty = true;
+        Ok(())
+    }
+
+    /// Creates the zstd compressors of each column, if the jar is compressed with dictionaries.
+    fn zstd_compressors(
+        jar: &NippyJar<H>,
+    ) -> Result<Option<Vec<Compressor<'static>>>, NippyJarError> {
+        match jar.compressor() {
+            Some(Compressors::Zstd(zstd)) if zstd.use_dict && zstd.is_ready() => zstd.compressors(),
+            _ => Ok(None),
+        }
+    }
+
     /// Consumes the writer and returns the associated [`NippyJar`].
     pub fn into_jar(self) -> NippyJar<H> {
         self.jar
@@ -227,7 +280,14 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
     /// Writes column to data file. If it's the last column of the row, call `finalize_row()`
     fn write_column(&mut self, value: &[u8]) -> Result<usize, NippyJarError> {
         self.uncompressed_row_size += value.len();
-        let len = if let Some(compression) = &self.jar.compressor {
+        let len = if let Some(compressors) = &mut self.zstd_compressors {
+            let compressed = compressors[self.column].compress(value)?;
+            self.data_file.write_all(&compressed)?;
+            compressed.len()
+        } else if let Some(compression) = &self.jar.compressor {
+            if !compression.is_ready() {
+                return Err(NippyJarError::CompressorNotReady)
+            }
             let before = self.tmp_buf.len();
             let len = compression.compress_to(value, &mut self.tmp_buf)?;
             self.data_file.write_all(&self.tmp_buf[before..before + len])?;
@@ -22,7 +22,10 @@ use reth_chain_state::{
 };
 use reth_chainspec::{ChainInfo, EthereumHardforks};
 use reth_db_api::{
-    models::{AccountBeforeTx, BlockNumberAddress, StoredBlockBodyIndices},
+    models::{
+        AccountBeforeTx, BlockNumberAddress, ContractCreation, HlSidecar, LogPosition, LogTopicKey,
+        NativeTransfer, StoredBlockBodyIndices, TokenBalanceDelta, TokenTransfer, TokenTransferKey,
+    },
     transaction::DbTx,
     Database,
 };
@@ -37,14 +40,16 @@ use reth_primitives_traits::BlockBody;
 use reth_prune_types::{PruneCheckpoint, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_storage_api::{
-    BlockBodyIndicesProvider, DBProvider, NodePrimitivesProvider, OmmersProvider,
-    StateCommitmentProvider, StorageChangeSetReader,
+    AddressTransactionsReader, BlockBodyIndicesProvider, ContractCreatorsReader, DBProvider,
+    HlSidecarReader, LogIndexEntries, LogIndexReader, NodePrimitivesProvider, OmmersProvider,
+    StateCommitmentProvider, StorageChangeSetReader, TokenTransferEntries, TokenTransfersReader,
 };
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie::HashedPostState;
 use reth_trie_db::StateCommitment;
 use revm_database::BundleState;
 use std::{
+    collections::BTreeMap,
     ops::{Add, RangeBounds, RangeInclusive, Sub},
     sync::Arc,
     time::Instant,
@@ -742,6 +747,146 @@ impl<N: ProviderNodeTypes> ChangeSetReader for BlockchainProvider<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> AddressTransactionsReader for BlockchainProvider<N> {
+    fn touched_addresses_with_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<BTreeMap<Address, Vec<TxNumber>>> {
+        self.database.provider()?.touched_addresses_with_range(range)
+    }
+
+    /// Only blocks that have been persisted to the database are indexed.
+    fn address_transactions_before(
+        &self,
+        address: Address,
+        tx_number: TxNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        self.database.provider()?.address_transactions_before(address, tx_number, limit)
+    }
+
+    /// Only blocks that have been persisted to the database are indexed.
+    fn address_transactions_after(
+        &self,
+        address: Address,
+        tx_number: TxNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        self.database.provider()?.address_transactions_after(address, tx_number, limit)
+    }
+}
+
+impl<N: ProviderNodeTypes> HlSidecarReader for BlockchainProvider<N> {
+    fn hl_sidecar(&self, block: BlockNumber) -> ProviderResult<Option<HlSidecar>> {
+        self.database.provider()?.hl_sidecar(block)
+    }
+}
+
+impl<N: ProviderNodeTypes> ContractCreatorsReader for BlockchainProvider<N> {
+    /// Only blocks that have been persisted to the database are indexed.
+    fn contract_creations_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.database.provider()?.contract_creations_range()
+    }
+
+    fn contract_creation(
+        &self,
+        address: Address,
+    ) -> ProviderResult<Option<(BlockNumber, ContractCreation)>> {
+        self.database.provider()?.contract_creation(address)
+    }
+
+    fn contract_creations_by_block_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumberAddress, ContractCreation)>> {
+        self.database.provider()?.contract_creations_by_block_range(range)
+    }
+}
+
+impl<N: ProviderNodeTypes> LogIndexReader for BlockchainProvider<N> {
+    /// Only blocks that have been persisted to the database are indexed.
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.database.provider()?.log_index_range()
+    }
+
+    fn log_index_entries_with_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<LogIndexEntries> {
+        self.database.provider()?.log_index_entries_with_range(range)
+    }
+
+    fn log_address_positions(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<LogPosition>> {
+        self.database.provider()?.log_address_positions(address, range)
+    }
+
+    fn log_topic_positions(
+        &self,
+        key: LogTopicKey,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<LogPosition>> {
+        self.database.provider()?.log_topic_positions(key, range)
+    }
+}
+
+impl<N: ProviderNodeTypes> TokenTransfersReader for BlockchainProvider<N> {
+    /// Only blocks that have been persisted to the database are indexed.
+    fn token_transfers_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.database.provider()?.token_transfers_range()
+    }
+
+    fn token_transfer_entries_with_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<TokenTransferEntries> {
+        self.database.provider()?.token_transfer_entries_with_range(range)
+    }
+
+    fn token_transfers(
+        &self,
+        token: Address,
+        start: (BlockNumber, u32),
+        end_block: BlockNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, TokenTransfer)>> {
+        self.database.provider()?.token_transfers(token, start, end_block, limit)
+    }
+
+    fn holder_token_transfers(
+        &self,
+        holder: Address,
+        token: Option<Address>,
+        start: (BlockNumber, u32),
+        end_block: BlockNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, TokenTransfer)>> {
+        self.database.provider()?.holder_token_transfers(holder, token, start, end_block, limit)
+    }
+
+    fn token_balance_deltas(
+        &self,
+        holder: Address,
+        token: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumber, TokenBalanceDelta)>> {
+        self.database.provider()?.token_balance_deltas(holder, token, range)
+    }
+
+    fn native_transfers(
+        &self,
+        user: Address,
+        start: (BlockNumber, u32),
+        end_block: BlockNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, NativeTransfer)>> {
+        self.database.provider()?.native_transfers(user, start, end_block, limit)
+    }
+}
+
 impl<N: ProviderNodeTypes> AccountReader for BlockchainProvider<N> {
     /// Get basic account information.
     fn basic_account(&self, address: &Address) -> ProviderResult<Option<Account>> {
@@ -1104,6 +1249,8 @@ mod tests {
                 )),
                 execution_output: Default::default(),
                 hashed_state: Default::default(),
+                internal_accounts: Default::default(),
+                contract_creations: Default::default(),
             },
             trie: Default::default(),
         });
@@ -1218,6 +1365,8 @@ mod tests {
                 )),
                 execution_output: Default::default(),
                 hashed_state: Default::default(),
+                internal_accounts: Default::default(),
+                contract_creations: Default::default(),
             },
             trie: Default::default(),
         });
@@ -2004,6 +2153,8 @@ mod tests {
                     )),
                     execution_output: Default::default(),
                     hashed_state: Default::default(),
+                    internal_accounts: Default::default(),
+                    contract_creations: Default::default(),
                 },
                 trie: Default::default(),
             },
@@ -2130,6 +2281,8 @@ mod tests {
                 )),
                 execution_output: Default::default(),
                 hashed_state: Default::default(),
+                internal_accounts: Default::default(),
+                contract_creations: Default::default(),
             },
             trie: Default::default(),
         });
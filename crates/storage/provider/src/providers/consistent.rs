@@ -1635,6 +1635,8 @@ mod tests {
                 )),
                 execution_output: Default::default(),
                 hashed_state: Default::default(),
+                internal_accounts: Default::default(),
+                contract_creations: Default::default(),
             },
             trie: Default::default(),
         });
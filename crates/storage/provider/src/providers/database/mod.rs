@@ -38,6 +38,7 @@ use tokio::sync::watch;
 use tracing::trace;
 
 mod provider;
+pub(crate) use provider::touched_addresses;
 pub use provider::{DatabaseProvider, DatabaseProviderRO, DatabaseProviderRW};
 
 use super::ProviderNodeTypes;
@@ -101,6 +102,11 @@ impl<N: NodeTypesWithDB> ProviderFactory<N> {
         self
     }
 
+    /// Returns the pruning configuration.
+    pub const fn prune_modes(&self) -> &PruneModes {
+        &self.prune_modes
+    }
+
     /// Returns reference to the underlying database.
     pub const fn db_ref(&self) -> &N::DB {
         &self.db
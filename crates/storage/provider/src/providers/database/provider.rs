@@ -24,7 +24,7 @@ use alloy_eips::{eip2718::Encodable2718, eip4895::Withdrawals, BlockHashOrNumber
 use alloy_primitives::{
     keccak256,
     map::{hash_map, B256Map, HashMap, HashSet},
-    Address, BlockHash, BlockNumber, TxHash, TxNumber, B256, U256,
+    Address, BlockHash, BlockNumber, Log, TxHash, TxKind, TxNumber, B256, U256,
 };
 use itertools::Itertools;
 use rayon::slice::ParallelSliceMut;
@@ -34,7 +34,9 @@ use reth_db_api::{
     database::Database,
     models::{
         sharded_key, storage_sharded_key::StorageShardedKey, AccountBeforeTx, BlockNumberAddress,
-        ShardedKey, StoredBlockBodyIndices,
+        ContractCreation, HlSidecar, InternalAccounts, LogPosition, LogTopicKey, NativeTransfer,
+        ShardedKey, StoredBlockBodyIndices, TokenBalanceDelta, TokenBalanceKey, TokenTransfer,
+        TokenTransferKey,
     },
     table::Table,
     tables,
@@ -54,8 +56,10 @@ use reth_prune_types::{
 };
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_storage_api::{
-    BlockBodyIndicesProvider, BlockBodyReader, NodePrimitivesProvider, OmmersProvider,
-    StateProvider, StorageChangeSetReader, TryIntoHistoricalStateProvider,
+    AddressTransactionsReader, BlockBodyIndicesProvider, BlockBodyReader, ContractCreatorsReader,
+    HlSidecarReader, LogIndexEntries, LogIndexReader, NodePrimitivesProvider, OmmersProvider,
+    StateProvider, StorageChangeSetReader, TokenTransferEntries, TokenTransfersReader,
+    TryIntoHistoricalStateProvider,
 };
 use reth_storage_errors::provider::{ProviderResult, RootMismatch};
 use reth_trie::{
@@ -71,7 +75,7 @@ use std::{
     cmp::Ordering,
     collections::{BTreeMap, BTreeSet},
     fmt::Debug,
-    ops::{Deref, DerefMut, Range, RangeBounds, RangeInclusive},
+    ops::{Bound, Deref, DerefMut, Range, RangeBounds, RangeInclusive},
     sync::{mpsc, Arc},
 };
 use tokio::sync::watch;
@@ -204,6 +208,122 @@ impl<TX: DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
         Ok(Box::new(state_provider))
     }
 
+    /// Returns the indices in the given range stored in the shards of a partial key of a history
+    /// index table, in ascending order.
+    fn history_index_range<P, T>(
+        &self,
+        partial_key: P,
+        range: RangeInclusive<u64>,
+    ) -> ProviderResult<Vec<u64>>
+    where
+        P: Clone + PartialEq,
+        T: Table<Key = ShardedKey<P>, Value = BlockNumberList>,
+    {
+        let mut indices = Vec::new();
+        let mut cursor = self.tx.cursor_read::<T>()?;
+        for entry in cursor.walk(Some(ShardedKey::new(partial_key.clone(), *range.start())))? {
+            let (sharded_key, list) = entry?;
+            if sharded_key.key != partial_key {
+                break
+            }
+            indices.extend(list.iter().filter(|index| range.contains(index)));
+            if sharded_key.highest_block_number >= *range.end() {
+                break
+            }
+        }
+        Ok(indices)
+    }
+
+    /// Returns the account changesets of the block range.
+    fn account_changesets_range(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumber, AccountBeforeTx)>> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        let changesets = self
+            .tx
+            .cursor_read::<tables::AccountChangeSets>()?
+            .walk_range(range)?
+            .collect::<Result<Vec<_>, _>>()?;
+        self.with_static_file_account_changesets(range, changesets)
+    }
+
+    /// Returns the storage changesets of the block range.
+    fn storage_changesets_range(
+        &self,
+        range: impl RangeBounds<BlockNumberAddress>,
+    ) -> ProviderResult<Vec<(BlockNumberAddress, StorageEntry)>> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        let changesets = self
+            .tx
+            .cursor_read::<tables::StorageChangeSets>()?
+            .walk_range(range)?
+            .collect::<Result<Vec<_>, _>>()?;
+        self.with_static_file_storage_changesets(range, changesets)
+    }
+
+    /// Replaces the account changesets of the blocks that were moved to static files with the
+    /// static file ones, as they may already be deleted from the database.
+    fn with_static_file_account_changesets(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+        database: Vec<(BlockNumber, AccountBeforeTx)>,
+    ) -> ProviderResult<Vec<(BlockNumber, AccountBeforeTx)>> {
+        let Some(highest) = self
+            .static_file_provider
+            .get_highest_static_file_block(StaticFileSegment::AccountChangeSets)
+        else {
+            return Ok(database)
+        };
+
+        let mut changesets = Vec::new();
+        for block in changeset_blocks(range.start_bound().cloned(), range.end_bound().cloned())
+            .take_while(|block| *block <= highest)
+            .filter(|block| range.contains(block))
+        {
+            if let Some(changeset) = self.static_file_provider.account_changeset(block)? {
+                changesets.extend(changeset.changes.into_iter().map(|change| (block, change)));
+            }
+        }
+        changesets.extend(database.into_iter().filter(|(block, _)| *block > highest));
+        Ok(changesets)
+    }
+
+    /// Replaces the storage changesets of the blocks that were moved to static files with the
+    /// static file ones, as they may already be deleted from the database.
+    fn with_static_file_storage_changesets(
+        &self,
+        range: impl RangeBounds<BlockNumberAddress>,
+        database: Vec<(BlockNumberAddress, StorageEntry)>,
+    ) -> ProviderResult<Vec<(BlockNumberAddress, StorageEntry)>> {
+        let Some(highest) = self
+            .static_file_provider
+            .get_highest_static_file_block(StaticFileSegment::StorageChangeSets)
+        else {
+            return Ok(database)
+        };
+
+        let mut changesets = Vec::new();
+        for block in changeset_blocks(
+            range.start_bound().map(BlockNumberAddress::block_number),
+            range.end_bound().map(BlockNumberAddress::block_number),
+        )
+        .take_while(|block| *block <= highest)
+        {
+            if let Some(changeset) = self.static_file_provider.storage_changeset(block)? {
+                changesets.extend(
+                    changeset
+                        .changes
+                        .into_iter()
+                        .map(|change| (BlockNumberAddress((block, change.address)), change.entry))
+                        .filter(|(key, _)| range.contains(key)),
+                );
+            }
+        }
+        changesets.extend(database.into_iter().filter(|(key, _)| key.block_number() > highest));
+        Ok(changesets)
+    }
+
     #[cfg(feature = "test-utils")]
     /// Sets the prune modes for provider.
     pub fn set_prune_modes(&mut self, prune_modes: PruneModes) {
@@ -260,11 +380,7 @@ impl<TX: DbTx + DbTxMut + 'static, N: NodeTypesForProvider> DatabaseProvider<TX,
         &self,
         range: RangeInclusive<BlockNumber>,
     ) -> ProviderResult<()> {
-        let changed_accounts = self
-            .tx
-            .cursor_read::<tables::AccountChangeSets>()?
-            .walk_range(range.clone())?
-            .collect::<Result<Vec<_>, _>>()?;
+        let changed_accounts = self.account_changesets_range(range.clone())?;
 
         // Unwind account hashes. Add changed accounts to account prefix set.
         let hashed_addresses = self.unwind_account_hashing(changed_accounts.iter())?;
@@ -279,13 +395,8 @@ impl<TX: DbTx + DbTxMut + 'static, N: NodeTypesForProvider> DatabaseProvider<TX,
 
         // Unwind account history indices.
         self.unwind_account_history_indices(changed_accounts.iter())?;
-        let storage_range = BlockNumberAddress::range(range.clone());
-
-        let changed_storages = self
-            .tx
-            .cursor_read::<tables::StorageChangeSets>()?
-            .walk_range(storage_range)?
-            .collect::<Result<Vec<_>, _>>()?;
+        let changed_storages =
+            self.storage_changesets_range(BlockNumberAddress::range(range.clone()))?;
 
         // Unwind storage hashes. Add changed account and storage keys to corresponding prefix
         // sets.
@@ -303,6 +414,18 @@ impl<TX: DbTx + DbTxMut + 'static, N: NodeTypesForProvider> DatabaseProvider<TX,
         // Unwind storage history indices.
         self.unwind_storage_history_indices(changed_storages.iter().copied())?;
 
+        // Unwind address transactions indices.
+        self.unwind_address_transactions_indices_range(range.clone())?;
+
+        // Unwind log indices.
+        self.unwind_log_indices_range(range.clone())?;
+
+        // Unwind contract creations.
+        self.unwind_contract_creations_range(range.clone())?;
+
+        // Unwind token transfers.
+        self.unwind_token_transfers_range(range.clone())?;
+
         // Calculate the reverted merkle root.
         // This is the same as `StateRoot::incremental_root_with_updates`, only the prefix sets
         // are pre-loaded.
@@ -366,6 +489,24 @@ impl<TX: DbTx + DbTxMut + 'static, N: NodeTypesForProvider> DatabaseProvider<TX,
 
         Ok(())
     }
+
+    /// Removes the changesets above the block from static files, if they were already copied
+    /// there.
+    fn remove_static_file_changesets_above(&self, block: BlockNumber) -> ProviderResult<()> {
+        for segment in [StaticFileSegment::AccountChangeSets, StaticFileSegment::StorageChangeSets]
+        {
+            if let Some(highest) = self
+                .static_file_provider
+                .get_highest_static_file_block(segment)
+                .filter(|highest| *highest > block)
+            {
+                self.static_file_provider
+                    .latest_writer(segment)?
+                    .prune_changesets(highest - block)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<TX: DbTx + 'static, N: NodeTypes> TryIntoHistoricalStateProvider for DatabaseProvider<TX, N> {
@@ -499,6 +640,50 @@ where
     Ok(Vec::new())
 }
 
+/// Returns the blocks of a range of changesets.
+fn changeset_blocks(
+    start: Bound<BlockNumber>,
+    end: Bound<BlockNumber>,
+) -> impl Iterator<Item = BlockNumber> {
+    let start = match start {
+        Bound::Included(block) => block,
+        Bound::Excluded(block) => block.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match end {
+        Bound::Included(block) | Bound::Excluded(block) => block,
+        Bound::Unbounded => BlockNumber::MAX,
+    };
+    start..=end
+}
+
+/// Returns the addresses touched by a transaction: its sender, its recipient or the contract it
+/// created, the emitters of its logs and the accounts changed by its internal calls.
+///
+/// The same address may be returned more than once.
+pub(crate) fn touched_addresses<'a, T, R>(
+    transaction: &T,
+    sender: Address,
+    receipt: Option<&'a R>,
+    internal_accounts: &'a [Address],
+) -> impl Iterator<Item = Address> + 'a
+where
+    T: SignedTransaction,
+    R: TxReceipt<Log = Log>,
+{
+    let target = match transaction.kind() {
+        TxKind::Call(to) => Some(to),
+        // Failed creations don't deploy any code, so there is nothing to index.
+        TxKind::Create => receipt
+            .is_none_or(|receipt| receipt.status())
+            .then(|| sender.create(transaction.nonce())),
+    };
+    core::iter::once(sender)
+        .chain(target)
+        .chain(receipt.into_iter().flat_map(|receipt| receipt.logs()).map(|log| log.address))
+        .chain(internal_accounts.iter().copied())
+}
+
 impl<TX: DbTx + 'static, N: NodeTypesForProvider> DatabaseProvider<TX, N> {
     /// Creates a provider with an inner read-only transaction.
     pub const fn new(
@@ -812,6 +997,41 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
         Ok(Vec::new())
     }
 
+    /// Unwind history index shards of the given partial keys.
+    ///
+    /// For each partial key, removes all indices higher than or equal to the given one, keeping the
+    /// remaining indices in the last shard.
+    fn unwind_history_index<P, T>(
+        &self,
+        unwound: impl IntoIterator<Item = (P, u64)>,
+    ) -> ProviderResult<usize>
+    where
+        P: Copy + PartialEq,
+        T: Table<Key = ShardedKey<P>, Value = BlockNumberList>,
+    {
+        let mut cursor = self.tx.cursor_write::<T>()?;
+        let mut count = 0;
+        for (partial_key, rem_index) in unwound {
+            let partial_shard = unwind_history_shards::<_, T, _>(
+                &mut cursor,
+                ShardedKey::last(partial_key),
+                rem_index,
+                |sharded_key| sharded_key.key == partial_key,
+            )?;
+
+            // Check the last returned partial shard.
+            // If it's not empty, the shard needs to be reinserted.
+            if !partial_shard.is_empty() {
+                cursor.insert(
+                    ShardedKey::last(partial_key),
+                    &BlockNumberList::new_pre_sorted(partial_shard),
+                )?;
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
+
     /// Insert history index to the database.
     ///
     /// For each updated partial key, this function removes the last shard from
@@ -907,6 +1127,14 @@ impl<TX: DbTx, N: NodeTypes> StorageChangeSetReader for DatabaseProvider<TX, N>
         &self,
         block_number: BlockNumber,
     ) -> ProviderResult<Vec<(BlockNumberAddress, StorageEntry)>> {
+        if let Some(changeset) = self.static_file_provider.storage_changeset(block_number)? {
+            return Ok(changeset
+                .changes
+                .into_iter()
+                .map(|change| (BlockNumberAddress((block_number, change.address)), change.entry))
+                .collect())
+        }
+
         let range = block_number..=block_number;
         let storage_range = BlockNumberAddress::range(range);
         self.tx
@@ -922,6 +1150,10 @@ impl<TX: DbTx, N: NodeTypes> ChangeSetReader for DatabaseProvider<TX, N> {
         &self,
         block_number: BlockNumber,
     ) -> ProviderResult<Vec<AccountBeforeTx>> {
+        if let Some(changeset) = self.static_file_provider.account_changeset(block_number)? {
+            return Ok(changeset.changes)
+        }
+
         let range = block_number..=block_number;
         self.tx
             .cursor_read::<tables::AccountChangeSets>()?
@@ -934,6 +1166,308 @@ impl<TX: DbTx, N: NodeTypes> ChangeSetReader for DatabaseProvider<TX, N> {
     }
 }
 
+impl<TX: DbTx + 'static, N: NodeTypesForProvider> AddressTransactionsReader
+    for DatabaseProvider<TX, N>
+{
+    fn touched_addresses_with_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<BTreeMap<Address, Vec<TxNumber>>> {
+        let mut touched = BTreeMap::<Address, Vec<TxNumber>>::new();
+        for body in self.block_body_indices_range(range)? {
+            if body.tx_count == 0 {
+                continue
+            }
+
+            let tx_range = body.tx_num_range();
+            let transactions = self.transactions_by_tx_range(tx_range.clone())?;
+            let mut senders = self.senders_by_tx_range(tx_range.clone())?;
+            // Senders might be pruned, recover them from the transactions instead.
+            if senders.len() != transactions.len() {
+                senders = transactions
+                    .iter()
+                    .map(|tx| {
+                        tx.recover_signer_unchecked()
+                            .map_err(|_| ProviderError::SenderRecoveryError)
+                    })
+                    .collect::<Result<_, _>>()?;
+            }
+            let receipts = self.receipts_by_tx_range(tx_range.clone())?;
+            let internal_accounts = self
+                .tx
+                .cursor_read::<tables::TransactionInternalAccounts>()?
+                .walk_range(tx_range.clone())?
+                .collect::<Result<BTreeMap<_, _>, _>>()?;
+
+            for (index, (tx_number, (transaction, sender))) in
+                tx_range.zip(transactions.iter().zip(senders)).enumerate()
+            {
+                let internal_accounts =
+                    internal_accounts.get(&tx_number).map_or(&[][..], |a| &a.addresses);
+                for address in
+                    touched_addresses(transaction, sender, receipts.get(index), internal_accounts)
+                {
+                    let tx_numbers = touched.entry(address).or_default();
+                    if tx_numbers.last() != Some(&tx_number) {
+                        tx_numbers.push(tx_number);
+                    }
+                }
+            }
+        }
+        Ok(touched)
+    }
+
+    fn address_transactions_before(
+        &self,
+        address: Address,
+        tx_number: TxNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        let mut tx_numbers = Vec::new();
+        let mut cursor = self.tx.cursor_read::<tables::AddressTransactions>()?;
+        for entry in cursor.walk_back(Some(ShardedKey::new(address, tx_number)))? {
+            let (sharded_key, list) = entry?;
+            if sharded_key.key != address || tx_numbers.len() >= limit {
+                break
+            }
+            tx_numbers.extend(
+                list.iter().rev().filter(|n| *n < tx_number).take(limit - tx_numbers.len()),
+            );
+        }
+        Ok(tx_numbers)
+    }
+
+    fn address_transactions_after(
+        &self,
+        address: Address,
+        tx_number: TxNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        let mut tx_numbers = Vec::new();
+        let mut cursor = self.tx.cursor_read::<tables::AddressTransactions>()?;
+        for entry in cursor.walk(Some(ShardedKey::new(address, tx_number)))? {
+            let (sharded_key, list) = entry?;
+            if sharded_key.key != address || tx_numbers.len() >= limit {
+                break
+            }
+            tx_numbers
+                .extend(list.iter().filter(|n| *n >= tx_number).take(limit - tx_numbers.len()));
+        }
+        Ok(tx_numbers)
+    }
+}
+
+impl<TX: DbTx + 'static, N: NodeTypesForProvider> HlSidecarReader for DatabaseProvider<TX, N> {
+    fn hl_sidecar(&self, block: BlockNumber) -> ProviderResult<Option<HlSidecar>> {
+        if let Some(sidecar) = self.static_file_provider.hl_sidecar(block)? {
+            return Ok(Some(sidecar).filter(|sidecar| !sidecar.is_empty()))
+        }
+        Ok(self.tx.get::<tables::HlSidecars>(block)?)
+    }
+}
+
+impl<TX: DbTx + 'static, N: NodeTypes> ContractCreatorsReader for DatabaseProvider<TX, N> {
+    fn contract_creations_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(self
+            .get_stage_checkpoint(StageId::IndexContractCreators)?
+            .map(|checkpoint| 0..=checkpoint.block_number))
+    }
+
+    fn contract_creation(
+        &self,
+        address: Address,
+    ) -> ProviderResult<Option<(BlockNumber, ContractCreation)>> {
+        let Some(block_number) = self.tx.get::<tables::ContractCreators>(address)? else {
+            return Ok(None)
+        };
+        Ok(self
+            .tx
+            .get::<tables::ContractCreations>(BlockNumberAddress((block_number, address)))?
+            .map(|creation| (block_number, creation)))
+    }
+
+    fn contract_creations_by_block_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumberAddress, ContractCreation)>> {
+        Ok(self
+            .tx
+            .cursor_read::<tables::ContractCreations>()?
+            .walk_range(BlockNumberAddress::range(range))?
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+}
+
+impl<TX: DbTx + 'static, N: NodeTypesForProvider> LogIndexReader for DatabaseProvider<TX, N> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        if self.prune_modes.log_index.is_some_and(|mode| mode.is_full()) {
+            return Ok(None)
+        }
+
+        let Some(end) = self.get_stage_checkpoint(StageId::IndexLogs)?.map(|c| c.block_number)
+        else {
+            return Ok(None)
+        };
+        let start = self
+            .get_prune_checkpoint(PruneSegment::LogIndex)?
+            .and_then(|checkpoint| checkpoint.block_number)
+            .map_or(0, |block_number| block_number + 1);
+
+        Ok(Some(start..=end))
+    }
+
+    fn log_index_entries_with_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<LogIndexEntries> {
+        let mut entries = LogIndexEntries::default();
+        let bodies = self.block_body_indices_range(range.clone())?;
+        let (Some(first), Some(last)) = (bodies.first(), bodies.last()) else { return Ok(entries) };
+
+        let first_tx_num = first.first_tx_num();
+        let receipts = self.receipts_by_tx_range(first_tx_num..last.next_tx_num())?;
+        for (block_number, body) in range.zip(&bodies) {
+            let start = (body.first_tx_num() - first_tx_num) as usize;
+            let block_receipts = &receipts[start..start + body.tx_count as usize];
+            entries.extend_block(block_number, block_receipts.iter().flat_map(|r| r.logs()));
+        }
+
+        Ok(entries)
+    }
+
+    fn log_address_positions(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<LogPosition>> {
+        let positions = self.history_index_range::<_, tables::LogAddressIndex>(
+            address,
+            LogPosition::range(range),
+        )?;
+        Ok(positions.into_iter().map(LogPosition::from).collect())
+    }
+
+    fn log_topic_positions(
+        &self,
+        key: LogTopicKey,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<LogPosition>> {
+        let positions =
+            self.history_index_range::<_, tables::LogTopicIndex>(key, LogPosition::range(range))?;
+        Ok(positions.into_iter().map(LogPosition::from).collect())
+    }
+}
+
+impl<TX: DbTx + 'static, N: NodeTypesForProvider> TokenTransfersReader for DatabaseProvider<TX, N> {
+    fn token_transfers_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        if self.prune_modes.token_transfers.is_some_and(|mode| mode.is_full()) {
+            return Ok(None)
+        }
+
+        let Some(end) =
+            self.get_stage_checkpoint(StageId::IndexTokenTransfers)?.map(|c| c.block_number)
+        else {
+            return Ok(None)
+        };
+        let start = self
+            .get_prune_checkpoint(PruneSegment::TokenTransfers)?
+            .and_then(|checkpoint| checkpoint.block_number)
+            .map_or(0, |block_number| block_number + 1);
+
+        Ok(Some(start..=end))
+    }
+
+    fn token_transfer_entries_with_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<TokenTransferEntries> {
+        let mut entries = TokenTransferEntries::default();
+        let bodies = self.block_body_indices_range(range.clone())?;
+        let (Some(first), Some(last)) = (bodies.first(), bodies.last()) else { return Ok(entries) };
+
+        let first_tx_num = first.first_tx_num();
+        let tx_range = first_tx_num..last.next_tx_num();
+        let transactions = self.transactions_by_tx_range(tx_range.clone())?;
+        let receipts = self.receipts_by_tx_range(tx_range)?;
+        for (block_number, body) in range.zip(&bodies) {
+            let start = (body.first_tx_num() - first_tx_num) as usize;
+            let end = start + body.tx_count as usize;
+            entries.extend_block(
+                block_number,
+                body.first_tx_num(),
+                transactions[start..end].iter().zip(&receipts[start..end]),
+            );
+        }
+
+        Ok(entries)
+    }
+
+    fn token_transfers(
+        &self,
+        token: Address,
+        start: (BlockNumber, u32),
+        end_block: BlockNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, TokenTransfer)>> {
+        Ok(self
+            .tx
+            .cursor_read::<tables::TokenTransfers>()?
+            .walk_range(TokenTransferKey::range(token, start, end_block))?
+            .take(limit)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    fn holder_token_transfers(
+        &self,
+        holder: Address,
+        token: Option<Address>,
+        start: (BlockNumber, u32),
+        end_block: BlockNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, TokenTransfer)>> {
+        let mut transfers = Vec::new();
+        let mut cursor = self.tx.cursor_read::<tables::HolderTokenTransfers>()?;
+        for entry in cursor.walk_range(TokenTransferKey::range(holder, start, end_block))? {
+            if transfers.len() == limit {
+                break
+            }
+            let (key, transfer) = entry?;
+            if token.is_none_or(|token| token == transfer.token) {
+                transfers.push((key, transfer));
+            }
+        }
+        Ok(transfers)
+    }
+
+    fn token_balance_deltas(
+        &self,
+        holder: Address,
+        token: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumber, TokenBalanceDelta)>> {
+        self.tx
+            .cursor_read::<tables::TokenBalanceDeltas>()?
+            .walk_range(TokenBalanceKey::range(holder, token, range))?
+            .map(|entry| entry.map(|(key, delta)| (key.block_number, delta)).map_err(Into::into))
+            .collect()
+    }
+
+    fn native_transfers(
+        &self,
+        user: Address,
+        start: (BlockNumber, u32),
+        end_block: BlockNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, NativeTransfer)>> {
+        Ok(self
+            .tx
+            .cursor_read::<tables::NativeTransfers>()?
+            .walk_range(TokenTransferKey::range(user, start, end_block))?
+            .take(limit)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+}
+
 impl<TX: DbTx + 'static, N: NodeTypesForProvider> HeaderSyncGapProvider
     for DatabaseProvider<TX, N>
 {
@@ -1701,6 +2235,18 @@ impl<TX: DbTxMut, N: NodeTypes> StageCheckpointWriter for DatabaseProvider<TX, N
                 },
             )?;
         }
+        // Optional stages are only updated if they were enabled before, so that a disabled stage
+        // doesn't claim to cover blocks that it never indexed.
+        for stage_id in StageId::OPTIONAL {
+            let Some((_, checkpoint)) = cursor.seek_exact(stage_id.to_string())? else { continue };
+            cursor.upsert(
+                stage_id.to_string(),
+                &StageCheckpoint {
+                    block_number,
+                    ..if drop_stage_checkpoint { Default::default() } else { checkpoint }
+                },
+            )?;
+        }
 
         Ok(())
     }
@@ -2056,6 +2602,19 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateWriter
         Ok(())
     }
 
+    fn write_internal_accounts(
+        &self,
+        accounts: impl IntoIterator<Item = (TxNumber, Vec<Address>)>,
+    ) -> ProviderResult<()> {
+        let mut cursor = self.tx.cursor_write::<tables::TransactionInternalAccounts>()?;
+        for (tx_number, addresses) in accounts {
+            if !addresses.is_empty() {
+                cursor.upsert(tx_number, &InternalAccounts { addresses })?;
+            }
+        }
+        Ok(())
+    }
+
     /// Remove the last N blocks of state.
     ///
     /// The latest state will be unwound
@@ -2097,8 +2656,14 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateWriter
 
         let storage_range = BlockNumberAddress::range(range.clone());
 
-        let storage_changeset = self.take::<tables::StorageChangeSets>(storage_range)?;
-        let account_changeset = self.take::<tables::AccountChangeSets>(range)?;
+        let storage_changeset = self.with_static_file_storage_changesets(
+            storage_range.clone(),
+            self.take::<tables::StorageChangeSets>(storage_range)?,
+        )?;
+        let account_changeset = self.with_static_file_account_changesets(
+            range.clone(),
+            self.take::<tables::AccountChangeSets>(range)?,
+        )?;
 
         // This is not working for blocks that are not at tip. as plain state is not the last
         // state of end range. We should rename the functions or add support to access
@@ -2147,6 +2712,10 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateWriter
         }
 
         self.remove_receipts_from(from_transaction_num, block, remove_receipts_from)?;
+        self.remove::<tables::TransactionInternalAccounts>(from_transaction_num..)?;
+        if remove_receipts_from.static_files() {
+            self.remove_static_file_changesets_above(block)?;
+        }
 
         Ok(())
     }
@@ -2195,8 +2764,14 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateWriter
 
         let storage_range = BlockNumberAddress::range(range.clone());
 
-        let storage_changeset = self.take::<tables::StorageChangeSets>(storage_range)?;
-        let account_changeset = self.take::<tables::AccountChangeSets>(range)?;
+        let storage_changeset = self.with_static_file_storage_changesets(
+            storage_range.clone(),
+            self.take::<tables::StorageChangeSets>(storage_range)?,
+        )?;
+        let account_changeset = self.with_static_file_account_changesets(
+            range.clone(),
+            self.take::<tables::AccountChangeSets>(range)?,
+        )?;
 
         // This is not working for blocks that are not at tip. as plain state is not the last
         // state of end range. We should rename the functions or add support to access
@@ -2282,6 +2857,10 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateWriter
         }
 
         self.remove_receipts_from(from_transaction_num, block, remove_receipts_from)?;
+        self.remove::<tables::TransactionInternalAccounts>(from_transaction_num..)?;
+        if remove_receipts_from.static_files() {
+            self.remove_static_file_changesets_above(block)?;
+        }
 
         Ok(ExecutionOutcome::new_init(
             state,
@@ -2414,11 +2993,7 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> HashingWriter for DatabaseProvi
         &self,
         range: impl RangeBounds<BlockNumber>,
     ) -> ProviderResult<BTreeMap<B256, Option<Account>>> {
-        let changesets = self
-            .tx
-            .cursor_read::<tables::AccountChangeSets>()?
-            .walk_range(range)?
-            .collect::<Result<Vec<_>, _>>()?;
+        let changesets = self.account_changesets_range(range)?;
         self.unwind_account_hashing(changesets.iter())
     }
 
@@ -2478,11 +3053,7 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> HashingWriter for DatabaseProvi
         &self,
         range: impl RangeBounds<BlockNumberAddress>,
     ) -> ProviderResult<HashMap<B256, BTreeSet<B256>>> {
-        let changesets = self
-            .tx
-            .cursor_read::<tables::StorageChangeSets>()?
-            .walk_range(range)?
-            .collect::<Result<Vec<_>, _>>()?;
+        let changesets = self.storage_changesets_range(range)?;
         self.unwind_storage_hashing(changesets.into_iter())
     }
 
@@ -2606,7 +3177,9 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> HashingWriter for DatabaseProvi
     }
 }
 
-impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> HistoryWriter for DatabaseProvider<TX, N> {
+impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> HistoryWriter
+    for DatabaseProvider<TX, N>
+{
     fn unwind_account_history_indices<'a>(
         &self,
         changesets: impl Iterator<Item = &'a (BlockNumber, AccountBeforeTx)>,
@@ -2645,11 +3218,7 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> HistoryWriter for DatabaseProvi
         &self,
         range: impl RangeBounds<BlockNumber>,
     ) -> ProviderResult<usize> {
-        let changesets = self
-            .tx
-            .cursor_read::<tables::AccountChangeSets>()?
-            .walk_range(range)?
-            .collect::<Result<Vec<_>, _>>()?;
+        let changesets = self.account_changesets_range(range)?;
         self.unwind_account_history_indices(changesets.iter())
     }
 
@@ -2703,11 +3272,7 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> HistoryWriter for DatabaseProvi
         &self,
         range: impl RangeBounds<BlockNumberAddress>,
     ) -> ProviderResult<usize> {
-        let changesets = self
-            .tx
-            .cursor_read::<tables::StorageChangeSets>()?
-            .walk_range(range)?
-            .collect::<Result<Vec<_>, _>>()?;
+        let changesets = self.storage_changesets_range(range)?;
         self.unwind_storage_history_indices(changesets.into_iter())
     }
 
@@ -2723,6 +3288,150 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> HistoryWriter for DatabaseProvi
         )
     }
 
+    fn unwind_address_transactions_indices_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<usize> {
+        let touched = self.touched_addresses_with_range(range)?;
+        self.unwind_history_index::<_, tables::AddressTransactions>(touched.into_iter().map(
+            |(address, tx_numbers)| {
+                (address, *tx_numbers.first().expect("touched addresses have transactions"))
+            },
+        ))
+    }
+
+    fn insert_address_transactions_index(
+        &self,
+        index_updates: impl IntoIterator<Item = (Address, impl IntoIterator<Item = TxNumber>)>,
+    ) -> ProviderResult<()> {
+        self.append_history_index::<_, tables::AddressTransactions>(index_updates, ShardedKey::new)
+    }
+
+    fn unwind_log_indices_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<usize> {
+        let LogIndexEntries { addresses, topics } = self.log_index_entries_with_range(range)?;
+        let first_position = |positions: Vec<LogPosition>| {
+            u64::from(*positions.first().expect("index entries have positions"))
+        };
+
+        let unwound_addresses = self.unwind_history_index::<_, tables::LogAddressIndex>(
+            addresses.into_iter().map(|(address, positions)| (address, first_position(positions))),
+        )?;
+        let unwound_topics = self.unwind_history_index::<_, tables::LogTopicIndex>(
+            topics.into_iter().map(|(key, positions)| (key, first_position(positions))),
+        )?;
+
+        Ok(unwound_addresses + unwound_topics)
+    }
+
+    fn insert_log_index(&self, entries: LogIndexEntries) -> ProviderResult<()> {
+        fn stored<K>((key, positions): (K, Vec<LogPosition>)) -> (K, impl Iterator<Item = u64>) {
+            (key, positions.into_iter().map(u64::from))
+        }
+
+        self.append_history_index::<_, tables::LogAddressIndex>(
+            entries.addresses.into_iter().map(stored),
+            ShardedKey::new,
+        )?;
+        self.append_history_index::<_, tables::LogTopicIndex>(
+            entries.topics.into_iter().map(stored),
+            ShardedKey::new,
+        )
+    }
+
+    fn unwind_contract_creations_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<usize> {
+        let mut creations = self.tx.cursor_write::<tables::ContractCreations>()?;
+        let mut creators = self.tx.cursor_write::<tables::ContractCreators>()?;
+        let mut count = 0;
+        let mut walker = creations.walk_range(BlockNumberAddress::range(range))?;
+        while let Some((BlockNumberAddress((block_number, address)), _)) =
+            walker.next().transpose()?
+        {
+            // Only remove the pointer if it still points to the unwound creation.
+            if creators.seek_exact(address)?.is_some_and(|(_, block)| block == block_number) {
+                creators.delete_current()?;
+            }
+            walker.delete_current()?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    fn insert_contract_creations(
+        &self,
+        creations: impl IntoIterator<Item = (BlockNumberAddress, ContractCreation)>,
+    ) -> ProviderResult<()> {
+        let mut creations_cursor = self.tx.cursor_write::<tables::ContractCreations>()?;
+        let mut creators_cursor = self.tx.cursor_write::<tables::ContractCreators>()?;
+        for (key, creation) in creations {
+            let BlockNumberAddress((block_number, address)) = key;
+            creations_cursor.upsert(key, &creation)?;
+            creators_cursor.upsert(address, &block_number)?;
+        }
+        Ok(())
+    }
+
+    fn unwind_token_transfers_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<usize> {
+        let entries = self.token_transfer_entries_with_range(range)?;
+
+        let mut holder_transfers = self.tx.cursor_write::<tables::HolderTokenTransfers>()?;
+        for (key, _) in entries.holder_transfers() {
+            if holder_transfers.seek_exact(key)?.is_some() {
+                holder_transfers.delete_current()?;
+            }
+        }
+        let mut balance_deltas = self.tx.cursor_write::<tables::TokenBalanceDeltas>()?;
+        for key in entries.balance_deltas.into_keys() {
+            if balance_deltas.seek_exact(key)?.is_some() {
+                balance_deltas.delete_current()?;
+            }
+        }
+        let mut transfers = self.tx.cursor_write::<tables::TokenTransfers>()?;
+        let mut count = 0;
+        for (key, _) in entries.transfers {
+            if transfers.seek_exact(key)?.is_some() {
+                transfers.delete_current()?;
+                count += 1;
+            }
+        }
+        let mut native_transfers = self.tx.cursor_write::<tables::NativeTransfers>()?;
+        for (key, _) in entries.native_transfers {
+            if native_transfers.seek_exact(key)?.is_some() {
+                native_transfers.delete_current()?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    fn insert_token_transfers(&self, entries: TokenTransferEntries) -> ProviderResult<()> {
+        let mut holder_transfers = self.tx.cursor_write::<tables::HolderTokenTransfers>()?;
+        for (key, transfer) in entries.holder_transfers() {
+            holder_transfers.upsert(key, &transfer)?;
+        }
+        let mut balance_deltas = self.tx.cursor_write::<tables::TokenBalanceDeltas>()?;
+        for (key, delta) in entries.balance_deltas {
+            balance_deltas.upsert(key, &delta)?;
+        }
+        let mut transfers = self.tx.cursor_write::<tables::TokenTransfers>()?;
+        for (key, transfer) in entries.transfers {
+            transfers.upsert(key, &transfer)?;
+        }
+        let mut native_transfers = self.tx.cursor_write::<tables::NativeTransfers>()?;
+        for (key, transfer) in entries.native_transfers {
+            native_transfers.upsert(key, &transfer)?;
+        }
+        Ok(())
+    }
+
     fn update_history_indices(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<()> {
         // account history stage
         {
@@ -3089,6 +3798,19 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider + 'static> BlockWrite
         durations_recorder.record_relative(metrics::Action::InsertHashes);
 
         self.update_history_indices(first_number..=last_block_number)?;
+        if self.prune_modes.address_transactions.is_none_or(|mode| !mode.is_full()) {
+            let indices = self.touched_addresses_with_range(first_number..=last_block_number)?;
+            self.insert_address_transactions_index(indices)?;
+        }
+        if self.prune_modes.log_index.is_none_or(|mode| !mode.is_full()) {
+            let entries = self.log_index_entries_with_range(first_number..=last_block_number)?;
+            self.insert_log_index(entries)?;
+        }
+        if self.prune_modes.token_transfers.is_none_or(|mode| !mode.is_full()) {
+            let entries =
+                self.token_transfer_entries_with_range(first_number..=last_block_number)?;
+            self.insert_token_transfers(entries)?;
+        }
         durations_recorder.record_relative(metrics::Action::InsertHistoryIndices);
 
         // Update pipeline progress
@@ -1,18 +1,25 @@
 use crate::{
     providers::state::macros::delegate_provider_impls, AccountReader, BlockHashReader,
     HashedPostStateProvider, ProviderError, StateProvider, StateRootProvider,
+    StaticFileProviderFactory,
 };
 use alloy_eips::merge::EPOCH_SLOTS;
-use alloy_primitives::{map::B256Map, Address, BlockNumber, Bytes, StorageKey, StorageValue, B256};
+use alloy_primitives::{
+    keccak256,
+    map::{AddressMap, B256Map},
+    Address, BlockNumber, Bytes, StorageKey, StorageValue, B256, U256,
+};
 use reth_db_api::{
     cursor::{DbCursorRO, DbDupCursorRO},
-    models::{storage_sharded_key::StorageShardedKey, ShardedKey},
+    models::{
+        storage_sharded_key::StorageShardedKey, BlockNumberAddress, ShardedKey, StorageBeforeTx,
+    },
     table::Table,
     tables,
     transaction::DbTx,
     BlockNumberList,
 };
-use reth_primitives::{Account, Bytecode};
+use reth_primitives::{Account, Bytecode, StaticFileSegment};
 use reth_storage_api::{
     BlockNumReader, DBProvider, StateCommitmentProvider, StateProofProvider, StorageRootProvider,
 };
@@ -21,8 +28,8 @@ use reth_trie::{
     proof::{Proof, StorageProof},
     updates::TrieUpdates,
     witness::TrieWitness,
-    AccountProof, HashedPostState, HashedStorage, MultiProof, MultiProofTargets, StateRoot,
-    StorageMultiProof, StorageRoot, TrieInput,
+    AccountProof, HashedPostState, HashedStorage, KeyHasher, MultiProof, MultiProofTargets,
+    StateRoot, StorageMultiProof, StorageRoot, TrieInput,
 };
 use reth_trie_db::{
     DatabaseHashedPostState, DatabaseHashedStorage, DatabaseProof, DatabaseStateRoot,
@@ -41,6 +48,10 @@ use std::fmt::Debug;
 /// - [`tables::StoragesHistory`]
 /// - [`tables::AccountChangeSets`]
 /// - [`tables::StorageChangeSets`]
+///
+/// The changesets of the blocks moved to static files are read from the
+/// [`StaticFileSegment::AccountChangeSets`] and [`StaticFileSegment::StorageChangeSets`] segments
+/// instead.
 #[derive(Debug)]
 pub struct HistoricalStateProviderRef<'b, Provider> {
     /// Database provider
@@ -59,8 +70,10 @@ pub enum HistoryInfo {
     MaybeInPlainState,
 }
 
-impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
-    HistoricalStateProviderRef<'b, Provider>
+impl<
+        'b,
+        Provider: DBProvider + BlockNumReader + StateCommitmentProvider + StaticFileProviderFactory,
+    > HistoricalStateProviderRef<'b, Provider>
 {
     /// Create new `StateProvider` for historical block number
     pub fn new(provider: &'b Provider, block_number: BlockNumber) -> Self {
@@ -134,9 +147,79 @@ impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
             );
         }
 
-        Ok(HashedPostState::from_reverts::<
-            <Provider::StateCommitment as StateCommitment>::KeyHasher,
-        >(self.tx(), self.block_number)?)
+        type KH<P> =
+            <<P as StateCommitmentProvider>::StateCommitment as StateCommitment>::KeyHasher;
+
+        // The changesets up to the highest static file block may be in static files, and the rest
+        // are in the database. Values of the earlier blocks take precedence.
+        let static_file_provider = self.provider.static_file_provider();
+        let Some(highest) =
+            [StaticFileSegment::AccountChangeSets, StaticFileSegment::StorageChangeSets]
+                .into_iter()
+                .filter_map(|segment| static_file_provider.get_highest_static_file_block(segment))
+                .max()
+                .filter(|highest| *highest >= self.block_number)
+        else {
+            return Ok(HashedPostState::from_reverts::<KH<Provider>>(self.tx(), self.block_number)?)
+        };
+
+        let mut account_changesets_cursor = self.tx().cursor_read::<tables::AccountChangeSets>()?;
+        let mut storage_changesets_cursor = self.tx().cursor_read::<tables::StorageChangeSets>()?;
+        let mut accounts = AddressMap::<Option<Account>>::default();
+        let mut storages = AddressMap::<B256Map<U256>>::default();
+        for block in self.block_number..=highest {
+            let account_changes = match static_file_provider.account_changeset(block)? {
+                Some(changeset) => changeset.changes,
+                None => account_changesets_cursor
+                    .walk_range(block..=block)?
+                    .map(|entry| entry.map(|(_, account_before)| account_before))
+                    .collect::<Result<_, _>>()?,
+            };
+            for change in account_changes {
+                accounts.entry(change.address).or_insert(change.info);
+            }
+
+            let storage_changes = match static_file_provider.storage_changeset(block)? {
+                Some(changeset) => changeset.changes,
+                None => storage_changesets_cursor
+                    .walk_range(BlockNumberAddress::range(block..=block))?
+                    .map(|entry| {
+                        entry.map(|(key, entry)| StorageBeforeTx { address: key.address(), entry })
+                    })
+                    .collect::<Result<_, _>>()?,
+            };
+            for change in storage_changes {
+                storages
+                    .entry(change.address)
+                    .or_default()
+                    .entry(change.entry.key)
+                    .or_insert(change.entry.value);
+            }
+        }
+
+        let mut revert_state =
+            HashedPostState::from_reverts::<KH<Provider>>(self.tx(), highest + 1)?;
+        revert_state.extend(HashedPostState {
+            accounts: accounts
+                .into_iter()
+                .map(|(address, info)| (KH::<Provider>::hash_key(address), info))
+                .collect(),
+            storages: storages
+                .into_iter()
+                .map(|(address, storage)| {
+                    (
+                        KH::<Provider>::hash_key(address),
+                        HashedStorage::from_iter(
+                            false,
+                            storage
+                                .into_iter()
+                                .map(|(slot, value)| (KH::<Provider>::hash_key(slot), value)),
+                        ),
+                    )
+                })
+                .collect(),
+        });
+        Ok(revert_state)
     }
 
     /// Retrieve revert hashed storage for this history provider and target address.
@@ -153,7 +236,31 @@ impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
             );
         }
 
-        Ok(HashedStorage::from_reverts(self.tx(), address, self.block_number)?)
+        let static_file_provider = self.provider.static_file_provider();
+        let Some(highest) = static_file_provider
+            .get_highest_static_file_block(StaticFileSegment::StorageChangeSets)
+            .filter(|highest| *highest >= self.block_number)
+        else {
+            return Ok(HashedStorage::from_reverts(self.tx(), address, self.block_number)?)
+        };
+
+        // Values of the earlier blocks take precedence, see `revert_state`.
+        let mut storage = B256Map::<U256>::default();
+        for block in self.block_number..=highest {
+            for change in static_file_provider
+                .storage_changeset(block)?
+                .unwrap_or_default()
+                .changes
+                .into_iter()
+                .filter(|change| change.address == address)
+            {
+                storage.entry(keccak256(change.entry.key)).or_insert(change.entry.value);
+            }
+        }
+
+        let mut revert_storage = HashedStorage::from_reverts(self.tx(), address, highest + 1)?;
+        revert_storage.extend(&HashedStorage::from_iter(false, storage));
+        Ok(revert_storage)
     }
 
     fn history_info<T, K>(
@@ -244,23 +351,38 @@ impl<Provider: DBProvider + BlockNumReader> HistoricalStateProviderRef<'_, Provi
     }
 }
 
-impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> AccountReader
-    for HistoricalStateProviderRef<'_, Provider>
+impl<
+        Provider: DBProvider + BlockNumReader + StateCommitmentProvider + StaticFileProviderFactory,
+    > AccountReader for HistoricalStateProviderRef<'_, Provider>
 {
     /// Get basic account information.
     fn basic_account(&self, address: &Address) -> ProviderResult<Option<Account>> {
         match self.account_history_lookup(*address)? {
             HistoryInfo::NotYetWritten => Ok(None),
-            HistoryInfo::InChangeset(changeset_block_number) => Ok(self
-                .tx()
-                .cursor_dup_read::<tables::AccountChangeSets>()?
-                .seek_by_key_subkey(changeset_block_number, *address)?
-                .filter(|acc| &acc.address == address)
-                .ok_or(ProviderError::AccountChangesetNotFound {
-                    block_number: changeset_block_number,
-                    address: *address,
-                })?
-                .info),
+            HistoryInfo::InChangeset(changeset_block_number) => {
+                let account_before = if let Some(mut changeset) = self
+                    .provider
+                    .static_file_provider()
+                    .account_changeset(changeset_block_number)?
+                {
+                    changeset
+                        .changes
+                        .binary_search_by_key(address, |change| change.address)
+                        .ok()
+                        .map(|index| changeset.changes.swap_remove(index))
+                } else {
+                    self.tx()
+                        .cursor_dup_read::<tables::AccountChangeSets>()?
+                        .seek_by_key_subkey(changeset_block_number, *address)?
+                        .filter(|acc| &acc.address == address)
+                };
+                Ok(account_before
+                    .ok_or(ProviderError::AccountChangesetNotFound {
+                        block_number: changeset_block_number,
+                        address: *address,
+                    })?
+                    .info)
+            }
             HistoryInfo::InPlainState | HistoryInfo::MaybeInPlainState => {
                 Ok(self.tx().get_by_encoded_key::<tables::PlainAccountState>(address)?)
             }
@@ -285,8 +407,9 @@ impl<Provider: DBProvider + BlockNumReader + BlockHashReader> BlockHashReader
     }
 }
 
-impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> StateRootProvider
-    for HistoricalStateProviderRef<'_, Provider>
+impl<
+        Provider: DBProvider + BlockNumReader + StateCommitmentProvider + StaticFileProviderFactory,
+    > StateRootProvider for HistoricalStateProviderRef<'_, Provider>
 {
     fn state_root(&self, hashed_state: HashedPostState) -> ProviderResult<B256> {
         let mut revert_state = self.revert_state()?;
@@ -321,8 +444,9 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> StateRootP
     }
 }
 
-impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> StorageRootProvider
-    for HistoricalStateProviderRef<'_, Provider>
+impl<
+        Provider: DBProvider + BlockNumReader + StateCommitmentProvider + StaticFileProviderFactory,
+    > StorageRootProvider for HistoricalStateProviderRef<'_, Provider>
 {
     fn storage_root(
         &self,
@@ -360,8 +484,9 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> StorageRoo
     }
 }
 
-impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> StateProofProvider
-    for HistoricalStateProviderRef<'_, Provider>
+impl<
+        Provider: DBProvider + BlockNumReader + StateCommitmentProvider + StaticFileProviderFactory,
+    > StateProofProvider for HistoricalStateProviderRef<'_, Provider>
 {
     /// Get account and storage proofs.
     fn proof(
@@ -403,8 +528,13 @@ impl<Provider: StateCommitmentProvider> HashedPostStateProvider
     }
 }
 
-impl<Provider: DBProvider + BlockNumReader + BlockHashReader + StateCommitmentProvider>
-    StateProvider for HistoricalStateProviderRef<'_, Provider>
+impl<
+        Provider: DBProvider
+            + BlockNumReader
+            + BlockHashReader
+            + StateCommitmentProvider
+            + StaticFileProviderFactory,
+    > StateProvider for HistoricalStateProviderRef<'_, Provider>
 {
     /// Get storage.
     fn storage(
@@ -414,18 +544,35 @@ impl<Provider: DBProvider + BlockNumReader + BlockHashReader + StateCommitmentPr
     ) -> ProviderResult<Option<StorageValue>> {
         match self.storage_history_lookup(address, storage_key)? {
             HistoryInfo::NotYetWritten => Ok(None),
-            HistoryInfo::InChangeset(changeset_block_number) => Ok(Some(
-                self.tx()
-                    .cursor_dup_read::<tables::StorageChangeSets>()?
-                    .seek_by_key_subkey((changeset_block_number, address).into(), storage_key)?
-                    .filter(|entry| entry.key == storage_key)
-                    .ok_or_else(|| ProviderError::StorageChangesetNotFound {
-                        block_number: changeset_block_number,
-                        address,
-                        storage_key: Box::new(storage_key),
-                    })?
-                    .value,
-            )),
+            HistoryInfo::InChangeset(changeset_block_number) => {
+                let entry = if let Some(changeset) = self
+                    .provider
+                    .static_file_provider()
+                    .storage_changeset(changeset_block_number)?
+                {
+                    changeset
+                        .changes
+                        .binary_search_by_key(&(address, storage_key), |change| {
+                            (change.address, change.entry.key)
+                        })
+                        .ok()
+                        .map(|index| changeset.changes[index].entry)
+                } else {
+                    self.tx()
+                        .cursor_dup_read::<tables::StorageChangeSets>()?
+                        .seek_by_key_subkey((changeset_block_number, address).into(), storage_key)?
+                        .filter(|entry| entry.key == storage_key)
+                };
+                Ok(Some(
+                    entry
+                        .ok_or_else(|| ProviderError::StorageChangesetNotFound {
+                            block_number: changeset_block_number,
+                            address,
+                            storage_key: Box::new(storage_key),
+                        })?
+                        .value,
+                ))
+            }
             HistoryInfo::InPlainState | HistoryInfo::MaybeInPlainState => Ok(self
                 .tx()
                 .cursor_dup_read::<tables::PlainStorageState>()?
@@ -460,8 +607,9 @@ pub struct HistoricalStateProvider<Provider> {
     lowest_available_blocks: LowestAvailableBlocks,
 }
 
-impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
-    HistoricalStateProvider<Provider>
+impl<
+        Provider: DBProvider + BlockNumReader + StateCommitmentProvider + StaticFileProviderFactory,
+    > HistoricalStateProvider<Provider>
 {
     /// Create new `StateProvider` for historical block number
     pub fn new(provider: Provider, block_number: BlockNumber) -> Self {
@@ -504,7 +652,7 @@ impl<Provider: StateCommitmentProvider> StateCommitmentProvider
 }
 
 // Delegates all provider impls to [HistoricalStateProviderRef]
-delegate_provider_impls!(HistoricalStateProvider<Provider> where [Provider: DBProvider + BlockNumReader + BlockHashReader + StateCommitmentProvider]);
+delegate_provider_impls!(HistoricalStateProvider<Provider> where [Provider: DBProvider + BlockNumReader + BlockHashReader + StateCommitmentProvider + StaticFileProviderFactory]);
 
 /// Lowest blocks at which different parts of the state are available.
 /// They may be [Some] if pruning is enabled.
@@ -537,18 +685,25 @@ impl LowestAvailableBlocks {
 #[cfg(test)]
 mod tests {
     use crate::{
-        providers::state::historical::{HistoryInfo, LowestAvailableBlocks},
+        providers::{
+            state::historical::{HistoryInfo, LowestAvailableBlocks},
+            StaticFileWriter,
+        },
         test_utils::create_test_provider_factory,
         AccountReader, HistoricalStateProvider, HistoricalStateProviderRef, StateProvider,
+        StaticFileProviderFactory,
     };
     use alloy_primitives::{address, b256, Address, B256, U256};
     use reth_db_api::{
-        models::{storage_sharded_key::StorageShardedKey, AccountBeforeTx, ShardedKey},
+        models::{
+            storage_sharded_key::StorageShardedKey, AccountBeforeTx, ShardedKey,
+            StaticFileAccountChangeSet, StaticFileStorageChangeSet, StorageBeforeTx,
+        },
         tables,
         transaction::{DbTx, DbTxMut},
         BlockNumberList,
     };
-    use reth_primitives::{Account, StorageEntry};
+    use reth_primitives::{Account, StaticFileSegment, StorageEntry};
     use reth_storage_api::{
         BlockHashReader, BlockNumReader, DBProvider, DatabaseProviderFactory,
         StateCommitmentProvider,
@@ -562,7 +717,11 @@ mod tests {
     const fn assert_state_provider<T: StateProvider>() {}
     #[allow(dead_code)]
     const fn assert_historical_state_provider<
-        T: DBProvider + BlockNumReader + BlockHashReader + StateCommitmentProvider,
+        T: DBProvider
+            + BlockNumReader
+            + BlockHashReader
+            + StateCommitmentProvider
+            + StaticFileProviderFactory,
     >() {
         assert_state_provider::<HistoricalStateProvider<T>>();
     }
@@ -775,6 +934,88 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn history_provider_get_from_static_files() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+
+        tx.put::<tables::AccountsHistory>(
+            ShardedKey { key: ADDRESS, highest_block_number: u64::MAX },
+            BlockNumberList::new([3]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::StoragesHistory>(
+            StorageShardedKey {
+                address: ADDRESS,
+                sharded_key: ShardedKey { key: STORAGE, highest_block_number: u64::MAX },
+            },
+            BlockNumberList::new([3]).unwrap(),
+        )
+        .unwrap();
+
+        let acc_plain = Account { nonce: 100, balance: U256::ZERO, bytecode_hash: None };
+        let acc_at3 = Account { nonce: 3, balance: U256::ZERO, bytecode_hash: None };
+        let entry_plain = StorageEntry { key: STORAGE, value: U256::from(100) };
+        let entry_at3 = StorageEntry { key: STORAGE, value: U256::from(3) };
+
+        // setup plain state, the changesets are only in static files
+        tx.put::<tables::PlainAccountState>(ADDRESS, acc_plain).unwrap();
+        tx.put::<tables::PlainStorageState>(ADDRESS, entry_plain).unwrap();
+        tx.commit().unwrap();
+
+        let static_file_provider = factory.static_file_provider();
+        let mut account_writer =
+            static_file_provider.latest_writer(StaticFileSegment::AccountChangeSets).unwrap();
+        let mut storage_writer =
+            static_file_provider.latest_writer(StaticFileSegment::StorageChangeSets).unwrap();
+        for block in 0..3 {
+            account_writer
+                .append_account_changeset(&StaticFileAccountChangeSet::default(), block)
+                .unwrap();
+            storage_writer
+                .append_storage_changeset(&StaticFileStorageChangeSet::default(), block)
+                .unwrap();
+        }
+        account_writer
+            .append_account_changeset(
+                &StaticFileAccountChangeSet {
+                    changes: vec![AccountBeforeTx { address: ADDRESS, info: Some(acc_at3) }],
+                },
+                3,
+            )
+            .unwrap();
+        storage_writer
+            .append_storage_changeset(
+                &StaticFileStorageChangeSet {
+                    changes: vec![StorageBeforeTx { address: ADDRESS, entry: entry_at3 }],
+                },
+                3,
+            )
+            .unwrap();
+        account_writer.commit().unwrap();
+        storage_writer.commit().unwrap();
+
+        let db = factory.provider().unwrap();
+
+        // run
+        assert!(matches!(
+            HistoricalStateProviderRef::new(&db, 2).basic_account(&ADDRESS),
+            Ok(Some(value)) if value == acc_at3
+        ));
+        assert!(matches!(
+            HistoricalStateProviderRef::new(&db, 2).storage(ADDRESS, STORAGE),
+            Ok(Some(value)) if value == entry_at3.value
+        ));
+        assert!(matches!(
+            HistoricalStateProviderRef::new(&db, 4).basic_account(&ADDRESS),
+            Ok(Some(value)) if value == acc_plain
+        ));
+        assert!(matches!(
+            HistoricalStateProviderRef::new(&db, 4).storage(ADDRESS, STORAGE),
+            Ok(Some(value)) if value == entry_plain.value
+        ));
+    }
+
     #[test]
     fn history_provider_unavailable() {
         let factory = create_test_provider_factory();
@@ -19,13 +19,16 @@ use reth_chainspec::{ChainInfo, ChainSpecProvider, EthChainSpec};
 use reth_db::{
     lockfile::StorageLock,
     static_file::{
-        iter_static_files, BlockHashMask, BodyIndicesMask, HeaderMask, HeaderWithHashMask,
-        ReceiptMask, StaticFileCursor, TDWithHashMask, TransactionMask,
+        iter_static_files, AccountChangeSetMask, BlockHashMask, BodyIndicesMask, HeaderMask,
+        HeaderWithHashMask, HlSidecarMask, ReceiptMask, StaticFileCursor, StorageChangeSetMask,
+        TDWithHashMask, TransactionMask,
     },
 };
 use reth_db_api::{
     cursor::DbCursorRO,
-    models::StoredBlockBodyIndices,
+    models::{
+        HlSidecar, StaticFileAccountChangeSet, StaticFileStorageChangeSet, StoredBlockBodyIndices,
+    },
     table::{Decompress, Table, Value},
     tables,
     transaction::DbTx,
@@ -727,7 +730,8 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
             // The updated `highest_block` may have decreased if we healed from a pruning
             // interruption.
             let mut highest_block = self.get_highest_static_file_block(segment);
-            if initial_highest_block != highest_block {
+            // Healed sidecar rows are copied again from the database, without unwinding the chain.
+            if initial_highest_block != highest_block && !segment.is_hl_sidecars() {
                 info!(
                     target: "reth::providers::static_file",
                     ?initial_highest_block,
@@ -803,6 +807,12 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
                         highest_block,
                         highest_block,
                     )?,
+                StaticFileSegment::AccountChangeSets | StaticFileSegment::StorageChangeSets => {
+                    self.ensure_changeset_invariants(provider, segment, highest_block)?
+                }
+                // Sidecars are recorded per block number independently of the executed state, so
+                // they stay valid across unwinds.
+                StaticFileSegment::HlSidecars => None,
             } {
                 update_unwind_target(unwind);
             }
@@ -889,7 +899,10 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
             .get_stage_checkpoint(match segment {
                 StaticFileSegment::Headers => StageId::Headers,
                 StaticFileSegment::Transactions | StaticFileSegment::BlockMeta => StageId::Bodies,
-                StaticFileSegment::Receipts => StageId::Execution,
+                StaticFileSegment::Receipts |
+                StaticFileSegment::AccountChangeSets |
+                StaticFileSegment::StorageChangeSets |
+                StaticFileSegment::HlSidecars => StageId::Execution,
             })?
             .unwrap_or_default()
             .block_number;
@@ -937,6 +950,42 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         Ok(None)
     }
 
+    /// Check invariants of the changeset static file segments.
+    ///
+    /// Changesets are only ever copied from the database, so the static files can't hold data
+    /// the database never had. However, if the execution checkpoint is behind the highest static
+    /// file block, the blocks were unwound from the database after being copied, and the extra
+    /// static file rows are removed.
+    fn ensure_changeset_invariants<Provider>(
+        &self,
+        provider: &Provider,
+        segment: StaticFileSegment,
+        highest_static_file_block: Option<BlockNumber>,
+    ) -> ProviderResult<Option<BlockNumber>>
+    where
+        Provider: StageCheckpointReader,
+    {
+        let Some(highest_static_file_block) = highest_static_file_block else { return Ok(None) };
+
+        let checkpoint_block_number =
+            provider.get_stage_checkpoint(StageId::Execution)?.unwrap_or_default().block_number;
+
+        if checkpoint_block_number < highest_static_file_block {
+            info!(
+                target: "reth::providers",
+                ?segment,
+                from = highest_static_file_block,
+                to = checkpoint_block_number,
+                "Unwinding static file segment."
+            );
+            let mut writer = self.latest_writer(segment)?;
+            writer.prune_changesets(highest_static_file_block - checkpoint_block_number)?;
+            writer.commit()?;
+        }
+
+        Ok(None)
+    }
+
     /// Gets the highest static file block if it exists for a static file segment.
     ///
     /// If there is nothing on disk for the given segment, this will return [`None`].
@@ -961,9 +1010,59 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
             receipts: self.get_highest_static_file_block(StaticFileSegment::Receipts),
             transactions: self.get_highest_static_file_block(StaticFileSegment::Transactions),
             block_meta: self.get_highest_static_file_block(StaticFileSegment::BlockMeta),
+            account_changesets: self
+                .get_highest_static_file_block(StaticFileSegment::AccountChangeSets),
+            storage_changesets: self
+                .get_highest_static_file_block(StaticFileSegment::StorageChangeSets),
+            hl_sidecars: self.get_highest_static_file_block(StaticFileSegment::HlSidecars),
         }
     }
 
+    /// Returns the account changeset of the block, or [`None`] if it isn't in static files.
+    pub fn account_changeset(
+        &self,
+        block: BlockNumber,
+    ) -> ProviderResult<Option<StaticFileAccountChangeSet>> {
+        self.get_segment_provider_from_block(StaticFileSegment::AccountChangeSets, block, None)
+            .and_then(|provider| provider.cursor()?.get_one::<AccountChangeSetMask>(block.into()))
+            .or_else(|err| {
+                if let ProviderError::MissingStaticFileBlock(_, _) = err {
+                    Ok(None)
+                } else {
+                    Err(err)
+                }
+            })
+    }
+
+    /// Returns the storage changeset of the block, or [`None`] if it isn't in static files.
+    pub fn storage_changeset(
+        &self,
+        block: BlockNumber,
+    ) -> ProviderResult<Option<StaticFileStorageChangeSet>> {
+        self.get_segment_provider_from_block(StaticFileSegment::StorageChangeSets, block, None)
+            .and_then(|provider| provider.cursor()?.get_one::<StorageChangeSetMask>(block.into()))
+            .or_else(|err| {
+                if let ProviderError::MissingStaticFileBlock(_, _) = err {
+                    Ok(None)
+                } else {
+                    Err(err)
+                }
+            })
+    }
+
+    /// Returns the Hyperliquid sidecar of the block, or [`None`] if it isn't in static files.
+    pub fn hl_sidecar(&self, block: BlockNumber) -> ProviderResult<Option<HlSidecar>> {
+        self.get_segment_provider_from_block(StaticFileSegment::HlSidecars, block, None)
+            .and_then(|provider| provider.cursor()?.get_one::<HlSidecarMask>(block.into()))
+            .or_else(|err| {
+                if let ProviderError::MissingStaticFileBlock(_, _) = err {
+                    Ok(None)
+                } else {
+                    Err(err)
+                }
+            })
+    }
+
     /// Iterates through segment `static_files` in reverse order, executing a function until it
     /// returns some object. Useful for finding objects by [`TxHash`] or [`BlockHash`].
     pub fn find_static_file<T>(
@@ -7,7 +7,8 @@ use alloy_primitives::{BlockHash, BlockNumber, TxNumber, U256};
 use parking_lot::{lock_api::RwLockWriteGuard, RawRwLock, RwLock};
 use reth_codecs::Compact;
 use reth_db_api::models::{
-    CompactU256, StoredBlockBodyIndices, StoredBlockOmmers, StoredBlockWithdrawals,
+    CompactU256, HlSidecar, StaticFileAccountChangeSet, StaticFileStorageChangeSet,
+    StoredBlockBodyIndices, StoredBlockOmmers, StoredBlockWithdrawals,
 };
 use reth_nippy_jar::{NippyJar, NippyJarError, NippyJarWriter};
 use reth_node_types::NodePrimitives;
@@ -19,12 +20,16 @@ use reth_storage_errors::provider::{ProviderError, ProviderResult, StaticFileWri
 use std::{
     borrow::Borrow,
     fmt::Debug,
+    ops::RangeInclusive,
     path::{Path, PathBuf},
     sync::{Arc, Weak},
     time::Instant,
 };
 use tracing::debug;
 
+/// Maximum size of the zstd dictionary of a [`StaticFileSegment::HlSidecars`] static file.
+const HL_SIDECARS_MAX_DICTIONARY_SIZE: usize = 64 * 1024;
+
 /// Static file writers for every known [`StaticFileSegment`].
 ///
 /// WARNING: Trying to use more than one writer for the same segment type **will result in a
@@ -35,6 +40,9 @@ pub(crate) struct StaticFileWriters<N> {
     transactions: RwLock<Option<StaticFileProviderRW<N>>>,
     receipts: RwLock<Option<StaticFileProviderRW<N>>>,
     block_meta: RwLock<Option<StaticFileProviderRW<N>>>,
+    account_changesets: RwLock<Option<StaticFileProviderRW<N>>>,
+    storage_changesets: RwLock<Option<StaticFileProviderRW<N>>>,
+    hl_sidecars: RwLock<Option<StaticFileProviderRW<N>>>,
 }
 
 impl<N> Default for StaticFileWriters<N> {
@@ -44,6 +52,9 @@ impl<N> Default for StaticFileWriters<N> {
             transactions: Default::default(),
             receipts: Default::default(),
             block_meta: Default::default(),
+            account_changesets: Default::default(),
+            storage_changesets: Default::default(),
+            hl_sidecars: Default::default(),
         }
     }
 }
@@ -59,6 +70,9 @@ impl<N: NodePrimitives> StaticFileWriters<N> {
             StaticFileSegment::Transactions => self.transactions.write(),
             StaticFileSegment::Receipts => self.receipts.write(),
             StaticFileSegment::BlockMeta => self.block_meta.write(),
+            StaticFileSegment::AccountChangeSets => self.account_changesets.write(),
+            StaticFileSegment::StorageChangeSets => self.storage_changesets.write(),
+            StaticFileSegment::HlSidecars => self.hl_sidecars.write(),
         };
 
         if write_guard.is_none() {
@@ -69,7 +83,14 @@ impl<N: NodePrimitives> StaticFileWriters<N> {
     }
 
     pub(crate) fn commit(&self) -> ProviderResult<()> {
-        for writer_lock in [&self.headers, &self.transactions, &self.receipts] {
+        for writer_lock in [
+            &self.headers,
+            &self.transactions,
+            &self.receipts,
+            &self.account_changesets,
+            &self.storage_changesets,
+            &self.hl_sidecars,
+        ] {
             let mut writer = writer_lock.write();
             if let Some(writer) = writer.as_mut() {
                 writer.commit()?;
@@ -204,7 +225,7 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
     /// [`NippyJarWriter`] for more on healing.
     fn ensure_end_range_consistency(&mut self) -> ProviderResult<()> {
         // If we have lost rows (in this run or previous), we need to update the [SegmentHeader].
-        let expected_rows = if self.user_header().segment().is_headers() {
+        let expected_rows = if self.user_header().segment().is_block_based() {
             self.user_header().block_len().unwrap_or_default()
         } else {
             self.user_header().tx_len().unwrap_or_default()
@@ -235,6 +256,9 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
                     self.prune_receipt_data(to_delete, last_block_number.expect("should exist"))?
                 }
                 StaticFileSegment::BlockMeta => todo!(),
+                StaticFileSegment::AccountChangeSets |
+                StaticFileSegment::StorageChangeSets |
+                StaticFileSegment::HlSidecars => self.prune_block_row_data(to_delete)?,
             }
         }
 
@@ -408,12 +432,12 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
                 let block_start = self.writer.user_header().expected_block_start();
 
                 // We only delete the file if it's NOT the first static file AND:
-                // * it's a Header segment  OR
+                // * it's a block-based segment  OR
                 // * it's a tx-based segment AND `last_block` is lower than the first block of this
                 //   file's block range. Otherwise, having no rows simply means that this block
                 //   range has no transactions, but the file should remain.
                 if block_start != 0 &&
-                    (segment.is_headers() || last_block.is_some_and(|b| b < block_start))
+                    (segment.is_block_based() || last_block.is_some_and(|b| b < block_start))
                 {
                     self.delete_current_and_open_previous()?;
                 } else {
@@ -604,6 +628,102 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
         Ok(())
     }
 
+    /// Appends the account changeset of a block to static file.
+    ///
+    /// It **CALLS** `increment_block()` since it's a block based segment.
+    pub fn append_account_changeset(
+        &mut self,
+        changeset: &StaticFileAccountChangeSet,
+        expected_block_number: BlockNumber,
+    ) -> ProviderResult<()> {
+        debug_assert!(self.writer.user_header().segment() == StaticFileSegment::AccountChangeSets);
+        self.append_block_row(changeset, expected_block_number)
+    }
+
+    /// Appends the storage changeset of a block to static file.
+    ///
+    /// It **CALLS** `increment_block()` since it's a block based segment.
+    pub fn append_storage_changeset(
+        &mut self,
+        changeset: &StaticFileStorageChangeSet,
+        expected_block_number: BlockNumber,
+    ) -> ProviderResult<()> {
+        debug_assert!(self.writer.user_header().segment() == StaticFileSegment::StorageChangeSets);
+        self.append_block_row(changeset, expected_block_number)
+    }
+
+    /// Appends the Hyperliquid sidecar of a block to static file.
+    ///
+    /// The zstd dictionary of a new static file is trained before its first sidecar is appended,
+    /// on the sidecars returned by `dictionary_samples` for the block range of the file.
+    ///
+    /// It **CALLS** `increment_block()` since it's a block based segment.
+    pub fn append_hl_sidecar<F>(
+        &mut self,
+        sidecar: &HlSidecar,
+        expected_block_number: BlockNumber,
+        dictionary_samples: F,
+    ) -> ProviderResult<()>
+    where
+        F: FnOnce(RangeInclusive<BlockNumber>) -> ProviderResult<Vec<HlSidecar>>,
+    {
+        let start = Instant::now();
+        self.ensure_no_queued_prune()?;
+
+        let segment = self.writer.user_header().segment();
+        debug_assert!(segment == StaticFileSegment::HlSidecars);
+        self.increment_block(expected_block_number)?;
+
+        if !self.writer.is_compression_ready() {
+            let header = self.writer.user_header();
+            let samples =
+                dictionary_samples(header.expected_block_start()..=header.expected_block_end())?
+                    .iter()
+                    .map(|sidecar| {
+                        let mut buf = Vec::new();
+                        sidecar.to_compact(&mut buf);
+                        buf
+                    })
+                    .collect::<Vec<_>>();
+            self.writer.prepare_compression(vec![samples]).map_err(ProviderError::other)?;
+        }
+        self.append_column(sidecar)?;
+
+        if let Some(metrics) = &self.metrics {
+            metrics.record_segment_operation(
+                segment,
+                StaticFileProviderOperation::Append,
+                Some(start.elapsed()),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Appends the data of a block as a single row.
+    fn append_block_row<T: Compact>(
+        &mut self,
+        value: &T,
+        expected_block_number: BlockNumber,
+    ) -> ProviderResult<()> {
+        let start = Instant::now();
+        self.ensure_no_queued_prune()?;
+
+        let segment = self.writer.user_header().segment();
+        self.increment_block(expected_block_number)?;
+        self.append_column(value)?;
+
+        if let Some(metrics) = &self.metrics {
+            metrics.record_segment_operation(
+                segment,
+                StaticFileProviderOperation::Append,
+                Some(start.elapsed()),
+            );
+        }
+
+        Ok(())
+    }
+
     /// Appends transaction to static file.
     ///
     /// It **DOES NOT CALL** `increment_block()`, it should be handled elsewhere. There might be
@@ -737,6 +857,19 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
         self.queue_prune(to_delete, None)
     }
 
+    /// Adds an instruction to prune the changesets of the last `to_delete` blocks during commit.
+    pub fn prune_changesets(&mut self, to_delete: u64) -> ProviderResult<()> {
+        debug_assert!(self.writer.user_header().segment().is_changesets());
+        self.queue_prune(to_delete, None)
+    }
+
+    /// Adds an instruction to prune the Hyperliquid sidecars of the last `to_delete` blocks during
+    /// commit.
+    pub fn prune_hl_sidecars(&mut self, to_delete: u64) -> ProviderResult<()> {
+        debug_assert_eq!(self.writer.user_header().segment(), StaticFileSegment::HlSidecars);
+        self.queue_prune(to_delete, None)
+    }
+
     /// Adds an instruction to prune `to_delete` elements during commit.
     ///
     /// Note: `last_block` refers to the block the unwinds ends at if dealing with transaction-based
@@ -826,6 +959,26 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
         Ok(())
     }
 
+    /// Prunes the single row data of the last `to_delete` blocks from the data file.
+    fn prune_block_row_data(&mut self, to_delete: u64) -> ProviderResult<()> {
+        let start = Instant::now();
+
+        let segment = self.writer.user_header().segment();
+        debug_assert!(segment.is_changesets() || segment.is_hl_sidecars());
+
+        self.truncate(to_delete, None)?;
+
+        if let Some(metrics) = &self.metrics {
+            metrics.record_segment_operation(
+                segment,
+                StaticFileProviderOperation::Prune,
+                Some(start.elapsed()),
+            );
+        }
+
+        Ok(())
+    }
+
     fn reader(&self) -> StaticFileProvider<N> {
         Self::upgrade_provider_to_strong_reference(&self.reader)
     }
@@ -880,8 +1033,13 @@ fn create_jar(
 
     // Transaction and Receipt already have the compression scheme used natively in its encoding.
     // (zstd-dictionary)
-    if segment.is_headers() {
+    if segment.is_headers() || segment.is_changesets() {
         jar = jar.with_lz4();
+    } else if segment.is_hl_sidecars() {
+        // Sidecars are large msgpack payloads, which compress better with zstd and a dictionary
+        // trained on the first sidecars of the static file, see
+        // `StaticFileProviderRW::append_hl_sidecar`.
+        jar = jar.with_zstd(true, HL_SIDECARS_MAX_DICTIONARY_SIZE);
     }
 
     jar
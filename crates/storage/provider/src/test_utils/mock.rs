@@ -18,7 +18,10 @@ use parking_lot::Mutex;
 use reth_chainspec::{ChainInfo, EthChainSpec};
 use reth_db_api::{
     mock::{DatabaseMock, TxMock},
-    models::{AccountBeforeTx, StoredBlockBodyIndices},
+    models::{
+        AccountBeforeTx, BlockNumberAddress, ContractCreation, HlSidecar, LogPosition, LogTopicKey,
+        NativeTransfer, StoredBlockBodyIndices, TokenBalanceDelta, TokenTransfer, TokenTransferKey,
+    },
 };
 use reth_execution_types::ExecutionOutcome;
 use reth_node_types::NodeTypes;
@@ -30,9 +33,10 @@ use reth_primitives_traits::SignedTransaction;
 use reth_prune_types::PruneModes;
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_storage_api::{
-    BlockBodyIndicesProvider, DBProvider, DatabaseProviderFactory, HashedPostStateProvider,
-    OmmersProvider, StageCheckpointReader, StateCommitmentProvider, StateProofProvider,
-    StorageRootProvider,
+    AddressTransactionsReader, BlockBodyIndicesProvider, ContractCreatorsReader, DBProvider,
+    DatabaseProviderFactory, HashedPostStateProvider, HlSidecarReader, LogIndexEntries,
+    LogIndexReader, OmmersProvider, StageCheckpointReader, StateCommitmentProvider,
+    StateProofProvider, StorageRootProvider, TokenTransferEntries, TokenTransfersReader,
 };
 use reth_storage_errors::provider::{ConsistentViewError, ProviderError, ProviderResult};
 use reth_trie::{
@@ -888,6 +892,147 @@ impl<T: Transaction, ChainSpec: EthChainSpec> BlockBodyIndicesProvider
     }
 }
 
+impl<T: Transaction, ChainSpec: EthChainSpec> AddressTransactionsReader
+    for MockEthProvider<T, ChainSpec>
+{
+    fn touched_addresses_with_range(
+        &self,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<BTreeMap<Address, Vec<TxNumber>>> {
+        Ok(BTreeMap::default())
+    }
+
+    fn address_transactions_before(
+        &self,
+        _address: Address,
+        _tx_number: TxNumber,
+        _limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        Ok(Vec::default())
+    }
+
+    fn address_transactions_after(
+        &self,
+        _address: Address,
+        _tx_number: TxNumber,
+        _limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        Ok(Vec::default())
+    }
+}
+
+impl<T: Transaction, ChainSpec: EthChainSpec> HlSidecarReader for MockEthProvider<T, ChainSpec> {
+    fn hl_sidecar(&self, _block: BlockNumber) -> ProviderResult<Option<HlSidecar>> {
+        Ok(None)
+    }
+}
+
+impl<T: Transaction, ChainSpec: EthChainSpec> ContractCreatorsReader
+    for MockEthProvider<T, ChainSpec>
+{
+    fn contract_creations_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(None)
+    }
+
+    fn contract_creation(
+        &self,
+        _address: Address,
+    ) -> ProviderResult<Option<(BlockNumber, ContractCreation)>> {
+        Ok(None)
+    }
+
+    fn contract_creations_by_block_range(
+        &self,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumberAddress, ContractCreation)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl<T: Transaction, ChainSpec: EthChainSpec> LogIndexReader for MockEthProvider<T, ChainSpec> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(None)
+    }
+
+    fn log_index_entries_with_range(
+        &self,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<LogIndexEntries> {
+        Ok(LogIndexEntries::default())
+    }
+
+    fn log_address_positions(
+        &self,
+        _address: Address,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<LogPosition>> {
+        Ok(Vec::default())
+    }
+
+    fn log_topic_positions(
+        &self,
+        _key: LogTopicKey,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<LogPosition>> {
+        Ok(Vec::default())
+    }
+}
+
+impl<T: Transaction, ChainSpec: EthChainSpec> TokenTransfersReader
+    for MockEthProvider<T, ChainSpec>
+{
+    fn token_transfers_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(None)
+    }
+
+    fn token_transfer_entries_with_range(
+        &self,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<TokenTransferEntries> {
+        Ok(TokenTransferEntries::default())
+    }
+
+    fn token_transfers(
+        &self,
+        _token: Address,
+        _start: (BlockNumber, u32),
+        _end_block: BlockNumber,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, TokenTransfer)>> {
+        Ok(Vec::default())
+    }
+
+    fn holder_token_transfers(
+        &self,
+        _holder: Address,
+        _token: Option<Address>,
+        _start: (BlockNumber, u32),
+        _end_block: BlockNumber,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, TokenTransfer)>> {
+        Ok(Vec::default())
+    }
+
+    fn token_balance_deltas(
+        &self,
+        _holder: Address,
+        _token: Address,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumber, TokenBalanceDelta)>> {
+        Ok(Vec::default())
+    }
+
+    fn native_transfers(
+        &self,
+        _user: Address,
+        _start: (BlockNumber, u32),
+        _end_block: BlockNumber,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, NativeTransfer)>> {
+        Ok(Vec::default())
+    }
+}
+
 impl<T: Transaction, ChainSpec: EthChainSpec> ChangeSetReader for MockEthProvider<T, ChainSpec> {
     fn account_block_changeset(
         &self,
@@ -1,9 +1,10 @@
 //! Helper provider traits to encapsulate all provider traits for simplicity.
 
 use crate::{
-    AccountReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader, DatabaseProviderFactory,
-    HeaderProvider, StageCheckpointReader, StateProviderFactory, StaticFileProviderFactory,
-    TransactionsProvider,
+    AccountReader, AddressTransactionsReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader,
+    ContractCreatorsReader, DatabaseProviderFactory, HeaderProvider, HlSidecarReader,
+    LogIndexReader, StageCheckpointReader, StateProviderFactory, StaticFileProviderFactory,
+    TokenTransfersReader, TransactionsProvider,
 };
 use reth_chain_state::{CanonStateSubscriptions, ForkChoiceSubscriptions};
 use reth_chainspec::EthereumHardforks;
@@ -24,6 +25,11 @@ pub trait FullProvider<N: NodeTypesWithDB>:
     + StateProviderFactory
     + ChainSpecProvider<ChainSpec = N::ChainSpec>
     + ChangeSetReader
+    + AddressTransactionsReader
+    + LogIndexReader
+    + ContractCreatorsReader
+    + TokenTransfersReader
+    + HlSidecarReader
     + CanonStateSubscriptions
     + ForkChoiceSubscriptions<Header = HeaderTy<N>>
     + StageCheckpointReader
@@ -46,6 +52,10 @@ impl<T, N: NodeTypesWithDB> FullProvider<N> for T where
         + StateProviderFactory
         + ChainSpecProvider<ChainSpec = N::ChainSpec>
         + ChangeSetReader
+        + AddressTransactionsReader
+        + LogIndexReader
+        + ContractCreatorsReader
+        + HlSidecarReader
         + CanonStateSubscriptions
         + ForkChoiceSubscriptions<Header = HeaderTy<N>>
         + StageCheckpointReader
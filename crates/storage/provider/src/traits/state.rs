@@ -1,4 +1,4 @@
-use alloy_primitives::BlockNumber;
+use alloy_primitives::{Address, BlockNumber, TxNumber};
 use reth_execution_types::ExecutionOutcome;
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie::HashedPostStateSorted;
@@ -38,6 +38,13 @@ pub trait StateWriter {
     /// Writes the hashed state changes to the database
     fn write_hashed_state(&self, hashed_state: &HashedPostStateSorted) -> ProviderResult<()>;
 
+    /// Writes the accounts changed by the internal calls of each transaction to the database.
+    /// Transactions without any are skipped.
+    fn write_internal_accounts(
+        &self,
+        accounts: impl IntoIterator<Item = (TxNumber, Vec<Address>)>,
+    ) -> ProviderResult<()>;
+
     /// Remove the block range of state above the given block. The state of the passed block is not
     /// removed.
     fn remove_state_above(
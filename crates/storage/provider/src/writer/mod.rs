@@ -1,18 +1,25 @@
 use crate::{
-    providers::{StaticFileProvider, StaticFileWriter as SfWriter},
+    providers::{touched_addresses, StaticFileProvider, StaticFileWriter as SfWriter},
     BlockExecutionWriter, BlockWriter, HistoryWriter, StateWriter, StaticFileProviderFactory,
     StorageLocation, TrieWriter,
 };
-use alloy_consensus::BlockHeader;
+use alloy_consensus::{BlockHeader, TxReceipt};
+use alloy_primitives::{Address, TxNumber};
 use reth_chain_state::{ExecutedBlock, ExecutedBlockWithTrieUpdates};
-use reth_db_api::transaction::{DbTx, DbTxMut};
+use reth_db_api::{
+    models::{BlockNumberAddress, ContractCreation},
+    transaction::{DbTx, DbTxMut},
+};
 use reth_errors::ProviderResult;
 use reth_primitives::{NodePrimitives, StaticFileSegment};
-use reth_primitives_traits::SignedTransaction;
-use reth_storage_api::{DBProvider, StageCheckpointWriter, TransactionsProviderExt};
+use reth_primitives_traits::{BlockBody as _, SignedTransaction};
+use reth_storage_api::{
+    DBProvider, LogIndexEntries, StageCheckpointWriter, TokenTransferEntries,
+    TransactionsProviderExt,
+};
 use reth_storage_errors::writer::UnifiedStorageWriterError;
 use revm_database::OriginalValuesKnown;
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 use tracing::debug;
 
 /// [`UnifiedStorageWriter`] is responsible for managing the writing to storage with both database
@@ -151,6 +158,23 @@ where
 
         debug!(target: "provider::storage_writer", block_count = %blocks.len(), "Writing blocks and execution data to storage");
 
+        // Receipts are only written to static files, which can't be read back before they are
+        // committed, so the address transactions, log and token transfer indices are built from
+        // the executed blocks. The contract creations were found while executing the blocks.
+        let index_address_transactions = self
+            .database()
+            .prune_modes_ref()
+            .address_transactions
+            .is_none_or(|mode| !mode.is_full());
+        let mut address_transactions = BTreeMap::<Address, Vec<TxNumber>>::new();
+        let index_logs =
+            self.database().prune_modes_ref().log_index.is_none_or(|mode| !mode.is_full());
+        let mut log_index = LogIndexEntries::default();
+        let index_token_transfers =
+            self.database().prune_modes_ref().token_transfers.is_none_or(|mode| !mode.is_full());
+        let mut token_transfers = TokenTransferEntries::default();
+        let mut contract_creations = Vec::new();
+
         // TODO: Do performant / batched writes for each type of object
         // instead of a loop over all blocks,
         // meaning:
@@ -161,13 +185,81 @@ where
         //  * indices (already done basically)
         // Insert the blocks
         for ExecutedBlockWithTrieUpdates {
-            block: ExecutedBlock { recovered_block, execution_output, hashed_state },
+            block:
+                ExecutedBlock {
+                    recovered_block,
+                    execution_output,
+                    hashed_state,
+                    internal_accounts,
+                    contract_creations: creations,
+                },
             trie,
         } in blocks
         {
-            self.database()
+            let touched = index_address_transactions.then(|| {
+                let receipts = execution_output.receipts.first();
+                recovered_block
+                    .transactions_with_sender()
+                    .enumerate()
+                    .map(|(index, (sender, tx))| {
+                        touched_addresses(
+                            tx,
+                            *sender,
+                            receipts.and_then(|r| r.get(index)),
+                            internal_accounts.get(index).map_or(&[], Vec::as_slice),
+                        )
+                        .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            });
+            if index_logs {
+                let logs = execution_output.receipts.first().into_iter().flatten();
+                log_index.extend_block(
+                    recovered_block.number(),
+                    logs.flat_map(|receipt| receipt.logs()),
+                );
+            }
+            if index_token_transfers {
+                // The parent block is either already persisted or inserted in a previous
+                // iteration.
+                let first_tx_num = self
+                    .database()
+                    .block_body_indices(recovered_block.number() - 1)?
+                    .map_or(0, |indices| indices.next_tx_num());
+                let receipts = execution_output.receipts.first().into_iter().flatten();
+                token_transfers.extend_block(
+                    recovered_block.number(),
+                    first_tx_num,
+                    recovered_block.body().transactions().iter().zip(receipts),
+                );
+            }
+
+            let block_number = recovered_block.number();
+            let body_indices = self
+                .database()
                 .insert_block(Arc::unwrap_or_clone(recovered_block), StorageLocation::Both)?;
 
+            contract_creations.extend(creations.iter().map(|creation| {
+                (
+                    BlockNumberAddress((block_number, creation.address)),
+                    ContractCreation {
+                        tx_number: body_indices.first_tx_num() + creation.transaction_index as u64,
+                        creator: creation.creator,
+                        factory: creation.factory,
+                    },
+                )
+            }));
+
+            let touched = touched.unwrap_or_default();
+            for (tx_number, addresses) in body_indices.tx_num_range().zip(touched) {
+                for address in addresses {
+                    let tx_numbers = address_transactions.entry(address).or_default();
+                    if tx_numbers.last() != Some(&tx_number) {
+                        tx_numbers.push(tx_number);
+                    }
+                }
+            }
+
             // Write state and changesets to the database.
             // Must be written after blocks because of the receipt lookup.
             self.database().write_state(
@@ -175,6 +267,12 @@ where
                 OriginalValuesKnown::No,
                 StorageLocation::StaticFiles,
             )?;
+            // The internal accounts are only read to index the address transactions
+            if index_address_transactions {
+                self.database().write_internal_accounts(
+                    body_indices.tx_num_range().zip(Arc::unwrap_or_clone(internal_accounts)),
+                )?;
+            }
 
             // insert hashes and intermediate merkle nodes
             self.database()
@@ -184,6 +282,10 @@ where
 
         // update history indices
         self.database().update_history_indices(first_number..=last_block_number)?;
+        self.database().insert_address_transactions_index(address_transactions)?;
+        self.database().insert_log_index(log_index)?;
+        self.database().insert_token_transfers(token_transfers)?;
+        self.database().insert_contract_creations(contract_creations)?;
 
         // Update pipeline progress
         self.database().update_pipeline_stages(last_block_number, false)?;
@@ -0,0 +1,39 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use alloy_primitives::{Address, BlockNumber, TxNumber};
+use auto_impl::auto_impl;
+use core::ops::RangeInclusive;
+use reth_storage_errors::provider::ProviderResult;
+
+/// Reader for the transactions that touched an address.
+///
+/// An address is touched by a transaction if it is the sender, the recipient, the contract created
+/// by the transaction or the emitter of one of its logs.
+#[auto_impl(&, Arc, Box)]
+pub trait AddressTransactionsReader: Send + Sync {
+    /// Iterate over the transactions of the given block range and return all addresses they
+    /// touched alongside the numbers of the transactions that touched them, in ascending order.
+    ///
+    /// NOTE: Get inclusive range of blocks.
+    fn touched_addresses_with_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<BTreeMap<Address, Vec<TxNumber>>>;
+
+    /// Returns at most `limit` numbers of transactions that touched the address and are strictly
+    /// lower than `tx_number`, in descending order.
+    fn address_transactions_before(
+        &self,
+        address: Address,
+        tx_number: TxNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>>;
+
+    /// Returns at most `limit` numbers of transactions that touched the address and are higher than
+    /// or equal to `tx_number`, in ascending order.
+    fn address_transactions_after(
+        &self,
+        address: Address,
+        tx_number: TxNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>>;
+}
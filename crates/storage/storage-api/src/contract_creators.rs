@@ -0,0 +1,30 @@
+use alloc::vec::Vec;
+use alloy_primitives::{Address, BlockNumber};
+use auto_impl::auto_impl;
+use core::ops::RangeInclusive;
+use reth_db_api::models::{BlockNumberAddress, ContractCreation};
+use reth_storage_errors::provider::ProviderResult;
+
+/// Reader for the index of contract creations.
+///
+/// The index covers contracts created by transactions as well as the ones deployed with `CREATE`
+/// or `CREATE2` by other contracts.
+#[auto_impl(&, Arc, Box)]
+pub trait ContractCreatorsReader: Send + Sync {
+    /// Returns the range of blocks covered by the contract creations index, or `None` if the index
+    /// is not available.
+    fn contract_creations_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>>;
+
+    /// Returns the block in which the contract was created, with its creation.
+    fn contract_creation(
+        &self,
+        address: Address,
+    ) -> ProviderResult<Option<(BlockNumber, ContractCreation)>>;
+
+    /// Returns the contracts created in the given block range, ordered by block number and
+    /// contract address.
+    fn contract_creations_by_block_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumberAddress, ContractCreation)>>;
+}
@@ -1,7 +1,8 @@
-use alloy_primitives::{Address, BlockNumber, B256};
+use crate::{LogIndexEntries, TokenTransferEntries};
+use alloy_primitives::{Address, BlockNumber, TxNumber, B256};
 use auto_impl::auto_impl;
 use core::ops::{RangeBounds, RangeInclusive};
-use reth_db_api::models::BlockNumberAddress;
+use reth_db_api::models::{BlockNumberAddress, ContractCreation};
 use reth_db_models::AccountBeforeTx;
 use reth_primitives_traits::StorageEntry;
 use reth_storage_errors::provider::ProviderResult;
@@ -53,6 +54,56 @@ pub trait HistoryWriter: Send + Sync {
         storage_transitions: impl IntoIterator<Item = ((Address, B256), impl IntoIterator<Item = u64>)>,
     ) -> ProviderResult<()>;
 
+    /// Unwind and clear address transactions indices in a given block range.
+    ///
+    /// Returns number of addresses unwound.
+    fn unwind_address_transactions_indices_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<usize>;
+
+    /// Insert address transactions index to database. Used inside IndexAddressTransactions stage
+    fn insert_address_transactions_index(
+        &self,
+        index_updates: impl IntoIterator<Item = (Address, impl IntoIterator<Item = TxNumber>)>,
+    ) -> ProviderResult<()>;
+
+    /// Unwind and clear log indices in a given block range.
+    ///
+    /// Returns number of addresses and topics unwound.
+    fn unwind_log_indices_range(&self, range: RangeInclusive<BlockNumber>)
+        -> ProviderResult<usize>;
+
+    /// Insert log index to database. Used inside IndexLogs stage
+    fn insert_log_index(&self, entries: LogIndexEntries) -> ProviderResult<()>;
+
+    /// Unwind and clear contract creations in a given block range.
+    ///
+    /// Returns number of contract creations unwound.
+    fn unwind_contract_creations_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<usize>;
+
+    /// Insert contract creations to database. Used inside IndexContractCreators stage
+    fn insert_contract_creations(
+        &self,
+        creations: impl IntoIterator<Item = (BlockNumberAddress, ContractCreation)>,
+    ) -> ProviderResult<()>;
+
+    /// Unwind and clear token transfers, balance deltas and native token transfers in a given
+    /// block range.
+    ///
+    /// Returns number of token and native token transfers unwound.
+    fn unwind_token_transfers_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<usize>;
+
+    /// Insert token transfers, balance deltas and native token transfers to database. Used inside
+    /// IndexTokenTransfers stage
+    fn insert_token_transfers(&self, entries: TokenTransferEntries) -> ProviderResult<()>;
+
     /// Read account/storage changesets and update account/storage history indices.
     fn update_history_indices(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<()>;
 }
@@ -0,0 +1,11 @@
+use alloy_primitives::BlockNumber;
+use auto_impl::auto_impl;
+use reth_db_api::models::HlSidecar;
+use reth_storage_errors::provider::ProviderResult;
+
+/// Reader for the Hyperliquid sidecars of the ingested blocks.
+#[auto_impl(&, Arc, Box)]
+pub trait HlSidecarReader: Send + Sync {
+    /// Returns the sidecar of the block, or [`None`] if it wasn't recorded.
+    fn hl_sidecar(&self, block: BlockNumber) -> ProviderResult<Option<HlSidecar>>;
+}
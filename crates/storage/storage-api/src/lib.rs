@@ -16,6 +16,9 @@ pub use reth_storage_errors as errors;
 mod account;
 pub use account::*;
 
+mod address_transactions;
+pub use address_transactions::*;
+
 mod block;
 pub use block::*;
 
@@ -28,9 +31,18 @@ pub use block_hash::*;
 mod chain;
 pub use chain::*;
 
+mod contract_creators;
+pub use contract_creators::*;
+
 mod header;
 pub use header::*;
 
+mod hl_sidecar;
+pub use hl_sidecar::*;
+
+mod log_index;
+pub use log_index::*;
+
 mod prune_checkpoint;
 pub use prune_checkpoint::*;
 
@@ -49,6 +61,9 @@ pub use storage::*;
 mod transactions;
 pub use transactions::*;
 
+mod token_transfers;
+pub use token_transfers::*;
+
 mod trie;
 pub use trie::*;
 
@@ -0,0 +1,73 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use alloy_primitives::{Address, BlockNumber, Log};
+use auto_impl::auto_impl;
+use core::ops::RangeInclusive;
+use reth_db_api::models::{LogPosition, LogTopicKey};
+use reth_storage_errors::provider::ProviderResult;
+
+/// Positions of logs, grouped by the log addresses and topics.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogIndexEntries {
+    /// Positions of the logs emitted by each address, in ascending order.
+    pub addresses: BTreeMap<Address, Vec<LogPosition>>,
+    /// Positions of the logs with each topic at each position of the topics, in ascending order.
+    pub topics: BTreeMap<LogTopicKey, Vec<LogPosition>>,
+}
+
+impl LogIndexEntries {
+    /// Adds all logs of a block, in the order they were emitted.
+    ///
+    /// Blocks must be added in ascending order.
+    pub fn extend_block<'a>(
+        &mut self,
+        block: BlockNumber,
+        logs: impl IntoIterator<Item = &'a Log>,
+    ) {
+        for (index, log) in logs.into_iter().enumerate() {
+            let position = LogPosition::new(block, index as u64);
+            self.addresses.entry(log.address).or_default().push(position);
+            for (topic_position, topic) in log.topics().iter().enumerate() {
+                let key = LogTopicKey::new(topic_position as u8, *topic);
+                self.topics.entry(key).or_default().push(position);
+            }
+        }
+    }
+
+    /// Returns `true` if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty() && self.topics.is_empty()
+    }
+}
+
+/// Reader for the index of the logs of an address or a topic.
+#[auto_impl(&, Arc, Box)]
+pub trait LogIndexReader: Send + Sync {
+    /// Returns the range of blocks covered by the log index, or `None` if the index is not
+    /// available.
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>>;
+
+    /// Iterate over the receipts of the given block range and return the positions of the logs of
+    /// each address and topic.
+    ///
+    /// NOTE: Get inclusive range of blocks.
+    fn log_index_entries_with_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<LogIndexEntries>;
+
+    /// Returns the positions of the logs emitted by the address in the given block range, in
+    /// ascending order.
+    fn log_address_positions(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<LogPosition>>;
+
+    /// Returns the positions of the logs with the topic at the position of the key in the given
+    /// block range, in ascending order.
+    fn log_topic_positions(
+        &self,
+        key: LogTopicKey,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<LogPosition>>;
+}
@@ -1,14 +1,16 @@
 //! Various noop implementations for traits.
 
 use crate::{
-    AccountReader, BlockBodyIndicesProvider, BlockHashReader, BlockIdReader, BlockNumReader,
-    BlockReader, BlockReaderIdExt, BlockSource, ChangeSetReader, HashedPostStateProvider,
-    HeaderProvider, NodePrimitivesProvider, OmmersProvider, PruneCheckpointReader, ReceiptProvider,
-    ReceiptProviderIdExt, StageCheckpointReader, StateProofProvider, StateProvider,
-    StateProviderBox, StateProviderFactory, StateRootProvider, StorageRootProvider,
-    TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    AccountReader, AddressTransactionsReader, BlockBodyIndicesProvider, BlockHashReader,
+    BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt, BlockSource, ChangeSetReader,
+    ContractCreatorsReader, HashedPostStateProvider, HeaderProvider, HlSidecarReader,
+    LogIndexEntries, LogIndexReader, NodePrimitivesProvider, OmmersProvider, PruneCheckpointReader,
+    ReceiptProvider, ReceiptProviderIdExt, StageCheckpointReader, StateProofProvider,
+    StateProvider, StateProviderBox, StateProviderFactory, StateRootProvider, StorageRootProvider,
+    TokenTransferEntries, TokenTransfersReader, TransactionVariant, TransactionsProvider,
+    WithdrawalsProvider,
 };
-use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeMap, string::String, sync::Arc, vec::Vec};
 use alloy_consensus::transaction::TransactionMeta;
 use alloy_eips::{eip4895::Withdrawals, BlockHashOrNumber, BlockId, BlockNumberOrTag};
 use alloy_primitives::{
@@ -20,6 +22,10 @@ use core::{
     ops::{RangeBounds, RangeInclusive},
 };
 use reth_chainspec::{ChainInfo, ChainSpecProvider, EthChainSpec, MAINNET};
+use reth_db_api::models::{
+    BlockNumberAddress, ContractCreation, HlSidecar, LogPosition, LogTopicKey, NativeTransfer,
+    TokenBalanceDelta, TokenTransfer, TokenTransferKey,
+};
 use reth_db_models::{AccountBeforeTx, StoredBlockBodyIndices};
 use reth_ethereum_primitives::EthPrimitives;
 use reth_primitives_traits::{
@@ -358,6 +364,141 @@ impl<C: Send + Sync, N: NodePrimitives> AccountReader for NoopProvider<C, N> {
     }
 }
 
+impl<C: Send + Sync, N: NodePrimitives> AddressTransactionsReader for NoopProvider<C, N> {
+    fn touched_addresses_with_range(
+        &self,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<BTreeMap<Address, Vec<TxNumber>>> {
+        Ok(BTreeMap::default())
+    }
+
+    fn address_transactions_before(
+        &self,
+        _address: Address,
+        _tx_number: TxNumber,
+        _limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        Ok(Vec::default())
+    }
+
+    fn address_transactions_after(
+        &self,
+        _address: Address,
+        _tx_number: TxNumber,
+        _limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        Ok(Vec::default())
+    }
+}
+
+impl<C: Send + Sync, N: NodePrimitives> HlSidecarReader for NoopProvider<C, N> {
+    fn hl_sidecar(&self, _block: BlockNumber) -> ProviderResult<Option<HlSidecar>> {
+        Ok(None)
+    }
+}
+
+impl<C: Send + Sync, N: NodePrimitives> ContractCreatorsReader for NoopProvider<C, N> {
+    fn contract_creations_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(None)
+    }
+
+    fn contract_creation(
+        &self,
+        _address: Address,
+    ) -> ProviderResult<Option<(BlockNumber, ContractCreation)>> {
+        Ok(None)
+    }
+
+    fn contract_creations_by_block_range(
+        &self,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumberAddress, ContractCreation)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl<C: Send + Sync, N: NodePrimitives> LogIndexReader for NoopProvider<C, N> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(None)
+    }
+
+    fn log_index_entries_with_range(
+        &self,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<LogIndexEntries> {
+        Ok(LogIndexEntries::default())
+    }
+
+    fn log_address_positions(
+        &self,
+        _address: Address,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<LogPosition>> {
+        Ok(Vec::default())
+    }
+
+    fn log_topic_positions(
+        &self,
+        _key: LogTopicKey,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<LogPosition>> {
+        Ok(Vec::default())
+    }
+}
+
+impl<C: Send + Sync, N: NodePrimitives> TokenTransfersReader for NoopProvider<C, N> {
+    fn token_transfers_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(None)
+    }
+
+    fn token_transfer_entries_with_range(
+        &self,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<TokenTransferEntries> {
+        Ok(TokenTransferEntries::default())
+    }
+
+    fn token_transfers(
+        &self,
+        _token: Address,
+        _start: (BlockNumber, u32),
+        _end_block: BlockNumber,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, TokenTransfer)>> {
+        Ok(Vec::default())
+    }
+
+    fn holder_token_transfers(
+        &self,
+        _holder: Address,
+        _token: Option<Address>,
+        _start: (BlockNumber, u32),
+        _end_block: BlockNumber,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, TokenTransfer)>> {
+        Ok(Vec::default())
+    }
+
+    fn token_balance_deltas(
+        &self,
+        _holder: Address,
+        _token: Address,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumber, TokenBalanceDelta)>> {
+        Ok(Vec::default())
+    }
+
+    fn native_transfers(
+        &self,
+        _user: Address,
+        _start: (BlockNumber, u32),
+        _end_block: BlockNumber,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, NativeTransfer)>> {
+        Ok(Vec::default())
+    }
+}
+
 impl<C: Send + Sync, N: NodePrimitives> ChangeSetReader for NoopProvider<C, N> {
     fn account_block_changeset(
         &self,
@@ -0,0 +1,369 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use alloy_consensus::{Transaction, TxReceipt};
+use alloy_primitives::{b256, Address, BlockNumber, Log, TxNumber, B256, U256};
+use auto_impl::auto_impl;
+use core::ops::RangeInclusive;
+use reth_db_api::models::{
+    NativeTransfer, NativeTransferKind, TokenBalanceDelta, TokenBalanceKey, TokenTransfer,
+    TokenTransferKey,
+};
+use reth_primitives_traits::{
+    transaction::signed::{is_impersonated_tx, NATIVE_TOKEN_SYSTEM_ADDRESS},
+    SignedTransaction,
+};
+use reth_storage_errors::provider::ProviderResult;
+
+/// Topic of the ERC20 `Transfer(address,address,uint256)` event.
+pub const TRANSFER_EVENT_TOPIC: B256 =
+    b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+/// Selector of the ERC20 `transfer(address,uint256)` function.
+const TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+
+/// A decoded ERC20 `Transfer` event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Erc20Transfer {
+    /// The token contract that emitted the event.
+    pub token: Address,
+    /// The sender of the tokens.
+    pub from: Address,
+    /// The recipient of the tokens.
+    pub to: Address,
+    /// The amount of tokens transferred.
+    pub value: U256,
+}
+
+impl Erc20Transfer {
+    /// Decodes an ERC20 `Transfer` event from the log.
+    ///
+    /// ERC721 `Transfer` events share the topic but index the token ID as a fourth topic, so
+    /// they are not decoded.
+    pub fn decode(log: &Log) -> Option<Self> {
+        let [topic, from, to] = log.topics() else { return None };
+        if *topic != TRANSFER_EVENT_TOPIC || log.data.data.len() != 32 {
+            return None
+        }
+        Some(Self {
+            token: log.address,
+            from: Address::from_word(*from),
+            to: Address::from_word(*to),
+            value: U256::from_be_slice(&log.data.data),
+        })
+    }
+
+    /// Decodes a `transfer(address,uint256)` call of the token by the sender.
+    fn decode_call(token: Address, from: Address, input: &[u8]) -> Option<Self> {
+        let (selector, args) = input.split_first_chunk::<4>()?;
+        if *selector != TRANSFER_SELECTOR || args.len() != 64 {
+            return None
+        }
+        Some(Self {
+            token,
+            from,
+            to: Address::from_word(B256::from_slice(&args[..32])),
+            value: U256::from_be_slice(&args[32..]),
+        })
+    }
+}
+
+/// Returns the index of the HyperCore spot token whose system address is `address`.
+///
+/// Spot tokens are bridged by their system address, `0x20` followed by the big endian token
+/// index.
+pub fn spot_token_index(address: Address) -> Option<u64> {
+    let (prefix, index) = address.split_at(12);
+    (prefix[0] == 0x20 && prefix[1..].iter().all(|byte| *byte == 0))
+        .then(|| u64::from_be_bytes(index.try_into().expect("index is 8 bytes")))
+}
+
+/// A transfer of the native token between HyperCore and HyperEVM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NativeBridgeTransfer {
+    /// The user the tokens were deposited to or withdrawn by.
+    pub user: Address,
+    /// The direction of the transfer.
+    pub kind: NativeTransferKind,
+    /// The amount of tokens transferred.
+    pub value: U256,
+}
+
+impl NativeBridgeTransfer {
+    /// Decodes the native transfer of a successful transaction.
+    ///
+    /// Deposits are system transactions of [`NATIVE_TOKEN_SYSTEM_ADDRESS`] sending value to the
+    /// user. Withdrawals are transactions sending value to [`NATIVE_TOKEN_SYSTEM_ADDRESS`].
+    /// Value sent to it by internal calls is not decoded, since it's not visible without tracing
+    /// the transaction.
+    pub fn decode<T: SignedTransaction, R: TxReceipt>(tx: &T, receipt: &R) -> Option<Self> {
+        let value = tx.value();
+        if value.is_zero() || !receipt.status() {
+            return None
+        }
+        if is_impersonated_tx(tx.signature(), tx.gas_price()) == Some(NATIVE_TOKEN_SYSTEM_ADDRESS) {
+            return Some(Self { user: tx.to()?, kind: NativeTransferKind::Deposit, value })
+        }
+        if tx.to() != Some(NATIVE_TOKEN_SYSTEM_ADDRESS) {
+            return None
+        }
+        let user = tx.recover_signer().ok()?;
+        Some(Self { user, kind: NativeTransferKind::Withdrawal, value })
+    }
+}
+
+/// Token transfers of a range of blocks, with the balance deltas of their holders.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenTransferEntries {
+    /// Transfers keyed by their token contract, in the order they happened.
+    pub transfers: Vec<(TokenTransferKey, TokenTransfer)>,
+    /// Tokens received and sent by each holder in each block.
+    pub balance_deltas: BTreeMap<TokenBalanceKey, TokenBalanceDelta>,
+    /// Native token transfers between HyperCore and HyperEVM keyed by their user, in the order
+    /// they happened.
+    pub native_transfers: Vec<(TokenTransferKey, NativeTransfer)>,
+}
+
+impl TokenTransferEntries {
+    /// Adds the token transfers of a block's transactions and receipts.
+    ///
+    /// Transfers are decoded from the ERC20 `Transfer` events. System transactions bridging spot
+    /// tokens from HyperCore are sent by the system address of the token, and are decoded from
+    /// their `transfer` call if the token didn't emit the event. Native token transfers are
+    /// decoded by [`NativeBridgeTransfer::decode`].
+    pub fn extend_block<'a, T, R>(
+        &mut self,
+        block: BlockNumber,
+        first_tx_num: TxNumber,
+        transactions: impl IntoIterator<Item = (&'a T, &'a R)>,
+    ) where
+        T: SignedTransaction + 'a,
+        R: TxReceipt<Log = Log> + 'a,
+    {
+        let mut index = 0u32;
+        let mut native_index = 0u32;
+        for (tx_number, (tx, receipt)) in (first_tx_num..).zip(transactions) {
+            if let Some(NativeBridgeTransfer { user, kind, value }) =
+                NativeBridgeTransfer::decode(tx, receipt)
+            {
+                self.native_transfers.push((
+                    TokenTransferKey::new(user, block, native_index),
+                    NativeTransfer { tx_number, value, kind },
+                ));
+                native_index += 1;
+            }
+
+            let mut transfers: Vec<_> =
+                receipt.logs().iter().filter_map(Erc20Transfer::decode).collect();
+            if let Some(bridge) = spot_bridge_transfer(tx, receipt, &transfers) {
+                transfers = vec![bridge];
+            }
+
+            for Erc20Transfer { token, from, to, value } in transfers {
+                let spot_token = spot_token_index(from).or_else(|| spot_token_index(to));
+                let transfer = TokenTransfer { tx_number, token, from, to, value, spot_token };
+                self.transfers.push((TokenTransferKey::new(token, block, index), transfer));
+                index += 1;
+
+                if from != Address::ZERO {
+                    let delta = self
+                        .balance_deltas
+                        .entry(TokenBalanceKey::new(from, token, block))
+                        .or_default();
+                    delta.sent = delta.sent.saturating_add(value);
+                }
+                if to != Address::ZERO {
+                    let delta = self
+                        .balance_deltas
+                        .entry(TokenBalanceKey::new(to, token, block))
+                        .or_default();
+                    delta.received = delta.received.saturating_add(value);
+                }
+            }
+        }
+    }
+
+    /// Returns the transfers keyed by their sender and recipient, skipping the zero address.
+    pub fn holder_transfers(&self) -> impl Iterator<Item = (TokenTransferKey, TokenTransfer)> + '_ {
+        self.transfers.iter().flat_map(|(key, transfer)| {
+            let recipient = (transfer.to != transfer.from).then_some(transfer.to);
+            [Some(transfer.from), recipient]
+                .into_iter()
+                .flatten()
+                .filter(|holder| *holder != Address::ZERO)
+                .map(|holder| {
+                    (TokenTransferKey::new(holder, key.block_number, key.index), *transfer)
+                })
+        })
+    }
+
+    /// Returns `true` if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.transfers.is_empty() && self.native_transfers.is_empty()
+    }
+}
+
+/// Returns the transfer of a spot token bridged from HyperCore by a system transaction, if the
+/// token didn't emit a `Transfer` event for it.
+fn spot_bridge_transfer<T: SignedTransaction, R: TxReceipt>(
+    tx: &T,
+    receipt: &R,
+    transfers: &[Erc20Transfer],
+) -> Option<Erc20Transfer> {
+    let system_address = is_impersonated_tx(tx.signature(), tx.gas_price())?;
+    let token = tx.to()?;
+    if system_address == NATIVE_TOKEN_SYSTEM_ADDRESS ||
+        spot_token_index(system_address).is_none() ||
+        !receipt.status() ||
+        transfers.iter().any(|transfer| transfer.token == token)
+    {
+        return None
+    }
+    Erc20Transfer::decode_call(token, system_address, tx.input())
+}
+
+/// Reader for the index of ERC20 token transfers by token and by holder.
+#[auto_impl(&, Arc, Box)]
+pub trait TokenTransfersReader: Send + Sync {
+    /// Returns the range of blocks covered by the token transfers index, or `None` if the index
+    /// is not available.
+    fn token_transfers_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>>;
+
+    /// Iterate over the transactions and receipts of the given block range and return their token
+    /// transfers.
+    ///
+    /// NOTE: Get inclusive range of blocks.
+    fn token_transfer_entries_with_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<TokenTransferEntries>;
+
+    /// Returns at most `limit` transfers of the token, starting at the `(block, index)` position
+    /// and up to the end of `end_block`, in the order they happened.
+    fn token_transfers(
+        &self,
+        token: Address,
+        start: (BlockNumber, u32),
+        end_block: BlockNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, TokenTransfer)>>;
+
+    /// Returns at most `limit` transfers sent or received by the holder, optionally only of the
+    /// given token, starting at the `(block, index)` position and up to the end of `end_block`, in
+    /// the order they happened.
+    fn holder_token_transfers(
+        &self,
+        holder: Address,
+        token: Option<Address>,
+        start: (BlockNumber, u32),
+        end_block: BlockNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, TokenTransfer)>>;
+
+    /// Returns the tokens received and sent by the holder in each block of the range in which its
+    /// balance of the token changed, in ascending order.
+    fn token_balance_deltas(
+        &self,
+        holder: Address,
+        token: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumber, TokenBalanceDelta)>>;
+
+    /// Returns at most `limit` native token transfers between HyperCore and HyperEVM of the user,
+    /// starting at the `(block, index)` position and up to the end of `end_block`, in the order
+    /// they happened.
+    fn native_transfers(
+        &self,
+        user: Address,
+        start: (BlockNumber, u32),
+        end_block: BlockNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<(TokenTransferKey, NativeTransfer)>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, LogData};
+
+    #[test]
+    fn decodes_erc20_transfers_only() {
+        let token = address!("0x2222222222222222222222222222222222222222");
+        let from = address!("0x000000000000000000000000000000000000dead");
+        let to = address!("0x000000000000000000000000000000000000beef");
+        let transfer = Log {
+            address: token,
+            data: LogData::new_unchecked(
+                vec![TRANSFER_EVENT_TOPIC, from.into_word(), to.into_word()],
+                U256::from(1000).to_be_bytes_vec().into(),
+            ),
+        };
+        assert_eq!(
+            Erc20Transfer::decode(&transfer),
+            Some(Erc20Transfer { token, from, to, value: U256::from(1000) })
+        );
+
+        // ERC721 transfers index the token ID
+        let mut nft_transfer = transfer.clone();
+        nft_transfer.data = LogData::new_unchecked(
+            vec![TRANSFER_EVENT_TOPIC, from.into_word(), to.into_word(), B256::with_last_byte(7)],
+            Default::default(),
+        );
+        assert_eq!(Erc20Transfer::decode(&nft_transfer), None);
+    }
+
+    #[test]
+    fn decodes_native_transfers() {
+        use alloy_consensus::{SignableTransaction, TxLegacy};
+        use alloy_primitives::{PrimitiveSignature as Signature, TxKind};
+        use reth_ethereum_primitives::{Receipt, Transaction, TransactionSigned};
+        use reth_primitives_traits::crypto::secp256k1::sign_message;
+
+        let user = address!("0x000000000000000000000000000000000000beef");
+        let success = Receipt { success: true, ..Default::default() };
+        let value = U256::from(1000);
+
+        let deposit = TransactionSigned::new_unhashed(
+            Transaction::Legacy(TxLegacy { to: TxKind::Call(user), value, ..Default::default() }),
+            Signature::new(U256::from(1), U256::from(1), true),
+        );
+        assert_eq!(
+            NativeBridgeTransfer::decode(&deposit, &success),
+            Some(NativeBridgeTransfer { user, kind: NativeTransferKind::Deposit, value })
+        );
+
+        let withdrawal = TxLegacy {
+            to: TxKind::Call(NATIVE_TOKEN_SYSTEM_ADDRESS),
+            value,
+            gas_price: 1,
+            ..Default::default()
+        };
+        let signature = sign_message(B256::repeat_byte(1), withdrawal.signature_hash()).unwrap();
+        let withdrawal =
+            TransactionSigned::new_unhashed(Transaction::Legacy(withdrawal), signature);
+        let sender = withdrawal.recover_signer().unwrap();
+        assert_eq!(
+            NativeBridgeTransfer::decode(&withdrawal, &success),
+            Some(NativeBridgeTransfer {
+                user: sender,
+                kind: NativeTransferKind::Withdrawal,
+                value
+            })
+        );
+
+        // Reverted withdrawals don't transfer the tokens
+        assert_eq!(NativeBridgeTransfer::decode(&withdrawal, &Receipt::default()), None);
+    }
+
+    #[test]
+    fn spot_token_system_addresses() {
+        assert_eq!(
+            spot_token_index(address!("0x2000000000000000000000000000000000000000")),
+            Some(0)
+        );
+        assert_eq!(
+            spot_token_index(address!("0x20000000000000000000000000000000000000c5")),
+            Some(197)
+        );
+        assert_eq!(spot_token_index(NATIVE_TOKEN_SYSTEM_ADDRESS), None);
+        assert_eq!(spot_token_index(address!("0x2000000000000000000000010000000000000001")), None);
+    }
+}